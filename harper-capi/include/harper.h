@@ -0,0 +1,71 @@
+/* C bindings for Harper, the grammar checker for developers.
+ * See `harper-capi/src/lib.rs` for the documentation of each function.
+ * No function unwinds into the caller: if Harper panics, the function returns null, false or
+ * zero instead. */
+
+#ifndef HARPER_H
+#define HARPER_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The markup language a document is written in.
+ * `harper_document_new` takes one of these values as a `uint32_t`, and returns null for any
+ * other value. */
+typedef enum HarperMarkup {
+  HARPER_MARKUP_PLAIN_TEXT = 0,
+  HARPER_MARKUP_MARKDOWN = 1,
+} HarperMarkup;
+
+/* A region of a document, measured in UTF-8 bytes from the start of its text. */
+typedef struct HarperSpan {
+  size_t start;
+  size_t end;
+} HarperSpan;
+
+/* A problem found in a document.
+ * The strings are owned by the `HarperLints` the lint came from. */
+typedef struct HarperLint {
+  HarperSpan span;
+  const char *message;
+  const char *kind;
+  /* Null if the rule is unknown. */
+  const char *rule;
+  size_t suggestion_count;
+} HarperLint;
+
+typedef struct HarperLinter HarperLinter;
+typedef struct HarperDocument HarperDocument;
+typedef struct HarperLints HarperLints;
+
+HarperLinter *harper_linter_new(void);
+void harper_linter_free(HarperLinter *linter);
+bool harper_linter_set_rule_enabled(HarperLinter *linter, const char *rule, bool enabled);
+bool harper_linter_add_word(HarperLinter *linter, const char *word);
+bool harper_linter_contains_word(const HarperLinter *linter, const char *word);
+
+HarperDocument *harper_document_new(const HarperLinter *linter, const char *text,
+                                    uint32_t markup);
+void harper_document_free(HarperDocument *document);
+
+HarperLints *harper_linter_lint(HarperLinter *linter, const HarperDocument *document);
+void harper_lints_free(HarperLints *lints);
+size_t harper_lints_count(const HarperLints *lints);
+bool harper_lints_get(const HarperLints *lints, size_t index, HarperLint *out);
+const char *harper_lints_suggestion_text(const HarperLints *lints, size_t lint_index,
+                                         size_t suggestion_index);
+char *harper_lints_apply_suggestion(const HarperLints *lints, const HarperDocument *document,
+                                    size_t lint_index, size_t suggestion_index);
+
+void harper_string_free(char *text);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HARPER_H */
@@ -0,0 +1,605 @@
+#![doc = include_str!("../README.md")]
+
+use std::ffi::{CStr, CString, c_char};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+use harper_core::linting::{Lint, LintGroup, Linter as _};
+use harper_core::parsers::{Markdown, Parser, PlainEnglish};
+use harper_core::{
+    CharString, Dictionary, Document, FstDictionary, LayeredDictionary, LineIndex,
+    MutableDictionary, PositionEncoding, WordMetadata, remove_overlaps,
+};
+
+/// The markup language a document is written in.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarperMarkup {
+    PlainText = 0,
+    Markdown = 1,
+}
+
+impl TryFrom<u32> for HarperMarkup {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(HarperMarkup::PlainText),
+            1 => Ok(HarperMarkup::Markdown),
+            _ => Err(value),
+        }
+    }
+}
+
+impl HarperMarkup {
+    fn create_parser(self) -> Box<dyn Parser> {
+        match self {
+            HarperMarkup::PlainText => Box::new(PlainEnglish),
+            HarperMarkup::Markdown => Box::new(Markdown::default()),
+        }
+    }
+}
+
+/// A region of a document, measured in UTF-8 bytes from the start of its text.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HarperSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A problem found in a document, filled in by [`harper_lints_get`].
+///
+/// The strings are owned by the [`HarperLints`] the lint came from, and remain valid until it is
+/// freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HarperLint {
+    pub span: HarperSpan,
+    /// A description of the problem, to be shown to the user.
+    pub message: *const c_char,
+    /// The general category of the problem, like `Spelling` or `Repetition`.
+    pub kind: *const c_char,
+    /// The name of the rule that found the problem, or null if it is unknown.
+    pub rule: *const c_char,
+    /// The number of suggestions that would fix the problem.
+    pub suggestion_count: usize,
+}
+
+/// The rules and dictionary used to check documents.
+pub struct HarperLinter {
+    lint_group: LintGroup,
+    /// Words added through [`harper_linter_add_word`].
+    user_dictionary: MutableDictionary,
+    dictionary: Arc<LayeredDictionary>,
+}
+
+impl HarperLinter {
+    fn new() -> Self {
+        let user_dictionary = MutableDictionary::new();
+        let dictionary = Self::construct_dictionary(&user_dictionary);
+
+        Self {
+            lint_group: LintGroup::new_curated(dictionary.clone()),
+            user_dictionary,
+            dictionary,
+        }
+    }
+
+    fn construct_dictionary(user_dictionary: &MutableDictionary) -> Arc<LayeredDictionary> {
+        Arc::new(
+            LayeredDictionary::new()
+                .with_dictionary(FstDictionary::curated())
+                .with_dictionary(Arc::new(user_dictionary.clone())),
+        )
+    }
+
+    /// Point [`Self::lint_group`] at a dictionary that includes the words in
+    /// [`Self::user_dictionary`], leaving the rest of its state alone.
+    fn synchronize_dictionary(&mut self) {
+        self.dictionary = Self::construct_dictionary(&self.user_dictionary);
+        self.lint_group
+            .set_curated_dictionary(self.dictionary.clone());
+    }
+}
+
+/// A parsed document, ready to be linted.
+pub struct HarperDocument {
+    document: Document,
+    line_index: LineIndex,
+}
+
+/// The results of [`harper_linter_lint`].
+pub struct HarperLints {
+    lints: Vec<Lint>,
+    /// The C representation of each lint in [`Self::lints`], along with the strings it points to.
+    entries: Vec<LintEntry>,
+}
+
+struct LintEntry {
+    span: HarperSpan,
+    message: CString,
+    kind: CString,
+    rule: Option<CString>,
+    suggestions: Vec<CString>,
+}
+
+/// Convert a string to a [`CString`], dropping any interior nul bytes.
+fn to_c_string(text: impl Into<String>) -> CString {
+    let mut text: String = text.into();
+    text.retain(|c| c != '\0');
+    CString::new(text).unwrap_or_default()
+}
+
+/// Run the body of an entry point, returning `fallback` instead if it panics, since unwinding
+/// into C is undefined behaviour.
+fn guard<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+/// Read a nul-terminated UTF-8 string, returning `None` if it is null or invalid.
+///
+/// # Safety
+///
+/// `text` must be null or point to a nul-terminated string.
+unsafe fn read_str<'a>(text: *const c_char) -> Option<&'a str> {
+    if text.is_null() {
+        return None;
+    }
+
+    unsafe { CStr::from_ptr(text) }.to_str().ok()
+}
+
+/// Create a linter with Harper's curated rules and dictionary.
+///
+/// Free it with [`harper_linter_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn harper_linter_new() -> *mut HarperLinter {
+    guard(ptr::null_mut(), || {
+        Box::into_raw(Box::new(HarperLinter::new()))
+    })
+}
+
+/// Free a linter created by [`harper_linter_new`].
+///
+/// # Safety
+///
+/// `linter` must be null or a pointer returned by [`harper_linter_new`] that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_linter_free(linter: *mut HarperLinter) {
+    guard((), || {
+        if !linter.is_null() {
+            drop(unsafe { Box::from_raw(linter) });
+        }
+    })
+}
+
+/// Turn a rule on or off.
+///
+/// Returns false if `rule` is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `linter` must be a valid linter, and `rule` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_linter_set_rule_enabled(
+    linter: *mut HarperLinter,
+    rule: *const c_char,
+    enabled: bool,
+) -> bool {
+    guard(false, || {
+        let (Some(linter), Some(rule)) = (unsafe { linter.as_mut() }, unsafe { read_str(rule) })
+        else {
+            return false;
+        };
+
+        linter.lint_group.config.set_rule_enabled(rule, enabled);
+        true
+    })
+}
+
+/// Add a word to the linter's dictionary, so it is no longer marked as misspelled.
+///
+/// This rebuilds the linter's rules, which takes a moment.
+/// Returns false if `word` is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `linter` must be a valid linter, and `word` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_linter_add_word(
+    linter: *mut HarperLinter,
+    word: *const c_char,
+) -> bool {
+    guard(false, || {
+        let (Some(linter), Some(word)) = (unsafe { linter.as_mut() }, unsafe { read_str(word) })
+        else {
+            return false;
+        };
+
+        linter
+            .user_dictionary
+            .append_word_str(word, WordMetadata::default());
+        linter.synchronize_dictionary();
+        true
+    })
+}
+
+/// Check whether a word is in the linter's dictionary.
+///
+/// # Safety
+///
+/// `linter` must be a valid linter, and `word` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_linter_contains_word(
+    linter: *const HarperLinter,
+    word: *const c_char,
+) -> bool {
+    guard(false, || {
+        let (Some(linter), Some(word)) = (unsafe { linter.as_ref() }, unsafe { read_str(word) })
+        else {
+            return false;
+        };
+
+        let word: CharString = word.chars().collect();
+        linter.dictionary.contains_word(&word)
+    })
+}
+
+/// Parse a document, using the linter's dictionary.
+///
+/// `markup` is one of the values of [`HarperMarkup`]. It is taken as an integer so that an
+/// unknown value can be rejected, rather than being undefined behaviour.
+///
+/// Returns null if any argument is null, `text` is not valid UTF-8 or `markup` is unknown.
+/// Free the document with [`harper_document_free`].
+///
+/// # Safety
+///
+/// `linter` must be a valid linter, and `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_document_new(
+    linter: *const HarperLinter,
+    text: *const c_char,
+    markup: u32,
+) -> *mut HarperDocument {
+    guard(ptr::null_mut(), || {
+        let (Some(linter), Some(text), Ok(markup)) = (
+            unsafe { linter.as_ref() },
+            unsafe { read_str(text) },
+            HarperMarkup::try_from(markup),
+        ) else {
+            return ptr::null_mut();
+        };
+
+        let document = Document::new(text, &markup.create_parser(), &linter.dictionary);
+        let line_index = LineIndex::new(document.get_source());
+
+        Box::into_raw(Box::new(HarperDocument {
+            document,
+            line_index,
+        }))
+    })
+}
+
+/// Free a document created by [`harper_document_new`].
+///
+/// # Safety
+///
+/// `document` must be null or a pointer returned by [`harper_document_new`] that has not been
+/// freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_document_free(document: *mut HarperDocument) {
+    guard((), || {
+        if !document.is_null() {
+            drop(unsafe { Box::from_raw(document) });
+        }
+    })
+}
+
+/// Lint a document.
+///
+/// Returns null if either argument is null.
+/// Free the results with [`harper_lints_free`].
+///
+/// # Safety
+///
+/// `linter` and `document` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_linter_lint(
+    linter: *mut HarperLinter,
+    document: *const HarperDocument,
+) -> *mut HarperLints {
+    guard(ptr::null_mut(), || {
+        let (Some(linter), Some(document)) =
+            (unsafe { linter.as_mut() }, unsafe { document.as_ref() })
+        else {
+            return ptr::null_mut();
+        };
+
+        let mut lints = linter.lint_group.lint(&document.document);
+        remove_overlaps(&mut lints);
+
+        let entries = lints
+            .iter()
+            .map(|lint| LintEntry {
+                span: HarperSpan {
+                    start: document
+                        .line_index
+                        .offset(lint.span.start, PositionEncoding::Utf8),
+                    end: document
+                        .line_index
+                        .offset(lint.span.end, PositionEncoding::Utf8),
+                },
+                message: to_c_string(lint.message.as_str()),
+                kind: to_c_string(lint.lint_kind.to_string_key()),
+                rule: lint.rule.as_deref().map(to_c_string),
+                suggestions: lint
+                    .suggestions
+                    .iter()
+                    .map(|suggestion| to_c_string(suggestion.to_string()))
+                    .collect(),
+            })
+            .collect();
+
+        Box::into_raw(Box::new(HarperLints { lints, entries }))
+    })
+}
+
+/// Free the results of [`harper_linter_lint`], along with the strings they point to.
+///
+/// # Safety
+///
+/// `lints` must be null or a pointer returned by [`harper_linter_lint`] that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_lints_free(lints: *mut HarperLints) {
+    guard((), || {
+        if !lints.is_null() {
+            drop(unsafe { Box::from_raw(lints) });
+        }
+    })
+}
+
+/// The number of lints found.
+///
+/// # Safety
+///
+/// `lints` must be null or valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_lints_count(lints: *const HarperLints) -> usize {
+    guard(0, || {
+        unsafe { lints.as_ref() }.map_or(0, |lints| lints.lints.len())
+    })
+}
+
+/// Fill `out` with the lint at `index`.
+///
+/// Returns false, leaving `out` untouched, if `index` is out of bounds or a pointer is null.
+///
+/// # Safety
+///
+/// `lints` must be valid, and `out` must be null or point to writable memory for a
+/// [`HarperLint`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_lints_get(
+    lints: *const HarperLints,
+    index: usize,
+    out: *mut HarperLint,
+) -> bool {
+    guard(false, || {
+        let Some(entry) = unsafe { lints.as_ref() }.and_then(|lints| lints.entries.get(index))
+        else {
+            return false;
+        };
+
+        if out.is_null() {
+            return false;
+        }
+
+        let lint = HarperLint {
+            span: entry.span,
+            message: entry.message.as_ptr(),
+            kind: entry.kind.as_ptr(),
+            rule: entry
+                .rule
+                .as_ref()
+                .map_or(ptr::null(), |rule| rule.as_ptr()),
+            suggestion_count: entry.suggestions.len(),
+        };
+
+        unsafe { out.write(lint) };
+        true
+    })
+}
+
+/// A description of one of a lint's suggestions, like `Replace with: “the”`, to be shown to the
+/// user.
+///
+/// The string is owned by `lints`. Returns null if either index is out of bounds.
+///
+/// # Safety
+///
+/// `lints` must be null or valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_lints_suggestion_text(
+    lints: *const HarperLints,
+    lint_index: usize,
+    suggestion_index: usize,
+) -> *const c_char {
+    guard(ptr::null(), || {
+        unsafe { lints.as_ref() }
+            .and_then(|lints| lints.entries.get(lint_index))
+            .and_then(|entry| entry.suggestions.get(suggestion_index))
+            .map_or(ptr::null(), |text| text.as_ptr())
+    })
+}
+
+/// Apply one of a lint's suggestions to the text of the document it was found in, returning the
+/// updated text.
+///
+/// Returns null if a pointer is null or either index is out of bounds.
+/// Free the string with [`harper_string_free`].
+///
+/// # Safety
+///
+/// `lints` and `document` must be valid, and `lints` must have been produced from `document`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_lints_apply_suggestion(
+    lints: *const HarperLints,
+    document: *const HarperDocument,
+    lint_index: usize,
+    suggestion_index: usize,
+) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let (Some(lints), Some(document)) =
+            (unsafe { lints.as_ref() }, unsafe { document.as_ref() })
+        else {
+            return ptr::null_mut();
+        };
+
+        let Some(lint) = lints.lints.get(lint_index) else {
+            return ptr::null_mut();
+        };
+
+        let Some(suggestion) = lint.suggestions.get(suggestion_index) else {
+            return ptr::null_mut();
+        };
+
+        let mut source = document.document.get_source().to_vec();
+        suggestion.apply(lint.span, &mut source);
+
+        to_c_string(source.into_iter().collect::<String>()).into_raw()
+    })
+}
+
+/// Free a string returned by Harper.
+///
+/// # Safety
+///
+/// `text` must be null or a string returned by [`harper_lints_apply_suggestion`] that has not
+/// been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn harper_string_free(text: *mut c_char) {
+    guard((), || {
+        if !text.is_null() {
+            drop(unsafe { CString::from_raw(text) });
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use std::mem::MaybeUninit;
+
+    use super::*;
+
+    fn lint_text(linter: *mut HarperLinter, text: &str) -> (*mut HarperDocument, *mut HarperLints) {
+        let text = CString::new(text).unwrap();
+
+        unsafe {
+            let document =
+                harper_document_new(linter, text.as_ptr(), HarperMarkup::PlainText as u32);
+            let lints = harper_linter_lint(linter, document);
+            (document, lints)
+        }
+    }
+
+    #[test]
+    fn lints_and_fixes_repeated_words() {
+        let linter = harper_linter_new();
+        let (document, lints) = lint_text(linter, "I like “the the” fruit.");
+
+        unsafe {
+            assert_eq!(harper_lints_count(lints), 1);
+
+            let mut lint = MaybeUninit::uninit();
+            assert!(harper_lints_get(lints, 0, lint.as_mut_ptr()));
+            let lint = lint.assume_init();
+
+            // The curly quote before the error is three bytes long.
+            assert_eq!(lint.span, HarperSpan { start: 10, end: 17 });
+            assert_eq!(CStr::from_ptr(lint.rule).to_str(), Ok("RepeatedWords"));
+            assert_eq!(lint.suggestion_count, 1);
+
+            let fixed = harper_lints_apply_suggestion(lints, document, 0, 0);
+            assert_eq!(CStr::from_ptr(fixed).to_str(), Ok("I like “the” fruit."));
+
+            harper_string_free(fixed);
+            harper_lints_free(lints);
+            harper_document_free(document);
+            harper_linter_free(linter);
+        }
+    }
+
+    #[test]
+    fn added_words_are_not_misspelled() {
+        let linter = harper_linter_new();
+        let word = CString::new("Harperish").unwrap();
+
+        unsafe {
+            assert!(!harper_linter_contains_word(linter, word.as_ptr()));
+            assert!(harper_linter_add_word(linter, word.as_ptr()));
+            assert!(harper_linter_contains_word(linter, word.as_ptr()));
+
+            let (document, lints) = lint_text(linter, "This is Harperish.");
+            assert_eq!(harper_lints_count(lints), 0);
+
+            harper_lints_free(lints);
+            harper_document_free(document);
+            harper_linter_free(linter);
+        }
+    }
+
+    #[test]
+    fn disabled_rules_are_skipped() {
+        let linter = harper_linter_new();
+        let rule = CString::new("RepeatedWords").unwrap();
+
+        unsafe {
+            assert!(harper_linter_set_rule_enabled(linter, rule.as_ptr(), false));
+
+            let (document, lints) = lint_text(linter, "I like the the fruit.");
+            assert_eq!(harper_lints_count(lints), 0);
+
+            harper_lints_free(lints);
+            harper_document_free(document);
+            harper_linter_free(linter);
+        }
+    }
+
+    #[test]
+    fn rejects_null_and_out_of_bounds() {
+        unsafe {
+            assert!(
+                harper_document_new(ptr::null(), ptr::null(), HarperMarkup::Markdown as u32)
+                    .is_null()
+            );
+            assert_eq!(harper_lints_count(ptr::null()), 0);
+            assert!(harper_lints_suggestion_text(ptr::null(), 0, 0).is_null());
+        }
+
+        let linter = harper_linter_new();
+        let (document, lints) = lint_text(linter, "Everything is fine.");
+
+        unsafe {
+            let mut lint = MaybeUninit::uninit();
+            assert!(!harper_lints_get(lints, 0, lint.as_mut_ptr()));
+            assert!(harper_lints_apply_suggestion(lints, document, 0, 0).is_null());
+
+            let text = CString::new("Everything is fine.").unwrap();
+            assert!(harper_document_new(linter, text.as_ptr(), 2).is_null());
+
+            harper_lints_free(lints);
+            harper_document_free(document);
+            harper_linter_free(linter);
+        }
+    }
+
+    #[test]
+    fn panics_do_not_cross_the_boundary() {
+        assert!(!guard(false, || panic!("Oh no!")));
+        assert_eq!(guard(1, || 2), 2);
+    }
+}
@@ -0,0 +1,89 @@
+//! Record the lints in a set of files, so later runs only report new ones.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use harper_core::linting::Lint;
+use harper_core::{Document, LintBaseline};
+use serde::{Deserialize, Serialize};
+
+/// Where the lint command looks for a baseline when none is given.
+pub const DEFAULT_PATH: &str = "harper-baseline.json";
+
+/// A [`LintBaseline`] for each file in a project.
+///
+/// Files are keyed by their path relative to the baseline, so it can be committed to version
+/// control and used from any checkout.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectBaseline {
+    /// The directory containing the baseline.
+    #[serde(skip)]
+    root: PathBuf,
+    files: BTreeMap<String, LintBaseline>,
+}
+
+impl ProjectBaseline {
+    /// Create an empty baseline that will be saved at `path`.
+    pub fn new(path: &Path) -> anyhow::Result<Self> {
+        Ok(Self {
+            root: root_of(path)?,
+            files: BTreeMap::new(),
+        })
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Unable to read the baseline at {}", path.display()))?;
+
+        let mut baseline: Self = serde_json::from_str(&contents)
+            .with_context(|| format!("Unable to parse the baseline at {}", path.display()))?;
+        baseline.root = root_of(path)?;
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    /// Record the lints found in a file, replacing any that were recorded before.
+    pub fn set_file(&mut self, file: &Path, lints: &[Lint], document: &Document) {
+        self.files
+            .insert(self.key(file), LintBaseline::from_lints(lints, document));
+    }
+
+    /// Remove the lints recorded for a file from a [`Vec`].
+    pub fn remove_baselined(&self, file: &Path, lints: &mut Vec<Lint>, document: &Document) {
+        if let Some(baseline) = self.files.get(&self.key(file)) {
+            baseline.remove_baselined(lints, document);
+        }
+    }
+
+    /// The total number of lints recorded.
+    pub fn len(&self) -> usize {
+        self.files.values().map(LintBaseline::len).sum()
+    }
+
+    fn key(&self, file: &Path) -> String {
+        let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+
+        relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// The directory a baseline at `path` is relative to.
+fn root_of(path: &Path) -> anyhow::Result<PathBuf> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    Ok(dir.canonicalize()?)
+}
@@ -0,0 +1,187 @@
+//! Walk through the lints in a file one at a time, letting the user decide what to do with each.
+
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use harper_core::fixer::apply_suggestions;
+use harper_core::linting::{Lint, LintGroup, LintKind, Linter, Suggestion};
+use harper_core::parsers::MarkdownOptions;
+use harper_core::{remove_overlaps, LineIndex, PositionEncoding, UserDictionary};
+use hashbrown::HashSet;
+
+use crate::load_file;
+
+/// Whether to keep reviewing files after the current one.
+pub enum Flow {
+    Continue,
+    Quit,
+}
+
+/// What the user chose to do with a lint.
+enum Choice {
+    Accept(usize),
+    Skip,
+    AddToDictionary,
+    Quit,
+}
+
+/// Review every lint in a file, applying the suggestions the user accepts once the file is done.
+///
+/// Words added to the dictionary are saved to `user_dictionary` right away, and other lints for
+/// the same word are skipped.
+pub fn review_file(
+    file: &Path,
+    linter: &mut LintGroup,
+    markdown_options: MarkdownOptions,
+    user_dictionary: &mut UserDictionary,
+    input: &mut impl BufRead,
+) -> anyhow::Result<Flow> {
+    let (document, _) = load_file(file, markdown_options)?;
+    let source = document.get_source();
+    let line_index = LineIndex::new(source);
+
+    let mut lints = linter.lint(&document);
+    remove_overlaps(&mut lints);
+    lints.sort_by_key(|lint| lint.span.start);
+
+    let mut accepted: Vec<(&Lint, &Suggestion)> = Vec::new();
+    let mut added_words = HashSet::new();
+    let mut flow = Flow::Continue;
+
+    for lint in &lints {
+        let word = document.get_span_content_str(lint.span);
+        let is_spelling = lint.lint_kind == LintKind::Spelling;
+
+        if is_spelling && added_words.contains(&word) {
+            continue;
+        }
+
+        let position = line_index.position(lint.span.start, PositionEncoding::Char);
+        println!();
+        println!(
+            "{}:{}:{}: {}",
+            file.display(),
+            position.line + 1,
+            position.column + 1,
+            lint.message
+        );
+        print_context(source, lint);
+
+        for (index, suggestion) in lint.suggestions.iter().enumerate() {
+            println!("  [{}] {}", index + 1, preview(source, lint, suggestion));
+        }
+
+        match prompt(lint, is_spelling, input)? {
+            Choice::Accept(index) => accepted.push((lint, &lint.suggestions[index])),
+            Choice::Skip => (),
+            Choice::AddToDictionary => {
+                user_dictionary.add_word(&word);
+                user_dictionary.save()?;
+                added_words.insert(word);
+            }
+            Choice::Quit => {
+                flow = Flow::Quit;
+                break;
+            }
+        }
+    }
+
+    let result = apply_suggestions(source, accepted);
+
+    if result.applied > 0 {
+        std::fs::write(file, result.text.iter().collect::<String>())?;
+        println!("Applied {} fixes to {}", result.applied, file.display());
+    }
+
+    Ok(flow)
+}
+
+/// Ask the user what to do with a lint until they give a valid answer.
+/// The end of the input is treated as a request to quit.
+fn prompt(lint: &Lint, is_spelling: bool, input: &mut impl BufRead) -> anyhow::Result<Choice> {
+    loop {
+        let mut options = Vec::new();
+
+        match lint.suggestions.len() {
+            0 => (),
+            1 => options.push("[1] accept".to_string()),
+            n => options.push(format!("[1-{n}] accept")),
+        }
+
+        options.push("[s]kip".to_string());
+
+        if is_spelling {
+            options.push("[a]dd to dictionary".to_string());
+        }
+
+        options.push("[q]uit".to_string());
+
+        print!("{}: ", options.join(", "));
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(Choice::Quit);
+        }
+
+        match line.trim() {
+            "s" | "" => return Ok(Choice::Skip),
+            "a" if is_spelling => return Ok(Choice::AddToDictionary),
+            "q" => return Ok(Choice::Quit),
+            other => {
+                if let Ok(index) = other.parse::<usize>() {
+                    if (1..=lint.suggestions.len()).contains(&index) {
+                        return Ok(Choice::Accept(index - 1));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Print the line a lint starts on, underlining the offending text.
+fn print_context(source: &[char], lint: &Lint) {
+    let (start, end) = line_bounds(source, lint.span.start);
+    let line: String = source[start..end].iter().collect();
+
+    let underline_start = lint.span.start - start;
+    let underline_len = (lint.span.end.min(end) - lint.span.start).max(1);
+
+    println!("  {line}");
+    println!(
+        "  {}{}",
+        " ".repeat(underline_start),
+        "^".repeat(underline_len)
+    );
+}
+
+/// Show the line a lint starts on as it would read after applying a suggestion.
+fn preview(source: &[char], lint: &Lint, suggestion: &Suggestion) -> String {
+    let (start, end) = line_bounds(source, lint.span.start);
+    let result = apply_suggestions(source, [(lint, suggestion)]);
+
+    let start = result.span_map.map_index(start);
+    let end = result.span_map.map_index(end).min(result.text.len());
+
+    result.text[start..end]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// The start and end of the line containing `index`, excluding the newline.
+fn line_bounds(source: &[char], index: usize) -> (usize, usize) {
+    let index = index.min(source.len());
+
+    let start = source[..index]
+        .iter()
+        .rposition(|c| *c == '\n')
+        .map_or(0, |i| i + 1);
+    let end = source[index..]
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(source.len(), |i| index + i);
+
+    (start, end)
+}
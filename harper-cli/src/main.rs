@@ -1,32 +1,57 @@
 #![doc = include_str!("../README.md")]
 
+mod baseline;
+mod interactive;
+
 use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::Arc;
 
-use anyhow::format_err;
+use anyhow::{format_err, Context};
 use ariadne::{Color, Label, Report, ReportKind, Source};
+use baseline::ProjectBaseline;
 use clap::Parser;
 use harper_comments::CommentParser;
-use harper_core::linting::{LintGroup, Linter};
+use harper_core::fixer::fix_safe;
+use harper_core::linting::{Lint, LintGroup, LintKind, LintProfile, LintStream, Linter, Severity};
 use harper_core::parsers::{Markdown, MarkdownOptions};
 use harper_core::spell::hunspell::parse_default_attribute_list;
 use harper_core::spell::hunspell::word_list::parse_word_list;
+use harper_core::spell::{export_hunspell, export_word_list, DictionaryDiff};
+use harper_core::stats::{count_rules, DocumentStats};
 use harper_core::{
-    remove_overlaps, CharString, Dictionary, Document, FstDictionary, TokenKind, WordMetadata,
+    remove_overlaps, CharString, Dictionary, Document, FstDictionary, HarperConfig, HarperIgnore,
+    LineIndex, MutableDictionary, Position, PositionEncoding, TokenKind, UserDictionary,
+    WordMetadata,
 };
 use harper_literate_haskell::LiterateHaskellParser;
 use hashbrown::HashMap;
+use ignore::WalkBuilder;
+use interactive::{review_file, Flow};
+use rayon::prelude::*;
 use serde::Serialize;
+use similar::TextDiff;
 
 /// A debugging tool for the Harper grammar checker.
 #[derive(Debug, Parser)]
 #[command(version, about)]
 enum Args {
     /// Lint a provided document.
+    ///
+    /// Settings are read from any `harper.toml` files in the document's directory, its
+    /// workspace and the user's configuration directory.
+    /// Files listed in a `.harperignore` file are skipped.
     Lint {
-        /// The file you wish to grammar check.
-        file: PathBuf,
+        /// The files you wish to grammar check.
+        ///
+        /// Directories are searched recursively for files Harper can parse, and glob patterns
+        /// (like `docs/**/*.md`) are expanded.
+        /// Files listed in `.gitignore` are skipped when searching directories.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
         /// Whether to merely print out the number of errors encountered,
         /// without further details.
         #[arg(short, long)]
@@ -35,6 +60,58 @@ enum Args {
         /// If omitted, `harper-cli` will run every rule.
         #[arg(short, long)]
         only_lint_with: Option<Vec<String>>,
+        /// Lint the paragraphs of each file on multiple threads, in addition to linting
+        /// several files at once.
+        /// Recommended for very large files.
+        #[arg(short, long)]
+        parallel: bool,
+        /// Read and lint the file a chunk at a time, printing each lint as a single line as soon
+        /// as it is found.
+        /// Useful for files too large to comfortably hold in memory.
+        #[arg(short, long, conflicts_with = "parallel")]
+        stream: bool,
+        /// How to print the lints that were found.
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
+        /// Apply the fixes that are safe to make without review, editing the file in place.
+        ///
+        /// A fix is safe when its lint has exactly one suggestion, which the rule marked as
+        /// certain.
+        #[arg(long, conflicts_with_all = ["stream", "count"])]
+        fix: bool,
+        /// Print the changes `--fix` would make as a unified diff, instead of writing them.
+        #[arg(long, conflicts_with_all = ["fix", "stream", "count"])]
+        fix_dry_run: bool,
+        /// Only fail because of lints of at least this severity.
+        /// Lints without a configured severity use the default for their kind: `warning` for
+        /// spelling and grammar, `info` for style.
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOn>,
+        /// Fail only if more than this many lints are found.
+        #[arg(long)]
+        max_lints: Option<usize>,
+        /// Walk through the lints one at a time, choosing whether to accept a suggestion, skip
+        /// the lint or add the word to the user dictionary.
+        ///
+        /// Words in the user dictionary (shared with `harper-ls`) are accepted in this mode.
+        #[arg(
+            short,
+            long,
+            conflicts_with_all = ["stream", "count", "fix", "fix_dry_run", "format"]
+        )]
+        interactive: bool,
+        /// Leave out the lints recorded in a baseline created by `harper-cli baseline create`.
+        ///
+        /// Defaults to `harper-baseline.json` in the current directory, if it exists.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Report every lint, even if a baseline exists.
+        #[arg(long, conflicts_with = "baseline")]
+        no_baseline: bool,
+        /// After linting, print how long each rule took and how many lints it produced, slowest
+        /// first.
+        #[arg(long, conflicts_with = "interactive")]
+        profile: bool,
     },
     /// Parse a provided document and print the detected symbols.
     Parse {
@@ -57,6 +134,209 @@ enum Args {
     Words,
     /// Print the default config with descriptions.
     Config,
+    /// Print readability scores, word counts, the share of sentences in the passive voice and
+    /// the most frequent lints for each file, and for all of them together.
+    Stats {
+        /// The files, directories or glob patterns to measure, as accepted by `lint`.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Manage the baseline of lints that the lint command leaves out.
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommand,
+    },
+    /// List every rule, along with the kind of lints it produces and whether it is enabled by
+    /// default.
+    Rules,
+    /// Describe a rule, with examples and the keys used to configure it.
+    Explain {
+        /// The name of the rule, as listed by `harper-cli rules`.
+        rule: String,
+    },
+    /// Export Harper's dictionary as a line-separated list of words.
+    ExportDictionary {
+        /// Write `<HUNSPELL>.dic` and `<HUNSPELL>.aff` in Hunspell's format instead.
+        #[arg(long)]
+        hunspell: Option<PathBuf>,
+    },
+    /// List the words that were added, removed or had their metadata changed between two
+    /// dictionaries.
+    /// Each can be a line-separated list of words (like a user dictionary) or a Hunspell
+    /// `.dic` file with its `.aff` file next to it.
+    DiffDictionaries { before: PathBuf, after: PathBuf },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum BaselineCommand {
+    /// Record the lints currently found in a set of files, so later runs of the lint command
+    /// only report new ones.
+    ///
+    /// Existing entries for other files are kept.
+    Create {
+        /// The files, directories or glob patterns to record, as accepted by `lint`.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        /// Where to save the baseline.
+        #[arg(short, long, default_value = baseline::DEFAULT_PATH)]
+        output: PathBuf,
+    },
+}
+
+/// The ways `harper-cli lint` can print lints.
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+enum OutputFormat {
+    /// A human-readable report.
+    #[default]
+    Text,
+    /// One JSON object per line for each lint, for use by other tools.
+    Json,
+}
+
+/// The severities accepted by `--fail-on`.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum FailOn {
+    Hint,
+    Info,
+    Warning,
+    Error,
+}
+
+impl From<FailOn> for Severity {
+    fn from(value: FailOn) -> Self {
+        match value {
+            FailOn::Hint => Severity::Hint,
+            FailOn::Info => Severity::Info,
+            FailOn::Warning => Severity::Warning,
+            FailOn::Error => Severity::Error,
+        }
+    }
+}
+
+/// When the lint command should exit with an error.
+///
+/// By default, any lint causes a failure.
+struct FailureThreshold {
+    /// The lowest severity that counts towards the threshold.
+    fail_on: Option<Severity>,
+    /// How many lints are tolerated.
+    max_lints: Option<usize>,
+}
+
+impl FailureThreshold {
+    fn is_set(&self) -> bool {
+        self.fail_on.is_some() || self.max_lints.is_some()
+    }
+
+    /// Whether a lint counts towards the threshold.
+    fn counts(&self, lint: &Lint) -> bool {
+        self.fail_on
+            .is_none_or(|fail_on| lint.severity_or_default() >= fail_on)
+    }
+
+    fn is_exceeded(&self, tally: &Tally) -> bool {
+        tally.failing > self.max_lints.unwrap_or(0)
+    }
+}
+
+/// The number of lints found by the lint command.
+#[derive(Default)]
+struct Tally {
+    total: usize,
+    /// The lints that count towards the [`FailureThreshold`].
+    failing: usize,
+}
+
+impl Tally {
+    fn add(&mut self, lints: &[Lint], threshold: &FailureThreshold) {
+        self.total += lints.len();
+        self.failing += lints.iter().filter(|lint| threshold.counts(lint)).count();
+    }
+}
+
+/// How the lint command reports what it found.
+struct OutputOptions {
+    /// Print only the number of lints found.
+    count: bool,
+    format: OutputFormat,
+    threshold: FailureThreshold,
+}
+
+/// A [`Lint`] as printed by `--format json`.
+#[derive(Serialize)]
+struct JsonLint<'a> {
+    file: &'a Path,
+    /// The one-indexed line on which the lint starts.
+    line: usize,
+    /// The one-indexed column, in characters, at which the lint starts.
+    column: usize,
+    /// The one-indexed line on which the lint ends.
+    end_line: usize,
+    /// The one-indexed column, in characters, just past the end of the lint.
+    end_column: usize,
+    /// The character offsets of the lint in the file.
+    start: usize,
+    end: usize,
+    rule: Option<&'a str>,
+    lint_kind: LintKind,
+    severity: Severity,
+    message: &'a str,
+    priority: u8,
+    suggestions: Vec<JsonSuggestion>,
+}
+
+/// A [`Suggestion`](harper_core::linting::Suggestion) as printed by `--format json`.
+#[derive(Serialize)]
+struct JsonSuggestion {
+    /// A short description of the fix.
+    title: String,
+    edits: Vec<JsonEdit>,
+}
+
+/// A single change to the file, replacing the characters from `start` to `end` with `text`.
+#[derive(Serialize)]
+struct JsonEdit {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+/// Print a lint as a single line of JSON.
+fn print_json_lint(file: &Path, lint: &Lint, start: Position, end: Position) -> anyhow::Result<()> {
+    let json = JsonLint {
+        file,
+        line: start.line + 1,
+        column: start.column + 1,
+        end_line: end.line + 1,
+        end_column: end.column + 1,
+        start: lint.span.start,
+        end: lint.span.end,
+        rule: lint.rule.as_deref(),
+        lint_kind: lint.lint_kind,
+        severity: lint.severity_or_default(),
+        message: &lint.message,
+        priority: lint.priority,
+        suggestions: lint
+            .suggestions
+            .iter()
+            .map(|suggestion| JsonSuggestion {
+                title: suggestion.to_string(),
+                edits: suggestion
+                    .to_edits(lint.span)
+                    .into_iter()
+                    .map(|edit| JsonEdit {
+                        start: edit.span.start,
+                        end: edit.span.end,
+                        text: edit.replacement.into_iter().collect(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    };
+
+    println!("{}", serde_json::to_string(&json)?);
+
+    Ok(())
 }
 
 fn main() -> anyhow::Result<()> {
@@ -66,57 +346,146 @@ fn main() -> anyhow::Result<()> {
 
     match args {
         Args::Lint {
-            file,
+            files,
             count,
             only_lint_with,
+            parallel,
+            stream,
+            format,
+            fix,
+            fix_dry_run,
+            fail_on,
+            max_lints,
+            interactive,
+            baseline,
+            no_baseline,
+            profile,
         } => {
-            let (doc, source) = load_file(&file, markdown_options)?;
+            let output = OutputOptions {
+                count,
+                format,
+                threshold: FailureThreshold {
+                    fail_on: fail_on.map(Severity::from),
+                    max_lints,
+                },
+            };
 
-            let mut linter = LintGroup::new_curated(dictionary);
+            let paths = collect_files(&files, markdown_options)?;
+            let many = paths.len() > 1 || files.iter().any(|file| !file.is_file());
 
-            if let Some(rules) = only_lint_with {
-                linter.set_all_rules_to(Some(false));
-
-                for rule in rules {
-                    linter.config.set_rule_enabled(rule, true);
+            let baseline = match baseline {
+                Some(path) => Some(ProjectBaseline::load(&path)?),
+                None if !no_baseline && Path::new(baseline::DEFAULT_PATH).is_file() => {
+                    Some(ProjectBaseline::load(Path::new(baseline::DEFAULT_PATH))?)
                 }
-            }
+                None => None,
+            };
+
+            let options = LintOptions {
+                only_lint_with,
+                parallel,
+                markdown_options,
+                baseline,
+                profile,
+            };
+
+            if interactive {
+                let mut user_dictionary = UserDictionary::open_default()?;
+                let mut input = io::stdin().lock();
 
-            let mut lints = linter.lint(&doc);
+                for path in &paths {
+                    let mut linter = options.build_linter(
+                        path,
+                        Arc::new(user_dictionary.layered_over(dictionary.clone())),
+                    )?;
+
+                    if let Flow::Quit = review_file(
+                        path,
+                        &mut linter,
+                        markdown_options,
+                        &mut user_dictionary,
+                        &mut input,
+                    )? {
+                        break;
+                    }
+                }
 
-            if count {
-                println!("{}", lints.len());
                 return Ok(());
             }
 
-            if lints.is_empty() {
-                println!("No lints found");
-                return Ok(());
+            if stream {
+                let mut tally = Tally::default();
+                let mut profile = LintProfile::new();
+
+                for path in &paths {
+                    let mut linter = options.build_linter(path, dictionary.clone())?;
+                    stream_lints(
+                        path,
+                        &output,
+                        &options,
+                        &mut tally,
+                        &mut linter,
+                        &dictionary,
+                    )?;
+
+                    if let Some(linter_profile) = &linter.profile {
+                        profile.merge_from(linter_profile);
+                    }
+                }
+
+                if options.profile {
+                    print_profile(&profile);
+                }
+
+                return finish(&tally, &output);
             }
 
-            remove_overlaps(&mut lints);
+            let reports: Vec<_> = paths
+                .par_iter()
+                .map(|path| {
+                    lint_file(path, &options, &dictionary)
+                        .with_context(|| format!("Unable to lint {}", path.display()))
+                })
+                .collect::<anyhow::Result<_>>()?;
 
-            let primary_color = Color::Magenta;
+            let mut tally = Tally::default();
 
-            let filename = file
-                .file_name()
-                .map(|s| s.to_string_lossy().into())
-                .unwrap_or("<file>".to_string());
+            for report in &reports {
+                tally.add(&report.lints, &output.threshold);
 
-            let mut report_builder = Report::build(ReportKind::Advice, &filename, 0);
+                if count {
+                    continue;
+                }
 
-            for lint in lints {
-                report_builder = report_builder.with_label(
-                    Label::new((&filename, lint.span.into()))
-                        .with_message(lint.message)
-                        .with_color(primary_color),
-                );
+                if fix || fix_dry_run {
+                    fix_file(report, fix_dry_run)?;
+                    continue;
+                }
+
+                print_report(report, format)?;
             }
 
-            let report = report_builder.finish();
-            report.print((&filename, Source::from(source)))?;
+            if fix || fix_dry_run {
+                return Ok(());
+            }
+
+            if many && !count && matches!(format, OutputFormat::Text) {
+                print_summary(&reports);
+            }
+
+            if options.profile {
+                let mut profile = LintProfile::new();
 
-            process::exit(1)
+                for report in &reports {
+                    if let Some(report_profile) = &report.profile {
+                        profile.merge_from(report_profile);
+                    }
+                }
+
+                print_profile(&profile);
+            }
+
+            finish(&tally, &output)
         }
         Args::Parse { file } => {
             let (doc, _) = load_file(&file, markdown_options)?;
@@ -251,12 +620,653 @@ fn main() -> anyhow::Result<()> {
 
             Ok(())
         }
+        Args::Stats { files } => {
+            let paths = collect_files(&files, markdown_options)?;
+
+            let options = LintOptions {
+                only_lint_with: None,
+                parallel: false,
+                markdown_options,
+                baseline: None,
+                profile: false,
+            };
+
+            let reports: Vec<_> = paths
+                .par_iter()
+                .map(|path| {
+                    lint_file(path, &options, &dictionary)
+                        .with_context(|| format!("Unable to measure {}", path.display()))
+                })
+                .collect::<anyhow::Result<_>>()?;
+
+            let mut total_stats = DocumentStats::default();
+            let mut total_rules = HashMap::new();
+
+            for report in &reports {
+                let stats = DocumentStats::new(&report.document);
+                let rules = count_rules(&report.lints);
+
+                print_stats(&report.path.display().to_string(), &stats, &rules);
+
+                total_stats.merge(&stats);
+                for (rule, count) in rules {
+                    *total_rules.entry(rule).or_default() += count;
+                }
+            }
+
+            if reports.len() > 1 {
+                print_stats(
+                    &format!("All {} files", reports.len()),
+                    &total_stats,
+                    &total_rules,
+                );
+            }
+
+            Ok(())
+        }
+        Args::Baseline {
+            command: BaselineCommand::Create { files, output },
+        } => {
+            let paths = collect_files(&files, markdown_options)?;
+
+            let options = LintOptions {
+                only_lint_with: None,
+                parallel: false,
+                markdown_options,
+                baseline: None,
+                profile: false,
+            };
+
+            let reports: Vec<_> = paths
+                .par_iter()
+                .map(|path| {
+                    lint_file(path, &options, &dictionary)
+                        .with_context(|| format!("Unable to lint {}", path.display()))
+                })
+                .collect::<anyhow::Result<_>>()?;
+
+            let mut project_baseline = if output.is_file() {
+                ProjectBaseline::load(&output)?
+            } else {
+                ProjectBaseline::new(&output)?
+            };
+
+            for report in &reports {
+                project_baseline.set_file(&report.path, &report.lints, &report.document);
+            }
+
+            project_baseline.save(&output)?;
+
+            println!(
+                "Recorded {} lints from {} files in {}",
+                project_baseline.len(),
+                reports.len(),
+                output.display()
+            );
+
+            Ok(())
+        }
+        Args::Rules => {
+            let rules = LintGroup::new_curated(dictionary).rule_descriptions();
+
+            let width = rules
+                .iter()
+                .map(|rule| rule.name.len())
+                .max()
+                .unwrap_or_default();
+
+            println!("{:width$}  {:7}  Kind", "Rule", "Default");
+
+            for rule in rules {
+                println!(
+                    "{:width$}  {:7}  {}",
+                    rule.name,
+                    if rule.default_enabled { "on" } else { "off" },
+                    rule.kind.map(|kind| kind.to_string()).unwrap_or_default()
+                );
+            }
+
+            Ok(())
+        }
+        Args::Explain { rule } => {
+            let Some(rule) = LintGroup::new_curated(dictionary)
+                .rule_descriptions()
+                .into_iter()
+                .find(|description| description.name.eq_ignore_ascii_case(&rule))
+            else {
+                return Err(format_err!(
+                    "There is no rule named `{rule}`. Run `harper-cli rules` to list them."
+                ));
+            };
+
+            println!("{}", rule.name);
+            println!();
+            println!("{}", rule.description);
+            println!();
+
+            if let Some(kind) = rule.kind {
+                println!("Kind: {kind}");
+            }
+            println!(
+                "Enabled by default: {}",
+                if rule.default_enabled { "yes" } else { "no" }
+            );
+
+            if !rule.examples.is_empty() {
+                println!();
+                println!("Examples:");
+
+                for example in &rule.examples {
+                    match &example.corrected {
+                        Some(corrected) => println!("  {} -> {}", example.incorrect, corrected),
+                        None => println!("  {}", example.incorrect),
+                    }
+                }
+            }
+
+            println!();
+            println!("Configuration:");
+            println!("  harper.toml: [rules] {} = true", rule.name);
+            println!("  harper.toml: [severities] {} = \"warning\"", rule.name);
+            println!("  harper-ls:   linters.{}", rule.name);
+
+            Ok(())
+        }
+        Args::ExportDictionary { hunspell } => {
+            let Some(hunspell) = hunspell else {
+                print!("{}", export_word_list(&dictionary));
+                return Ok(());
+            };
+
+            let export = export_hunspell(&dictionary);
+            std::fs::write(hunspell.with_extension("dic"), export.dic)?;
+            std::fs::write(hunspell.with_extension("aff"), export.aff)?;
+
+            Ok(())
+        }
+        Args::DiffDictionaries { before, after } => {
+            let diff =
+                DictionaryDiff::between(&load_dictionary(&before)?, &load_dictionary(&after)?);
+
+            print!("{diff}");
+
+            Ok(())
+        }
+    }
+}
+
+/// Load a dictionary from a Hunspell `.dic` file (and the `.aff` file beside it) or a
+/// line-separated list of words.
+fn load_dictionary(path: &Path) -> anyhow::Result<MutableDictionary> {
+    if path.extension().is_some_and(|ext| ext == "dic") {
+        let dic = std::fs::read_to_string(path)?;
+        let aff = std::fs::read_to_string(path.with_extension("aff"))?;
+
+        return Ok(MutableDictionary::from_hunspell(&dic, &aff)?);
+    }
+
+    let mut dictionary = MutableDictionary::new();
+    dictionary.extend_words(
+        std::fs::read_to_string(path)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                (
+                    line.chars().collect::<CharString>(),
+                    WordMetadata::default(),
+                )
+            }),
+    );
+
+    Ok(dictionary)
+}
+
+/// Pick the label color for a lint based on its configured [`Severity`].
+fn severity_color(severity: Option<Severity>) -> Color {
+    match severity {
+        Some(Severity::Error) => Color::Red,
+        Some(Severity::Warning) => Color::Yellow,
+        Some(Severity::Hint) => Color::Blue,
+        Some(Severity::Info) | None => Color::Magenta,
+    }
+}
+
+/// The settings that apply to every file linted by the lint command.
+struct LintOptions {
+    only_lint_with: Option<Vec<String>>,
+    parallel: bool,
+    markdown_options: MarkdownOptions,
+    /// Lints recorded here are left out.
+    baseline: Option<ProjectBaseline>,
+    /// Whether to record how long each rule takes.
+    profile: bool,
+}
+
+impl LintOptions {
+    /// Create the linter for a file, configured by the `harper.toml` files that apply to it.
+    fn build_linter(
+        &self,
+        file: &Path,
+        dictionary: Arc<dyn Dictionary>,
+    ) -> anyhow::Result<LintGroup> {
+        let path = file.canonicalize()?;
+        let dir = path
+            .parent()
+            .ok_or(format_err!("Could not find the file's directory."))?;
+
+        let harper_config = HarperConfig::discover(dir)?;
+        let mut linter = harper_config.build_lint_group(dictionary)?;
+
+        if let Some(rules) = &self.only_lint_with {
+            linter.set_all_rules_to(Some(false));
+
+            for rule in rules {
+                linter.config.set_rule_enabled(rule.clone(), true);
+            }
+        }
+
+        if self.profile {
+            linter = linter.with_profile(LintProfile::new());
+        }
+
+        Ok(linter)
+    }
+}
+
+/// The lints found in a single file.
+struct FileReport {
+    path: PathBuf,
+    source: String,
+    document: Document,
+    lints: Vec<Lint>,
+    /// How long each rule took, if profiling.
+    profile: Option<LintProfile>,
+}
+
+fn lint_file(
+    path: &Path,
+    options: &LintOptions,
+    dictionary: &Arc<FstDictionary>,
+) -> anyhow::Result<FileReport> {
+    let mut linter = options.build_linter(path, dictionary.clone())?;
+    let (document, source) = load_file(path, options.markdown_options)?;
+
+    let mut lints = if options.parallel {
+        linter.lint_parallel(&document)
+    } else {
+        linter.lint(&document)
+    };
+
+    if let Some(baseline) = &options.baseline {
+        baseline.remove_baselined(path, &mut lints, &document);
+    }
+
+    Ok(FileReport {
+        path: path.to_path_buf(),
+        source,
+        document,
+        lints,
+        profile: linter.profile,
+    })
+}
+
+/// Expand the files, directories and glob patterns given to the lint command into the files
+/// to lint.
+///
+/// Directories are searched recursively for files Harper can parse, skipping anything listed in
+/// a `.gitignore` or `.harperignore` file.
+fn collect_files(
+    inputs: &[PathBuf],
+    markdown_options: MarkdownOptions,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for input in inputs {
+        if input.is_dir() {
+            let walker = WalkBuilder::new(input)
+                .add_custom_ignore_filename(HarperIgnore::FILE_NAME)
+                .build();
+
+            for entry in walker {
+                let entry = entry?;
+
+                if entry.file_type().is_some_and(|t| t.is_file())
+                    && parser_for_file(entry.path(), markdown_options).is_ok()
+                {
+                    files.push(entry.into_path());
+                }
+            }
+
+            continue;
+        }
+
+        if input.exists() {
+            if is_ignored(input)? {
+                eprintln!(
+                    "Skipped {}, since it is listed in a .harperignore file",
+                    input.display()
+                );
+            } else {
+                files.push(input.clone());
+            }
+
+            continue;
+        }
+
+        let pattern = input.to_string_lossy();
+        let mut matched = false;
+
+        for path in glob::glob(&pattern)? {
+            let path = path?;
+            matched = true;
+
+            if path.is_file()
+                && !is_ignored(&path)?
+                && parser_for_file(&path, markdown_options).is_ok()
+            {
+                files.push(path);
+            }
+        }
+
+        if !matched {
+            return Err(format_err!("No files match {}", input.display()));
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+/// Whether a file is listed in a `.harperignore` file.
+fn is_ignored(file: &Path) -> anyhow::Result<bool> {
+    let path = file.canonicalize()?;
+    let dir = path
+        .parent()
+        .ok_or(format_err!("Could not find the file's directory."))?;
+
+    Ok(HarperIgnore::discover(dir)?.is_ignored(&path, false))
+}
+
+/// Apply the safe fixes for a file's lints, or print them as a diff.
+fn fix_file(report: &FileReport, dry_run: bool) -> anyhow::Result<()> {
+    let result = fix_safe(report.document.get_source(), &report.lints);
+    let fixed: String = result.text.iter().collect();
+
+    if dry_run {
+        let name = report.path.display().to_string();
+        print!(
+            "{}",
+            TextDiff::from_lines(&report.source, &fixed)
+                .unified_diff()
+                .header(&name, &name)
+        );
+    } else if result.applied > 0 {
+        std::fs::write(&report.path, fixed)?;
+        println!(
+            "Applied {} fixes to {}",
+            result.applied,
+            report.path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Print a file's lints in the requested format.
+fn print_report(report: &FileReport, format: OutputFormat) -> anyhow::Result<()> {
+    let mut lints = report.lints.clone();
+    remove_overlaps(&mut lints);
+
+    if let OutputFormat::Json = format {
+        let line_index = LineIndex::new(report.document.get_source());
+
+        for lint in &lints {
+            print_json_lint(
+                &report.path,
+                lint,
+                line_index.position(lint.span.start, PositionEncoding::Char),
+                line_index.position(lint.span.end, PositionEncoding::Char),
+            )?;
+        }
+
+        return Ok(());
+    }
+
+    if lints.is_empty() {
+        return Ok(());
     }
+
+    let filename = report.path.display().to_string();
+
+    let mut report_builder = Report::build(ReportKind::Advice, &filename, 0);
+
+    for lint in lints {
+        report_builder = report_builder.with_label(
+            Label::new((&filename, lint.span.into()))
+                .with_message(lint.message)
+                .with_color(severity_color(lint.severity)),
+        );
+    }
+
+    let ariadne_report = report_builder.finish();
+    ariadne_report.print((&filename, Source::from(&report.source)))?;
+
+    Ok(())
+}
+
+/// Print the measurements of a file (or group of files) for the stats command.
+fn print_stats(title: &str, stats: &DocumentStats, rules: &HashMap<String, usize>) {
+    let score = |value: Option<f64>| value.map_or("n/a".to_string(), |v| format!("{v:.1}"));
+
+    println!("{title}");
+    println!("  Words: {}", stats.words);
+    println!("  Sentences: {}", stats.sentences);
+    println!(
+        "  Words per sentence: {}",
+        score(stats.words_per_sentence())
+    );
+    println!(
+        "  Flesch reading ease: {}",
+        score(stats.flesch_reading_ease())
+    );
+    println!(
+        "  Flesch-Kincaid grade: {}",
+        score(stats.flesch_kincaid_grade())
+    );
+    println!(
+        "  Passive voice: {}",
+        stats
+            .passive_percentage()
+            .map_or("n/a".to_string(), |v| format!("{v:.1}% of sentences"))
+    );
+
+    let mut rules: Vec<_> = rules.iter().collect();
+    rules.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    if rules.is_empty() {
+        println!("  Most frequent lints: none");
+    } else {
+        println!(
+            "  Most frequent lints: {}",
+            rules
+                .iter()
+                .take(5)
+                .map(|(rule, count)| format!("{rule} ({count})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    println!();
+}
+
+/// Print a table of how many lints were found in each file.
+fn print_summary(reports: &[FileReport]) {
+    let total_label = format!("Total ({} files)", reports.len());
+
+    let width = reports
+        .iter()
+        .map(|report| report.path.display().to_string().chars().count())
+        .chain([total_label.len(), "File".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!();
+    println!("{:width$}  Lints", "File");
+
+    for report in reports {
+        println!(
+            "{:width$}  {}",
+            report.path.display().to_string(),
+            report.lints.len()
+        );
+    }
+
+    println!(
+        "{:width$}  {}",
+        total_label,
+        reports
+            .iter()
+            .map(|report| report.lints.len())
+            .sum::<usize>()
+    );
+}
+
+/// Print the time taken and lints produced by each rule, slowest first.
+///
+/// Printed to stderr, so it doesn't mix with lints printed as JSON.
+fn print_profile(profile: &LintProfile) {
+    let rules = profile.slowest();
+
+    let width = rules
+        .iter()
+        .map(|(rule, _)| rule.len())
+        .chain(["Rule".len(), "Total".len()])
+        .max()
+        .unwrap_or_default();
+
+    eprintln!();
+    eprintln!(
+        "{:width$}  {:>12}  {:>6}  {:>6}",
+        "Rule", "Time", "Runs", "Lints"
+    );
+
+    for (rule, rule_profile) in &rules {
+        eprintln!(
+            "{:width$}  {:>12}  {:>6}  {:>6}",
+            rule,
+            format!("{:.2?}", rule_profile.time),
+            rule_profile.runs,
+            rule_profile.lints
+        );
+    }
+
+    eprintln!(
+        "{:width$}  {:>12}  {:>6}  {:>6}",
+        "Total",
+        format!("{:.2?}", profile.total_time()),
+        rules.iter().map(|(_, rule)| rule.runs).sum::<usize>(),
+        rules.iter().map(|(_, rule)| rule.lints).sum::<usize>()
+    );
+}
+
+/// Lint a file one chunk at a time, printing lints as they are found.
+fn stream_lints(
+    file: &Path,
+    output: &OutputOptions,
+    options: &LintOptions,
+    tally: &mut Tally,
+    linter: &mut LintGroup,
+    dictionary: &Arc<FstDictionary>,
+) -> anyhow::Result<()> {
+    let parser = parser_for_file(file, options.markdown_options)?;
+    let reader = BufReader::new(File::open(file)?);
+
+    for chunk in LintStream::new(reader, &parser, dictionary, linter) {
+        let chunk = chunk?;
+        tally.add(&chunk.lints, &output.threshold);
+
+        if output.count {
+            continue;
+        }
+
+        let line_index = LineIndex::new(chunk.document.get_source());
+
+        if let OutputFormat::Json = output.format {
+            // Report positions within the whole file, rather than the chunk.
+            let in_file = |index: usize| {
+                let position = line_index.position(index, PositionEncoding::Char);
+                Position::new(chunk.first_line + position.line, position.column)
+            };
+
+            for (lint, chunk_lint) in chunk.lints_in_stream().zip(&chunk.lints) {
+                print_json_lint(
+                    file,
+                    &lint,
+                    in_file(chunk_lint.span.start),
+                    in_file(chunk_lint.span.end),
+                )?;
+            }
+
+            continue;
+        }
+
+        for lint in &chunk.lints {
+            let position = line_index.position(lint.span.start, PositionEncoding::Char);
+
+            println!(
+                "{}:{}:{}: {}",
+                file.display(),
+                chunk.first_line + position.line + 1,
+                position.column + 1,
+                lint.message
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the final result of the lint command, exiting with an error if the lints found exceed
+/// the threshold.
+fn finish(tally: &Tally, output: &OutputOptions) -> anyhow::Result<()> {
+    let threshold = &output.threshold;
+
+    if output.count {
+        println!("{}", tally.total);
+
+        // Counting only fails when asked to.
+        if !threshold.is_set() {
+            return Ok(());
+        }
+    } else if tally.total == 0 {
+        if let OutputFormat::Text = output.format {
+            println!("No lints found");
+        }
+    }
+
+    if threshold.is_exceeded(tally) {
+        process::exit(1)
+    }
+
+    Ok(())
 }
 
 fn load_file(file: &Path, markdown_options: MarkdownOptions) -> anyhow::Result<(Document, String)> {
     let source = std::fs::read_to_string(file)?;
+    let parser = parser_for_file(file, markdown_options)?;
 
+    Ok((Document::new_curated(&source, &parser), source))
+}
+
+/// Pick a parser based on a file's extension.
+fn parser_for_file(
+    file: &Path,
+    markdown_options: MarkdownOptions,
+) -> anyhow::Result<Box<dyn harper_core::parsers::Parser>> {
     let parser: Box<dyn harper_core::parsers::Parser> =
         match file.extension().map(|v| v.to_str().unwrap()) {
             Some("md") => Box::new(Markdown::default()),
@@ -271,5 +1281,5 @@ fn load_file(file: &Path, markdown_options: MarkdownOptions) -> anyhow::Result<(
             ),
         };
 
-    Ok((Document::new_curated(&source, &parser), source))
+    Ok(parser)
 }
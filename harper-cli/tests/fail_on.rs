@@ -0,0 +1,25 @@
+use std::process::{Command, Output};
+
+/// Run `harper-cli lint` over `misspelled.md`, failing on lints of at least `fail_on`.
+fn lint_misspelled(fail_on: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_harper-cli"))
+        .args(["lint", "--fail-on", fail_on])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/sources/misspelled.md"
+        ))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn misspellings_fail_on_warning() {
+    let output = lint_misspelled("warning");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn misspellings_pass_on_error() {
+    let output = lint_misspelled("error");
+    assert!(output.status.success());
+}
@@ -0,0 +1,42 @@
+use std::process::Command;
+
+use serde_json::Value;
+
+/// Run `harper-cli lint --format json` over `misspelled.md`, parsing each line of output.
+fn lint_misspelled_json() -> Vec<Value> {
+    let output = Command::new(env!("CARGO_BIN_EXE_harper-cli"))
+        .args(["lint", "--format", "json", "--no-baseline"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/sources/misspelled.md"
+        ))
+        .output()
+        .unwrap();
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn reports_where_lints_start_and_end() {
+    let lints = lint_misspelled_json();
+    let lint = &lints[0];
+
+    assert_eq!(lint["line"], 1);
+    assert_eq!(lint["column"], 21);
+    assert_eq!(lint["end_line"], 1);
+    assert_eq!(lint["end_column"], 30);
+}
+
+#[test]
+fn suggestions_are_edits_with_text() {
+    let lints = lint_misspelled_json();
+    let edits = &lints[0]["suggestions"][0]["edits"];
+
+    assert_eq!(edits[0]["start"], 20);
+    assert_eq!(edits[0]["end"], 29);
+    assert_eq!(edits[0]["text"], "misspelled");
+}
@@ -2,14 +2,30 @@ use std::path::Path;
 
 use comment_parsers::{Go, JavaDoc, JsDoc, Unit};
 use harper_core::parsers::{self, MarkdownOptions, Parser};
-use harper_core::{MutableDictionary, Token};
+use harper_core::{Masker, MutableDictionary, Span, Token};
 use harper_tree_sitter::TreeSitterMasker;
+use serde::{Deserialize, Serialize};
 use tree_sitter::Node;
 
 use crate::comment_parsers;
 
+/// The parts of source code, besides comments, that a [`CommentParser`] checks.
+///
+/// Everything is off by default, since most projects only want their comments checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CodeScope {
+    /// Check the contents of string literals, like `"Hello, world!"`.
+    pub string_literals: bool,
+    /// Check identifiers, like `getUserById` or `get_user_by_id`, by splitting them into the
+    /// words they are made of.
+    pub identifiers: bool,
+}
+
 pub struct CommentParser {
     inner: parsers::Mask<TreeSitterMasker, Box<dyn Parser>>,
+    /// Finds the comments alone, regardless of the [`CodeScope`] being checked.
+    comments: TreeSitterMasker,
 }
 
 impl CommentParser {
@@ -20,6 +36,16 @@ impl CommentParser {
     pub fn new_from_language_id(
         language_id: &str,
         markdown_options: MarkdownOptions,
+    ) -> Option<Self> {
+        Self::new_from_language_id_with_scope(language_id, markdown_options, CodeScope::default())
+    }
+
+    /// Like [`Self::new_from_language_id`], but also check the parts of the code included in
+    /// `scope`.
+    pub fn new_from_language_id_with_scope(
+        language_id: &str,
+        markdown_options: MarkdownOptions,
+        scope: CodeScope,
     ) -> Option<Self> {
         let language = match language_id {
             "rust" => tree_sitter_rust::language(),
@@ -55,11 +81,19 @@ impl CommentParser {
             _ => Box::new(Unit::new_markdown(markdown_options)),
         };
 
+        let mut inner = parsers::Mask::new(
+            TreeSitterMasker::new(language, Self::node_condition(scope)),
+            comment_parser,
+        );
+
+        // Strings and identifiers are snippets, not the prose around them.
+        if scope != CodeScope::default() {
+            inner = inner.with_separate_chunks();
+        }
+
         Some(Self {
-            inner: parsers::Mask::new(
-                TreeSitterMasker::new(language, Self::node_condition),
-                comment_parser,
-            ),
+            inner,
+            comments: TreeSitterMasker::new(language, is_comment),
         })
     }
 
@@ -73,7 +107,7 @@ impl CommentParser {
     ///
     /// Note to contributors: try to keep this in sync with
     /// [`Self::new_from_language_id`]
-    fn filename_to_filetype(path: &Path) -> Option<&'static str> {
+    pub fn filename_to_filetype(path: &Path) -> Option<&'static str> {
         Some(match path.extension()?.to_str()? {
             "py" => "python",
             "nix" => "nix",
@@ -102,13 +136,41 @@ impl CommentParser {
         })
     }
 
-    fn node_condition(n: &Node) -> bool {
-        n.kind().contains("comment")
+    /// Choose which nodes to check, based on the given [`CodeScope`].
+    fn node_condition(scope: CodeScope) -> fn(&Node) -> bool {
+        match (scope.string_literals, scope.identifiers) {
+            (false, false) => is_comment,
+            (true, false) => |n| is_comment(n) || is_string_literal(n),
+            (false, true) => |n| is_comment(n) || is_identifier(n),
+            (true, true) => |n| is_comment(n) || is_string_literal(n) || is_identifier(n),
+        }
     }
 }
 
+fn is_comment(n: &Node) -> bool {
+    n.kind().contains("comment")
+}
+
+/// Grammars name their string literals differently (`string_literal`, `string`,
+/// `interpreted_string_literal`, ...), but they almost always include "string".
+fn is_string_literal(n: &Node) -> bool {
+    n.is_named() && n.kind().contains("string") && !n.kind().contains("type")
+}
+
+fn is_identifier(n: &Node) -> bool {
+    n.child_count() == 0 && n.kind().contains("ident")
+}
+
 impl Parser for CommentParser {
     fn parse(&self, source: &[char]) -> Vec<Token> {
         self.inner.parse(source)
     }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.comments
+            .create_mask(source)
+            .iter_allowed(source)
+            .map(|(span, _)| span)
+            .collect()
+    }
 }
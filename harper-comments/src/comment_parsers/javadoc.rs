@@ -60,14 +60,12 @@ impl Parser for JavaDoc {
         super::jsdoc::mark_inline_tags(&mut tokens);
 
         // Mark @tags as unlintable
-        for i in 3..tokens.len() {
-            let a = tokens[i - 3];
-            let b = tokens[i - 2];
-            let c = tokens[i - 1];
-            let d = tokens[i];
-
-            if a.kind.is_at() && b.kind.is_word() && c.kind.is_space() && d.kind.is_word() {
-                tokens[i - 3].kind = TokenKind::Unlintable;
+        for i in 2..tokens.len() {
+            let a = tokens[i - 2];
+            let b = tokens[i - 1];
+            let c = tokens[i];
+
+            if a.kind.is_mention() && b.kind.is_space() && c.kind.is_word() {
                 tokens[i - 2].kind = TokenKind::Unlintable;
                 tokens[i - 1].kind = TokenKind::Unlintable;
                 tokens[i].kind = TokenKind::Unlintable;
@@ -1,7 +1,6 @@
 use harper_core::Lrc;
 use harper_core::parsers::{Markdown, MarkdownOptions, Parser};
 use harper_core::{Punctuation, Span, Token, TokenKind};
-use itertools::Itertools;
 
 use super::without_initiators;
 
@@ -63,21 +62,7 @@ fn parse_line(source: &[char], parser: Lrc<dyn Parser>) -> Vec<Token> {
     mark_inline_tags(&mut new_tokens);
 
     // Handle the block tag, if it exists on the current line.
-    if let Some(tag_start) = new_tokens.iter().tuple_windows().position(|(a, b)| {
-        matches!(
-            (a, b),
-            (
-                Token {
-                    kind: TokenKind::Punctuation(Punctuation::At),
-                    ..
-                },
-                Token {
-                    kind: TokenKind::Word(..),
-                    ..
-                }
-            )
-        )
-    }) {
+    if let Some(tag_start) = new_tokens.iter().position(|t| t.kind.is_mention()) {
         for token in &mut new_tokens[tag_start..] {
             token.kind = TokenKind::Unlintable;
         }
@@ -132,11 +117,7 @@ fn parse_inline_tag(tokens: &[Token]) -> Option<usize> {
                 ..
             },
             Token {
-                kind: TokenKind::Punctuation(Punctuation::At),
-                ..
-            },
-            Token {
-                kind: TokenKind::Word(..),
+                kind: TokenKind::Mention,
                 ..
             },
             ..,
@@ -145,7 +126,7 @@ fn parse_inline_tag(tokens: &[Token]) -> Option<usize> {
         return None;
     }
 
-    let mut cursor = 3;
+    let mut cursor = 2;
 
     while !matches!(
         tokens.get(cursor),
@@ -195,7 +176,6 @@ mod tests {
                 TokenKind::Unlintable,
                 TokenKind::Unlintable,
                 TokenKind::Unlintable,
-                TokenKind::Unlintable,
                 TokenKind::Space(1),
                 TokenKind::Word(..),
                 TokenKind::Space(1),
@@ -213,7 +193,6 @@ mod tests {
                 TokenKind::Unlintable,
                 TokenKind::Unlintable,
                 TokenKind::Unlintable,
-                TokenKind::Unlintable,
                 TokenKind::Punctuation(Punctuation::Period),
             ]
         ));
@@ -2,4 +2,4 @@
 
 mod comment_parser;
 mod comment_parsers;
-pub use comment_parser::CommentParser;
+pub use comment_parser::{CodeScope, CommentParser};
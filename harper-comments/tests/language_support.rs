@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use harper_comments::CommentParser;
+use harper_comments::{CodeScope, CommentParser};
 use harper_core::linting::{LintGroup, Linter};
 use harper_core::parsers::MarkdownOptions;
 use harper_core::{Document, FstDictionary};
@@ -57,3 +57,66 @@ create_test!(issue_229.js, 0);
 create_test!(issue_229.c, 0);
 create_test!(issue_229.cs, 0);
 create_test!(eof.rs, 0);
+
+/// Lint `code_scope.rs` with the given [`CodeScope`], returning the number of lints.
+fn lint_code_scope(scope: CodeScope) -> usize {
+    let source = include_str!("./language_support_sources/code_scope.rs");
+
+    let parser =
+        CommentParser::new_from_language_id_with_scope("rust", MarkdownOptions::default(), scope)
+            .unwrap();
+    let dict = FstDictionary::curated();
+    let document = Document::new(source, &parser, &dict);
+
+    let mut linter = LintGroup::new_curated(dict);
+    linter.lint(&document).len()
+}
+
+#[test]
+fn only_checks_comments_by_default() {
+    assert_eq!(lint_code_scope(CodeScope::default()), 0);
+}
+
+#[test]
+fn checks_string_literals() {
+    assert_eq!(
+        lint_code_scope(CodeScope {
+            string_literals: true,
+            ..Default::default()
+        }),
+        1
+    );
+}
+
+#[test]
+fn checks_identifiers() {
+    assert_eq!(
+        lint_code_scope(CodeScope {
+            identifiers: true,
+            ..Default::default()
+        }),
+        2
+    );
+}
+
+#[test]
+fn only_honors_directives_in_comments() {
+    let source = include_str!("./language_support_sources/inline_directives.rs");
+
+    let parser = CommentParser::new_from_language_id_with_scope(
+        "rust",
+        MarkdownOptions::default(),
+        CodeScope {
+            string_literals: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let dict = FstDictionary::curated();
+    let document = Document::new(source, &parser, &dict);
+
+    let mut linter = LintGroup::new_curated(dict);
+    // The string is checked like any other text, so its "harper" is flagged, but it doesn't
+    // disable the comment after it.
+    assert_eq!(linter.lint(&document).len(), 2);
+}
@@ -0,0 +1,6 @@
+/// Check whether any messages have arrived.
+fn has_messages(recieved_messages: &[&str]) -> bool {
+    let greeting = "This sentense has a typo.";
+
+    !recieved_messages.is_empty() && !greeting.is_empty()
+}
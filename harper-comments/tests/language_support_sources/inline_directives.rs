@@ -0,0 +1,8 @@
+// harper: ignore-next-line SpellCheck
+// This comment has a mispelled word, but it is ignored.
+
+fn main() {
+    println!("harper: disable");
+}
+
+// This comment has a mispelled word too.
@@ -3,9 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::Number;
 
-#[derive(Debug, Is, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Hash)]
+#[derive(
+    Debug, Is, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Hash,
+)]
 pub enum Currency {
     // $
+    #[default]
     Dollar,
     // ¢
     Cent,
@@ -61,11 +64,53 @@ impl Currency {
         }
     }
 
+    /// Parse an ISO 4217 currency code, like `USD` or `EUR`.
+    /// Codes must be written in uppercase.
+    pub fn from_code(code: &[char]) -> Option<Self> {
+        let code: String = code.iter().collect();
+
+        let cur = match code.as_str() {
+            "USD" => Self::Dollar,
+            "EUR" => Self::Euro,
+            "RUB" => Self::Ruble,
+            "TRY" => Self::Lira,
+            "GBP" => Self::Pound,
+            "JPY" => Self::Yen,
+            "THB" => Self::Baht,
+            "KRW" => Self::Won,
+            "LAK" => Self::Kip,
+            _ => return None,
+        };
+
+        Some(cur)
+    }
+
+    /// The ISO 4217 code of the currency.
+    /// `None` for [`Currency::Cent`], which is a fraction of another currency.
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            Self::Dollar => Some("USD"),
+            Self::Cent => None,
+            Self::Euro => Some("EUR"),
+            Self::Ruble => Some("RUB"),
+            Self::Lira => Some("TRY"),
+            Self::Pound => Some("GBP"),
+            Self::Yen => Some("JPY"),
+            Self::Baht => Some("THB"),
+            Self::Won => Some("KRW"),
+            Self::Kip => Some("LAK"),
+        }
+    }
+
     /// Format an amount of the specific currency.
     pub fn format_amount(&self, amount: Number) -> String {
-        let c = self.to_char();
+        self.format_amount_text(&amount.to_string())
+    }
 
-        let amount = amount.to_string();
+    /// Place the currency symbol around an amount that has already been written out,
+    /// like `1,000.50`.
+    pub fn format_amount_text(&self, amount: &str) -> String {
+        let c = self.to_char();
 
         match self {
             Currency::Dollar => format!("{}{amount}", c),
@@ -81,3 +126,83 @@ impl Currency {
         }
     }
 }
+
+/// An amount of money, like `$1,000`, `25 ¥` or `50 EUR`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Hash)]
+pub struct CurrencyAmount {
+    pub currency: Currency,
+    pub amount: Number,
+    pub notation: CurrencyNotation,
+    pub position: CurrencyPosition,
+}
+
+/// How the currency of a [`CurrencyAmount`] was written.
+#[derive(
+    Debug, Is, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Hash,
+)]
+pub enum CurrencyNotation {
+    /// Like `$`.
+    #[default]
+    Symbol,
+    /// Like `USD`.
+    Code,
+}
+
+/// Where the currency of a [`CurrencyAmount`] was written, relative to the number.
+#[derive(
+    Debug, Is, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Hash,
+)]
+pub enum CurrencyPosition {
+    #[default]
+    Before,
+    After,
+}
+
+impl CurrencyAmount {
+    /// Given the source text of the amount's token, get the characters of just the number.
+    pub fn amount_text<'a>(&self, content: &'a [char]) -> &'a [char] {
+        let marker_len = match self.notation {
+            CurrencyNotation::Symbol => 1,
+            CurrencyNotation::Code => 3,
+        };
+
+        let rest = match self.position {
+            CurrencyPosition::Before => &content[marker_len.min(content.len())..],
+            CurrencyPosition::After => &content[..content.len().saturating_sub(marker_len)],
+        };
+
+        let start = rest
+            .iter()
+            .position(|c| !c.is_whitespace())
+            .unwrap_or(rest.len());
+        let end = rest
+            .iter()
+            .rposition(|c| !c.is_whitespace())
+            .map_or(start, |i| i + 1);
+
+        &rest[start..end]
+    }
+
+    /// Write the amount in the given notation, using the conventional symbol placement for
+    /// [`CurrencyNotation::Symbol`] and the given position for [`CurrencyNotation::Code`].
+    pub fn format_as(
+        &self,
+        content: &[char],
+        notation: CurrencyNotation,
+        position: CurrencyPosition,
+    ) -> Option<String> {
+        let amount: String = self.amount_text(content).iter().collect();
+
+        match notation {
+            CurrencyNotation::Symbol => Some(self.currency.format_amount_text(&amount)),
+            CurrencyNotation::Code => {
+                let code = self.currency.code()?;
+
+                Some(match position {
+                    CurrencyPosition::Before => format!("{code} {amount}"),
+                    CurrencyPosition::After => format!("{amount} {code}"),
+                })
+            }
+        }
+    }
+}
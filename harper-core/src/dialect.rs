@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A regional variety of English, which decides things like whether "color" or "colour" is the
+/// correct spelling.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum Dialect {
+    /// `en-US`
+    #[default]
+    American,
+    /// `en-GB`
+    British,
+    /// `en-CA`
+    Canadian,
+    /// `en-AU`
+    Australian,
+}
+
+impl Dialect {
+    pub const ALL: [Dialect; 4] = [
+        Dialect::American,
+        Dialect::British,
+        Dialect::Canadian,
+        Dialect::Australian,
+    ];
+
+    /// The dialect's BCP 47 language tag, like `en-GB`.
+    pub fn language_tag(self) -> &'static str {
+        match self {
+            Dialect::American => "en-US",
+            Dialect::British => "en-GB",
+            Dialect::Canadian => "en-CA",
+            Dialect::Australian => "en-AU",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.language_tag())
+    }
+}
+
+impl FromStr for Dialect {
+    type Err = String;
+
+    /// Parses either the name of the dialect (like `British`) or its language tag (like
+    /// `en-GB`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Dialect::ALL
+            .into_iter()
+            .find(|dialect| {
+                dialect.language_tag().eq_ignore_ascii_case(s)
+                    || format!("{dialect:?}").eq_ignore_ascii_case(s)
+            })
+            .ok_or_else(|| format!("Unknown dialect “{s}”."))
+    }
+}
+
+/// The set of dialects a dictionary entry belongs to.
+///
+/// An empty set means the entry isn't specific to any dialect, so it is valid in all of them.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(from = "Vec<Dialect>", into = "Vec<Dialect>")]
+pub struct DialectFlags(u8);
+
+impl DialectFlags {
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, dialect: Dialect) -> bool {
+        self.0 & dialect.bit() != 0
+    }
+
+    pub fn insert(&mut self, dialect: Dialect) {
+        self.0 |= dialect.bit();
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Whether an entry with these flags is valid in a given dialect.
+    pub fn allows(self, dialect: Dialect) -> bool {
+        self.is_empty() || self.contains(dialect)
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Dialect> {
+        Dialect::ALL
+            .into_iter()
+            .filter(move |dialect| self.contains(*dialect))
+    }
+}
+
+impl FromIterator<Dialect> for DialectFlags {
+    fn from_iter<T: IntoIterator<Item = Dialect>>(iter: T) -> Self {
+        let mut flags = Self::empty();
+
+        for dialect in iter {
+            flags.insert(dialect);
+        }
+
+        flags
+    }
+}
+
+impl From<Vec<Dialect>> for DialectFlags {
+    fn from(value: Vec<Dialect>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<DialectFlags> for Vec<Dialect> {
+    fn from(value: DialectFlags) -> Self {
+        value.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dialect, DialectFlags};
+
+    #[test]
+    fn parses_tags_and_names() {
+        assert_eq!("en-GB".parse(), Ok(Dialect::British));
+        assert_eq!("australian".parse(), Ok(Dialect::Australian));
+        assert!("en-NZ".parse::<Dialect>().is_err());
+    }
+
+    #[test]
+    fn empty_flags_allow_everything() {
+        let untagged = DialectFlags::empty();
+        let commonwealth: DialectFlags = [Dialect::British, Dialect::Australian]
+            .into_iter()
+            .collect();
+
+        assert!(Dialect::ALL.iter().all(|d| untagged.allows(*d)));
+        assert!(commonwealth.allows(Dialect::British));
+        assert!(!commonwealth.allows(Dialect::American));
+    }
+}
@@ -4,20 +4,30 @@ use std::fmt::Display;
 
 use paste::paste;
 
+use crate::fixer::{self, FixResult};
+use crate::linting::{Lint, Suggestion};
+use crate::normalization;
 use crate::parsers::{Markdown, MarkdownOptions, Parser, PlainEnglish};
 use crate::patterns::{
     DocPattern, EitherPattern, Pattern, RepeatingPattern, SequencePattern, WordSet,
 };
 use crate::punctuation::Punctuation;
 use crate::vec_ext::VecExt;
-use crate::{Dictionary, FatToken, FstDictionary, Lrc, Token, TokenKind, TokenStringExt};
-use crate::{NumberSuffix, Span};
+use crate::{
+    Currency, CurrencyAmount, CurrencyNotation, CurrencyPosition, Number, NumberSuffix, Span,
+};
+use crate::{
+    Dictionary, FatToken, FstDictionary, Heading, ListItem, Lrc, Token, TokenKind, TokenStringExt,
+};
 
 /// A document containing some amount of lexed and parsed English text.
 #[derive(Debug, Clone)]
 pub struct Document {
     source: Lrc<Vec<char>>,
     tokens: Vec<Token>,
+    list_items: Vec<ListItem>,
+    headings: Vec<Heading>,
+    comments: Vec<Span>,
 }
 
 impl Default for Document {
@@ -60,14 +70,106 @@ impl Document {
         parser: &impl Parser,
         dictionary: &impl Dictionary,
     ) -> Self {
-        let tokens = parser.parse(&source);
-
-        let mut document = Self { source, tokens };
+        let tokens = Self::parse_normalized(&source, parser);
+        let list_items = parser.list_items(&source);
+        let headings = parser.headings(&source);
+        let comments = parser.comments(&source);
+
+        let mut document = Self {
+            source,
+            tokens,
+            list_items,
+            headings,
+            comments,
+        };
         document.parse(dictionary);
 
         document
     }
 
+    /// Parse the source as if it were in Unicode Normalization Form C, so words with
+    /// decomposed accents (like "café" written with a combining accent) aren't split apart.
+    ///
+    /// The resulting tokens refer to the original source.
+    fn parse_normalized(source: &[char], parser: &impl Parser) -> Vec<Token> {
+        match normalization::normalize(source) {
+            Some(normalized) => parser
+                .parse(&normalized.chars)
+                .into_iter()
+                .map(|token| normalized.map_token_back(token))
+                .collect(),
+            None => parser.parse(source),
+        }
+    }
+
+    /// Build a standalone document from the tokens that lie entirely within `span`, such as the
+    /// region returned by [`Self::apply_edit`].
+    ///
+    /// Returns the new document, along with the span it covers in this one.
+    pub fn sub_document_within(&self, span: Span) -> Option<(Span, Self)> {
+        let start = self.tokens.partition_point(|t| t.span.start < span.start);
+        let end = self.tokens.partition_point(|t| t.span.end <= span.end);
+
+        self.sub_document(self.tokens.get(start..end)?)
+    }
+
+    /// Build a standalone document from a contiguous run of this document's tokens, such as a
+    /// sentence or paragraph.
+    ///
+    /// Returns the new document, along with the span it covers in this one.
+    pub(crate) fn sub_document(&self, tokens: &[Token]) -> Option<(Span, Self)> {
+        let span = tokens.span()?;
+
+        let tokens = tokens
+            .iter()
+            .map(|token| {
+                let mut token = *token;
+                token.span.pull_by(span.start);
+                token
+            })
+            .collect();
+
+        let list_items = self
+            .list_items
+            .iter()
+            .filter(|item| span.start <= item.span.start && item.span.end <= span.end)
+            .map(|item| {
+                let mut item = *item;
+                item.span.pull_by(span.start);
+                item
+            })
+            .collect();
+
+        let headings = self
+            .headings
+            .iter()
+            .filter(|heading| span.start <= heading.span.start && heading.span.end <= span.end)
+            .map(|heading| {
+                let mut heading = heading.clone();
+                heading.span.pull_by(span.start);
+                heading
+            })
+            .collect();
+
+        let comments = self
+            .comments
+            .iter()
+            .filter(|comment| span.start <= comment.start && comment.end <= span.end)
+            .filter_map(|comment| comment.pulled_by(span.start))
+            .collect();
+
+        let mut document = Self {
+            source: Lrc::new(self.get_span_content(span).to_vec()),
+            tokens,
+            list_items,
+            headings,
+            comments,
+        };
+        document.match_quotes();
+
+        Some((span, document))
+    }
+
     /// Parse text to produce a document using the built-in [`PlainEnglish`]
     /// parser and curated dictionary.
     pub fn new_plain_english_curated(text: &str) -> Self {
@@ -121,7 +223,9 @@ impl Document {
         self.newlines_to_breaks();
         self.condense_contractions();
         self.condense_dotted_initialisms();
+        self.condense_abbreviations(dictionary);
         self.condense_number_suffixes();
+        self.condense_currency_amounts();
         self.condense_ellipsis();
         self.condense_latin();
         self.match_quotes();
@@ -272,6 +376,249 @@ impl Document {
         &self.tokens
     }
 
+    /// The items of the document's bulleted and numbered lists, as found by the parser it was
+    /// created with.
+    ///
+    /// Empty if the parser doesn't recognize lists.
+    pub fn get_list_items(&self) -> &[ListItem] {
+        &self.list_items
+    }
+
+    /// The document's headings, as found by the parser it was created with.
+    ///
+    /// Empty for formats without headings, like plain English.
+    pub fn get_headings(&self) -> &[Heading] {
+        &self.headings
+    }
+
+    /// The spans of the document's comments, as found by the parser it was created with.
+    ///
+    /// Empty for formats without comments, like plain English.
+    pub fn get_comments(&self) -> &[Span] {
+        &self.comments
+    }
+
+    /// How many quotations each token is nested inside, in the same order as
+    /// [`Self::get_tokens`].
+    ///
+    /// Quotation marks themselves count as outside the quotation they delimit.
+    /// Linters can use this to avoid flagging quoted material, which the author may not be
+    /// free to change.
+    pub fn quote_depths(&self) -> Vec<usize> {
+        let mut depths = Vec::with_capacity(self.tokens.len());
+        let mut depth: usize = 0;
+
+        for (index, token) in self.tokens.iter().enumerate() {
+            let twin_loc = token.kind.as_quote().and_then(|quote| quote.twin_loc);
+
+            match twin_loc {
+                Some(twin) if twin < index => {
+                    depth = depth.saturating_sub(1);
+                    depths.push(depth);
+                }
+                Some(_) => {
+                    depths.push(depth);
+                    depth += 1;
+                }
+                None => depths.push(depth),
+            }
+        }
+
+        depths
+    }
+
+    /// Replace the text covered by `span` with `new_text`, re-parsing only the paragraphs the
+    /// edit touches and reusing the existing tokens everywhere else.
+    ///
+    /// The `parser` and `dictionary` should be the same ones the document was created with.
+    /// If the affected region contains anything the parser marked unlintable (like a code
+    /// block), or block structure like a list item or code fence, its meaning may depend on
+    /// context outside the region, so the whole document is re-parsed instead.
+    ///
+    /// Returns the span of the re-parsed region in the updated source, which callers can use to
+    /// determine which lints may have changed.
+    pub fn apply_edit(
+        &mut self,
+        span: Span,
+        new_text: &[char],
+        parser: &impl Parser,
+        dictionary: &impl Dictionary,
+    ) -> Span {
+        let end = span.end.min(self.source.len());
+        let span = Span::new(span.start.min(end), end);
+
+        let old_region = self.paragraphs_around(span);
+
+        let region = Span::new(
+            old_region.start,
+            old_region.end - span.len() + new_text.len(),
+        );
+
+        let had_fence = contains_code_fence(old_region.get_content(&self.source));
+
+        // Only copies the source if it is shared with something else.
+        Lrc::make_mut(&mut self.source).splice(span.start..span.end, new_text.iter().copied());
+
+        // An edit can change which list a distant item belongs to, so list items, headings and
+        // comments are always found again from scratch.
+        let old_list_items =
+            std::mem::replace(&mut self.list_items, parser.list_items(&self.source));
+        self.headings = parser.headings(&self.source);
+        self.comments = parser.comments(&self.source);
+
+        let touches_block_structure = had_fence
+            || contains_code_fence(region.get_content(&self.source))
+            || old_list_items
+                .iter()
+                .any(|item| item.span.overlaps_with(old_region))
+            || self
+                .list_items
+                .iter()
+                .any(|item| item.span.overlaps_with(region));
+
+        if touches_block_structure {
+            return self.reparse_fully(parser, dictionary);
+        }
+
+        let first_tok = self
+            .tokens
+            .partition_point(|t| t.span.end <= old_region.start);
+        let last_tok = self
+            .tokens
+            .partition_point(|t| t.span.start < old_region.end);
+
+        let mut new_tokens = Self::new_from_vec(
+            Lrc::new(region.get_content(&self.source).to_vec()),
+            parser,
+            dictionary,
+        )
+        .tokens;
+
+        let old_tokens = &self.tokens[first_tok..last_tok];
+
+        // A token that crosses the edge of the region, or a pair of breaks that would be merged
+        // across it, means the region can't be parsed on its own.
+        let is_break = |t: &Token| t.kind.is_newline() || t.kind.is_paragraph_break();
+        let joined_at_start = first_tok
+            .checked_sub(1)
+            .is_some_and(|i| is_break(&self.tokens[i]))
+            && new_tokens.first().is_none_or(is_break);
+        let joined_at_end = self.tokens.get(last_tok).is_some_and(is_break)
+            && new_tokens.last().is_none_or(is_break);
+
+        // Blocks like code can grow to take in the region, or the tokens after it.
+        let borders_unlintable = first_tok
+            .checked_sub(1)
+            .and_then(|i| self.tokens.get(i))
+            .into_iter()
+            .chain(self.tokens.get(last_tok))
+            .any(|t| t.kind.is_unlintable());
+
+        let needs_full_parse = joined_at_start
+            || joined_at_end
+            || borders_unlintable
+            || old_tokens
+                .first()
+                .is_some_and(|t| t.span.start < old_region.start)
+            || old_tokens
+                .last()
+                .is_some_and(|t| t.span.end > old_region.end)
+            || old_tokens
+                .iter()
+                .chain(&new_tokens)
+                .any(|t| t.kind.is_unlintable());
+
+        if needs_full_parse {
+            return self.reparse_fully(parser, dictionary);
+        }
+
+        new_tokens
+            .iter_mut()
+            .for_each(|t| t.span.push_by(region.start));
+
+        let shift = region.end as isize - old_region.end as isize;
+        for token in &mut self.tokens[last_tok..] {
+            token.span.start = (token.span.start as isize + shift) as usize;
+            token.span.end = (token.span.end as isize + shift) as usize;
+        }
+
+        self.tokens.splice(first_tok..last_tok, new_tokens);
+
+        // Token indices have shifted, so quote pairs must be found again.
+        self.match_quotes();
+
+        region
+    }
+
+    /// Parse the current source from scratch, returning the span of the whole document.
+    fn reparse_fully(&mut self, parser: &impl Parser, dictionary: &impl Dictionary) -> Span {
+        self.tokens = Self::parse_normalized(&self.source, parser);
+        self.parse(dictionary);
+        Span::new(0, self.source.len())
+    }
+
+    /// Expand a span to cover the whole of every paragraph it touches, along with the
+    /// whitespace that follows the last one.
+    ///
+    /// Paragraphs are separated by runs of whitespace that contain at least two newlines.
+    /// These are found in the source, rather than the tokens, since some parsers also end
+    /// paragraphs at things like headings.
+    fn paragraphs_around(&self, span: Span) -> Span {
+        let mut start = 0;
+        let mut end = self.source.len();
+
+        let mut idx = 0;
+        while idx < self.source.len() {
+            if !self.source[idx].is_whitespace() {
+                idx += 1;
+                continue;
+            }
+
+            let run_start = idx;
+            let mut newlines = 0;
+            // Any indentation after the last newline belongs to the next paragraph.
+            let mut boundary = idx;
+
+            while idx < self.source.len() && self.source[idx].is_whitespace() {
+                if self.source[idx] == '\n' {
+                    newlines += 1;
+                    boundary = idx + 1;
+                }
+                idx += 1;
+            }
+
+            if newlines < 2 {
+                continue;
+            }
+
+            if boundary < span.start {
+                start = boundary;
+            } else if run_start >= span.end {
+                end = boundary;
+                break;
+            }
+        }
+
+        Span::new(start, end)
+    }
+
+    /// Apply a suggestion belonging to `lint` to the document's source.
+    ///
+    /// The document itself is left untouched.
+    /// See [`fixer::apply_suggestions`].
+    pub fn apply_suggestion(&self, lint: &Lint, suggestion: &Suggestion) -> FixResult {
+        fixer::apply_suggestions(&self.source, [(lint, suggestion)])
+    }
+
+    /// Apply the first suggestion of each lint to the document's source, skipping any that
+    /// conflict with a more important one.
+    ///
+    /// The document itself is left untouched.
+    /// See [`fixer::fix_all`].
+    pub fn fix_all(&self, lints: &[Lint]) -> FixResult {
+        fixer::fix_all(&self.source, lints)
+    }
+
     /// Searches for quotation marks and fills the
     /// [`Punctuation::Quote::twin_loc`] field. This is on a best-effort
     /// basis.
@@ -280,6 +627,10 @@ impl Document {
     fn match_quotes(&mut self) {
         let quote_indices: Vec<usize> = self.tokens.iter_quote_indices().collect();
 
+        for idx in &quote_indices {
+            self.tokens[*idx].kind.as_mut_quote().unwrap().twin_loc = None;
+        }
+
         for i in 0..quote_indices.len() / 2 {
             let a_i = quote_indices[i * 2];
             let b_i = quote_indices[i * 2 + 1];
@@ -322,6 +673,150 @@ impl Document {
         self.condense_indices(&replace_starts, 2);
     }
 
+    /// Condenses amounts of money, like `$1,000`, `25 ¥` or `50 EUR`, into single
+    /// [`TokenKind::CurrencyAmount`] tokens.
+    fn condense_currency_amounts(&mut self) {
+        let mut remove_indices = VecDeque::new();
+        let mut cursor = 0;
+
+        while cursor < self.tokens.len() {
+            let Some((len, amount)) = self.currency_amount_at(cursor) else {
+                cursor += 1;
+                continue;
+            };
+
+            let end = cursor + len;
+            self.tokens[cursor].span = self.tokens[cursor..end].span().unwrap();
+            self.tokens[cursor].kind = TokenKind::CurrencyAmount(amount);
+            remove_indices.extend(cursor + 1..end);
+
+            cursor = end;
+        }
+
+        self.tokens.remove_indices(remove_indices);
+    }
+
+    /// Checks whether a currency amount starts at the token at `index`.
+    /// Returns the number of tokens it covers.
+    fn currency_amount_at(&self, index: usize) -> Option<(usize, CurrencyAmount)> {
+        let marker = |idx: usize| -> Option<(Currency, CurrencyNotation)> {
+            let tok = self.tokens.get(idx)?;
+
+            match tok.kind {
+                TokenKind::Punctuation(Punctuation::Currency(currency)) => {
+                    Some((currency, CurrencyNotation::Symbol))
+                }
+                TokenKind::Word(..) => Some((
+                    Currency::from_code(self.get_span_content(tok.span))?,
+                    CurrencyNotation::Code,
+                )),
+                _ => None,
+            }
+        };
+
+        let is_space = |idx: usize| self.tokens.get(idx).is_some_and(|t| t.kind.is_space());
+
+        if let Some((currency, notation)) = marker(index) {
+            let mut num_start = index + 1;
+
+            if is_space(num_start) {
+                num_start += 1;
+            } else if notation.is_code() {
+                return None;
+            }
+
+            let (num_len, amount) = self.grouped_number_at(num_start)?;
+
+            return Some((
+                num_start + num_len - index,
+                CurrencyAmount {
+                    currency,
+                    amount,
+                    notation,
+                    position: CurrencyPosition::Before,
+                },
+            ));
+        }
+
+        let (num_len, amount) = self.grouped_number_at(index)?;
+        let mut marker_idx = index + num_len;
+
+        if is_space(marker_idx) {
+            marker_idx += 1;
+        }
+
+        let (currency, notation) = marker(marker_idx)?;
+
+        if notation.is_code() && marker_idx == index + num_len {
+            return None;
+        }
+
+        Some((
+            marker_idx + 1 - index,
+            CurrencyAmount {
+                currency,
+                amount,
+                notation,
+                position: CurrencyPosition::After,
+            },
+        ))
+    }
+
+    /// Reads a number at `index`, including any thousands separators (like `1,000,000`).
+    /// Returns the number of tokens it covers.
+    fn grouped_number_at(&self, index: usize) -> Option<(usize, Number)> {
+        let mut number = *self.tokens.get(index)?.kind.as_number()?;
+        let mut len = 1;
+
+        if !number.kind.is_plain() || number.radix != 10 || number.suffix.is_some() {
+            return Some((len, number));
+        }
+
+        let mut digits: String = self
+            .get_span_content(self.tokens[index].span)
+            .iter()
+            .collect();
+
+        while let (Some(comma), Some(group)) = (
+            self.tokens.get(index + len),
+            self.tokens.get(index + len + 1),
+        ) {
+            let Some(group_number) = group.kind.as_number() else {
+                break;
+            };
+
+            let group_chars = self.get_span_content(group.span);
+            let int_len = group_chars
+                .iter()
+                .position(|c| !c.is_ascii_digit())
+                .unwrap_or(group_chars.len());
+
+            if !comma.kind.is_comma()
+                || int_len != 3
+                || digits.contains('.')
+                || !group_number.kind.is_plain()
+                || group_number.prefix.is_some()
+            {
+                break;
+            }
+
+            digits.extend(group_chars.iter());
+            number.precision = group_number.precision;
+            number.suffix = group_number.suffix;
+            len += 2;
+        }
+
+        if len > 1 {
+            let numeric: String = digits
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+            number.value = numeric.parse::<f64>().ok()?.into();
+        }
+
+        Some((len, number))
+    }
+
     /// Searches for multiple sequential space tokens and condenses them down
     /// into one.
     fn condense_spaces(&mut self) {
@@ -460,7 +955,7 @@ impl Document {
 
         let mut initialism_start = None;
 
-        loop {
+        while cursor < self.tokens.len() {
             let a = self.tokens[cursor - 1];
             let b = self.tokens[cursor];
 
@@ -486,10 +981,69 @@ impl Document {
             }
 
             cursor += 1;
+        }
 
-            if cursor >= self.tokens.len() - 1 {
-                break;
+        // The initialism may run to the end of the document.
+        if let (Some(start), Some(last)) = (initialism_start, to_remove.back()) {
+            self.tokens[start].span.end = self.tokens[*last].span.end;
+        }
+
+        self.tokens.remove_indices(to_remove);
+    }
+
+    /// Abbreviations that come before a name, rather than after one.
+    const TITLE_ABBREVIATIONS: &'static [&'static str] = &[
+        "Dr.", "Mr.", "Mrs.", "Ms.", "Prof.", "St.", "Mt.", "Capt.", "Sgt.", "Lt.", "Gen.", "Col.",
+        "Rev.", "Hon.", "Gov.", "Sen.", "Rep.", "Fig.",
+    ];
+
+    /// Condenses abbreviations like "Dr." into single words, so their periods aren't mistaken
+    /// for the ends of sentences.
+    ///
+    /// A word and the period after it are an abbreviation if the dictionary contains them
+    /// together.
+    /// If nothing but whitespace follows in the paragraph, the period is left to end the
+    /// sentence.
+    ///
+    /// Titles like "Dr." lead into a name, so they are always condensed.
+    /// Any other abbreviation (like "Inc.") is only condensed when the next word starts with a
+    /// lowercase letter or a digit, since it could just as well be ending the sentence.
+    fn condense_abbreviations(&mut self, dictionary: &impl Dictionary) {
+        let mut to_remove = VecDeque::new();
+        let mut cursor = 0;
+
+        while cursor + 1 < self.tokens.len() {
+            let word = self.tokens[cursor];
+            let period = self.tokens[cursor + 1];
+
+            if !word.kind.is_word() || !period.kind.is_period() {
+                cursor += 1;
+                continue;
+            }
+
+            let next = self.tokens[cursor + 2..]
+                .iter()
+                .find(|t| !t.kind.is_space());
+
+            let ends_paragraph =
+                next.is_none_or(|t| t.kind.is_newline() || t.kind.is_paragraph_break());
+
+            let mut abbreviation = word.span.get_content(&self.source).to_vec();
+            abbreviation.push('.');
+
+            let continues_sentence = Self::TITLE_ABBREVIATIONS
+                .iter()
+                .any(|title| title.chars().eq(abbreviation.iter().copied()))
+                || next
+                    .and_then(|t| t.span.get_content(&self.source).first())
+                    .is_some_and(|c| c.is_lowercase() || c.is_ascii_digit());
+
+            if !ends_paragraph && continues_sentence && dictionary.contains_word(&abbreviation) {
+                self.tokens[cursor].span.end = period.span.end;
+                to_remove.push_back(cursor + 1);
             }
+
+            cursor += 2;
         }
 
         self.tokens.remove_indices(to_remove);
@@ -612,6 +1166,10 @@ impl TokenStringExt for Document {
     fn iter_sentences(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
         self.tokens.iter_sentences()
     }
+
+    fn iter_clauses(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_clauses()
+    }
 }
 
 impl Display for Document {
@@ -624,12 +1182,24 @@ impl Display for Document {
     }
 }
 
+/// Whether any line of `text` starts a fenced code block, as in Markdown.
+fn contains_code_fence(text: &[char]) -> bool {
+    text.split(|c| *c == '\n').any(|line| {
+        let line: String = line.iter().collect();
+        let line = line.trim_start();
+
+        line.starts_with("```") || line.starts_with("~~~")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
+    use quickcheck_macros::quickcheck;
 
     use super::Document;
-    use crate::{Span, parsers::MarkdownOptions};
+    use crate::parsers::{Markdown, Parser, PlainEnglish};
+    use crate::{Currency, FstDictionary, Span, TokenStringExt, parsers::MarkdownOptions};
 
     fn assert_condensed_contractions(text: &str, final_tok_count: usize) {
         let document = Document::new_plain_english_curated(text);
@@ -666,6 +1236,111 @@ mod tests {
         assert_condensed_contractions("There's no way", 5);
     }
 
+    fn sentence_count(text: &str) -> usize {
+        Document::new_plain_english_curated(text)
+            .iter_sentences()
+            .count()
+    }
+
+    #[test]
+    fn abbreviations_do_not_end_sentences() {
+        assert_eq!(
+            sentence_count("Dr. Smith went to Washington D.C. yesterday."),
+            1
+        );
+        assert_eq!(sentence_count("Acme Inc. makes widgets. They sell."), 2);
+        assert_eq!(sentence_count("It costs approx. 5 dollars."), 1);
+    }
+
+    #[test]
+    fn abbreviations_can_end_sentences() {
+        assert_eq!(sentence_count("We hired Acme Inc. They start Monday."), 2);
+        assert_eq!(sentence_count("It was signed by Smith Jr. He left."), 2);
+    }
+
+    #[test]
+    fn abbreviations_can_end_text() {
+        assert_eq!(sentence_count("It was founded by Smith Jr."), 1);
+        assert_eq!(
+            Document::new_plain_english_curated("Smith Jr.")
+                .iter_sentence_terminators()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn dialogue_attribution_continues_sentence() {
+        assert_eq!(sentence_count("\"Stop!\" she said. They left."), 2);
+        assert_eq!(sentence_count("\"Stop,\" she said."), 1);
+    }
+
+    #[test]
+    fn clauses_split_on_separators() {
+        let document =
+            Document::new_plain_english_curated("I came; I saw, I left. It rained: hard.");
+
+        let clauses: Vec<String> = document
+            .iter_clauses()
+            .map(|c| document.get_span_content_str(c.span().unwrap()))
+            .collect();
+
+        assert_eq!(
+            clauses,
+            vec!["I came;", " I saw,", " I left.", " It rained:", " hard."]
+        );
+    }
+
+    #[test]
+    fn quotations_end_at_closing_quote() {
+        let document = Document::new_plain_english_curated("He said \"go.\" Then he left.");
+
+        let sentences: Vec<String> = document
+            .iter_sentences()
+            .map(|s| document.get_span_content_str(s.span().unwrap()))
+            .collect();
+
+        assert_eq!(sentences, vec!["He said \"go.\"", " Then he left."]);
+    }
+
+    #[test]
+    fn finds_quote_depths() {
+        let document = Document::new_plain_english_curated("a \"b\" c");
+
+        assert_eq!(document.quote_depths(), vec![0, 0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn condenses_abbreviations() {
+        assert_token_count("Mrs. Robinson", 3);
+    }
+
+    #[test]
+    fn condenses_currency_amounts() {
+        assert_token_count("$1,000", 1);
+        assert_token_count("25 ¥", 1);
+        assert_token_count("50 EUR", 1);
+        assert_token_count("USD 50", 1);
+        assert_token_count("one, 2, 3", 7);
+    }
+
+    #[test]
+    fn reads_currency_amounts() {
+        let document = Document::new_plain_english_curated("It was $1,234.50 or 20 EUR.");
+        let amounts: Vec<_> = document
+            .tokens()
+            .filter_map(|t| t.kind.as_currency_amount().copied())
+            .collect();
+
+        assert_eq!(amounts.len(), 2);
+        assert_eq!(amounts[0].currency, Currency::Dollar);
+        assert_eq!(amounts[0].amount.value.0, 1234.5);
+        assert!(amounts[0].notation.is_symbol());
+        assert_eq!(amounts[1].currency, Currency::Euro);
+        assert!(amounts[1].notation.is_code());
+        assert!(amounts[1].position.is_after());
+    }
+
     #[test]
     fn selects_token_at_char_index() {
         let text = "There were three little pigs. They built three little homes.";
@@ -713,6 +1388,33 @@ mod tests {
         assert_token_count(r#"Condenses words like "i.e.", "e.g." and "N.S.A.""#, 20);
     }
 
+    #[test]
+    fn initialism_at_end_keeps_its_periods() {
+        let document = Document::new_plain_english_curated("A.");
+
+        assert_eq!(document.get_tokens().len(), 1);
+        assert_eq!(document.get_tokens()[0].span, Span::new(0, 2));
+    }
+
+    #[test]
+    fn initialisms_near_the_end_leave_no_gaps() {
+        let document = Document::new_plain_english_curated("Ask the N.S.A.");
+        let tokens = document.get_tokens();
+
+        for (a, b) in tokens.iter().zip(&tokens[1..]) {
+            assert_eq!(a.span.end, b.span.start);
+        }
+        assert_eq!(tokens.last().unwrap().span.end, document.get_source().len());
+    }
+
+    #[test]
+    fn initialism_mid_sentence_keeps_its_periods() {
+        let document = Document::new_plain_english_curated("The N.S.A. agreed.");
+        let initialism = document.get_tokens()[2];
+
+        assert_eq!(document.get_span_content_str(initialism.span), "N.S.A.");
+    }
+
     #[test]
     fn parses_ellipsis() {
         assert_token_count("...", 1);
@@ -727,4 +1429,154 @@ mod tests {
     fn parses_short_ellipsis() {
         assert_token_count("..", 1);
     }
+
+    /// Apply an edit incrementally, then check the result against a document parsed from
+    /// scratch.
+    fn assert_edit_matches_full_parse(
+        text: &str,
+        span: Span,
+        new_text: &str,
+        parser: &impl Parser,
+    ) -> Span {
+        let dict = FstDictionary::curated();
+
+        let mut document = Document::new(text, parser, &dict);
+        let new_text: Vec<_> = new_text.chars().collect();
+        let region = document.apply_edit(span, &new_text, parser, &dict);
+
+        let expected = Document::new(&document.get_full_string(), parser, &dict);
+
+        assert_eq!(document.get_tokens(), expected.get_tokens());
+
+        region
+    }
+
+    #[test]
+    fn edit_only_reparses_its_paragraph() {
+        let region = assert_edit_matches_full_parse(
+            "The first paragraph.\n\nThis is teh second one.\n\nAnd a \"third\" one.",
+            Span::new(30, 33),
+            "the",
+            &PlainEnglish,
+        );
+
+        assert_eq!(region, Span::new(22, 47));
+    }
+
+    #[test]
+    fn edit_can_insert_paragraph_breaks() {
+        assert_edit_matches_full_parse(
+            "One sentence. Another sentence.\n\nA final one.",
+            Span::new(13, 14),
+            "\n\n",
+            &PlainEnglish,
+        );
+    }
+
+    #[test]
+    fn edit_can_remove_paragraph_breaks() {
+        assert_edit_matches_full_parse(
+            "\"One\" paragraph.\n\nAnother \"one\".\n\nA final one.",
+            Span::new(15, 17),
+            " ",
+            &PlainEnglish,
+        );
+    }
+
+    #[test]
+    fn edit_at_end_of_markdown_paragraph() {
+        let region = assert_edit_matches_full_parse(
+            "# A Heading\n\nSome text here.\n\nMore text.",
+            Span::new(28, 28),
+            " And more",
+            &Markdown::default(),
+        );
+
+        assert_eq!(region, Span::new(13, 39));
+    }
+
+    #[test]
+    fn edit_inside_code_block_reparses_everything() {
+        let source = "Some text.\n\n```\nlet x = 1;\n\nlet y = 2;\n```\n\nMore text.";
+
+        let region =
+            assert_edit_matches_full_parse(source, Span::new(24, 25), "3", &Markdown::default());
+
+        assert_eq!(region, Span::new(0, source.len()));
+    }
+
+    #[test]
+    fn edit_to_list_item_reparses_everything() {
+        let source = "Intro.\n\n- First item.\n\n- Second item.\n\nOutro.";
+
+        let region =
+            assert_edit_matches_full_parse(source, Span::new(10, 15), "Last", &Markdown::default());
+
+        assert_eq!(region, Span::new(0, source.len() - 1));
+    }
+
+    #[test]
+    fn opening_a_code_fence_reparses_everything() {
+        let source = "Some text.\n\nMore text.\n\nThe end.";
+
+        let region = assert_edit_matches_full_parse(
+            source,
+            Span::new(12, 12),
+            "```\n",
+            &Markdown::default(),
+        );
+
+        assert_eq!(region, Span::new(0, source.len() + 4));
+    }
+
+    /// Fragments that are likely to change the structure of a document when inserted.
+    const EDIT_FRAGMENTS: &[&str] = &[
+        "",
+        " ",
+        "\n",
+        "\n\n",
+        "word",
+        "teh",
+        "\"",
+        ".",
+        "- ",
+        "1. ",
+        "# ",
+        "```",
+        "\n```\n",
+        "> ",
+        "`",
+        "*",
+        "[link](x)",
+    ];
+
+    #[quickcheck]
+    fn incremental_edits_match_full_parse(edits: Vec<(u8, u8, u8)>, markdown: bool) {
+        let dict = FstDictionary::curated();
+        let parser: Box<dyn Parser> = if markdown {
+            Box::new(Markdown::default())
+        } else {
+            Box::new(PlainEnglish)
+        };
+
+        let mut document = Document::new(
+            "# Title\n\nSome \"text\" here.\n\n- An item.\n- Another.\n\n```\ncode\n```\n\nThe end.",
+            &parser,
+            &dict,
+        );
+
+        for (start, len, fragment) in edits.into_iter().take(10) {
+            let source_len = document.get_source().len();
+            let start = start as usize % (source_len + 1);
+            let end = (start + len as usize % 8).min(source_len);
+            let new_text: Vec<_> = EDIT_FRAGMENTS[fragment as usize % EDIT_FRAGMENTS.len()]
+                .chars()
+                .collect();
+
+            document.apply_edit(Span::new(start, end), &new_text, &parser, &dict);
+
+            let expected = Document::new(&document.get_full_string(), &parser, &dict);
+            assert_eq!(document.get_tokens(), expected.get_tokens());
+        }
+    }
 }
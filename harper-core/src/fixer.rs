@@ -0,0 +1,313 @@
+//! Apply [`Suggestion`]s to text, many at a time.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Span;
+use crate::linting::{Confidence, Edit, Lint, Suggestion};
+
+/// The outcome of applying one or more suggestions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixResult {
+    /// The text after all accepted edits were applied.
+    pub text: Vec<char>,
+    /// Translates positions in the original text to positions in [`Self::text`].
+    pub span_map: SpanMap,
+    /// How many suggestions were applied.
+    /// Suggestions that conflicted with a more important one are skipped.
+    pub applied: usize,
+}
+
+/// A table of the edits made to a text, used to translate positions from the original text to
+/// the edited one.
+///
+/// This allows callers to keep cursors and remaining lints aligned after a fix.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpanMap {
+    /// The spans that were replaced in the original text, along with the length of their
+    /// replacements.
+    /// Sorted and non-overlapping.
+    edits: Vec<(Span, usize)>,
+}
+
+impl SpanMap {
+    /// Translate a character index in the original text.
+    ///
+    /// Indices inside a replaced region are clamped to the replacement.
+    /// Indices at the location of an insertion are moved past the inserted text.
+    pub fn map_index(&self, idx: usize) -> usize {
+        let mut shift: isize = 0;
+
+        for (span, new_len) in &self.edits {
+            if span.end <= idx {
+                shift += *new_len as isize - span.len() as isize;
+            } else if span.start < idx {
+                let new_start = (span.start as isize + shift) as usize;
+                return new_start + (idx - span.start).min(*new_len);
+            } else {
+                break;
+            }
+        }
+
+        (idx as isize + shift) as usize
+    }
+
+    /// Translate a span in the original text.
+    ///
+    /// Returns [`None`] if any of its content was changed, since the span then no longer refers
+    /// to the same text.
+    pub fn map_span(&self, span: Span) -> Option<Span> {
+        if self.edits.iter().any(|(edit, _)| edit.overlaps_with(span)) {
+            return None;
+        }
+
+        let start = self.map_index(span.start);
+        Some(Span::new(start, start + span.len()))
+    }
+
+    /// Translate a lint's span, discarding it if it was touched by an edit.
+    pub fn map_lint(&self, mut lint: Lint) -> Option<Lint> {
+        lint.span = self.map_span(lint.span)?;
+        Some(lint)
+    }
+}
+
+/// Apply a set of suggestions to `source`.
+///
+/// Each item is the lint a suggestion belongs to, along with the suggestion itself.
+/// When two suggestions conflict, the one belonging to the more important lint (see
+/// [`Lint::priority`]) wins.
+/// Ties are broken by position.
+pub fn apply_suggestions<'a>(
+    source: &[char],
+    fixes: impl IntoIterator<Item = (&'a Lint, &'a Suggestion)>,
+) -> FixResult {
+    let mut candidates: Vec<_> = fixes
+        .into_iter()
+        .map(|(lint, suggestion)| (lint.priority, lint.span, suggestion.to_edits(lint.span)))
+        .collect();
+
+    candidates.sort_by_key(|(priority, span, _)| (*priority, span.start, span.end));
+
+    let mut accepted: Vec<Edit> = Vec::new();
+    let mut applied = 0;
+
+    for (_, _, edits) in candidates {
+        // Malformed suggestions are skipped rather than allowed to corrupt the text.
+        if edits.iter().any(|e| e.span.end > source.len())
+            || edits.windows(2).any(|w| w[0].span.end > w[1].span.start)
+        {
+            continue;
+        }
+
+        if edits
+            .iter()
+            .any(|e| accepted.iter().any(|a| edits_conflict(&e.span, &a.span)))
+        {
+            continue;
+        }
+
+        accepted.extend(edits);
+        applied += 1;
+    }
+
+    accepted.sort_by_key(|e| (e.span.start, e.span.end));
+
+    let mut text = Vec::with_capacity(source.len());
+    let mut cursor = 0;
+
+    for edit in &accepted {
+        text.extend_from_slice(&source[cursor..edit.span.start]);
+        text.extend_from_slice(&edit.replacement);
+        cursor = edit.span.end;
+    }
+
+    text.extend_from_slice(&source[cursor..]);
+
+    FixResult {
+        text,
+        span_map: SpanMap {
+            edits: accepted
+                .into_iter()
+                .map(|e| (e.span, e.replacement.len()))
+                .collect(),
+        },
+        applied,
+    }
+}
+
+/// Apply the first suggestion of every lint that has one.
+pub fn fix_all(source: &[char], lints: &[Lint]) -> FixResult {
+    apply_suggestions(
+        source,
+        lints
+            .iter()
+            .filter_map(|lint| Some((lint, lint.suggestions.first()?))),
+    )
+}
+
+/// Whether a lint's fix can be applied without a human choosing it.
+///
+/// The lint must offer exactly one suggestion, and the rule that produced it must be certain of
+/// it ([`Confidence::High`]).
+pub fn is_safe_fix(lint: &Lint) -> bool {
+    matches!(lint.suggestions.as_slice(), [only] if only.confidence == Confidence::High)
+}
+
+/// Apply the suggestion of every lint that [`is_safe_fix`].
+pub fn fix_safe(source: &[char], lints: &[Lint]) -> FixResult {
+    apply_suggestions(
+        source,
+        lints
+            .iter()
+            .filter(|lint| is_safe_fix(lint))
+            .map(|lint| (lint, &lint.suggestions[0])),
+    )
+}
+
+/// Whether two edits cannot both be applied.
+///
+/// Insertions conflict with each other when they happen at the same location, since their
+/// relative order would be ambiguous.
+fn edits_conflict(a: &Span, b: &Span) -> bool {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => a.start == b.start,
+        (true, false) => b.start < a.start && a.start < b.end,
+        (false, true) => a.start < b.start && b.start < a.end,
+        (false, false) => a.overlaps_with(*b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_suggestions, fix_all, fix_safe};
+    use crate::linting::{Confidence, Edit, Lint, LintGroup, LintKind, Linter, Suggestion};
+    use crate::{Document, FstDictionary, Span};
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn fixes_everything_in_curated_group() {
+        let document =
+            Document::new_markdown_default_curated("This is an test. There is is a problem.");
+        let lints = LintGroup::new_curated(FstDictionary::curated()).lint(&document);
+
+        let result = fix_all(document.get_source(), &lints);
+
+        assert_eq!(
+            result.text.iter().collect::<String>(),
+            "This is a test. There is a problem."
+        );
+    }
+
+    #[test]
+    fn safe_fixes_skip_guesses() {
+        let source = chars("teh cat");
+
+        let spelling = Lint {
+            span: Span::new(0, 3),
+            lint_kind: LintKind::Spelling,
+            suggestions: vec![
+                Suggestion::replace_with(chars("the")).with_confidence(Confidence::Low),
+            ],
+            ..Default::default()
+        };
+        let ambiguous = Lint {
+            span: Span::new(4, 7),
+            suggestions: vec![
+                Suggestion::replace_with(chars("dog")).with_confidence(Confidence::High),
+                Suggestion::replace_with(chars("cow")).with_confidence(Confidence::High),
+            ],
+            ..Default::default()
+        };
+        let unsure = Lint {
+            span: Span::new(0, 0),
+            suggestions: vec![Suggestion::insert_before(chars("A "))],
+            ..Default::default()
+        };
+        let certain = Lint {
+            span: Span::new(3, 4),
+            suggestions: vec![
+                Suggestion::replace_with(chars("  ")).with_confidence(Confidence::High),
+            ],
+            ..Default::default()
+        };
+
+        let result = fix_safe(&source, &[spelling, ambiguous, unsure, certain]);
+
+        assert_eq!(result.text, chars("teh  cat"));
+        assert_eq!(result.applied, 1);
+    }
+
+    #[test]
+    fn more_important_lint_wins() {
+        let source = chars("teh cat");
+
+        let important = Lint {
+            span: Span::new(0, 3),
+            suggestions: vec![Suggestion::replace_with(chars("the"))],
+            priority: 1,
+            ..Default::default()
+        };
+        let unimportant = Lint {
+            span: Span::new(0, 7),
+            suggestions: vec![Suggestion::replace_with(chars("a dog"))],
+            priority: 100,
+            ..Default::default()
+        };
+
+        let result = fix_all(&source, &[unimportant, important]);
+
+        assert_eq!(result.text, chars("the cat"));
+        assert_eq!(result.applied, 1);
+    }
+
+    #[test]
+    fn maps_spans_after_edits() {
+        let source = chars("a b c d");
+
+        let lint = Lint {
+            span: Span::new(2, 3),
+            ..Default::default()
+        };
+        let sug = Suggestion::replace_with(chars("bbb"));
+
+        let result = apply_suggestions(&source, [(&lint, &sug)]);
+
+        assert_eq!(result.text, chars("a bbb c d"));
+        assert_eq!(
+            result.span_map.map_span(Span::new(0, 1)),
+            Some(Span::new(0, 1))
+        );
+        assert_eq!(
+            result.span_map.map_span(Span::new(4, 5)),
+            Some(Span::new(6, 7))
+        );
+        assert_eq!(result.span_map.map_span(Span::new(2, 3)), None);
+        assert_eq!(result.span_map.map_index(3), 5);
+    }
+
+    #[test]
+    fn insertions_at_same_point_conflict() {
+        let source = chars("a b");
+
+        let first = Lint {
+            span: Span::new(0, 1),
+            suggestions: vec![Suggestion::insert_after(vec![','])],
+            priority: 1,
+            ..Default::default()
+        };
+        let second = Lint {
+            span: Span::new(0, 1),
+            suggestions: vec![Suggestion::edits(vec![Edit::insert(1, vec![';'])])],
+            priority: 2,
+            ..Default::default()
+        };
+
+        let result = fix_all(&source, &[first, second]);
+
+        assert_eq!(result.text, chars("a, b"));
+        assert_eq!(result.span_map.map_index(1), 2);
+    }
+}
@@ -0,0 +1,601 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use hashbrown::HashMap;
+
+use crate::linting::{
+    DocumentGenre, IgnorePattern, LintGroup, LintGroupConfig, SensitiveWord, SensitiveWords,
+    SensitivityTier, SeverityConfig, SkipWords, SpellCheck, Terminology, TransitionOveruse,
+};
+use crate::{Dialect, Dictionary, LayeredDictionary, UserDictionary};
+
+/// Settings shared by all of Harper's integrations, as read from a `harper.toml` file.
+///
+/// ```toml
+/// dialect = "British"
+/// genre = "Reference"
+/// dictionaries = ["./words.txt"]
+///
+/// [rules]
+/// SpellCheck = true
+/// OxfordComma = false
+///
+/// [severities]
+/// SpellCheck = "error"
+///
+/// [thresholds]
+/// learning = 3
+/// transition_paragraphs = 3
+///
+/// [spelling]
+/// ignore_patterns = ['[A-Z]{2,}-\d+']
+/// skip_words_with_digits = true
+///
+/// [terminology]
+/// backend = ["back end", "back-end"]
+/// "Wi-Fi" = ["wifi"]
+/// API = { expansion = "application programming interface" }
+///
+/// [screening]
+/// min_tier = "moderate"
+///
+/// [screening.words]
+/// blasted = { tier = "moderate", replacements = ["dratted"] }
+/// ```
+///
+/// Every setting is optional.
+/// Files are usually found with [`Self::discover`], which layers the files of a directory, its
+/// workspace and the user over one another.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HarperConfig {
+    /// The variety of English to check against.
+    pub dialect: Option<Dialect>,
+    /// The kind of document being written.
+    /// See [`PersonalVoice`](crate::linting::PersonalVoice).
+    pub genre: Option<DocumentGenre>,
+    /// Whether each rule is enabled.
+    /// Rules that aren't listed keep their default state.
+    pub rules: LintGroupConfig,
+    /// The severity of each rule's lints.
+    pub severities: SeverityConfig,
+    pub thresholds: Thresholds,
+    pub spelling: Spelling,
+    /// Preferred spellings of project-specific terms, each mapped to the variants it replaces.
+    /// See [`Terminology`].
+    pub terminology: Terminology,
+    pub screening: Screening,
+    /// Line-separated word lists to accept, in addition to Harper's dictionary.
+    /// When loaded from a file, relative paths are resolved from the file's directory.
+    pub dictionaries: Vec<PathBuf>,
+}
+
+/// The numeric limits in a [`HarperConfig`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Thresholds {
+    /// Unknown words used at least this many times in a document are reported together.
+    /// See [`SpellCheck::with_learning_threshold`].
+    pub learning: Option<usize>,
+    /// See [`TransitionOveruse::with_max_consecutive_paragraphs`].
+    pub transition_paragraphs: Option<usize>,
+    /// See [`TransitionOveruse::with_max_per_paragraph`].
+    pub transitions_per_paragraph: Option<usize>,
+}
+
+impl Thresholds {
+    /// Override the counts of `transition_overuse` that were set.
+    pub fn layer_transition_overuse(
+        &self,
+        mut transition_overuse: TransitionOveruse,
+    ) -> TransitionOveruse {
+        if let Some(max) = self.transition_paragraphs {
+            transition_overuse = transition_overuse.with_max_consecutive_paragraphs(max);
+        }
+
+        if let Some(max) = self.transitions_per_paragraph {
+            transition_overuse = transition_overuse.with_max_per_paragraph(max);
+        }
+
+        transition_overuse
+    }
+
+    /// Merge the contents of another [`Thresholds`] into this one.
+    /// The other will be left empty after this operation.
+    fn merge_from(&mut self, other: &mut Thresholds) {
+        if let Some(learning) = other.learning.take() {
+            self.learning = Some(learning);
+        }
+
+        if let Some(max) = other.transition_paragraphs.take() {
+            self.transition_paragraphs = Some(max);
+        }
+
+        if let Some(max) = other.transitions_per_paragraph.take() {
+            self.transitions_per_paragraph = Some(max);
+        }
+    }
+}
+
+/// The settings for [`SpellCheck`] in a [`HarperConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Spelling {
+    /// Regular expressions for text that is never spell checked, like ticket IDs.
+    /// See [`SpellCheck::with_ignore_patterns`].
+    pub ignore_patterns: Vec<IgnorePattern>,
+    /// Whether to skip words containing digits.
+    /// See [`SkipWords::digits`].
+    pub skip_words_with_digits: Option<bool>,
+    /// Whether to skip words joined by underscores.
+    /// See [`SkipWords::underscores`].
+    pub skip_words_with_underscores: Option<bool>,
+    /// Whether to skip words written in more than one script.
+    /// See [`SkipWords::mixed_scripts`].
+    pub skip_mixed_script_words: Option<bool>,
+}
+
+impl Spelling {
+    /// Override the parts of `skip_words` that were set.
+    pub fn layer_skip_words(&self, mut skip_words: SkipWords) -> SkipWords {
+        if let Some(digits) = self.skip_words_with_digits {
+            skip_words.digits = digits;
+        }
+
+        if let Some(underscores) = self.skip_words_with_underscores {
+            skip_words.underscores = underscores;
+        }
+
+        if let Some(mixed_scripts) = self.skip_mixed_script_words {
+            skip_words.mixed_scripts = mixed_scripts;
+        }
+
+        skip_words
+    }
+
+    /// Merge the contents of another [`Spelling`] into this one.
+    /// The other will be left empty after this operation.
+    fn merge_from(&mut self, other: &mut Spelling) {
+        self.ignore_patterns.append(&mut other.ignore_patterns);
+
+        if let Some(digits) = other.skip_words_with_digits.take() {
+            self.skip_words_with_digits = Some(digits);
+        }
+
+        if let Some(underscores) = other.skip_words_with_underscores.take() {
+            self.skip_words_with_underscores = Some(underscores);
+        }
+
+        if let Some(mixed_scripts) = other.skip_mixed_script_words.take() {
+            self.skip_mixed_script_words = Some(mixed_scripts);
+        }
+    }
+}
+
+/// The settings for [`SensitiveWords`] in a [`HarperConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Screening {
+    /// Whether to flag the words marked in Harper's dictionary.
+    /// See [`SensitiveWords::with_builtin`].
+    pub builtin: Option<bool>,
+    /// See [`SensitiveWords::with_min_tier`].
+    pub min_tier: Option<SensitivityTier>,
+    /// Words to screen for, in addition to those in Harper's dictionary.
+    pub words: HashMap<String, SensitiveWord>,
+}
+
+impl Screening {
+    /// Create a [`SensitiveWords`] rule with these settings.
+    pub fn build_sensitive_words(&self) -> SensitiveWords {
+        let mut sensitive_words = SensitiveWords::new();
+
+        if let Some(builtin) = self.builtin {
+            sensitive_words = sensitive_words.with_builtin(builtin);
+        }
+
+        if let Some(min_tier) = self.min_tier {
+            sensitive_words = sensitive_words.with_min_tier(min_tier);
+        }
+
+        for (word, entry) in &self.words {
+            sensitive_words.add(word, entry.clone());
+        }
+
+        sensitive_words
+    }
+
+    /// Merge the contents of another [`Screening`] into this one.
+    /// The other will be left empty after this operation.
+    fn merge_from(&mut self, other: &mut Screening) {
+        if let Some(builtin) = other.builtin.take() {
+            self.builtin = Some(builtin);
+        }
+
+        if let Some(min_tier) = other.min_tier.take() {
+            self.min_tier = Some(min_tier);
+        }
+
+        self.words.extend(other.words.drain());
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HarperConfigError {
+    #[error("Unable to read {path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("Unable to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+impl HarperConfig {
+    /// The name of the file Harper looks for.
+    pub const FILE_NAME: &'static str = "harper.toml";
+
+    /// The name of the word list shared by everyone working in a workspace, which is loaded
+    /// whether or not a `harper.toml` lists it.
+    pub const WORKSPACE_DICTIONARY: &'static str = ".harper-dictionary.txt";
+
+    /// Parse the contents of a `harper.toml` file.
+    pub fn from_toml_str(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Load a `harper.toml` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HarperConfigError> {
+        let path = path.as_ref();
+
+        let contents = fs::read_to_string(path).map_err(|source| HarperConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let mut config =
+            Self::from_toml_str(&contents).map_err(|source| HarperConfigError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        if let Some(dir) = path.parent() {
+            for dictionary in &mut config.dictionaries {
+                if dictionary.is_relative() {
+                    *dictionary = dir.join(&dictionary);
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The location of the user's `harper.toml` (for example, `~/.config/harper/harper.toml` on
+    /// Linux), whose settings apply everywhere.
+    pub fn user_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("harper").join(Self::FILE_NAME))
+    }
+
+    /// Find the `harper.toml` files that apply to a directory, from the lowest to the highest
+    /// precedence: the user's, then those in each directory from the root of the workspace (the
+    /// closest directory containing `.git`) down to `dir` itself.
+    ///
+    /// If `dir` is not in a workspace, only its own file is used.
+    pub fn discover_paths(dir: impl AsRef<Path>) -> Vec<PathBuf> {
+        Self::user_path()
+            .filter(|path| path.is_file())
+            .into_iter()
+            .chain(find_in_workspace(dir, Self::FILE_NAME))
+            .collect()
+    }
+
+    /// Load and layer every `harper.toml` that applies to a directory.
+    /// See [`Self::discover_paths`] for where they are looked for.
+    ///
+    /// The workspace dictionary (see [`Self::workspace_dictionary_path`]) is included if it
+    /// exists.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Self, HarperConfigError> {
+        let dir = dir.as_ref();
+        let mut config = Self::default();
+
+        for path in Self::discover_paths(dir) {
+            config.merge_from(&mut Self::load(path)?);
+        }
+
+        let workspace_dictionary = Self::workspace_dictionary_path(dir);
+
+        if workspace_dictionary.is_file() && !config.dictionaries.contains(&workspace_dictionary) {
+            config.dictionaries.insert(0, workspace_dictionary);
+        }
+
+        Ok(config)
+    }
+
+    /// Where the workspace dictionary for a directory is kept: at the root of its workspace (the
+    /// closest directory containing `.git`), or in `dir` itself if it is not in a workspace.
+    pub fn workspace_dictionary_path(dir: impl AsRef<Path>) -> PathBuf {
+        let dir = dir.as_ref();
+
+        dir.ancestors()
+            .find(|ancestor| ancestor.join(".git").exists())
+            .unwrap_or(dir)
+            .join(Self::WORKSPACE_DICTIONARY)
+    }
+
+    /// Merge the contents of another [`HarperConfig`] into this one.
+    /// The other config will be left empty after this operation.
+    ///
+    /// Conflicting settings will be overridden by the value in the other config, while the lists
+    /// of dictionaries, ignore patterns and terms are combined.
+    pub fn merge_from(&mut self, other: &mut HarperConfig) {
+        if let Some(dialect) = other.dialect.take() {
+            self.dialect = Some(dialect);
+        }
+
+        if let Some(genre) = other.genre.take() {
+            self.genre = Some(genre);
+        }
+
+        self.rules.merge_from(&mut other.rules);
+        self.severities.merge_from(&mut other.severities);
+
+        self.thresholds.merge_from(&mut other.thresholds);
+
+        self.spelling.merge_from(&mut other.spelling);
+        self.terminology.merge_from(&mut other.terminology);
+        self.screening.merge_from(&mut other.screening);
+        self.dictionaries.append(&mut other.dictionaries);
+    }
+
+    /// Load the listed dictionaries and push them onto the top of a [`LayeredDictionary`].
+    pub fn push_dictionaries(
+        &self,
+        layered: &mut LayeredDictionary,
+    ) -> Result<(), HarperConfigError> {
+        for path in &self.dictionaries {
+            let dictionary =
+                UserDictionary::open(path).map_err(|source| HarperConfigError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+
+            layered.push_dictionary(Arc::new(dictionary));
+        }
+
+        Ok(())
+    }
+
+    /// Apply the settings for [`SpellCheck`] that were set.
+    pub fn configure_spell_check<T: Dictionary>(
+        &self,
+        mut spell_check: SpellCheck<T>,
+    ) -> SpellCheck<T> {
+        if let Some(dialect) = self.dialect {
+            spell_check = spell_check.with_dialect(dialect);
+        }
+
+        if let Some(learning) = self.thresholds.learning {
+            spell_check = spell_check.with_learning_threshold(Some(learning));
+        }
+
+        if !self.spelling.ignore_patterns.is_empty() {
+            spell_check = spell_check.with_ignore_patterns(self.spelling.ignore_patterns.clone());
+        }
+
+        spell_check =
+            spell_check.with_skip_words(self.spelling.layer_skip_words(SkipWords::default()));
+
+        spell_check
+    }
+
+    /// Layer the dialect, genre, thresholds, enabled rules, severities, terminology and screening
+    /// settings over those of a [`LintGroup`].
+    pub fn configure_lint_group(&self, group: &mut LintGroup) {
+        if let Some(dialect) = self.dialect {
+            group.set_dialect(dialect);
+        }
+
+        if self.genre.is_some() {
+            group.set_document_genre(self.genre);
+        }
+
+        group.set_transition_overuse(
+            self.thresholds
+                .layer_transition_overuse(TransitionOveruse::default()),
+        );
+
+        if !self.terminology.is_empty() {
+            group.set_terminology(self.terminology.clone());
+        }
+
+        if self.screening != Screening::default() {
+            group.set_sensitive_words(self.screening.build_sensitive_words());
+        }
+
+        group.config.merge_from(&mut self.rules.clone());
+        group
+            .severity_config
+            .merge_from(&mut self.severities.clone());
+    }
+
+    /// Create a curated [`LintGroup`] configured by this file, checking spelling against
+    /// `dictionary` and the listed dictionaries.
+    pub fn build_lint_group(
+        &self,
+        dictionary: Arc<dyn Dictionary>,
+    ) -> Result<LintGroup, HarperConfigError> {
+        let mut layered = LayeredDictionary::new().with_dictionary(dictionary);
+        self.push_dictionaries(&mut layered)?;
+        let dictionary = Arc::new(layered);
+
+        let mut group = LintGroup::new_curated(dictionary.clone())
+            .with_spell_check(self.configure_spell_check(SpellCheck::new(dictionary)));
+        self.configure_lint_group(&mut group);
+
+        Ok(group)
+    }
+}
+
+/// Find the files with a given name in each directory from the root of the workspace (the closest
+/// directory containing `.git`) down to `dir` itself.
+///
+/// If `dir` is not in a workspace, only its own file is returned.
+pub(crate) fn find_in_workspace(dir: impl AsRef<Path>, file_name: &str) -> Vec<PathBuf> {
+    let dir = dir.as_ref();
+
+    let mut found = Vec::new();
+    let mut in_workspace = false;
+
+    for ancestor in dir.ancestors() {
+        found.push(ancestor.join(file_name));
+
+        if ancestor.join(".git").exists() {
+            in_workspace = true;
+            break;
+        }
+    }
+
+    if !in_workspace {
+        found.truncate(1);
+    }
+
+    found.reverse();
+    found.retain(|path| path.is_file());
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::HarperConfig;
+    use crate::linting::{DocumentGenre, SensitivityTier, Severity};
+    use crate::{Dialect, Dictionary, FstDictionary, LayeredDictionary};
+
+    const EXAMPLE: &str = r#"
+dialect = "British"
+genre = "Academic"
+dictionaries = ["words.txt"]
+
+[rules]
+OxfordComma = false
+
+[severities]
+SpellCheck = "error"
+
+[thresholds]
+learning = 3
+transition_paragraphs = 4
+
+[spelling]
+ignore_patterns = ['0x[0-9a-f]+']
+skip_words_with_digits = true
+
+[terminology]
+backend = ["back end"]
+API = { expansion = "application programming interface" }
+
+[screening]
+min_tier = "moderate"
+
+[screening.words]
+blasted = { tier = "severe", replacements = ["dratted"] }
+"#;
+
+    #[test]
+    fn parses_example() {
+        let config = HarperConfig::from_toml_str(EXAMPLE).unwrap();
+
+        assert_eq!(config.dialect, Some(Dialect::British));
+        assert_eq!(config.genre, Some(DocumentGenre::Academic));
+        assert!(!config.rules.is_rule_enabled("OxfordComma"));
+        assert_eq!(
+            config.severities.get_rule_severity("SpellCheck"),
+            Some(Severity::Error)
+        );
+        assert_eq!(config.thresholds.learning, Some(3));
+        assert_eq!(config.thresholds.transition_paragraphs, Some(4));
+        assert_eq!(config.spelling.ignore_patterns.len(), 1);
+        assert!(config.spelling.layer_skip_words(Default::default()).digits);
+        assert_eq!(
+            config.terminology.get("backend").unwrap().variants,
+            ["back end"]
+        );
+        assert_eq!(
+            config.terminology.get("API").unwrap().expansion.as_deref(),
+            Some("application programming interface")
+        );
+        assert_eq!(config.screening.min_tier, Some(SensitivityTier::Moderate));
+        assert_eq!(
+            config.screening.words["blasted"].tier,
+            SensitivityTier::Severe
+        );
+        assert_eq!(config.dictionaries.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_ignore_patterns() {
+        assert!(HarperConfig::from_toml_str("[spelling]\nignore_patterns = ['(']").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(HarperConfig::from_toml_str("dialekt = \"British\"").is_err());
+    }
+
+    #[test]
+    fn closer_files_take_precedence() {
+        let mut base = HarperConfig::from_toml_str(EXAMPLE).unwrap();
+        let mut closer = HarperConfig::from_toml_str(
+            "dialect = \"Australian\"\ndictionaries = [\"more.txt\"]\n[rules]\nOxfordComma = true",
+        )
+        .unwrap();
+
+        base.merge_from(&mut closer);
+
+        assert_eq!(base.dialect, Some(Dialect::Australian));
+        assert!(base.rules.is_rule_enabled("OxfordComma"));
+        assert_eq!(base.thresholds.learning, Some(3));
+        assert_eq!(base.dictionaries.len(), 2);
+    }
+
+    #[test]
+    fn discovers_workspace_layers() {
+        let root = std::env::temp_dir().join(format!("harper-config-{}", std::process::id()));
+        let nested = root.join("docs");
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(&nested).unwrap();
+
+        fs::write(
+            root.join(HarperConfig::FILE_NAME),
+            "dialect = \"British\"\ndictionaries = [\"words.txt\"]",
+        )
+        .unwrap();
+        fs::write(root.join("words.txt"), "Harperific\n").unwrap();
+        fs::write(
+            root.join(HarperConfig::WORKSPACE_DICTIONARY),
+            "Workspacey\n",
+        )
+        .unwrap();
+        fs::write(
+            nested.join(HarperConfig::FILE_NAME),
+            "[rules]\nSpellCheck = false",
+        )
+        .unwrap();
+
+        let config = HarperConfig::discover(&nested).unwrap();
+        let mut dictionary = LayeredDictionary::new().with_dictionary(FstDictionary::curated());
+        config.push_dictionaries(&mut dictionary).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(config.dialect, Some(Dialect::British));
+        assert!(!config.rules.is_rule_enabled("SpellCheck"));
+        assert!(dictionary.contains_word_str("Harperific"));
+        assert!(dictionary.contains_word_str("Workspacey"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+use crate::harper_config::find_in_workspace;
+
+/// Paths that Harper should skip, as listed in `.harperignore` files.
+///
+/// The files use the same syntax as `.gitignore`, so whole directories, generated documentation
+/// and vendored content can be excluded from linting.
+/// Like `.gitignore`, patterns are relative to the file they appear in, and the files closer to a
+/// path take precedence.
+#[derive(Debug, Clone, Default)]
+pub struct HarperIgnore {
+    /// From the highest to the lowest precedence.
+    matchers: Vec<Gitignore>,
+}
+
+impl HarperIgnore {
+    /// The name of the file Harper looks for.
+    pub const FILE_NAME: &'static str = ".harperignore";
+
+    /// Load and layer every `.harperignore` from the root of the workspace (the closest
+    /// directory containing `.git`) down to `dir` itself.
+    ///
+    /// If `dir` is not in a workspace, only its own file is used.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Self, ignore::Error> {
+        let mut matchers = Vec::new();
+
+        for path in find_in_workspace(dir, Self::FILE_NAME) {
+            let mut builder = GitignoreBuilder::new(path.parent().unwrap_or(Path::new("")));
+
+            if let Some(err) = builder.add(&path) {
+                return Err(err);
+            }
+
+            matchers.push(builder.build()?);
+        }
+
+        matchers.reverse();
+
+        Ok(Self { matchers })
+    }
+
+    /// Parse the contents of a `.harperignore` file whose patterns are relative to `root`.
+    pub fn from_contents(root: impl AsRef<Path>, contents: &str) -> Result<Self, ignore::Error> {
+        let mut builder = GitignoreBuilder::new(root);
+
+        for line in contents.lines() {
+            builder.add_line(None, line)?;
+        }
+
+        Ok(Self {
+            matchers: vec![builder.build()?],
+        })
+    }
+
+    /// Whether Harper should skip a path, either because it is listed or because one of its
+    /// parent directories is.
+    pub fn is_ignored(&self, path: impl AsRef<Path>, is_dir: bool) -> bool {
+        let path = path.as_ref();
+
+        for matcher in &self.matchers {
+            if !path.starts_with(matcher.path()) {
+                continue;
+            }
+
+            let matched = matcher.matched_path_or_any_parents(path, is_dir);
+
+            if matched.is_ignore() {
+                return true;
+            }
+
+            if matched.is_whitelist() {
+                return false;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::HarperIgnore;
+
+    #[test]
+    fn ignores_listed_paths() {
+        let ignore = HarperIgnore::from_contents("/project", "vendor/\n*.generated.md").unwrap();
+
+        assert!(ignore.is_ignored("/project/vendor/README.md", false));
+        assert!(ignore.is_ignored("/project/docs/api.generated.md", false));
+        assert!(!ignore.is_ignored("/project/docs/guide.md", false));
+        assert!(!ignore.is_ignored("/elsewhere/vendor/README.md", false));
+    }
+
+    #[test]
+    fn closer_files_take_precedence() {
+        let root = std::env::temp_dir().join(format!("harper-ignore-{}", std::process::id()));
+        let nested = root.join("docs");
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(&nested).unwrap();
+
+        fs::write(root.join(HarperIgnore::FILE_NAME), "*.md\n").unwrap();
+        fs::write(nested.join(HarperIgnore::FILE_NAME), "!guide.md\n").unwrap();
+
+        let ignore = HarperIgnore::discover(&nested).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(ignore.is_ignored(root.join("README.md"), false));
+        assert!(ignore.is_ignored(nested.join("api.md"), false));
+        assert!(!ignore.is_ignored(nested.join("guide.md"), false));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::Span;
+
+/// Suppression comments written directly into a document.
+///
+/// Directives are only honored inside the comments reported by the document's parser (see
+/// [`Parser::comments`](crate::parsers::Parser::comments)), so a document can mention them in
+/// prose or inline code without effect. For example:
+///
+/// - `<!-- harper: disable SpellCheck -->` in Markdown or HTML.
+/// - `// harper: ignore-next-line` in a code comment.
+/// - `#harper.ignore` in Typst.
+///
+/// The supported commands are:
+///
+/// - `disable [Rule...]`: suppress the listed rules (or all rules, if none are listed) until a
+///   matching `enable` or the end of the document.
+/// - `enable [Rule...]`: close regions opened by `disable`. Without rule names, all open regions
+///   are closed. With rule names, only regions opened for those specific rules are closed.
+/// - `ignore-next-line [Rule...]` (or just `ignore`): suppress the listed rules on the following
+///   line.
+///
+/// The directive itself is never linted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InlineDirectives {
+    regions: Vec<SuppressedRegion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SuppressedRegion {
+    span: Span,
+    /// If empty, all rules are suppressed.
+    rules: Vec<String>,
+}
+
+impl SuppressedRegion {
+    fn applies_to(&self, rule: &str) -> bool {
+        self.rules.is_empty() || self.rules.iter().any(|r| r == rule)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Disable,
+    Enable,
+    IgnoreNextLine,
+}
+
+impl Command {
+    fn from_chars(chars: &[char]) -> Option<Self> {
+        let s: String = chars.iter().collect();
+
+        match s.as_str() {
+            "disable" => Some(Self::Disable),
+            "enable" => Some(Self::Enable),
+            "ignore-next-line" | "ignore" => Some(Self::IgnoreNextLine),
+            _ => None,
+        }
+    }
+}
+
+struct Directive {
+    command: Command,
+    rules: Vec<String>,
+    /// The span of the directive text itself, from `harper` to the end of the rule list.
+    span: Span,
+}
+
+impl InlineDirectives {
+    /// Scan the `comments` in a document's source for directives.
+    pub fn parse(source: &[char], comments: &[Span]) -> Self {
+        let mut regions = Vec::new();
+        let mut open: Vec<(usize, Vec<String>)> = Vec::new();
+
+        let lines = line_spans(source);
+
+        for (line_idx, line) in lines.iter().enumerate() {
+            // The parts of the line inside comments.
+            let commented = comments.iter().filter_map(|comment| {
+                let start = comment.start.max(line.start);
+                let end = comment.end.min(line.end);
+
+                (start < end).then(|| Span::new(start, end))
+            });
+
+            for directive in commented.flat_map(|part| parse_line(source, part)) {
+                regions.push(SuppressedRegion {
+                    span: Span::new(directive.span.start, line.end),
+                    rules: Vec::new(),
+                });
+
+                match directive.command {
+                    Command::Disable => open.push((directive.span.end, directive.rules)),
+                    Command::Enable => {
+                        let (closed, kept): (Vec<_>, Vec<_>) =
+                            open.drain(..).partition(|(_, rules)| {
+                                directive.rules.is_empty()
+                                    || (!rules.is_empty()
+                                        && rules.iter().all(|r| directive.rules.contains(r)))
+                            });
+                        open = kept;
+
+                        for (start, rules) in closed {
+                            regions.push(SuppressedRegion {
+                                span: Span::new(start, directive.span.start),
+                                rules,
+                            });
+                        }
+                    }
+                    Command::IgnoreNextLine => {
+                        if let Some(next) = lines.get(line_idx + 1) {
+                            regions.push(SuppressedRegion {
+                                span: *next,
+                                rules: directive.rules,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        for (start, rules) in open {
+            regions.push(SuppressedRegion {
+                span: Span::new(start, source.len()),
+                rules,
+            });
+        }
+
+        Self { regions }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Whether lints from the rule `rule` that begin in `span` should be hidden.
+    pub fn is_suppressed(&self, rule: &str, span: Span) -> bool {
+        self.regions
+            .iter()
+            .any(|region| region.span.contains(span.start) && region.applies_to(rule))
+    }
+}
+
+/// Split the source into lines, excluding the newline characters themselves.
+fn line_spans(source: &[char]) -> Vec<Span> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    for (idx, c) in source.iter().enumerate() {
+        if *c == '\n' {
+            lines.push(Span::new(start, idx));
+            start = idx + 1;
+        }
+    }
+
+    lines.push(Span::new(start, source.len()));
+    lines
+}
+
+fn parse_line(source: &[char], line: Span) -> Vec<Directive> {
+    const MARKER: [char; 6] = ['h', 'a', 'r', 'p', 'e', 'r'];
+
+    let mut directives = Vec::new();
+    let mut cursor = line.start;
+
+    while cursor + MARKER.len() < line.end {
+        let at_word_boundary = cursor == line.start || !source[cursor - 1].is_alphanumeric();
+
+        if !at_word_boundary || source[cursor..cursor + MARKER.len()] != MARKER {
+            cursor += 1;
+            continue;
+        }
+
+        let start = cursor;
+        cursor += MARKER.len();
+
+        if !matches!(source[cursor], ':' | '.') {
+            continue;
+        }
+        cursor += 1;
+
+        cursor = skip_while(source, cursor, line.end, |c| c == ' ' || c == '\t');
+        let command_end = skip_while(source, cursor, line.end, |c| {
+            c.is_ascii_lowercase() || c == '-'
+        });
+
+        let Some(command) = Command::from_chars(&source[cursor..command_end]) else {
+            continue;
+        };
+        cursor = command_end;
+
+        let mut rules = Vec::new();
+        let mut end = cursor;
+
+        loop {
+            cursor = skip_while(source, cursor, line.end, |c| c.is_whitespace() || c == ',');
+            let rule_end = skip_while(source, cursor, line.end, |c| {
+                c.is_ascii_alphanumeric() || c == '_'
+            });
+
+            if rule_end == cursor {
+                break;
+            }
+
+            rules.push(source[cursor..rule_end].iter().collect());
+            cursor = rule_end;
+            end = rule_end;
+        }
+
+        directives.push(Directive {
+            command,
+            rules,
+            span: Span::new(start, end),
+        });
+    }
+
+    directives
+}
+
+fn skip_while(
+    source: &[char],
+    mut cursor: usize,
+    end: usize,
+    pred: impl Fn(char) -> bool,
+) -> usize {
+    while cursor < end && pred(source[cursor]) {
+        cursor += 1;
+    }
+
+    cursor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InlineDirectives;
+    use crate::Span;
+
+    /// Parse directives, treating every line as a comment.
+    fn parse(source: &str) -> (InlineDirectives, Vec<char>) {
+        let chars: Vec<char> = source.chars().collect();
+        let comments = super::line_spans(&chars);
+
+        (InlineDirectives::parse(&chars, &comments), chars)
+    }
+
+    fn index_of(source: &[char], needle: &str) -> usize {
+        let s: String = source.iter().collect();
+        s[..s.find(needle).unwrap()].chars().count()
+    }
+
+    #[test]
+    fn no_directives() {
+        let (directives, _) = parse("This is harper: a grammar checker.");
+        assert!(directives.is_empty());
+    }
+
+    #[test]
+    fn disable_enable_region() {
+        let (directives, chars) = parse(
+            "before\n<!-- harper: disable SpellCheck -->\ninside\n<!-- harper: enable SpellCheck -->\nafter",
+        );
+
+        let inside = Span::new_with_len(index_of(&chars, "inside"), 6);
+        let before = Span::new_with_len(index_of(&chars, "before"), 6);
+        let after = Span::new_with_len(index_of(&chars, "after"), 5);
+
+        assert!(directives.is_suppressed("SpellCheck", inside));
+        assert!(!directives.is_suppressed("AnA", inside));
+        assert!(!directives.is_suppressed("SpellCheck", before));
+        assert!(!directives.is_suppressed("SpellCheck", after));
+    }
+
+    #[test]
+    fn disable_all_until_end() {
+        let (directives, chars) = parse("<!-- harper: disable -->\nanything goes");
+        let span = Span::new_with_len(index_of(&chars, "anything"), 8);
+
+        assert!(directives.is_suppressed("SpellCheck", span));
+        assert!(directives.is_suppressed("AnA", span));
+    }
+
+    #[test]
+    fn ignore_next_line_only() {
+        let (directives, chars) =
+            parse("// harper: ignore-next-line\n// first line\n// second line");
+
+        let first = Span::new_with_len(index_of(&chars, "first"), 5);
+        let second = Span::new_with_len(index_of(&chars, "second"), 6);
+
+        assert!(directives.is_suppressed("SpellCheck", first));
+        assert!(!directives.is_suppressed("SpellCheck", second));
+    }
+
+    #[test]
+    fn typst_ignore() {
+        let (directives, chars) = parse("#harper.ignore\nsome text");
+        let span = Span::new_with_len(index_of(&chars, "some"), 4);
+
+        assert!(directives.is_suppressed("SpellCheck", span));
+    }
+
+    #[test]
+    fn directive_itself_is_suppressed() {
+        let (directives, chars) = parse("// harper: ignore-next-line SpellCheck, AnA");
+        let span = Span::new_with_len(index_of(&chars, "SpellCheck"), 10);
+
+        assert!(directives.is_suppressed("SpellCheck", span));
+    }
+
+    #[test]
+    fn enable_rule_does_not_close_disable_all() {
+        let (directives, chars) =
+            parse("<!-- harper: disable -->\n<!-- harper: enable SpellCheck -->\nstill off");
+        let span = Span::new_with_len(index_of(&chars, "still"), 5);
+
+        assert!(directives.is_suppressed("SpellCheck", span));
+    }
+
+    #[test]
+    fn ignores_directives_outside_comments() {
+        let chars: Vec<char> = "Use harper: disable to turn it off.\nanything goes"
+            .chars()
+            .collect();
+        let comments = [Span::new(0, 3)];
+
+        assert!(InlineDirectives::parse(&chars, &comments).is_empty());
+        assert!(InlineDirectives::parse(&chars, &[]).is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Document, Span, linting::Lint};
+
+/// A snapshot of the lints present in a document at some point in time.
+///
+/// Lints recorded in a baseline are filtered out of later runs, which makes it possible to adopt
+/// Harper on an existing body of text without fixing every problem up front.
+///
+/// Lints are fingerprinted by the rule that produced them, their message and the text
+/// surrounding them, rather than by their position.
+/// That way, a baseline survives unrelated edits elsewhere in the document.
+/// Unlike [`IgnoredLints`](super::IgnoredLints), the fingerprints depend on neither Rust's
+/// hasher nor dictionary metadata, so a baseline can be committed to version control.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LintBaseline {
+    /// Each fingerprint, mapped to the number of lints that share it.
+    /// We use a binary map so serialized baselines diff cleanly.
+    entries: BTreeMap<String, usize>,
+}
+
+impl LintBaseline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a baseline that contains every lint in `lints`.
+    pub fn from_lints(lints: &[Lint], document: &Document) -> Self {
+        let mut baseline = Self::new();
+        baseline.add_lints(lints, document);
+        baseline
+    }
+
+    /// Record a lint in the baseline.
+    pub fn add_lint(&mut self, lint: &Lint, document: &Document) {
+        *self.entries.entry(fingerprint(lint, document)).or_default() += 1;
+    }
+
+    pub fn add_lints(&mut self, lints: &[Lint], document: &Document) {
+        for lint in lints {
+            self.add_lint(lint, document);
+        }
+    }
+
+    /// Move entries from another baseline into this one.
+    pub fn append(&mut self, other: Self) {
+        for (key, count) in other.entries {
+            *self.entries.entry(key).or_default() += count;
+        }
+    }
+
+    /// The total number of lints recorded.
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove lints that are present in the baseline from a [`Vec`].
+    ///
+    /// If a fingerprint was recorded `n` times, at most `n` matching lints are removed, so new
+    /// occurrences of an existing problem are still reported.
+    pub fn remove_baselined(&self, lints: &mut Vec<Lint>, document: &Document) {
+        let mut remaining = self.entries.clone();
+
+        lints.retain(
+            |lint| match remaining.get_mut(&fingerprint(lint, document)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            },
+        );
+    }
+}
+
+/// Produce a location-agnostic, version-stable identifier for a lint.
+fn fingerprint(lint: &Lint, document: &Document) -> String {
+    let source = document.get_source();
+
+    let window = Span::new(
+        lint.span.start.saturating_sub(2),
+        (lint.span.end + 2).min(source.len()),
+    );
+
+    let context = document
+        .token_indices_intersecting(window)
+        .into_iter()
+        .flat_map(|idx| document.get_token(idx))
+        .map(|tok| tok.span)
+        .reduce(|a, b| Span::new(a.start.min(b.start), a.end.max(b.end)))
+        .unwrap_or(lint.span);
+
+    let mut hash = Fnv1a::default();
+    hash.write_str(&lint.message);
+    hash.write_chars(context.try_get_content(source).unwrap_or_default());
+
+    let rule = lint
+        .rule
+        .clone()
+        .unwrap_or_else(|| lint.lint_kind.to_string_key());
+
+    format!("{rule}:{:016x}", hash.0)
+}
+
+/// A tiny implementation of the FNV-1a hash.
+///
+/// We can't use [`std::hash::DefaultHasher`], since its output is allowed to change between Rust
+/// releases.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv1a {
+    fn write_u32(&mut self, value: u32) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_chars(&mut self, chars: &[char]) {
+        for c in chars {
+            self.write_u32(*c as u32);
+        }
+        // Separator, so consecutive writes can't be confused with each other.
+        self.write_u32(u32::MAX);
+    }
+
+    fn write_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.write_u32(c as u32);
+        }
+        self.write_u32(u32::MAX);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LintBaseline;
+    use crate::{
+        Document, FstDictionary,
+        linting::{Lint, LintGroup, Linter},
+    };
+
+    fn lint(text: &str) -> (Document, Vec<Lint>) {
+        let document = Document::new_markdown_default_curated(text);
+        let lints = LintGroup::new_curated(FstDictionary::curated()).lint(&document);
+        (document, lints)
+    }
+
+    #[test]
+    fn removes_everything_it_was_built_from() {
+        let (document, mut lints) = lint("There is an problm in this text.");
+        assert!(!lints.is_empty());
+
+        let baseline = LintBaseline::from_lints(&lints, &document);
+        baseline.remove_baselined(&mut lints, &document);
+
+        assert!(lints.is_empty());
+    }
+
+    #[test]
+    fn survives_unrelated_edits() {
+        let (document, lints) = lint("There is a problm in this text.");
+        let baseline = LintBaseline::from_lints(&lints, &document);
+
+        let (document, mut lints) =
+            lint("Here is a brand new first sentence. There is a problm in this text.");
+        baseline.remove_baselined(&mut lints, &document);
+
+        assert!(lints.is_empty());
+    }
+
+    #[test]
+    fn reports_new_occurrences() {
+        let (document, lints) = lint("There is a problm in this text.");
+        let baseline = LintBaseline::from_lints(&lints, &document);
+
+        let (document, mut lints) =
+            lint("There is a problm in this text. There is a problm in this text.");
+        baseline.remove_baselined(&mut lints, &document);
+
+        assert_eq!(lints.len(), 1);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let (document, lints) = lint("There is an problm in this text.");
+        let baseline = LintBaseline::from_lints(&lints, &document);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        let restored: LintBaseline = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(baseline, restored);
+        assert_eq!(restored.len(), lints.len());
+    }
+}
@@ -1,15 +1,19 @@
+mod inline_directives;
+mod lint_baseline;
 mod lint_context;
 
 use std::hash::{DefaultHasher, Hash, Hasher};
 
 use hashbrown::HashSet;
+pub use inline_directives::InlineDirectives;
+pub use lint_baseline::LintBaseline;
 use lint_context::LintContext;
 use serde::{Deserialize, Serialize};
 
 use crate::{Document, linting::Lint};
 
 /// A structure that keeps track of lints that have been ignored by users.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct IgnoredLints {
     context_hashes: HashSet<u64>,
 }
@@ -48,9 +48,117 @@ pub fn is_likely_english(toks: &[Token], source: &[char], dict: &impl Dictionary
     true
 }
 
+/// Common words of languages that are often mixed with English, used to recognize them.
+///
+/// Words that are also common in English (like "a" or "no") are left out.
+const FOREIGN_STOPWORDS: [&[&str]; 6] = [
+    // Spanish
+    &[
+        "el", "la", "los", "las", "es", "está", "y", "que", "del", "con", "para", "por", "una",
+        "pero", "como", "más", "esto", "este", "esta", "muy", "también", "sí",
+    ],
+    // French
+    &[
+        "le", "les", "est", "et", "des", "du", "une", "pour", "avec", "dans", "sur", "pas", "qui",
+        "ce", "cette", "sont", "mais", "ou", "très", "vous", "nous", "je", "il",
+    ],
+    // German
+    &[
+        "der", "das", "und", "ist", "nicht", "mit", "ein", "eine", "ich", "sie", "wir", "auf",
+        "für", "von", "zu", "den", "dem", "auch", "sich", "werden", "wird",
+    ],
+    // Italian
+    &[
+        "il", "della", "di", "che", "è", "per", "sono", "gli", "uno", "non", "questo", "anche",
+        "più", "molto", "delle", "nel", "alla",
+    ],
+    // Portuguese
+    &[
+        "os", "da", "em", "um", "uma", "não", "são", "para", "com", "muito", "também", "mais",
+        "isso", "ele", "ela", "você",
+    ],
+    // Dutch
+    &[
+        "het", "een", "en", "van", "ik", "niet", "zijn", "dat", "met", "voor", "ook", "maar",
+        "wij", "jij", "naar", "deze",
+    ],
+];
+
+const ENGLISH_STOPWORDS: [&str; 30] = [
+    "the", "and", "is", "are", "was", "were", "of", "to", "in", "that", "it", "for", "with",
+    "this", "you", "we", "they", "be", "have", "has", "not", "but", "or", "on", "at", "from", "by",
+    "will", "can", "an",
+];
+
+/// How confident we can be that tokens are written in a language other than English, from
+/// `0.0` (not at all) to `1.0` (certain).
+///
+/// Unlike [`is_likely_english`], this doesn't consult a dictionary, so it doesn't mistake
+/// English full of jargon or typos for another language.
+/// Instead, it looks at the script the words are written in and at how many of them are
+/// common words of other languages.
+pub fn non_english_confidence(toks: &[Token], source: &[char]) -> f32 {
+    let mut latin_letters = 0;
+    let mut other_letters = 0;
+    let mut english_hits = 0;
+    let mut foreign_hits = [0; FOREIGN_STOPWORDS.len()];
+
+    for token in toks {
+        let content = token.span.get_content(source);
+
+        // Other scripts aren't always lexed as words.
+        for c in content.iter().filter(|c| c.is_alphabetic()) {
+            if is_latin(*c) {
+                latin_letters += 1;
+            } else {
+                other_letters += 1;
+            }
+        }
+
+        if !token.kind.is_word() {
+            continue;
+        }
+
+        let word: String = content.iter().collect::<String>().to_lowercase();
+
+        if ENGLISH_STOPWORDS.contains(&word.as_str()) {
+            english_hits += 1;
+        }
+
+        for (hits, stopwords) in foreign_hits.iter_mut().zip(FOREIGN_STOPWORDS) {
+            if stopwords.contains(&word.as_str()) {
+                *hits += 1;
+            }
+        }
+    }
+
+    let total_letters = latin_letters + other_letters;
+    if total_letters == 0 {
+        return 0.0;
+    }
+
+    let other_script = other_letters as f32 / total_letters as f32;
+    if other_script > 0.5 {
+        return other_script;
+    }
+
+    let foreign_hits = foreign_hits.into_iter().max().unwrap_or(0);
+
+    // Too little to go on.
+    if foreign_hits + english_hits < 3 {
+        return 0.0;
+    }
+
+    foreign_hits as f32 / (foreign_hits + english_hits) as f32
+}
+
+fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic() || ('\u{00C0}'..='\u{024F}').contains(&c)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::is_doc_likely_english;
+    use super::{is_doc_likely_english, non_english_confidence};
     use crate::{Document, FstDictionary};
 
     fn assert_not_english(source: &'static str) {
@@ -140,4 +248,23 @@ def fibIter(n):
     fn english_motto() {
         assert_english("I have a simple motto in life");
     }
+
+    fn confidence(source: &str) -> f32 {
+        let doc = Document::new_plain_english_curated(source);
+        non_english_confidence(doc.get_tokens(), doc.get_source())
+    }
+
+    #[test]
+    fn confident_about_other_languages() {
+        assert!(confidence("Esto es muy bueno y también está en el README.") > 0.9);
+        assert!(confidence("Das ist nicht gut, und wir werden es auch sehen.") > 0.9);
+        assert!(confidence("如果你渴了，就喝水。") > 0.9);
+    }
+
+    #[test]
+    fn not_confident_about_english() {
+        assert!(confidence("This is perfectly valid English, evn if it has a cople typos.") < 0.1);
+        assert!(confidence("Run cargo build to compile the crate.") < 0.1);
+        assert_eq!(confidence("Hola"), 0.0);
+    }
 }
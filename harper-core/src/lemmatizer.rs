@@ -0,0 +1,416 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use smallvec::{SmallVec, ToSmallVec};
+
+use crate::spell::hunspell::{
+    MarkedWord, default_word_list, parse_default_attribute_list, parse_default_word_list,
+};
+use crate::{CharString, CharStringExt, Dictionary, MutableDictionary, PosTag, WordMetadata};
+
+/// A way a word can be inflected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inflection {
+    Plural,
+    Possessive,
+    /// Present tense forms other than the lemma itself, like "runs" or "is".
+    Present,
+    PastTense,
+    PastParticiple,
+    PresentParticiple,
+    Comparative,
+    Superlative,
+}
+
+impl Inflection {
+    pub const ALL: [Inflection; 8] = [
+        Inflection::Plural,
+        Inflection::Possessive,
+        Inflection::Present,
+        Inflection::PastTense,
+        Inflection::PastParticiple,
+        Inflection::PresentParticiple,
+        Inflection::Comparative,
+        Inflection::Superlative,
+    ];
+
+    /// The part of speech of words with this inflection.
+    pub fn pos(self) -> PosTag {
+        match self {
+            Inflection::Plural | Inflection::Possessive => PosTag::Noun,
+            Inflection::Present
+            | Inflection::PastTense
+            | Inflection::PastParticiple
+            | Inflection::PresentParticiple => PosTag::Verb,
+            Inflection::Comparative | Inflection::Superlative => PosTag::Adjective,
+        }
+    }
+}
+
+/// An inflected form of a lemma.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Form {
+    lemma: CharString,
+    inflection: Inflection,
+}
+
+/// Maps inflected words (like "ran" or "cities") back to their lemmas ("run" and "city"), and
+/// lemmas to their inflections.
+///
+/// Regular inflections come from the affix rules of Harper's curated dictionary, along with
+/// the inflected forms it lists as words of their own.
+/// Irregular ones come from a separate, much shorter list.
+#[derive(Debug, Default)]
+pub struct Lemmatizer {
+    /// Keyed by the lowercase form.
+    forms: HashMap<CharString, SmallVec<[Form; 1]>>,
+    /// Keyed by the lowercase lemma, with the forms in the order they were added.
+    inflections: HashMap<CharString, Vec<(Inflection, CharString)>>,
+}
+
+/// The affix flags of the curated dictionary that inflect a word (rather than derive a new
+/// one from it, like "re-" or "-ness" do).
+const INFLECTING_FLAGS: [char; 6] = ['S', 'M', 'D', 'G', 'R', 'T'];
+
+lazy_static! {
+    static ref CURATED: Arc<Lemmatizer> = Arc::new(Lemmatizer::uncached_curated());
+}
+
+impl Lemmatizer {
+    /// A lemmatizer for the words in Harper's curated dictionary.
+    ///
+    /// Without the `embedded-dictionary` feature, this only knows irregular forms until the word
+    /// list is installed.
+    pub fn curated() -> Arc<Self> {
+        if default_word_list().is_none() {
+            return Arc::new(Self::uncached_curated());
+        }
+
+        CURATED.clone()
+    }
+
+    fn uncached_curated() -> Self {
+        let mut lemmatizer = Self::default();
+
+        // Irregular forms come first, since they are more specific.
+        for line in include_str!("../irregular_forms.txt").lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let (Some(kind), Some(lemma)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+
+            let inflections: &[Inflection] = match kind {
+                "verb" => &[Inflection::PastTense, Inflection::PastParticiple],
+                "noun" => &[Inflection::Plural],
+                "adjective" => &[Inflection::Comparative, Inflection::Superlative],
+                _ => &[],
+            };
+
+            let lemma: CharString = lemma.chars().collect();
+
+            for (index, form) in parts.enumerate() {
+                let inflection = inflections
+                    .get(index)
+                    .copied()
+                    .unwrap_or(Inflection::Present);
+
+                lemmatizer.add(&lemma, inflection, &form.chars().collect::<CharString>());
+            }
+        }
+
+        let dictionary = MutableDictionary::curated();
+        let attributes = parse_default_attribute_list();
+        let mut expanded = HashMap::new();
+
+        for word in parse_default_word_list().unwrap() {
+            let metadata = dictionary
+                .get_word_metadata(&word.letters)
+                .unwrap_or_default();
+
+            for flag in INFLECTING_FLAGS {
+                if !word.attributes.contains(&flag) {
+                    continue;
+                }
+
+                let inflections = Self::inflections_for_flag(flag, &metadata);
+                if inflections.is_empty() {
+                    continue;
+                }
+
+                expanded.clear();
+                attributes.expand_marked_word(
+                    MarkedWord {
+                        letters: word.letters.clone(),
+                        attributes: vec![flag],
+                    },
+                    &mut expanded,
+                );
+
+                for form in expanded.keys().filter(|form| **form != word.letters) {
+                    for inflection in inflections {
+                        lemmatizer.add(&word.letters, *inflection, form);
+                    }
+                }
+            }
+        }
+
+        // Some inflected forms are listed as words of their own, rather than through affixes.
+        for word in dictionary.words_iter() {
+            if lemmatizer.forms.contains_key(word.to_lower().as_ref()) {
+                continue;
+            }
+
+            let metadata = dictionary.get_word_metadata(word).unwrap_or_default();
+
+            for (inflection, lemma) in Self::guess_lemmas(word) {
+                let lemma_metadata = dictionary.get_word_metadata(&lemma);
+
+                let same_pos = |metadata: &WordMetadata| match inflection.pos() {
+                    PosTag::Noun => metadata.is_noun(),
+                    PosTag::Verb => metadata.is_verb(),
+                    _ => metadata.is_adjective(),
+                };
+
+                if lemma.len() >= 2
+                    && same_pos(&metadata)
+                    && lemma_metadata.is_some_and(|m| same_pos(&m))
+                    && dictionary.contains_exact_word(&lemma)
+                {
+                    lemmatizer.add(&lemma, inflection, word);
+                }
+            }
+        }
+
+        lemmatizer
+    }
+
+    /// Undo the regular spelling rules of English inflections, producing every lemma a word
+    /// could have.
+    /// Most of the results aren't real words.
+    fn guess_lemmas(word: &[char]) -> Vec<(Inflection, CharString)> {
+        const RULES: [(&str, &str, &[Inflection]); 12] = [
+            ("ies", "y", &[Inflection::Plural, Inflection::Present]),
+            ("es", "", &[Inflection::Plural, Inflection::Present]),
+            ("s", "", &[Inflection::Plural, Inflection::Present]),
+            ("ied", "y", &[Inflection::PastTense]),
+            ("ed", "", &[Inflection::PastTense]),
+            ("ed", "e", &[Inflection::PastTense]),
+            ("ing", "", &[Inflection::PresentParticiple]),
+            ("ing", "e", &[Inflection::PresentParticiple]),
+            ("ier", "y", &[Inflection::Comparative]),
+            ("er", "", &[Inflection::Comparative]),
+            ("iest", "y", &[Inflection::Superlative]),
+            ("est", "", &[Inflection::Superlative]),
+        ];
+
+        let text: String = word.iter().collect::<String>().to_lowercase();
+        let mut guesses = Vec::new();
+
+        for (suffix, replacement, inflections) in RULES {
+            let Some(stem) = text.strip_suffix(suffix) else {
+                continue;
+            };
+
+            // "boss" isn't the plural of "bos".
+            if suffix == "s" && stem.ends_with('s') {
+                continue;
+            }
+
+            let mut stems = vec![format!("{stem}{replacement}")];
+
+            // Final consonants are often doubled, like in "running".
+            let mut chars = stem.chars().rev();
+            if let (Some(last), Some(second_last)) = (chars.next(), chars.next())
+                && replacement.is_empty()
+                && last == second_last
+            {
+                stems.push(stem[..stem.len() - last.len_utf8()].to_string());
+            }
+
+            for stem in stems {
+                for inflection in inflections {
+                    guesses.push((*inflection, stem.chars().collect()));
+                }
+            }
+        }
+
+        guesses
+    }
+
+    /// The inflections an affix flag produces for a word with the given metadata.
+    fn inflections_for_flag(flag: char, metadata: &WordMetadata) -> &'static [Inflection] {
+        match flag {
+            'S' if metadata.is_noun() && metadata.is_verb() => {
+                &[Inflection::Plural, Inflection::Present]
+            }
+            'S' if metadata.is_verb() => &[Inflection::Present],
+            'S' => &[Inflection::Plural],
+            'M' => &[Inflection::Possessive],
+            'D' => &[Inflection::PastTense, Inflection::PastParticiple],
+            'G' => &[Inflection::PresentParticiple],
+            // These are also used to derive nouns from verbs, like "runner".
+            'R' if metadata.is_adjective() => &[Inflection::Comparative],
+            'T' if metadata.is_adjective() => &[Inflection::Superlative],
+            _ => &[],
+        }
+    }
+
+    fn add(&mut self, lemma: &[char], inflection: Inflection, form: &[char]) {
+        let entry = Form {
+            lemma: lemma.to_smallvec(),
+            inflection,
+        };
+
+        let forms = self.forms.entry(form.to_lower().to_smallvec()).or_default();
+        if !forms.contains(&entry) {
+            forms.push(entry);
+        }
+
+        let inflections = self
+            .inflections
+            .entry(lemma.to_lower().to_smallvec())
+            .or_default();
+        if !inflections
+            .iter()
+            .any(|(i, f)| *i == inflection && f.as_slice() == form)
+        {
+            inflections.push((inflection, form.to_smallvec()));
+        }
+    }
+
+    /// Find the lemma (or dictionary form) of a word, like "run" for "ran".
+    ///
+    /// Some forms belong to several lemmas, like "leaves" (of "leaf" and "leave"), so the word's
+    /// part of speech can be given to pick between them.
+    /// If nothing is known about the word (or it isn't inflected as the given part of speech),
+    /// it is returned as-is.
+    pub fn lemmatize(&self, word: &[char], pos: Option<PosTag>) -> CharString {
+        let Some(forms) = self.forms.get(word.to_lower().as_ref()) else {
+            return word.to_smallvec();
+        };
+
+        let found = match pos {
+            Some(pos) => forms.iter().find(|form| form.inflection.pos() == pos),
+            None => forms.first(),
+        };
+
+        match found {
+            Some(form) => form.lemma.clone(),
+            None => word.to_smallvec(),
+        }
+    }
+
+    /// Convenience method over [`Self::lemmatize`].
+    pub fn lemmatize_str(&self, word: &str, pos: Option<PosTag>) -> String {
+        let chars: CharString = word.chars().collect();
+        self.lemmatize(&chars, pos).iter().collect()
+    }
+
+    /// The form of a lemma with a given inflection, like "ran" for "run" in the past tense.
+    pub fn inflect(&self, lemma: &str, inflection: Inflection) -> Option<String> {
+        let lemma: CharString = lemma.to_lowercase().chars().collect();
+
+        self.inflections
+            .get(&lemma)?
+            .iter()
+            .find(|(i, _)| *i == inflection)
+            .map(|(_, form)| form.iter().collect())
+    }
+
+    /// Every form of a lemma, starting with the lemma itself, like "run", "runs", "ran" and
+    /// "running".
+    pub fn all_forms(&self, lemma: &str) -> Vec<String> {
+        let mut forms = vec![lemma.to_string()];
+        let key: CharString = lemma.to_lowercase().chars().collect();
+
+        for (_, form) in self.inflections.get(&key).into_iter().flatten() {
+            let form: String = form.iter().collect();
+
+            if !forms.contains(&form) {
+                forms.push(form);
+            }
+        }
+
+        forms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Inflection, Lemmatizer};
+    use crate::PosTag;
+
+    #[test]
+    fn regular_inflections() {
+        let lemmatizer = Lemmatizer::curated();
+
+        assert_eq!(lemmatizer.lemmatize_str("cities", None), "city");
+        assert_eq!(lemmatizer.lemmatize_str("walked", None), "walk");
+        assert_eq!(lemmatizer.lemmatize_str("Running", None), "run");
+        assert_eq!(lemmatizer.lemmatize_str("greenest", None), "green");
+    }
+
+    #[test]
+    fn irregular_inflections() {
+        let lemmatizer = Lemmatizer::curated();
+
+        assert_eq!(lemmatizer.lemmatize_str("ran", None), "run");
+        assert_eq!(lemmatizer.lemmatize_str("children", None), "child");
+        assert_eq!(lemmatizer.lemmatize_str("better", None), "good");
+        assert_eq!(lemmatizer.lemmatize_str("is", None), "be");
+    }
+
+    #[test]
+    fn pos_picks_lemma() {
+        let lemmatizer = Lemmatizer::curated();
+
+        assert_eq!(
+            lemmatizer.lemmatize_str("leaves", Some(PosTag::Noun)),
+            "leaf"
+        );
+        assert_eq!(
+            lemmatizer.lemmatize_str("leaves", Some(PosTag::Verb)),
+            "leave"
+        );
+        assert_eq!(lemmatizer.lemmatize_str("saw", Some(PosTag::Noun)), "saw");
+        assert_eq!(lemmatizer.lemmatize_str("saw", Some(PosTag::Verb)), "see");
+    }
+
+    #[test]
+    fn unknown_words_are_their_own_lemma() {
+        assert_eq!(
+            Lemmatizer::curated().lemmatize_str("zorblaxes", None),
+            "zorblaxes"
+        );
+    }
+
+    #[test]
+    fn inflects_lemmas() {
+        let lemmatizer = Lemmatizer::curated();
+
+        assert_eq!(
+            lemmatizer.inflect("run", Inflection::PastTense).as_deref(),
+            Some("ran")
+        );
+        assert_eq!(
+            lemmatizer
+                .inflect("walk", Inflection::PresentParticiple)
+                .as_deref(),
+            Some("walking")
+        );
+    }
+
+    #[test]
+    fn lists_all_forms() {
+        let forms = Lemmatizer::curated().all_forms("run");
+
+        for form in ["run", "runs", "ran", "running"] {
+            assert!(forms.iter().any(|f| f == form), "missing {form}");
+        }
+    }
+}
@@ -5,8 +5,16 @@ use super::hostname::lex_hostname;
 use crate::TokenKind;
 
 pub fn lex_email_address(source: &[char]) -> Option<FoundToken> {
-    // Location of the @ sign
-    let (at_loc, _) = source.iter().enumerate().rev().find(|(_, c)| **c == '@')?;
+    // Leading punctuation, like the brace in "{@link}" or the quote in "'user@example.com'",
+    // belongs to the surrounding text.
+    if !source
+        .first()
+        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '"' | '_'))
+    {
+        return None;
+    }
+
+    let at_loc = find_at_sign(source)?;
 
     let local_part = &source[0..at_loc];
 
@@ -26,6 +34,37 @@ pub fn lex_email_address(source: &[char]) -> Option<FoundToken> {
     })
 }
 
+/// The location of the @ sign that ends the local part of an address at the start of `source`.
+///
+/// Only the first @ sign counts, so the address doesn't run on into a later mention, like the
+/// "@octocat" in "user@example.com or @octocat".
+fn find_at_sign(source: &[char]) -> Option<usize> {
+    // Local parts are at most 64 characters long.
+    let candidates = &source[..source.len().min(65)];
+
+    if candidates.first() != Some(&'"') {
+        return candidates.iter().position(|c| *c == '@');
+    }
+
+    // Quoted local parts can contain @ signs of their own, so look after the closing quote.
+    let mut escaped = false;
+
+    for (index, c) in candidates.iter().enumerate().skip(1) {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => escaped = true,
+            '"' => return (candidates.get(index + 1) == Some(&'@')).then_some(index + 1),
+            _ => (),
+        }
+    }
+
+    None
+}
+
 /// Check to see if a given slice is a valid local part of an email address.
 fn validate_local_part(mut local_part: &[char]) -> bool {
     if local_part.len() > 64 || local_part.is_empty() {
@@ -171,6 +210,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn does_not_take_leading_punctuation() {
+        for source in ["{@link Foo}", "'user@example.com'", "(user@example.com)"] {
+            let source: Vec<_> = source.chars().collect();
+            assert!(lex_email_address(&source).is_none());
+        }
+    }
+
     /// Tests that the email parser will not throw a panic under some random
     /// situations.
     #[test]
@@ -0,0 +1,77 @@
+use super::FoundToken;
+use crate::TokenKind;
+
+/// Lex a mention of a user, like `@octocat`.
+pub fn lex_mention(source: &[char]) -> Option<FoundToken> {
+    let len = lex_tag(source, '@')?;
+
+    Some(FoundToken {
+        next_index: len,
+        token: TokenKind::Mention,
+    })
+}
+
+/// Lex a hashtag, like `#rustlang`.
+///
+/// Hashtags must start with a letter, so issue references like `#123` aren't included.
+pub fn lex_hashtag(source: &[char]) -> Option<FoundToken> {
+    let len = lex_tag(source, '#')?;
+
+    if !source[1].is_alphabetic() {
+        return None;
+    }
+
+    Some(FoundToken {
+        next_index: len,
+        token: TokenKind::Hashtag,
+    })
+}
+
+/// The length of a sigil followed by a name.
+fn lex_tag(source: &[char], sigil: char) -> Option<usize> {
+    if source.first() != Some(&sigil) {
+        return None;
+    }
+
+    let mut len = 1 + source[1..]
+        .iter()
+        .position(|c| !(c.is_alphanumeric() || matches!(c, '_' | '-')))
+        .unwrap_or(source.len() - 1);
+
+    while len > 1 && source[len - 1] == '-' {
+        len -= 1;
+    }
+
+    (len > 1 && source[1] != '-').then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lex_hashtag, lex_mention};
+    use crate::TokenKind;
+
+    fn lex(source: &str) -> Option<(usize, TokenKind)> {
+        let chars: Vec<char> = source.chars().collect();
+
+        lex_mention(&chars)
+            .or_else(|| lex_hashtag(&chars))
+            .map(|found| (found.next_index, found.token))
+    }
+
+    #[test]
+    fn lexes_mentions() {
+        assert_eq!(lex("@octo-cat, hi"), Some((9, TokenKind::Mention)));
+    }
+
+    #[test]
+    fn lexes_hashtags() {
+        assert_eq!(lex("#rust_lang!"), Some((10, TokenKind::Hashtag)));
+    }
+
+    #[test]
+    fn ignores_lone_sigils_and_numbers() {
+        assert_eq!(lex("@ home"), None);
+        assert_eq!(lex("#123"), None);
+        assert_eq!(lex("# Heading"), None);
+    }
+}
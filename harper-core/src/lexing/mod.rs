@@ -1,15 +1,19 @@
 mod email_address;
 mod hostname;
+mod mention;
+mod path;
 mod url;
 
 use hostname::lex_hostname_token;
+use mention::{lex_hashtag, lex_mention};
 use ordered_float::OrderedFloat;
+use path::lex_path;
 use url::lex_url;
 
 use self::email_address::lex_email_address;
 use crate::char_ext::CharExt;
 use crate::punctuation::{Punctuation, Quote};
-use crate::{Number, TokenKind};
+use crate::{Number, NumberKind, NumberPrefix, NumberSuffix, TokenKind};
 
 #[derive(Debug)]
 pub struct FoundToken {
@@ -19,29 +23,41 @@ pub struct FoundToken {
     pub token: TokenKind,
 }
 
-pub fn lex_token(source: &[char]) -> Option<FoundToken> {
-    let lexers = [
+/// Lex the token at the start of `source`.
+///
+/// `previous` is the character just before `source`, if there is one.
+/// Mentions and hashtags can only start at a word boundary, so the "#b" in "a#b" isn't one.
+pub fn lex_token(source: &[char], previous: Option<char>) -> Option<FoundToken> {
+    let leading_lexers = [
+        lex_url,           // Before lex_path, which would match the part after the scheme
+        lex_path,          // Before lex_punctuation, which would match an initial slash
+        lex_email_address, // Before lex_number and lex_word, which would match the local part
+        lex_hex_number,    // Before lex_number, which would match the initial 0
+        lex_long_decade,   // Before lex_number, which would match the digits up to the -s
+        lex_number,        // Before lex_punctuation, which would match a "#" prefix
+        lex_hostname_token,
+    ];
+
+    // Before lex_punctuation, which would match the sigil
+    let tag_lexers = [lex_mention, lex_hashtag];
+
+    let trailing_lexers = [
         lex_punctuation,
         lex_tabs,
         lex_spaces,
         lex_newlines,
-        lex_hex_number,  // Before lex_number, which would match the initial 0
-        lex_long_decade, // Before lex_number, which would match the digits up to the -s
-        lex_number,
-        lex_url,
-        lex_email_address,
-        lex_hostname_token,
         lex_word,
         lex_catch,
     ];
 
-    for lexer in lexers {
-        if let Some(f) = lexer(source) {
-            return Some(f);
-        }
-    }
+    let at_word_boundary = !previous.is_some_and(char::is_alphanumeric);
+    let tag_lexers: &[_] = if at_word_boundary { &tag_lexers } else { &[] };
 
-    None
+    leading_lexers
+        .iter()
+        .chain(tag_lexers)
+        .chain(&trailing_lexers)
+        .find_map(|lexer| lexer(source))
 }
 
 fn lex_word(source: &[char]) -> Option<FoundToken> {
@@ -61,36 +77,73 @@ fn lex_word(source: &[char]) -> Option<FoundToken> {
 }
 
 pub fn lex_number(source: &[char]) -> Option<FoundToken> {
-    if source.is_empty() {
-        return None;
-    }
+    let (prefix, prefix_len) = match source {
+        ['v', d, ..] if d.is_ascii_digit() => (Some(NumberPrefix::V), 1),
+        ['#', d, ..] if d.is_ascii_digit() => (Some(NumberPrefix::Hash), 1),
+        _ => (None, 0),
+    };
+
+    let rest = &source[prefix_len..];
+
+    // A "v" prefix means even two components are a version, not a decimal.
+    let min_version_components = if prefix == Some(NumberPrefix::V) {
+        2
+    } else {
+        3
+    };
+
+    let (mut number, len) = lex_version(rest, min_version_components)
+        .or_else(|| lex_decimal(rest).map(|(number, len)| extend_decimal(rest, number, len)))?;
+
+    number.prefix = prefix;
 
-    if !source[0].is_numeric() {
+    Some(FoundToken {
+        token: TokenKind::Number(number),
+        next_index: prefix_len + len,
+    })
+}
+
+/// Lex a plain decimal number, like `12` or `6.02e23`.
+fn lex_decimal(source: &[char]) -> Option<(Number, usize)> {
+    if !source.first()?.is_ascii_digit() {
         return None;
     }
 
     let end = source
         .iter()
-        .enumerate()
-        .rev()
-        .find_map(|(i, v)| v.is_ascii_digit().then_some(i))?;
+        .position(|c| !matches!(c, '0'..='9' | '.' | 'e' | 'E' | '+' | '-'))
+        .unwrap_or(source.len());
 
-    let mut s: String = source[0..end + 1].iter().collect();
+    let mut s: String = source[0..end].iter().collect();
 
     // Find the longest possible valid number
     while !s.is_empty() {
-        if let Ok(n) = s.parse::<f64>() {
-            let precision = s.chars().rev().position(|c| c == '.').unwrap_or_default();
+        // A trailing period is more likely to end a sentence.
+        if !s.ends_with('.')
+            && let Ok(n) = s.parse::<f64>()
+        {
+            let is_scientific = s.contains(['e', 'E']);
+            let precision = if is_scientific {
+                0
+            } else {
+                s.chars().rev().position(|c| c == '.').unwrap_or_default()
+            };
 
-            return Some(FoundToken {
-                token: TokenKind::Number(Number {
+            return Some((
+                Number {
                     value: n.into(),
                     suffix: None,
                     radix: 10,
                     precision,
-                }),
-                next_index: s.len(),
-            });
+                    kind: if is_scientific {
+                        NumberKind::Scientific
+                    } else {
+                        NumberKind::Plain
+                    },
+                    prefix: None,
+                },
+                s.len(),
+            ));
         }
 
         s.pop();
@@ -99,6 +152,102 @@ pub fn lex_number(source: &[char]) -> Option<FoundToken> {
     None
 }
 
+/// Include whatever follows a plain number and changes its meaning, like a percent sign,
+/// the denominator of a fraction or an ordinal suffix.
+fn extend_decimal(source: &[char], mut number: Number, len: usize) -> (Number, usize) {
+    if number.kind != NumberKind::Plain {
+        return (number, len);
+    }
+
+    let rest = &source[len..];
+    let is_integer = number.precision == 0 && number.value.0.fract() == 0.0;
+    let ends_at = |i: usize| rest.get(i).is_none_or(|c| !c.is_alphanumeric());
+
+    if rest.first() == Some(&'%') {
+        number.kind = NumberKind::Percentage;
+        return (number, len + 1);
+    }
+
+    if is_integer && rest.first() == Some(&'/') {
+        let denominator_len = rest[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let denominator: Option<u32> = rest[1..1 + denominator_len]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok();
+
+        // Dates, like 12/05/2024, aren't fractions.
+        let is_date = rest.get(1 + denominator_len) == Some(&'/');
+
+        if let Some(denominator) = denominator.filter(|d| *d != 0 && !is_date) {
+            number.value = (number.value.0 / denominator as f64).into();
+            number.kind = NumberKind::Fraction { denominator };
+            return (number, len + 1 + denominator_len);
+        }
+    }
+
+    if is_integer
+        && ends_at(2)
+        && let Some(suffix) = NumberSuffix::from_chars(rest)
+    {
+        number.suffix = Some(suffix);
+        return (number, len + 2);
+    }
+
+    (number, len)
+}
+
+/// Lex a version number with at least `min_components` components, like `1.2.3`.
+fn lex_version(source: &[char], min_components: usize) -> Option<(Number, usize)> {
+    let mut components = Vec::new();
+    let mut len = 0;
+
+    loop {
+        let digits = source[len..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            break;
+        }
+
+        components.push(
+            source[len..len + digits]
+                .iter()
+                .collect::<String>()
+                .parse::<u32>()
+                .ok()?,
+        );
+        len += digits;
+
+        if source.get(len) == Some(&'.') && source.get(len + 1).is_some_and(char::is_ascii_digit) {
+            len += 1;
+        } else {
+            break;
+        }
+    }
+
+    if components.len() < min_components {
+        return None;
+    }
+
+    Some((
+        Number {
+            value: (components[0] as f64).into(),
+            suffix: None,
+            radix: 10,
+            precision: 0,
+            kind: NumberKind::Version {
+                minor: components[1],
+                patch: components.get(2).copied(),
+            },
+            prefix: None,
+        },
+        len,
+    ))
+}
+
 pub fn lex_hex_number(source: &[char]) -> Option<FoundToken> {
     // < 3 to avoid accepting 0x alone
     if source.len() < 3 || source[0] != '0' || source[1] != 'x' || !source[2].is_ascii_hexdigit() {
@@ -132,6 +281,8 @@ pub fn lex_hex_number(source: &[char]) -> Option<FoundToken> {
                 suffix: None,
                 radix: 16,
                 precision: 0,
+                kind: NumberKind::Plain,
+                prefix: None,
             }),
             next_index: s.len() + 2,
         });
@@ -250,6 +401,74 @@ mod tests {
     use super::lex_word;
     use super::{FoundToken, TokenKind};
 
+    fn lex_number_str(source: &str) -> Option<(crate::Number, usize)> {
+        let source: Vec<_> = source.chars().collect();
+
+        lex_number(&source).map(|found| (found.token.number().unwrap(), found.next_index))
+    }
+
+    #[test]
+    fn lexes_richer_numbers() {
+        use crate::{NumberKind, NumberPrefix, NumberSuffix};
+
+        let (number, len) = lex_number_str("3/4 cup").unwrap();
+        assert_eq!(
+            (number.kind, number.value.0, len),
+            (NumberKind::Fraction { denominator: 4 }, 0.75, 3)
+        );
+
+        let (number, len) = lex_number_str("50% off").unwrap();
+        assert_eq!(
+            (number.kind, number.value.0, len),
+            (NumberKind::Percentage, 50.0, 3)
+        );
+
+        let (number, len) = lex_number_str("6.02e23 atoms").unwrap();
+        assert_eq!((number.kind, len), (NumberKind::Scientific, 7));
+
+        let (number, len) = lex_number_str("1.2.3.").unwrap();
+        assert_eq!(
+            (number.kind, len),
+            (
+                NumberKind::Version {
+                    minor: 2,
+                    patch: Some(3)
+                },
+                5
+            )
+        );
+
+        let (number, len) = lex_number_str("v2.0 is out").unwrap();
+        assert_eq!(
+            (number.prefix, number.kind, len),
+            (
+                Some(NumberPrefix::V),
+                NumberKind::Version {
+                    minor: 0,
+                    patch: None
+                },
+                4
+            )
+        );
+
+        let (number, len) = lex_number_str("#42").unwrap();
+        assert_eq!(
+            (number.prefix, number.value.0, len),
+            (Some(NumberPrefix::Hash), 42.0, 3)
+        );
+
+        let (number, len) = lex_number_str("21st century").unwrap();
+        assert_eq!((number.suffix, len), (Some(NumberSuffix::St), 4));
+    }
+
+    #[test]
+    fn leaves_dates_and_sentence_ends() {
+        assert_eq!(lex_number_str("12/05/2024").unwrap().1, 2);
+        assert_eq!(lex_number_str("It was 5.").map(|n| n.1), None);
+        assert_eq!(lex_number_str("5. Then").unwrap().1, 1);
+        assert_eq!(lex_number_str("1.5").unwrap().0.value.0, 1.5);
+    }
+
     // test various kinds of number
     #[test]
     fn lexes_0() {
@@ -357,7 +576,7 @@ mod tests {
     fn lexes_youtube_as_hostname() {
         let source: Vec<_> = "YouTube.com".chars().collect();
         assert!(matches!(
-            lex_token(&source),
+            lex_token(&source, None),
             Some(FoundToken {
                 token: TokenKind::Hostname,
                 ..
@@ -501,7 +720,7 @@ mod tests {
     fn lexes_word_before_decade() {
         let source: Vec<_> = "late 1980s".chars().collect();
         assert!(matches!(
-            lex_token(&source),
+            lex_token(&source, None),
             Some(FoundToken {
                 token: TokenKind::Word(_),
                 ..
@@ -513,7 +732,7 @@ mod tests {
     fn lexes_word_after_decade() {
         let source: Vec<_> = "1980s and".chars().collect();
         assert!(matches!(
-            lex_token(&source),
+            lex_token(&source, None),
             Some(FoundToken {
                 token: TokenKind::Decade,
                 ..
@@ -589,7 +808,7 @@ mod tests {
                 break; // Exit if we've processed the entire source
             }
 
-            let token = lex_token(&sentence[next_index..]).expect("Failed to lex token");
+            let token = lex_token(&sentence[next_index..], None).expect("Failed to lex token");
             assert_eq!(token.token, *expected_token);
             next_index += token.next_index;
         }
@@ -615,7 +834,7 @@ mod tests {
                 break; // Exit if we've processed the entire source
             }
 
-            let token = lex_token(&sentence[next_index..]).expect("Failed to lex token");
+            let token = lex_token(&sentence[next_index..], None).expect("Failed to lex token");
 
             if i < 6 {
                 assert_eq!(token.token, *expected_token);
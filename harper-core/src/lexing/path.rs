@@ -0,0 +1,114 @@
+use super::FoundToken;
+use crate::TokenKind;
+
+/// Lex a file path, like `/usr/bin/env`, `./README.md`, `~/.config`, `src/main.rs` or
+/// `C:\Users`.
+///
+/// Slashes are common in prose too (as in "and/or"), so paths without an obvious prefix must
+/// have more than one segment, or end in a file extension.
+pub fn lex_path(source: &[char]) -> Option<FoundToken> {
+    let drive_len = match source {
+        [letter, ':', '\\' | '/', ..] if letter.is_ascii_alphabetic() => 2,
+        _ => 0,
+    };
+
+    let rest = &source[drive_len..];
+    let mut len = rest
+        .iter()
+        .position(|c| !is_path_char(*c))
+        .unwrap_or(rest.len());
+
+    // Punctuation at the end probably belongs to the sentence.
+    while len > 0 && matches!(rest[len - 1], '.' | ',' | '-') {
+        len -= 1;
+    }
+
+    let path = &rest[..len];
+
+    let is_path = if drive_len > 0 {
+        path.len() > 1
+    } else if let Some(after_prefix) = strip_prefix(path) {
+        !after_prefix.is_empty()
+    } else if path.first() == Some(&'/') {
+        path[1..]
+            .split(|c| *c == '/')
+            .filter(|segment| !segment.is_empty())
+            .count()
+            >= 2
+    } else {
+        is_relative_file(path)
+    };
+
+    is_path.then_some(FoundToken {
+        next_index: drive_len + len,
+        token: TokenKind::Path,
+    })
+}
+
+fn is_path_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '\\' | '~' | '+')
+}
+
+/// The path after a prefix that can only start a path, like `./` or `~/`.
+fn strip_prefix(path: &[char]) -> Option<&[char]> {
+    ["./", "../", "~/"].into_iter().find_map(|prefix| {
+        let prefix: Vec<char> = prefix.chars().collect();
+        path.strip_prefix(prefix.as_slice())
+    })
+}
+
+/// Whether a path like `src/main.rs` leads to a file with an extension.
+fn is_relative_file(path: &[char]) -> bool {
+    let mut segments = path.split(|c| *c == '/');
+
+    let Some(file) = segments.next_back() else {
+        return false;
+    };
+
+    let has_directories = path.contains(&'/');
+    let segments_valid = path.split(|c| *c == '/').all(|s| !s.is_empty());
+
+    let has_extension = file
+        .iter()
+        .rposition(|c| *c == '.')
+        .is_some_and(|dot| dot > 0 && file.get(dot + 1).is_some_and(|c| c.is_alphabetic()));
+
+    has_directories && segments_valid && has_extension
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lex_path;
+
+    fn lexed_len(source: &str) -> Option<usize> {
+        let chars: Vec<char> = source.chars().collect();
+        lex_path(&chars).map(|found| found.next_index)
+    }
+
+    #[test]
+    fn lexes_paths() {
+        for path in [
+            "/usr/bin/env",
+            "./README.md",
+            "../lib",
+            "~/.config",
+            "src/main.rs",
+            "C:\\Users\\harper",
+        ] {
+            assert_eq!(lexed_len(path), Some(path.chars().count()), "{path}");
+        }
+    }
+
+    #[test]
+    fn leaves_trailing_period() {
+        assert_eq!(lexed_len("/usr/bin/env."), Some(12));
+    }
+
+    #[test]
+    fn ignores_slashes_in_prose() {
+        assert_eq!(lexed_len("and/or"), None);
+        assert_eq!(lexed_len("/or"), None);
+        assert_eq!(lexed_len("12/05/2024"), None);
+        assert_eq!(lexed_len("./"), None);
+    }
+}
@@ -4,20 +4,31 @@
 mod char_ext;
 mod char_string;
 mod currency;
+mod dialect;
 mod document;
 mod edit_distance;
 mod fat_token;
+pub mod fixer;
+mod harper_config;
+mod harper_ignore;
 mod ignored_lints;
 pub mod language_detection;
+mod lemmatizer;
 mod lexing;
+mod line_index;
 pub mod linting;
+mod list_item;
 mod mask;
+mod normalization;
 mod number;
+mod outline;
 pub mod parsers;
 pub mod patterns;
+mod pos_tag;
 mod punctuation;
 mod span;
 pub mod spell;
+pub mod stats;
 mod sync;
 mod title_case;
 mod token;
@@ -29,23 +40,39 @@ mod word_metadata;
 use std::collections::VecDeque;
 
 pub use char_string::{CharString, CharStringExt};
-pub use currency::Currency;
+pub use currency::{Currency, CurrencyAmount, CurrencyNotation, CurrencyPosition};
+pub use dialect::{Dialect, DialectFlags};
 pub use document::Document;
 pub use fat_token::FatToken;
-pub use ignored_lints::IgnoredLints;
+pub use harper_config::{HarperConfig, HarperConfigError, Screening, Spelling, Thresholds};
+pub use harper_ignore::HarperIgnore;
+pub use ignored_lints::{IgnoredLints, InlineDirectives, LintBaseline};
+pub use lemmatizer::{Inflection, Lemmatizer};
+pub use line_index::{LineIndex, Position, PositionEncoding};
 use linting::Lint;
+pub use list_item::{ItemNumber, ListItem};
 pub use mask::{Mask, Masker};
-pub use number::{Number, NumberSuffix};
+pub use number::{Number, NumberKind, NumberPrefix, NumberSuffix};
+pub use outline::{Heading, OutlineNode, Outliner, nest_headings};
+pub use pos_tag::{PosTag, TaggedWord};
 pub use punctuation::{Punctuation, Quote};
 pub use span::Span;
-pub use spell::{Dictionary, FstDictionary, MergedDictionary, MutableDictionary};
+pub use spell::{
+    Dictionary, DictionaryLayer, FstDictionary, KeyboardLayout, LayeredDictionary,
+    MergedDictionary, MutableDictionary, UserDictionary,
+};
 pub use sync::Lrc;
-pub use title_case::{make_title_case, make_title_case_str};
+pub use title_case::{
+    TitleCaseStyle, make_sentence_case, make_sentence_case_str, make_title_case,
+    make_title_case_str, make_title_case_str_with_style, make_title_case_with_style,
+};
 pub use token::Token;
 pub use token_kind::TokenKind;
 pub use token_string_ext::TokenStringExt;
 pub use vec_ext::VecExt;
-pub use word_metadata::{AdverbData, ConjunctionData, NounData, Tense, VerbData, WordMetadata};
+pub use word_metadata::{
+    AdverbData, ConjunctionData, NounData, Register, Tense, VerbData, WordMetadata,
+};
 
 /// A utility function that removes overlapping lints in a vector,
 /// keeping the more important ones.
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Span;
+
+/// A zero-indexed line and column in a document.
+///
+/// The unit of [`Self::column`] depends on the [`PositionEncoding`] used to create it.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// The unit used to count columns in a [`Position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PositionEncoding {
+    /// Unicode scalar values, the same unit used by [`Span`].
+    #[default]
+    Char,
+    /// UTF-8 code units (bytes), as used by Rust strings.
+    Utf8,
+    /// UTF-16 code units, as used by JavaScript strings and the Language Server Protocol.
+    Utf16,
+}
+
+/// A precomputed table for converting the char indices used by [`Span`] into line/column
+/// [`Position`]s and back again.
+///
+/// Build it once per document, then perform as many conversions as needed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineIndex {
+    /// The char index at which each line starts.
+    line_starts: Vec<usize>,
+    /// The UTF-8 offset of each char, plus one for the end of the source.
+    utf8_offsets: Vec<usize>,
+    /// The UTF-16 offset of each char, plus one for the end of the source.
+    utf16_offsets: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: &[char]) -> Self {
+        let mut line_starts = vec![0];
+        let mut utf8_offsets = Vec::with_capacity(source.len() + 1);
+        let mut utf16_offsets = Vec::with_capacity(source.len() + 1);
+
+        let mut utf8 = 0;
+        let mut utf16 = 0;
+
+        for (idx, c) in source.iter().enumerate() {
+            utf8_offsets.push(utf8);
+            utf16_offsets.push(utf16);
+
+            utf8 += c.len_utf8();
+            utf16 += c.len_utf16();
+
+            if *c == '\n' {
+                line_starts.push(idx + 1);
+            }
+        }
+
+        utf8_offsets.push(utf8);
+        utf16_offsets.push(utf16);
+
+        Self {
+            line_starts,
+            utf8_offsets,
+            utf16_offsets,
+        }
+    }
+
+    /// The number of chars in the source.
+    fn len(&self) -> usize {
+        self.utf8_offsets.len() - 1
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The offset of a char index in a given encoding, from the start of the document.
+    pub fn offset(&self, index: usize, encoding: PositionEncoding) -> usize {
+        let index = index.min(self.len());
+
+        match encoding {
+            PositionEncoding::Char => index,
+            PositionEncoding::Utf8 => self.utf8_offsets[index],
+            PositionEncoding::Utf16 => self.utf16_offsets[index],
+        }
+    }
+
+    /// Convert an offset in a given encoding, from the start of the document, back to a char
+    /// index.
+    /// The inverse of [`Self::offset`].
+    ///
+    /// Offsets past the end of the source are clamped.
+    /// Offsets that land inside a multi-unit character resolve to the start of that character.
+    pub fn index_at_offset(&self, offset: usize, encoding: PositionEncoding) -> usize {
+        let offsets = match encoding {
+            PositionEncoding::Char => return offset.min(self.len()),
+            PositionEncoding::Utf8 => &self.utf8_offsets,
+            PositionEncoding::Utf16 => &self.utf16_offsets,
+        };
+
+        offsets.partition_point(|o| *o <= offset) - 1
+    }
+
+    /// The char index and end (excluding the line break) of a line.
+    fn line_bounds(&self, line: usize) -> (usize, usize) {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map(|next| next - 1)
+            .unwrap_or(self.len());
+
+        (start, end)
+    }
+
+    /// Convert a char index to a [`Position`].
+    /// Indices past the end of the source are clamped.
+    pub fn position(&self, index: usize, encoding: PositionEncoding) -> Position {
+        let index = index.min(self.len());
+        let line = self.line_starts.partition_point(|start| *start <= index) - 1;
+        let line_start = self.line_starts[line];
+
+        Position {
+            line,
+            column: self.offset(index, encoding) - self.offset(line_start, encoding),
+        }
+    }
+
+    /// Convert a [`Position`] to a char index.
+    ///
+    /// Columns past the end of a line are clamped to the end of that line.
+    /// Lines past the end of the source are clamped to the end of the source.
+    /// Columns that land inside a multi-unit character resolve to the start of that character.
+    pub fn index(&self, position: Position, encoding: PositionEncoding) -> usize {
+        if position.line >= self.line_count() {
+            return self.len();
+        }
+
+        let (start, end) = self.line_bounds(position.line);
+        let target = self.offset(start, encoding) + position.column;
+
+        if target >= self.offset(end, encoding) {
+            return end;
+        }
+
+        let offsets = match encoding {
+            PositionEncoding::Char => return target,
+            PositionEncoding::Utf8 => &self.utf8_offsets[start..=end],
+            PositionEncoding::Utf16 => &self.utf16_offsets[start..=end],
+        };
+
+        start + offsets.partition_point(|offset| *offset <= target) - 1
+    }
+
+    /// Convert a [`Span`] to a pair of start and end [`Position`]s.
+    pub fn span_to_range(&self, span: Span, encoding: PositionEncoding) -> (Position, Position) {
+        (
+            self.position(span.start, encoding),
+            self.position(span.end, encoding),
+        )
+    }
+
+    /// Convert a pair of start and end [`Position`]s to a [`Span`].
+    pub fn range_to_span(
+        &self,
+        start: Position,
+        end: Position,
+        encoding: PositionEncoding,
+    ) -> Span {
+        Span::new(self.index(start, encoding), self.index(end, encoding))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineIndex, Position, PositionEncoding};
+    use crate::Span;
+
+    fn index(source: &str) -> LineIndex {
+        LineIndex::new(&source.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn first_line() {
+        let idx = index("Hello there.");
+
+        assert_eq!(
+            idx.position(4, PositionEncoding::Utf16),
+            Position::new(0, 4)
+        );
+        assert_eq!(idx.index(Position::new(0, 4), PositionEncoding::Utf16), 4);
+    }
+
+    #[test]
+    fn offsets_round_trip() {
+        let idx = index("A 😀 emoji.\nNext line.");
+
+        for i in 0..=21 {
+            for encoding in [
+                PositionEncoding::Char,
+                PositionEncoding::Utf8,
+                PositionEncoding::Utf16,
+            ] {
+                assert_eq!(idx.index_at_offset(idx.offset(i, encoding), encoding), i);
+            }
+        }
+
+        assert_eq!(idx.index_at_offset(3, PositionEncoding::Utf16), 2);
+        assert_eq!(idx.index_at_offset(100, PositionEncoding::Utf16), 21);
+    }
+
+    #[test]
+    fn later_lines() {
+        let idx = index("There was a man,\n his voice had timbre,\n unlike a boy.");
+
+        assert_eq!(idx.line_count(), 3);
+        assert_eq!(
+            idx.position(19, PositionEncoding::Char),
+            Position::new(1, 2)
+        );
+        assert_eq!(idx.index(Position::new(1, 2), PositionEncoding::Char), 19);
+    }
+
+    #[test]
+    fn multi_byte_columns() {
+        // The emoji is one char, two UTF-16 code units, and four bytes.
+        let idx = index("a😀b\nc");
+
+        assert_eq!(idx.position(2, PositionEncoding::Char), Position::new(0, 2));
+        assert_eq!(
+            idx.position(2, PositionEncoding::Utf16),
+            Position::new(0, 3)
+        );
+        assert_eq!(idx.position(2, PositionEncoding::Utf8), Position::new(0, 5));
+        assert_eq!(idx.position(4, PositionEncoding::Utf8), Position::new(1, 0));
+
+        assert_eq!(idx.index(Position::new(0, 3), PositionEncoding::Utf16), 2);
+        assert_eq!(idx.index(Position::new(0, 5), PositionEncoding::Utf8), 2);
+    }
+
+    #[test]
+    fn column_inside_surrogate_pair_rounds_down() {
+        let idx = index("a😀b");
+
+        assert_eq!(idx.index(Position::new(0, 2), PositionEncoding::Utf16), 1);
+    }
+
+    #[test]
+    fn clamps_out_of_range_positions() {
+        let idx = index("This is a short test\n");
+
+        assert_eq!(
+            idx.index(Position::new(0, 100), PositionEncoding::Utf16),
+            20
+        );
+        assert_eq!(idx.index(Position::new(1, 20), PositionEncoding::Utf16), 21);
+        assert_eq!(idx.index(Position::new(5, 0), PositionEncoding::Utf16), 21);
+        assert_eq!(
+            idx.position(100, PositionEncoding::Char),
+            Position::new(1, 0)
+        );
+    }
+
+    #[test]
+    fn round_trips_spans() {
+        let idx = index("One line.\nAnother líne.");
+        let span = Span::new(12, 21);
+
+        let (start, end) = idx.span_to_range(span, PositionEncoding::Utf16);
+        assert_eq!(idx.range_to_span(start, end, PositionEncoding::Utf16), span);
+    }
+}
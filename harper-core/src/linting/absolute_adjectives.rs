@@ -0,0 +1,133 @@
+use super::{Lint, LintKind, PatternLinter, RuleExample, Suggestion};
+use crate::patterns::{Pattern, SequencePattern, WordSet};
+use crate::{Token, TokenStringExt};
+
+/// Looks for absolute adjectives, like "unique" or "perfect", that are modified as if they could
+/// be had to a greater or lesser degree, as in "very unique" or "more perfect".
+///
+/// Which adjectives are absolute is decided by
+/// [`WordMetadata::is_absolute_adjective`](crate::WordMetadata::is_absolute_adjective).
+pub struct AbsoluteAdjectives {
+    pattern: Box<dyn Pattern>,
+}
+
+impl Default for AbsoluteAdjectives {
+    fn default() -> Self {
+        let pattern = SequencePattern::default()
+            .then(WordSet::new(&[
+                "very",
+                "extremely",
+                "incredibly",
+                "highly",
+                "really",
+                "rather",
+                "somewhat",
+                "fairly",
+                "slightly",
+                "more",
+                "most",
+                "less",
+                "least",
+            ]))
+            .then_whitespace()
+            .then(|tok: &Token, _source: &[char]| tok.kind.is_absolute_adjective());
+
+        Self {
+            pattern: Box::new(pattern),
+        }
+    }
+}
+
+impl PatternLinter for AbsoluteAdjectives {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Option<Lint> {
+        let span = matched_tokens.span()?;
+        let modifier = matched_tokens[0].span.get_content_string(source);
+        let adjective = matched_tokens.last()?.span.get_content(source);
+
+        // Dropping the modifier, while keeping the capitalization of the start of a sentence.
+        let suggestion =
+            Suggestion::replace_with_match_case(adjective.to_vec(), span.get_content(source));
+
+        Some(Lint {
+            span,
+            lint_kind: LintKind::WordChoice,
+            suggestions: vec![suggestion],
+            message: format!(
+                "“{}” is absolute, so it can't be made stronger or weaker with “{modifier}”.",
+                adjective.iter().collect::<String>()
+            ),
+            priority: 63,
+            ..Default::default()
+        })
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for adjectives like “unique” or “perfect”, which describe all-or-nothing qualities, being modified by words like “very” or “more”."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "It was a very unique design.",
+            "It was a unique design.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbsoluteAdjectives;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn removes_intensifiers() {
+        assert_suggestion_result(
+            "It was a very unique design.",
+            AbsoluteAdjectives::default(),
+            "It was a unique design.",
+        );
+    }
+
+    #[test]
+    fn removes_comparatives() {
+        assert_suggestion_result(
+            "We want a more perfect union.",
+            AbsoluteAdjectives::default(),
+            "We want a perfect union.",
+        );
+    }
+
+    #[test]
+    fn handles_capitalization() {
+        assert_suggestion_result(
+            "Extremely essential steps come first.",
+            AbsoluteAdjectives::default(),
+            "Essential steps come first.",
+        );
+    }
+
+    #[test]
+    fn allows_gradable_adjectives() {
+        assert_lint_count(
+            "It was a very unusual and more interesting design.",
+            AbsoluteAdjectives::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_unmodified_absolutes() {
+        assert_lint_count(
+            "It was a unique and perfect design.",
+            AbsoluteAdjectives::default(),
+            0,
+        );
+    }
+}
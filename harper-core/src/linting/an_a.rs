@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use itertools::Itertools;
 
-use crate::linting::{Lint, LintKind, Linter, Suggestion};
+use crate::linting::{Confidence, Lint, LintKind, Linter, RuleExample, Suggestion};
 use crate::{Document, TokenStringExt};
 
 #[derive(Debug, Default)]
@@ -59,12 +59,13 @@ impl Linter for AnA {
                     lints.push(Lint {
                         span: first.span,
                         lint_kind: LintKind::Miscellaneous,
-                        suggestions: vec![Suggestion::replace_with_match_case(
-                            replacement,
-                            chars_first,
-                        )],
+                        suggestions: vec![
+                            Suggestion::replace_with_match_case(replacement, chars_first)
+                                .with_confidence(Confidence::High),
+                        ],
                         message: "Incorrect indefinite article.".to_string(),
                         priority: 31,
+                        ..Default::default()
                     })
                 }
             }
@@ -76,6 +77,17 @@ impl Linter for AnA {
     fn description(&self) -> &'static str {
         "A rule that looks for incorrect indefinite articles. For example, `this is an mule` would be flagged as incorrect."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "Here is a HTML document.",
+            "Here is an HTML document.",
+        )]
+    }
 }
 
 fn to_lower_word(word: &[char]) -> Cow<'_, [char]> {
@@ -0,0 +1,232 @@
+use aho_corasick::AhoCorasick;
+use hashbrown::{HashMap, HashSet};
+
+use super::Linter;
+use crate::{CharString, Document, Span};
+
+/// Finds the rules in a [`LintGroup`](super::LintGroup) that might produce lints for a document,
+/// by searching for the [anchor words](Linter::anchor_words) of every rule at once with an
+/// Aho–Corasick automaton.
+///
+/// Rules without anchor words always run.
+pub(crate) struct AnchorFilter {
+    automaton: AhoCorasick,
+    /// For each of the automaton's patterns, the indices of the rules it anchors.
+    pattern_rules: Vec<Vec<usize>>,
+    /// The index of each rule that has anchor words.
+    rule_indices: HashMap<String, usize>,
+    /// Every rule whose anchor words were asked for, whether or not it had any.
+    considered: HashSet<String>,
+}
+
+impl AnchorFilter {
+    /// Build a filter from the anchor words of the provided rules.
+    ///
+    /// Returns `None` if no rule has anchor words, or if they can't be searched for.
+    pub fn new<'a>(rules: impl IntoIterator<Item = (&'a str, &'a dyn Linter)>) -> Option<Self> {
+        let mut words: Vec<String> = Vec::new();
+        let mut word_indices: HashMap<String, usize> = HashMap::new();
+        let mut pattern_rules: Vec<Vec<usize>> = Vec::new();
+        let mut rule_indices = HashMap::new();
+        let mut considered = HashSet::new();
+
+        for (rule, linter) in rules {
+            considered.insert(rule.to_string());
+
+            let Some(anchors) = linter.anchor_words() else {
+                continue;
+            };
+
+            let rule_index = rule_indices.len();
+            rule_indices.insert(rule.to_string(), rule_index);
+
+            for anchor in anchors.iter().filter(|anchor| !anchor.is_empty()) {
+                let word = normalize(anchor);
+
+                let pattern = *word_indices.entry(word.clone()).or_insert_with(|| {
+                    words.push(word);
+                    pattern_rules.push(Vec::new());
+                    pattern_rules.len() - 1
+                });
+
+                if pattern_rules[pattern].last() != Some(&rule_index) {
+                    pattern_rules[pattern].push(rule_index);
+                }
+            }
+        }
+
+        if rule_indices.is_empty() {
+            return None;
+        }
+
+        let automaton = AhoCorasick::builder()
+            .ascii_case_insensitive(true)
+            .build(&words)
+            .ok()?;
+
+        Some(Self {
+            automaton,
+            pattern_rules,
+            rule_indices,
+            considered,
+        })
+    }
+
+    /// Whether the anchor words of a rule were asked for when building the filter.
+    pub fn has_considered(&self, rule: &str) -> bool {
+        self.considered.contains(rule)
+    }
+
+    /// Search a document for anchor words.
+    pub fn search(&self, document: &Document) -> AnchorHits<'_> {
+        let source = document.get_source();
+
+        // The automaton searches bytes, so we need to know where each character starts.
+        let mut text = String::with_capacity(source.len());
+        let mut offsets = Vec::with_capacity(source.len() + 1);
+
+        for c in source {
+            offsets.push(text.len());
+            text.push(*c);
+        }
+        offsets.push(text.len());
+
+        // The byte range of each word token, in order, along with where it starts in chars.
+        let words: Vec<(usize, usize, usize)> = document
+            .get_tokens()
+            .iter()
+            .filter(|token| token.kind.is_word())
+            .map(|token| {
+                (
+                    offsets[token.span.start],
+                    offsets[token.span.end],
+                    token.span.start,
+                )
+            })
+            .collect();
+
+        let mut found_at = vec![Vec::new(); self.rule_indices.len()];
+
+        for found in self.automaton.find_overlapping_iter(&text) {
+            let Ok(index) = words.binary_search_by_key(&found.start(), |(start, ..)| *start) else {
+                continue;
+            };
+
+            let (_, end, char_start) = words[index];
+
+            if found.end() > end {
+                continue;
+            }
+
+            for rule in &self.pattern_rules[found.pattern().as_usize()] {
+                found_at[*rule].push(char_start);
+            }
+        }
+
+        AnchorHits {
+            filter: self,
+            found_at,
+        }
+    }
+}
+
+/// The rules whose anchor words were found by [`AnchorFilter::search`].
+pub(crate) struct AnchorHits<'a> {
+    filter: &'a AnchorFilter,
+    /// Where the anchor words of each rule were found, by index, as the char offsets of the
+    /// words they start.
+    found_at: Vec<Vec<usize>>,
+}
+
+impl AnchorHits<'_> {
+    /// Whether a rule might produce lints for the document that was searched.
+    pub fn may_match(&self, rule: &str) -> bool {
+        match self.filter.rule_indices.get(rule) {
+            Some(index) => !self.found_at[*index].is_empty(),
+            None => true,
+        }
+    }
+
+    /// Whether a rule might produce lints within a span of the document that was searched.
+    ///
+    /// Rules only match near their anchor words, so a span without any can be skipped.
+    pub fn may_match_within(&self, rule: &str, span: Span) -> bool {
+        match self.filter.rule_indices.get(rule) {
+            Some(index) => self.found_at[*index].iter().any(|at| span.contains(*at)),
+            None => true,
+        }
+    }
+
+    /// Whether a rule has anchor words, so it only needs to run near them.
+    pub fn is_anchored(&self, rule: &str) -> bool {
+        self.filter.rule_indices.contains_key(rule)
+    }
+}
+
+/// Lowercase a word the same way the automaton ignores case, so duplicates can be merged.
+fn normalize(word: &CharString) -> String {
+    word.iter().map(|c| c.to_ascii_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnchorFilter;
+    use crate::linting::{Linter, MapPhraseLinter, RepeatedWords};
+    use crate::{Document, Span};
+
+    fn phrase(phrase: &str) -> MapPhraseLinter {
+        MapPhraseLinter::new_exact_phrase(phrase, ["correct"], "", "")
+    }
+
+    #[test]
+    fn finds_rules_with_anchor_words_present() {
+        let tact = phrase("change tact");
+        let art = phrase("state of art");
+        let repeated = RepeatedWords::default();
+
+        let rules: Vec<(&str, &dyn Linter)> =
+            vec![("Tact", &tact), ("Art", &art), ("Repeated", &repeated)];
+        let filter = AnchorFilter::new(rules).unwrap();
+
+        let document = Document::new_plain_english_curated("Time to CHANGE TACT.");
+        let hits = filter.search(&document);
+
+        assert!(hits.may_match("Tact"));
+        assert!(!hits.may_match("Art"));
+        assert!(hits.may_match("Repeated"));
+        assert!(hits.may_match("Unknown"));
+    }
+
+    #[test]
+    fn finds_spans_with_anchor_words() {
+        let tact = phrase("change tact");
+        let filter = AnchorFilter::new([("Tact", &tact as &dyn Linter)]).unwrap();
+
+        let document = Document::new_plain_english_curated("One thing.\n\nTime to change tact.");
+        let hits = filter.search(&document);
+
+        assert!(hits.is_anchored("Tact"));
+        assert!(!hits.may_match_within("Tact", Span::new(0, 10)));
+        assert!(hits.may_match_within("Tact", Span::new(12, 32)));
+    }
+
+    #[test]
+    fn ignores_anchor_words_inside_other_words() {
+        let art = phrase("state of art");
+        let filter = AnchorFilter::new([("Art", &art as &dyn Linter)]).unwrap();
+
+        let document = Document::new_plain_english_curated("Upstate, the art is nice.");
+
+        assert!(!filter.search(&document).may_match("Art"));
+    }
+
+    #[test]
+    fn finds_anchor_words_before_possessives() {
+        let art = phrase("state of art");
+        let filter = AnchorFilter::new([("Art", &art as &dyn Linter)]).unwrap();
+
+        let document = Document::new_plain_english_curated("The state's art.");
+
+        assert!(filter.search(&document).may_match("Art"));
+    }
+}
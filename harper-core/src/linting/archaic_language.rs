@@ -0,0 +1,112 @@
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, Register};
+
+/// Flags words that have fallen out of everyday use, like "thou" or "betwixt".
+///
+/// Which words are affected is decided by [`crate::WordMetadata::register`].
+#[derive(Debug, Default)]
+pub struct ArchaicLanguage;
+
+/// Modern replacements for archaic words, where there is an obvious one.
+fn modern_alternative(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "thee" | "thou" => "you",
+        "thy" => "your",
+        "thine" => "yours",
+        "ere" => "before",
+        "hither" => "here",
+        "thither" => "there",
+        "wherefore" => "why",
+        "betwixt" => "between",
+        "forsooth" => "indeed",
+        "verily" => "truly",
+        "methinks" => "I think",
+        "whilom" => "formerly",
+        "prithee" => "please",
+        "shalt" => "shall",
+        "dost" => "do",
+        "canst" => "can",
+        _ => return None,
+    })
+}
+
+impl Linter for ArchaicLanguage {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        // Quotations, like those from old texts, are left as they were written.
+        let depths = document.quote_depths();
+
+        document
+            .get_tokens()
+            .iter()
+            .zip(depths)
+            .filter(|(t, depth)| *depth == 0 && t.kind.is_word())
+            .map(|(t, _)| t)
+            .filter(|t| t.kind.register() == Some(Register::Archaic))
+            .map(|t| {
+                let word = document.get_span_content_str(t.span);
+
+                let suggestions = modern_alternative(&word.to_lowercase())
+                    .map(|alt| {
+                        let mut alt: Vec<char> = alt.chars().collect();
+
+                        if word.chars().next().is_some_and(char::is_uppercase) {
+                            alt[0] = alt[0].to_ascii_uppercase();
+                        }
+
+                        Suggestion::replace_with(alt)
+                    })
+                    .into_iter()
+                    .collect();
+
+                Lint {
+                    span: t.span,
+                    lint_kind: LintKind::Style,
+                    suggestions,
+                    message: format!("“{word}” is archaic. Consider a more modern word."),
+                    priority: 127,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for words that have fallen out of everyday use, like `thou` or `betwixt`."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "The gap betwixt the two releases was short.",
+            "The gap between the two releases was short.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArchaicLanguage;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn modernizes_betwixt() {
+        assert_suggestion_result(
+            "The gap betwixt the two releases was short.",
+            ArchaicLanguage,
+            "The gap between the two releases was short.",
+        );
+    }
+
+    #[test]
+    fn capitalizes_replacements() {
+        assert_suggestion_result("Thou art late.", ArchaicLanguage, "You art late.");
+    }
+
+    #[test]
+    fn allows_modern_text() {
+        assert_lint_count("You are late.", ArchaicLanguage, 0);
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Lint, LintKind, Linter};
-use crate::{Document, TokenStringExt};
+use crate::{Document, Register, TokenStringExt};
 
 #[derive(Debug, Default)]
 pub struct AvoidCurses;
@@ -8,13 +8,14 @@ impl Linter for AvoidCurses {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
         document
             .iter_words()
-            .filter(|t| t.kind.is_swear())
+            .filter(|t| t.kind.is_swear() || t.kind.register() == Some(Register::Offensive))
             .map(|t| Lint {
                 span: t.span,
                 lint_kind: LintKind::Miscellaneous,
                 suggestions: vec![],
                 message: "Try to avoid offensive language.".to_string(),
                 priority: 63,
+                ..Default::default()
             })
             .collect()
     }
@@ -22,6 +23,10 @@ impl Linter for AvoidCurses {
     fn description(&self) -> &'static str {
         "A rule that looks for common offensive language."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +38,9 @@ mod tests {
     fn detects_shit() {
         assert_lint_count("He ate shit when he fell off the bike.", AvoidCurses, 1);
     }
+
+    #[test]
+    fn detects_slurs() {
+        assert_lint_count("The vendor tried to gyp us.", AvoidCurses, 1);
+    }
 }
@@ -53,12 +53,17 @@ impl PatternLinter for BackInTheDay {
             )],
             message: "Use the more idiomatic version of this phrase.".to_owned(),
             priority: 127,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "This linter flags instances of the nonstandard phrase `back in the days`. The correct, more accepted form is `back in the day`"
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
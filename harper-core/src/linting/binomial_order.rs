@@ -0,0 +1,116 @@
+use crate::linting::{LintGroup, LintKind};
+
+use super::MapPhraseLinter;
+
+/// Produce a [`LintGroup`] that looks for binomials (fixed pairs of words, like "back and forth")
+/// written in the reverse of their conventional order.
+/// Native speakers expect these pairs in a set order, so reversed forms read as mistakes.
+pub fn lint_group() -> LintGroup {
+    let mut group = LintGroup::empty();
+
+    macro_rules! add_binomial_mappings {
+        ($group:expr, { $($name:expr => ($reversed:expr, $conventional:expr)),+ $(,)? }) => {
+            $(
+                $group.add_lazy($name, || {
+                    MapPhraseLinter::new_exact_phrase(
+                        $reversed,
+                        [$conventional],
+                        format!("This pair is usually written `{}`.", $conventional),
+                        format!(
+                            "Looks for `{}`, which reads as a mistake for the conventional `{}`.",
+                            $reversed, $conventional
+                        ),
+                    )
+                    .with_lint_kind(LintKind::WordChoice)
+                });
+            )+
+        };
+    }
+
+    // The first column is the name of the rule (which shows up in settings).
+    // The second column is the reversed form of the pair and the third column is its
+    // conventional order.
+    add_binomial_mappings!(group, {
+        "BackAndForth"      => ("forth and back", "back and forth"),
+        "BitsAndPieces"     => ("pieces and bits", "bits and pieces"),
+        "BlackAndWhite"     => ("white and black", "black and white"),
+        "BreadAndButter"    => ("butter and bread", "bread and butter"),
+        "ByAndLarge"        => ("large and by", "by and large"),
+        "FewAndFarBetween"  => ("far and few between", "few and far between"),
+        "GiveAndTake"       => ("take and give", "give and take"),
+        "HitOrMiss"         => ("miss or hit", "hit or miss"),
+        "LadiesAndGentlemen" => ("gentlemen and ladies", "ladies and gentlemen"),
+        "LawAndOrder"       => ("order and law", "law and order"),
+        "LoudAndClear"      => ("clear and loud", "loud and clear"),
+        "MoreOrLess"        => ("less or more", "more or less"),
+        "NutsAndBolts"      => ("bolts and nuts", "nuts and bolts"),
+        "OddsAndEnds"       => ("ends and odds", "odds and ends"),
+        "PeaceAndQuiet"     => ("quiet and peace", "peace and quiet"),
+        "ProsAndCons"       => ("cons and pros", "pros and cons"),
+        "RankAndFile"       => ("file and rank", "rank and file"),
+        "SafeAndSound"      => ("sound and safe", "safe and sound"),
+        "SaltAndPepper"     => ("pepper and salt", "salt and pepper"),
+        "ShortAndSweet"     => ("sweet and short", "short and sweet"),
+        "SoonerOrLater"     => ("later or sooner", "sooner or later"),
+        "TrialAndError"     => ("error and trial", "trial and error"),
+        "TriedAndTrue"      => ("true and tried", "tried and true"),
+        "UpsAndDowns"       => ("downs and ups", "ups and downs"),
+        "WearAndTear"       => ("tear and wear", "wear and tear"),
+    });
+
+    group.set_all_rules_to(Some(true));
+
+    group
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    use super::lint_group;
+
+    #[test]
+    fn forth_and_back() {
+        assert_suggestion_result(
+            "The dog ran forth and back across the yard.",
+            lint_group(),
+            "The dog ran back and forth across the yard.",
+        );
+    }
+
+    #[test]
+    fn pepper_and_salt() {
+        assert_suggestion_result(
+            "Season it with pepper and salt.",
+            lint_group(),
+            "Season it with salt and pepper.",
+        );
+    }
+
+    #[test]
+    fn white_and_black() {
+        assert_suggestion_result(
+            "White and black photos look timeless.",
+            lint_group(),
+            "Black and white photos look timeless.",
+        );
+    }
+
+    #[test]
+    fn far_and_few_between() {
+        assert_suggestion_result(
+            "Good answers are far and few between.",
+            lint_group(),
+            "Good answers are few and far between.",
+        );
+    }
+
+    #[test]
+    fn allows_conventional_order() {
+        assert_lint_count(
+            "We went back and forth on the pros and cons, more or less.",
+            lint_group(),
+            0,
+        );
+    }
+}
@@ -42,10 +42,15 @@ impl PatternLinter for BoringWords {
                 matched_word
             ),
             priority: 127,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "This rule looks for particularly boring or overused words. Using varied language is an easy way to keep a reader's attention."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Enhancement)
+    }
 }
@@ -0,0 +1,113 @@
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Dictionary, Document, TokenStringExt};
+
+/// Flags words whose capitalization is fixed (like "iOS", "npm" or "GitHub") but have been
+/// written differently, wherever they appear.
+///
+/// Which words are affected is decided by [`crate::WordMetadata::case_sensitive`].
+pub struct CanonicalCasing<T>
+where
+    T: Dictionary,
+{
+    dictionary: T,
+}
+
+impl<T: Dictionary> CanonicalCasing<T> {
+    pub fn new(dictionary: T) -> Self {
+        Self { dictionary }
+    }
+}
+
+impl<T: Dictionary> Linter for CanonicalCasing<T> {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for word in document.iter_words() {
+            let text = document.get_span_content(word.span);
+
+            let Some(canonical) = self.dictionary.get_correct_capitalization_of(text) else {
+                continue;
+            };
+
+            if canonical == text
+                || !self
+                    .dictionary
+                    .get_word_metadata(canonical)
+                    .is_some_and(|metadata| metadata.case_sensitive)
+            {
+                continue;
+            }
+
+            let canonical_str: String = canonical.iter().collect();
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Capitalization,
+                suggestions: vec![Suggestion::replace_with(canonical.to_vec())],
+                message: format!("This word is always written as “{canonical_str}”."),
+                priority: 31,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Some names, like `iOS`, `npm` and `GitHub`, are always written with the same capitalization, even at the start of a sentence."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Capitalization)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "Install it with NPM on your Iphone.",
+            "Install it with npm on your iPhone.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CanonicalCasing;
+    use crate::FstDictionary;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn fixes_mixed_case_names() {
+        assert_suggestion_result(
+            "I pushed it to Github from my IOS device.",
+            CanonicalCasing::new(FstDictionary::curated()),
+            "I pushed it to GitHub from my iOS device.",
+        );
+    }
+
+    #[test]
+    fn keeps_lowercase_names_lowercase() {
+        assert_suggestion_result(
+            "Npm installs packages.",
+            CanonicalCasing::new(FstDictionary::curated()),
+            "npm installs packages.",
+        );
+    }
+
+    #[test]
+    fn allows_correct_casing() {
+        assert_lint_count(
+            "npm, iOS and JavaScript are all spelled correctly here.",
+            CanonicalCasing::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_ordinary_words() {
+        assert_lint_count(
+            "The MARCH began in March.",
+            CanonicalCasing::new(FstDictionary::curated()),
+            0,
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, Punctuation, Token, TokenKind, TokenStringExt};
+
+/// A style guide's rule for whether the first word after a colon is capitalized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColonCapitalizationStyle {
+    /// The Chicago Manual of Style.
+    /// The first word after a colon is lowercase unless it is a proper noun.
+    #[default]
+    Chicago,
+    /// The Associated Press Stylebook.
+    /// The first word after a colon is capitalized if it starts a complete sentence, and
+    /// lowercase otherwise.
+    Ap,
+}
+
+/// Checks the capitalization of the first word after a colon against a
+/// [`ColonCapitalizationStyle`].
+///
+/// Whether a colon introduces a complete sentence or a list is decided heuristically, so this is
+/// off by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CapitalizationAfterColon {
+    style: ColonCapitalizationStyle,
+}
+
+impl CapitalizationAfterColon {
+    pub fn new(style: ColonCapitalizationStyle) -> Self {
+        Self { style }
+    }
+}
+
+/// Whether the tokens after a colon read like a complete sentence, rather than a list or a
+/// fragment.
+fn is_complete_sentence(clause: &[Token]) -> bool {
+    let words = clause.iter().filter(|t| t.kind.is_word()).collect_vec();
+
+    // A series, like "flour, sugar, and eggs".
+    if clause.iter().any(|t| t.kind.is_comma()) {
+        return false;
+    }
+
+    // Short sentences, like "it works", are only recognized by their pronoun subject.
+    if let [first, second, ..] = words.as_slice()
+        && first.kind.is_pronoun()
+        && second.kind.is_verb()
+    {
+        return true;
+    }
+
+    if words.len() < 4 {
+        return false;
+    }
+
+    // Look for a subject that comes before its verb.
+    words
+        .iter()
+        .position(|t| t.kind.is_noun())
+        .is_some_and(|subject| words[subject + 1..].iter().any(|t| t.kind.is_verb()))
+}
+
+impl Linter for CapitalizationAfterColon {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for sentence in document.iter_sentences() {
+            for (index, colon) in sentence.iter().enumerate() {
+                if !matches!(colon.kind, TokenKind::Punctuation(Punctuation::Colon)) {
+                    continue;
+                }
+
+                let Some([space, word]) = sentence.get(index + 1..index + 3) else {
+                    continue;
+                };
+
+                if !space.kind.is_space() || !matches!(word.kind, TokenKind::Word(Some(_))) {
+                    continue;
+                }
+
+                // Names, acronyms and "I" keep their capitalization everywhere.
+                if word.kind.is_proper_noun() || word.kind.is_case_sensitive() {
+                    continue;
+                }
+
+                let letters = document.get_span_content(word.span);
+                let Some((first_letter, rest)) = letters.split_first() else {
+                    continue;
+                };
+
+                if rest.is_empty() || !first_letter.is_alphabetic() {
+                    continue;
+                }
+
+                if rest.iter().any(|c| c.is_uppercase()) {
+                    continue;
+                }
+
+                let capitalize = match self.style {
+                    ColonCapitalizationStyle::Chicago => false,
+                    ColonCapitalizationStyle::Ap => is_complete_sentence(&sentence[index + 2..]),
+                };
+
+                if capitalize == first_letter.is_uppercase() {
+                    continue;
+                }
+
+                let (replacement, message) = if capitalize {
+                    (
+                        first_letter.to_uppercase().collect_vec(),
+                        "A complete sentence after a colon should start with a capital letter.",
+                    )
+                } else {
+                    (
+                        first_letter.to_lowercase().collect_vec(),
+                        "The first word after a colon should be lowercase.",
+                    )
+                };
+
+                lints.push(Lint {
+                    span: word.span.with_len(1),
+                    lint_kind: LintKind::Capitalization,
+                    suggestions: vec![Suggestion::replace_with(replacement)],
+                    message: message.to_string(),
+                    priority: 63,
+                    ..Default::default()
+                });
+            }
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        match self.style {
+            ColonCapitalizationStyle::Chicago => {
+                "Following the Chicago Manual of Style, the first word after a colon should be lowercase unless it is a proper noun."
+            }
+            ColonCapitalizationStyle::Ap => {
+                "Following the AP Stylebook, the first word after a colon should be capitalized only when it starts a complete sentence."
+            }
+        }
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Capitalization)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        match self.style {
+            ColonCapitalizationStyle::Chicago => vec![RuleExample::new(
+                "The rule is simple: The tests must pass before you merge.",
+                "The rule is simple: the tests must pass before you merge.",
+            )],
+            ColonCapitalizationStyle::Ap => vec![RuleExample::new(
+                "The rule is simple: the tests must pass before you merge.",
+                "The rule is simple: The tests must pass before you merge.",
+            )],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CapitalizationAfterColon, ColonCapitalizationStyle};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    fn ap() -> CapitalizationAfterColon {
+        CapitalizationAfterColon::new(ColonCapitalizationStyle::Ap)
+    }
+
+    #[test]
+    fn chicago_lowercases_sentences() {
+        assert_suggestion_result(
+            "The rule is simple: The tests must pass before you merge.",
+            CapitalizationAfterColon::default(),
+            "The rule is simple: the tests must pass before you merge.",
+        );
+    }
+
+    #[test]
+    fn chicago_allows_proper_nouns() {
+        assert_lint_count(
+            "She has lived in one city: London.",
+            CapitalizationAfterColon::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn ap_capitalizes_sentences() {
+        assert_suggestion_result(
+            "The rule is simple: the tests must pass before you merge.",
+            ap(),
+            "The rule is simple: The tests must pass before you merge.",
+        );
+    }
+
+    #[test]
+    fn ap_capitalizes_short_sentences() {
+        assert_suggestion_result("I tried it: it works.", ap(), "I tried it: It works.");
+    }
+
+    #[test]
+    fn ap_lowercases_lists() {
+        assert_suggestion_result(
+            "You will need three things: Flour, sugar, and eggs.",
+            ap(),
+            "You will need three things: flour, sugar, and eggs.",
+        );
+    }
+
+    #[test]
+    fn ap_allows_fragments() {
+        assert_lint_count("He had one goal: to win.", ap(), 0);
+    }
+
+    #[test]
+    fn ignores_times_and_acronyms() {
+        assert_lint_count(
+            "We met at 10:30 to discuss one topic: NASA.",
+            CapitalizationAfterColon::default(),
+            0,
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{NounData, TokenKind, TokenStringExt, WordMetadata};
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Lint, LintKind, Linter, RuleExample, Suggestion};
 
 /// A super-simple linter that makes sure you capitalize "I".
 #[derive(Default)]
@@ -24,10 +24,13 @@ impl Linter for CapitalizePersonalPronouns {
                     lints.push(Lint {
                         span: tok.span,
                         lint_kind: LintKind::Capitalization,
-                        suggestions: vec![Suggestion::ReplaceWith(vec!['I'])],
+                        suggestions: vec![
+                            Suggestion::replace_with(vec!['I']).with_confidence(Confidence::High),
+                        ],
                         message: "The first-person singular subject pronoun must be capitalized."
                             .to_string(),
                         priority: 31,
+                        ..Default::default()
                     });
                 }
             }
@@ -39,6 +42,14 @@ impl Linter for CapitalizePersonalPronouns {
     fn description(&self) -> &'static str {
         "Forgetting to capitalize personal pronouns, like \"I\" or \"I'm\" is one of the most common errors. This rule helps with that."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Capitalization)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new("i am hungry", "I am hungry")]
+    }
 }
 
 #[cfg(test)]
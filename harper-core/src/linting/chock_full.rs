@@ -49,12 +49,17 @@ impl PatternLinter for ChockFull {
                 }
             ),
             priority: 126,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Flags common soundalikes of \"chock-full\" and makes sure they're hyphenated."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
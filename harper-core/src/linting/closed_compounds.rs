@@ -8,10 +8,7 @@ pub fn lint_group() -> LintGroup {
     macro_rules! add_compound_mappings {
         ($group:expr, { $($name:expr => ($bad:expr, $good:expr)),+ $(,)? }) => {
             $(
-                $group.add(
-                    $name,
-                    Box::new(MapPhraseLinter::new_closed_compound($bad, $good)),
-                );
+                $group.add_lazy($name, || MapPhraseLinter::new_closed_compound($bad, $good));
             )+
         };
     }
@@ -86,10 +86,15 @@ impl PatternLinter for GeneralCompoundNouns {
                 word.to_string()
             ),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &str {
         "Detects compound nouns split by a space and suggests merging them when both parts form a valid noun. Has checks to avoid erroneous cases."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
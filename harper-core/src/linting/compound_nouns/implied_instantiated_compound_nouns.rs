@@ -53,10 +53,15 @@ impl PatternLinter for ImpliedInstantiatedCompoundNouns {
                 word.to_string()
             ),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &str {
         "Detects split compound nouns preceding an action and suggests merging them."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
@@ -46,16 +46,21 @@ impl PatternLinter for ImpliedOwnershipCompoundNouns {
         Some(Lint {
             span,
             lint_kind: LintKind::WordChoice,
-            suggestions: vec![Suggestion::ReplaceWith(word.to_vec())],
+            suggestions: vec![Suggestion::replace_with(word.to_vec())],
             message: format!(
                 "The possessive noun implies ownership of the closed compound noun “{}”.",
                 word.to_string()
             ),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &str {
         "Detects split compound nouns following a possessive noun and suggests merging them."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
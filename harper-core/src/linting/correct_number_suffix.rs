@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Lint, LintKind, Linter, RuleExample, Suggestion};
 use crate::{Document, NumberSuffix, Span, TokenKind};
 use crate::{Number, TokenStringExt};
 
@@ -28,7 +28,10 @@ impl Linter for CorrectNumberSuffix {
                             lint_kind: LintKind::Miscellaneous,
                             message: "This number needs a different suffix to sound right."
                                 .to_string(),
-                            suggestions: vec![Suggestion::ReplaceWith(correct_suffix.to_chars())],
+                            suggestions: vec![
+                                Suggestion::replace_with(correct_suffix.to_chars())
+                                    .with_confidence(Confidence::High),
+                            ],
                             ..Default::default()
                         })
                     }
@@ -42,6 +45,14 @@ impl Linter for CorrectNumberSuffix {
     fn description(&self) -> &'static str {
         "When making quick edits, it is common for authors to change the value of a number without changing its suffix. This rule looks for these cases, for example: `2st`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new("2st", "2nd")]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,160 @@
+use hashbrown::HashMap;
+
+use crate::{Currency, CurrencyNotation, CurrencyPosition, Document, Span};
+
+use super::{Lint, LintKind, Linter, Suggestion};
+
+/// Makes sure each currency is written the same way throughout a document,
+/// either with its symbol (`$50`) or with its code before (`USD 50`) or after (`50 USD`) the
+/// amount.
+#[derive(Debug, Default)]
+pub struct CurrencyConsistency;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Symbol,
+    Code(CurrencyPosition),
+}
+
+impl Style {
+    fn notation(&self) -> CurrencyNotation {
+        match self {
+            Style::Symbol => CurrencyNotation::Symbol,
+            Style::Code(_) => CurrencyNotation::Code,
+        }
+    }
+
+    fn position(&self) -> CurrencyPosition {
+        match self {
+            Style::Symbol => CurrencyPosition::Before,
+            Style::Code(position) => *position,
+        }
+    }
+}
+
+impl Linter for CurrencyConsistency {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let amounts: Vec<_> = document
+            .tokens()
+            .filter_map(|tok| {
+                let amount = tok.kind.as_currency_amount()?;
+                let style = match amount.notation {
+                    CurrencyNotation::Symbol => Style::Symbol,
+                    CurrencyNotation::Code => Style::Code(amount.position),
+                };
+
+                Some((tok.span, *amount, style))
+            })
+            .collect();
+
+        // For each currency, every style in the order it first appears, alongside how often it
+        // is used and where it first appears.
+        let mut seen: HashMap<Currency, Vec<(Style, usize, Span)>> = HashMap::new();
+
+        for (span, amount, style) in &amounts {
+            let styles = seen.entry(amount.currency).or_default();
+
+            match styles.iter_mut().find(|(s, ..)| s == style) {
+                Some((_, count, _)) => *count += 1,
+                None => styles.push((*style, 1, *span)),
+            }
+        }
+
+        let mut lints = Vec::new();
+
+        for (span, amount, style) in amounts {
+            let styles = &seen[&amount.currency];
+
+            // Ties go to the style that appears first.
+            let Some((dominant, _, example)) = styles
+                .iter()
+                .rev()
+                .max_by_key(|(_, count, _)| *count)
+                .copied()
+            else {
+                continue;
+            };
+
+            if dominant == style {
+                continue;
+            }
+
+            let content = document.get_span_content(span);
+            let Some(replacement) =
+                amount.format_as(content, dominant.notation(), dominant.position())
+            else {
+                continue;
+            };
+
+            lints.push(Lint {
+                span,
+                lint_kind: LintKind::Style,
+                suggestions: vec![Suggestion::replace_with(replacement.chars().collect())],
+                message: format!(
+                    "Elsewhere, this document writes amounts like `{}`.",
+                    document.get_span_content_str(example)
+                ),
+                priority: 63,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &str {
+        "Currencies can be written with a symbol or with a code, before or after the amount. Whichever you choose, stick with it throughout the document."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    use super::CurrencyConsistency;
+
+    #[test]
+    fn allows_consistent_symbols() {
+        assert_lint_count(
+            "It cost $50 last year and $1,000 this year.",
+            CurrencyConsistency,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_different_currencies() {
+        assert_lint_count("It cost $50 here and 40 EUR there.", CurrencyConsistency, 0);
+    }
+
+    #[test]
+    fn prefers_majority_notation() {
+        assert_suggestion_result(
+            "Tickets are €20 for adults, €10 for children, and 5 EUR for pets.",
+            CurrencyConsistency,
+            "Tickets are €20 for adults, €10 for children, and €5 for pets.",
+        );
+    }
+
+    #[test]
+    fn ties_go_to_first_style() {
+        assert_suggestion_result(
+            "It cost USD 50 here and $1,000 there.",
+            CurrencyConsistency,
+            "It cost USD 50 here and USD 1,000 there.",
+        );
+    }
+
+    #[test]
+    fn flags_code_placement() {
+        assert_suggestion_result(
+            "We paid 20 GBP, then 30 GBP, then GBP 40.",
+            CurrencyConsistency,
+            "We paid 20 GBP, then 30 GBP, then 40 GBP.",
+        );
+    }
+}
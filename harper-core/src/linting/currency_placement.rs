@@ -1,6 +1,4 @@
-use itertools::Itertools;
-
-use crate::{Document, Token, TokenStringExt, remove_overlaps};
+use crate::{CurrencyPosition, Document, Token};
 
 use super::{Lint, LintKind, Linter, Suggestion};
 
@@ -9,56 +7,43 @@ pub struct CurrencyPlacement {}
 
 impl Linter for CurrencyPlacement {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
-        let mut lints = Vec::new();
-
-        for chunk in document.iter_chunks() {
-            for (a, b) in chunk.iter().tuple_windows() {
-                lints.extend(generate_lint_for_tokens(*a, *b, document));
-            }
-
-            for (p, a, b, c) in chunk.iter().tuple_windows() {
-                if !b.kind.is_whitespace() || p.kind.is_currency() {
-                    continue;
-                }
-
-                lints.extend(generate_lint_for_tokens(*a, *c, document));
-            }
-        }
-
-        remove_overlaps(&mut lints);
-
-        lints
+        document
+            .tokens()
+            .filter_map(|tok| generate_lint_for_token(tok, document))
+            .collect()
     }
 
     fn description(&self) -> &str {
         "The location of currency symbols varies by country. The rule looks for and corrects improper positioning."
     }
-}
 
-// Given two tokens that may have an error, check if they do and create a [`Lint`].
-fn generate_lint_for_tokens(a: Token, b: Token, document: &Document) -> Option<Lint> {
-    let matched_tokens = [a, b];
-
-    let punct = matched_tokens
-        .first_punctuation()?
-        .kind
-        .expect_punctuation();
-    let currency = punct.as_currency()?;
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+}
 
-    let number = matched_tokens.first_number()?.kind.expect_number();
+// Given a token that may be a misformatted currency amount, create a [`Lint`] if it is.
+fn generate_lint_for_token(tok: Token, document: &Document) -> Option<Lint> {
+    let amount = tok.kind.as_currency_amount()?;
 
-    let span = matched_tokens.span().unwrap();
+    if !amount.notation.is_symbol() {
+        return None;
+    }
 
-    let correct: Vec<_> = currency.format_amount(number).chars().collect();
-    let actual = document.get_span_content(span);
+    let actual = document.get_span_content(tok.span);
+    let correct: Vec<_> = amount
+        .format_as(actual, amount.notation, CurrencyPosition::Before)?
+        .chars()
+        .collect();
 
     if correct != actual {
         Some(Lint {
-            span,
+            span: tok.span,
             lint_kind: LintKind::Formatting,
-            suggestions: vec![Suggestion::ReplaceWith(correct)],
+            suggestions: vec![Suggestion::replace_with(correct)],
             message: "The position of the currency symbol matters.".to_string(),
             priority: 63,
+            ..Default::default()
         })
     } else {
         None
@@ -143,4 +128,13 @@ mod tests {
     fn seven_even_two_decimal_clean() {
         assert_lint_count("$7.00", CurrencyPlacement::default(), 0);
     }
+
+    #[test]
+    fn keeps_thousands_separators() {
+        assert_suggestion_result(
+            "It cost 1,000,000$ to build.",
+            CurrencyPlacement::default(),
+            "It cost $1,000,000 to build.",
+        );
+    }
 }
@@ -0,0 +1,248 @@
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::patterns::{NounPhrase, Pattern};
+use crate::{CharStringExt, Document, Lemmatizer, PosTag, Token, TokenStringExt};
+
+/// Participles that commonly open a sentence without describing its subject, like "According to
+/// the report,", so they are never flagged.
+const ABSOLUTE_PARTICIPLES: &[&str] = &[
+    "according",
+    "assuming",
+    "barring",
+    "beginning",
+    "concerning",
+    "considering",
+    "depending",
+    "excluding",
+    "following",
+    "generally",
+    "given",
+    "including",
+    "judging",
+    "pending",
+    "regarding",
+    "speaking",
+    "starting",
+];
+
+/// Nouns ending in "-ing" that the [`Lemmatizer`] can mistake for participles.
+const ING_NOUNS: &[&str] = &[
+    "anything",
+    "building",
+    "ceiling",
+    "evening",
+    "everything",
+    "morning",
+    "nothing",
+    "something",
+    "wedding",
+];
+
+/// Nouns that can't perform the actions participial phrases usually describe, like walking or
+/// finishing a report.
+const INANIMATE_NOUNS: &[&str] = &[
+    "answer", "book", "building", "car", "computer", "data", "day", "door", "email", "house",
+    "meeting", "music", "night", "phone", "problem", "question", "rain", "report", "result",
+    "results", "road", "room", "sky", "snow", "storm", "sun", "time", "view", "weather", "wind",
+    "work",
+];
+
+/// Possessive determiners, which can open a subject like "my phone".
+const POSSESSIVES: &[&str] = &["my", "your", "his", "her", "its", "our", "their"];
+
+/// Looks for sentences that open with a participial phrase their subject can't perform, like
+/// "Walking to the store, the rain started", where it was someone else doing the walking.
+///
+/// Deciding who can perform an action takes more than a dictionary, so this is off by default
+/// and only flags subjects that clearly can't, like "it", "there" and nouns such as "the rain".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DanglingModifiers;
+
+/// Whether a word is a present participle, like "walking", that opens a phrase describing the
+/// subject.
+fn is_present_participle(token: &Token, source: &[char], lemmatizer: &Lemmatizer) -> bool {
+    let lower = token.span.get_content(source).to_lower();
+    let text = lower.to_string();
+
+    !token.kind.is_preposition()
+        && text.ends_with("ing")
+        && !ING_NOUNS.contains(&text.as_str())
+        && !ABSOLUTE_PARTICIPLES.contains(&text.as_str())
+        && lemmatizer.lemmatize(&lower, Some(PosTag::Verb)).as_slice() != lower.as_ref()
+}
+
+/// Whether a word could be a verb, including inflected forms the dictionary doesn't mark.
+fn is_verb(token: &Token, source: &[char], lemmatizer: &Lemmatizer) -> bool {
+    if !token.kind.is_word() {
+        return false;
+    }
+
+    let word = token.span.get_content(source).to_lower();
+
+    token.kind.is_verb()
+        || lemmatizer.lemmatize(&word, Some(PosTag::Verb)).as_slice() != word.as_ref()
+}
+
+/// The subject at the start of `tokens`, if it clearly can't perform an action, along with the
+/// number of tokens it covers.
+fn inanimate_subject(tokens: &[Token], source: &[char]) -> Option<usize> {
+    let text = |token: &Token| token.span.get_content_string(source).to_lowercase();
+    let first = tokens.first()?;
+
+    if matches!(text(first).as_str(), "it" | "there") {
+        return Some(1);
+    }
+
+    // Skip past a possessive, like the "my" in "my phone".
+    let start = if POSSESSIVES.contains(&text(first).as_str()) {
+        2
+    } else {
+        0
+    };
+
+    let len = start + NounPhrase.matches(tokens.get(start..)?, source);
+
+    if len == start || !INANIMATE_NOUNS.contains(&text(&tokens[len - 1]).as_str()) {
+        return None;
+    }
+
+    Some(len)
+}
+
+impl Linter for DanglingModifiers {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let lemmatizer = Lemmatizer::curated();
+        let mut lints = Vec::new();
+
+        for sentence in document.iter_sentences() {
+            let Some(start) = sentence.iter().position(|t| !t.kind.is_whitespace()) else {
+                continue;
+            };
+            let sentence = &sentence[start..];
+
+            let Some(comma) = sentence.iter().position(|t| t.kind.is_comma()) else {
+                continue;
+            };
+
+            let phrase = &sentence[..comma];
+
+            if !phrase.iter().all(|t| t.kind.is_word() || t.kind.is_space()) {
+                continue;
+            }
+
+            let Some(participle) = phrase.first() else {
+                continue;
+            };
+
+            if !is_present_participle(participle, source, &lemmatizer) {
+                continue;
+            }
+
+            let Some(rest) = sentence.get(comma + 2..) else {
+                continue;
+            };
+
+            let Some(subject_len) = inanimate_subject(rest, source) else {
+                continue;
+            };
+
+            // Make sure the subject really is one, by checking that a verb follows it.
+            if !rest
+                .get(subject_len + 1)
+                .is_some_and(|t| is_verb(t, source, &lemmatizer))
+            {
+                continue;
+            }
+
+            let (Some(phrase_span), Some(subject_span)) =
+                (phrase.span(), rest[..subject_len].span())
+            else {
+                continue;
+            };
+
+            lints.push(Lint {
+                span: phrase_span,
+                lint_kind: LintKind::Readability,
+                suggestions: vec![],
+                message: format!(
+                    "This phrase describes “{}”, which can't be doing it. Consider naming who is.",
+                    subject_span.get_content_string(source)
+                ),
+                priority: 127,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for sentences that open with an action their subject can't perform, like “Walking to the store, the rain started.”"
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Readability)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::without_correction(
+            "Walking to the store, the rain started.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DanglingModifiers;
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_inanimate_subjects() {
+        assert_lint_count(
+            "Walking to the store, the rain started.",
+            DanglingModifiers,
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_expletive_subjects() {
+        assert_lint_count(
+            "Walking to the store, it started to rain.",
+            DanglingModifiers,
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_possessive_subjects() {
+        assert_lint_count("Finishing the report, my phone rang.", DanglingModifiers, 1);
+    }
+
+    #[test]
+    fn allows_people() {
+        assert_lint_count(
+            "Walking to the store, I saw a friend. Walking home, Sarah saw the rain.",
+            DanglingModifiers,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_absolute_participles() {
+        assert_lint_count(
+            "According to the forecast, the rain will stop. Considering the cost, it is worth it.",
+            DanglingModifiers,
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_other_openings() {
+        assert_lint_count(
+            "During the walk, the rain started. Morning came, the sun rose.",
+            DanglingModifiers,
+            0,
+        );
+    }
+}
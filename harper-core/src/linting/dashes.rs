@@ -38,16 +38,18 @@ impl PatternLinter for Dashes {
             2 => Some(Lint {
                 span,
                 lint_kind,
-                suggestions: vec![Suggestion::ReplaceWith(vec!['–'])],
+                suggestions: vec![Suggestion::replace_with(vec!['–'])],
                 message: "A sequence of hyphens is not an en dash.".to_owned(),
                 priority: 63,
+                ..Default::default()
             }),
             3 => Some(Lint {
                 span,
                 lint_kind,
-                suggestions: vec![Suggestion::ReplaceWith(vec!['—'])],
+                suggestions: vec![Suggestion::replace_with(vec!['—'])],
                 message: "A sequence of hyphens is not an em dash.".to_owned(),
                 priority: 63,
+                ..Default::default()
             }),
             _ => panic!("Received unexpected number of tokens."),
         }
@@ -56,6 +58,10 @@ impl PatternLinter for Dashes {
     fn description(&self) -> &'static str {
         "Rather than outright using an em dash or en dash, authors often use a sequence of hyphens, expecting them to be condensed.\nThis rule does so."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
 }
 
 #[cfg(test)]
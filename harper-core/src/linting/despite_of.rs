@@ -3,7 +3,7 @@ use crate::{
     patterns::{Pattern, SequencePattern},
 };
 
-use super::{Lint, LintKind, PatternLinter, Suggestion};
+use super::{Lint, LintKind, PatternLinter, RuleExample, Suggestion};
 
 pub struct DespiteOf {
     pattern: Box<dyn Pattern>,
@@ -39,12 +39,24 @@ impl PatternLinter for DespiteOf {
             ],
             message: "The phrase “despite of” is incorrect. Please use either “despite” or “in spite of” instead.".to_string(),
             priority: 126,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Corrects the misuse of `despite of` and suggests the proper alternatives `despite` or `in spite of`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "Despite of the rain, we went for a walk.",
+            "Despite the rain, we went for a walk.",
+        )]
+    }
 }
 
 #[cfg(test)]
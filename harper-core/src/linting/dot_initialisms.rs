@@ -1,6 +1,6 @@
 use hashbrown::HashMap;
 
-use super::{Lint, LintKind, PatternLinter, Suggestion};
+use super::{Confidence, Lint, LintKind, PatternLinter, RuleExample, Suggestion};
 use crate::patterns::{Pattern, SequencePattern, WordPatternGroup};
 use crate::{Token, TokenStringExt};
 
@@ -46,15 +46,30 @@ impl PatternLinter for DotInitialisms {
         Some(Lint {
             span: matched_tokens.span()?,
             lint_kind: LintKind::Formatting,
-            suggestions: vec![Suggestion::ReplaceWith(correction.chars().collect())],
+            suggestions: vec![
+                Suggestion::replace_with(correction.chars().collect())
+                    .with_confidence(Confidence::High),
+            ],
             message: "Initialisms should have dot-separated letters.".to_owned(),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Ensures common initialisms (like \"i.e.\") are properly dot-separated."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "Some text here (eg. more text).",
+            "Some text here (e.g. more text).",
+        )]
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Lint, LintKind, Linter, RuleExample, Suggestion};
 use crate::TokenStringExt;
 
 /// A linter that checks that an ellipsis doesn't contain too many periods (or
@@ -26,9 +26,13 @@ impl Linter for EllipsisLength {
                 lints.push(Lint {
                     span: tok.span,
                     lint_kind: LintKind::Formatting,
-                    suggestions: vec![Suggestion::ReplaceWith(vec!['.', '.', '.'])],
+                    suggestions: vec![
+                        Suggestion::replace_with(vec!['.', '.', '.'])
+                            .with_confidence(Confidence::High),
+                    ],
                     message: "Horizontal ellipsis must have 3 dots.".to_string(),
                     priority: 31,
+                    ..Default::default()
                 })
             }
         }
@@ -39,6 +43,14 @@ impl Linter for EllipsisLength {
     fn description(&self) -> &'static str {
         "Make sure you have the correct number of dots in your ellipsis."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(".....", "...")]
+    }
 }
 
 #[cfg(test)]
@@ -86,15 +86,20 @@ impl PatternLinter for ExpandTimeShorthands {
         Some(Lint {
             span: offending_span,
             lint_kind: LintKind::WordChoice,
-            suggestions: vec![Suggestion::ReplaceWith(replacement_chars)],
+            suggestions: vec![Suggestion::replace_with(replacement_chars)],
             message: format!("Did you mean `{}`?", replacement),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &str {
         "Expands time-related abbreviations (`hr`, `hrs`, `min`, `mins`, `sec`, `secs`, `ms`, `msec`, `msecs`) to their full forms (`hour`, `hours`, `minute`, `minutes`, `second`, `seconds`, `millisecond`, `milliseconds`)."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,170 @@
+use hashbrown::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::{Document, TokenStringExt};
+
+/// A word that a style guide bans, along with what should be used instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForbiddenWord {
+    /// The words to suggest in its place.
+    #[serde(default)]
+    pub replacements: Vec<String>,
+    /// Why the word is banned, shown to the user in place of the default message.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Flags words that have been explicitly forbidden, such as those a company's style guide
+/// bans (like "utilize" or "leverage").
+///
+/// Words are matched regardless of capitalization.
+/// Serializes as a map from each forbidden word to its [`ForbiddenWord`] entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    from = "HashMap<String, ForbiddenWord>",
+    into = "HashMap<String, ForbiddenWord>"
+)]
+pub struct ForbiddenWords {
+    /// Keyed by the lowercase version of each word.
+    words: HashMap<String, ForbiddenWord>,
+}
+
+impl ForbiddenWords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forbid a word, replacing any existing entry for it.
+    pub fn forbid(&mut self, word: &str, entry: ForbiddenWord) {
+        self.words.insert(word.to_lowercase(), entry);
+    }
+
+    pub fn with_forbidden(mut self, word: &str, entry: ForbiddenWord) -> Self {
+        self.forbid(word, entry);
+        self
+    }
+
+    /// Look up the entry for a word, in any capitalization.
+    pub fn get(&self, word: &str) -> Option<&ForbiddenWord> {
+        self.words.get(&word.to_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl From<HashMap<String, ForbiddenWord>> for ForbiddenWords {
+    fn from(value: HashMap<String, ForbiddenWord>) -> Self {
+        let mut words = Self::new();
+
+        for (word, entry) in value {
+            words.forbid(&word, entry);
+        }
+
+        words
+    }
+}
+
+impl From<ForbiddenWords> for HashMap<String, ForbiddenWord> {
+    fn from(value: ForbiddenWords) -> Self {
+        value.words
+    }
+}
+
+impl Linter for ForbiddenWords {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        if self.words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lints = Vec::new();
+
+        for word in document.iter_words() {
+            let text = document.get_span_content_str(word.span);
+
+            let Some(entry) = self.get(&text) else {
+                continue;
+            };
+
+            let original = document.get_span_content(word.span);
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Forbidden,
+                suggestions: entry
+                    .replacements
+                    .iter()
+                    .map(|r| Suggestion::replace_with_match_case(r.chars().collect(), original))
+                    .collect(),
+                message: entry
+                    .reason
+                    .clone()
+                    .unwrap_or_else(|| format!("“{text}” is not allowed by your style guide.")),
+                priority: 31,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags words that have been explicitly forbidden, such as those banned by a style guide."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Forbidden)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ForbiddenWord, ForbiddenWords};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    fn utilize() -> ForbiddenWords {
+        ForbiddenWords::new().with_forbidden(
+            "utilize",
+            ForbiddenWord {
+                replacements: vec!["use".to_string()],
+                reason: None,
+            },
+        )
+    }
+
+    #[test]
+    fn flags_any_capitalization() {
+        assert_lint_count("Utilize it. Please UTILIZE it.", utilize(), 2);
+    }
+
+    #[test]
+    fn suggests_replacement() {
+        assert_suggestion_result("Utilize the tool.", utilize(), "Use the tool.");
+    }
+
+    #[test]
+    fn empty_by_default() {
+        assert_lint_count("We should utilize this.", ForbiddenWords::default(), 0);
+    }
+
+    #[test]
+    fn deserializes_from_map() {
+        let words: ForbiddenWords = serde_json::from_str(
+            r#"{ "Leverage": { "replacements": ["use"], "reason": "Avoid jargon." } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            words.get("leverage").unwrap().reason.as_deref(),
+            Some("Avoid jargon.")
+        );
+        assert_lint_count("We leverage synergy.", words, 1);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::linting::{LintGroup, LintKind};
+
+use super::MapPhraseLinter;
+
+/// Produce a [`LintGroup`] that suggests gender-neutral alternatives to gendered role nouns and
+/// pronoun pairs.
+/// Every rule is off by default, since whether they apply depends on a project's style guide.
+pub fn lint_group() -> LintGroup {
+    let mut group = LintGroup::empty();
+
+    macro_rules! add_neutral_mappings {
+        ($group:expr, $message:expr, {
+            $($name:expr => ($gendered:expr, $neutral:expr)),+ $(,)?
+        }) => {
+            $(
+                $group.add_lazy($name, || {
+                    let gendered: &[&str] = &$gendered;
+                    let neutral: &[&str] = &$neutral;
+
+                    MapPhraseLinter::new_exact_phrases(
+                        gendered,
+                        neutral,
+                        format!($message, neutral[0]),
+                        format!("Suggests `{}` in place of `{}`.", neutral[0], gendered[0]),
+                    )
+                    .with_lint_kind(LintKind::WordChoice)
+                });
+            )+
+        };
+    }
+
+    // The first column is the name of the rule (which shows up in settings).
+    // The second column lists the gendered forms and the third their neutral alternatives, the
+    // first of which is used in messages.
+    add_neutral_mappings!(group, "Consider the gender-neutral `{}`.", {
+        "Businessman"   => (["businessman"], ["businessperson"]),
+        "Businessmen"   => (["businessmen"], ["businesspeople"]),
+        "Cameraman"     => (["cameraman", "cameramen"], ["camera operator"]),
+        "Chairman"      => (["chairman"], ["chair", "chairperson"]),
+        "Chairmen"      => (["chairmen"], ["chairs", "chairpersons"]),
+        "Fireman"       => (["fireman"], ["firefighter"]),
+        "Firemen"       => (["firemen"], ["firefighters"]),
+        "Foreman"       => (["foreman"], ["supervisor"]),
+        "Layman"        => (["layman"], ["layperson"]),
+        "Laymen"        => (["laymen"], ["laypeople"]),
+        "Mailman"       => (["mailman"], ["mail carrier"]),
+        "Mankind"       => (["mankind"], ["humankind", "humanity"]),
+        "ManMade"       => (["man-made"], ["artificial", "synthetic"]),
+        "Manpower"      => (["manpower"], ["staffing", "workforce"]),
+        "Policeman"     => (["policeman", "policewoman"], ["police officer"]),
+        "Policemen"     => (["policemen", "policewomen"], ["police officers"]),
+        "Salesman"      => (["salesman", "saleswoman"], ["salesperson"]),
+        "Spokesman"     => (["spokesman", "spokeswoman"], ["spokesperson"]),
+        "Stewardess"    => (["stewardess"], ["flight attendant"]),
+    });
+
+    add_neutral_mappings!(group, "Consider singular `{}`, and check that the verb agrees with it.", {
+        "HeOrShe"       => (["he or she", "she or he", "he/she", "she/he", "s/he"], ["they"]),
+        "HimOrHer"      => (["him or her", "her or him", "him/her"], ["them"]),
+        "HisOrHer"      => (["his or her", "her or his", "his/her"], ["their"]),
+        "HimselfOrHerself" => (["himself or herself", "herself or himself"], ["themselves"]),
+    });
+
+    group.set_all_rules_to(Some(false));
+
+    group
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linting::LintGroup;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    use super::lint_group;
+
+    fn enabled_group() -> LintGroup {
+        let mut group = lint_group();
+        group.set_all_rules_to(Some(true));
+        group
+    }
+
+    #[test]
+    fn off_by_default() {
+        assert_lint_count("The chairman called for more manpower.", lint_group(), 0);
+    }
+
+    #[test]
+    fn chairman() {
+        assert_suggestion_result(
+            "Chairman Lee opened the meeting.",
+            enabled_group(),
+            "Chair Lee opened the meeting.",
+        );
+    }
+
+    #[test]
+    fn manpower() {
+        assert_suggestion_result(
+            "We lack the manpower to ship it.",
+            enabled_group(),
+            "We lack the staffing to ship it.",
+        );
+    }
+
+    #[test]
+    fn he_or_she() {
+        assert_suggestion_result(
+            "Ask the user whether he or she agreed.",
+            enabled_group(),
+            "Ask the user whether they agreed.",
+        );
+    }
+
+    #[test]
+    fn his_slash_her() {
+        assert_suggestion_result(
+            "Each reviewer submits his/her notes.",
+            enabled_group(),
+            "Each reviewer submits their notes.",
+        );
+    }
+
+    #[test]
+    fn allows_neutral_text() {
+        assert_lint_count(
+            "The chair asked the firefighters and their supervisor to speak.",
+            enabled_group(),
+            0,
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, Punctuation, TokenKind};
+
+/// Looks for headings that end with a period or a colon.
+///
+/// Relies on the headings found by the document's parser (see [`Document::get_headings`]), so it
+/// only works on formats with headings, like Markdown and Typst.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeadingPunctuation {
+    allow_colons: bool,
+}
+
+impl HeadingPunctuation {
+    /// Whether headings may end with a colon, as is common when a heading introduces a list.
+    pub fn with_colons_allowed(mut self, allow_colons: bool) -> Self {
+        self.allow_colons = allow_colons;
+        self
+    }
+}
+
+impl Linter for HeadingPunctuation {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let tokens = document.get_tokens();
+        let mut lints = Vec::new();
+
+        for heading in document.get_headings() {
+            let start = tokens.partition_point(|t| t.span.start < heading.span.start);
+            let end = tokens.partition_point(|t| t.span.end <= heading.span.end);
+
+            let Some(heading_tokens) = tokens.get(start..end) else {
+                continue;
+            };
+
+            let mut content = heading_tokens.iter().rev().skip_while(|t| {
+                t.kind.is_whitespace() || t.kind.is_paragraph_break() || t.kind.is_unlintable()
+            });
+
+            let Some(last) = content.next() else {
+                continue;
+            };
+
+            let message = match last.kind {
+                // Three periods are an ellipsis, which can trail off a heading on purpose.
+                TokenKind::Punctuation(Punctuation::Period)
+                    if !content.next().is_some_and(|t| t.kind.is_period()) =>
+                {
+                    "Headings shouldn't end with a period."
+                }
+                TokenKind::Punctuation(Punctuation::Colon) if !self.allow_colons => {
+                    "Headings shouldn't end with a colon."
+                }
+                _ => continue,
+            };
+
+            lints.push(Lint {
+                span: last.span,
+                lint_kind: LintKind::Formatting,
+                suggestions: vec![Suggestion::remove()],
+                message: message.to_string(),
+                priority: 63,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Headings are titles, not sentences, so they shouldn't end with a period or a colon."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "## Getting started.\n\nInstall the package.",
+            "## Getting started\n\nInstall the package.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeadingPunctuation;
+    use crate::Document;
+    use crate::linting::Linter;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn removes_trailing_period() {
+        assert_suggestion_result(
+            "# Getting started.\n\nText.",
+            HeadingPunctuation::default(),
+            "# Getting started\n\nText.",
+        );
+    }
+
+    #[test]
+    fn removes_trailing_colon() {
+        assert_suggestion_result(
+            "Requirements:\n---\n\nText.",
+            HeadingPunctuation::default(),
+            "Requirements\n---\n\nText.",
+        );
+    }
+
+    #[test]
+    fn allows_colons_when_configured() {
+        assert_lint_count(
+            "## Requirements:\n\n- Rust",
+            HeadingPunctuation::default().with_colons_allowed(true),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_other_endings() {
+        assert_lint_count(
+            "# Why?\n\n## To be continued...\n\n## Done! ##\n\n## Version 1.2",
+            HeadingPunctuation::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_sentences_outside_headings() {
+        assert_lint_count(
+            "# Usage\n\nRun the tool. It needs:\n\n- Rust",
+            HeadingPunctuation::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_formats_without_headings() {
+        let document = Document::new_plain_english_curated("# Getting started.");
+
+        assert!(HeadingPunctuation::default().lint(&document).is_empty());
+    }
+}
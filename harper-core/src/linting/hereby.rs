@@ -46,6 +46,10 @@ impl PatternLinter for Hereby {
     fn description(&self) -> &'static str {
         "`Here by` in some contexts should be `hereby`"
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
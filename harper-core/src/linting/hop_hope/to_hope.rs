@@ -46,4 +46,8 @@ impl PatternLinter for ToHope {
     fn description(&self) -> &'static str {
         "Detects incorrect use of 'hop' when the correct verb 'hope' should be used in a sentence."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
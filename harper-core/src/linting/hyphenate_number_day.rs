@@ -48,18 +48,23 @@ impl PatternLinter for HyphenateNumberDay {
         Some(Lint {
             span: space.span,
             lint_kind: LintKind::Miscellaneous,
-            suggestions: vec![Suggestion::ReplaceWith(vec!['-'])],
+            suggestions: vec![Suggestion::replace_with(vec!['-'])],
             message: format!(
                 "Use a hyphen in `{}-day` when forming an adjectival compound.",
                 number
             ),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Ensures a hyphen is used in `X-day` when it is part of a compound adjective, such as `4-day work week`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
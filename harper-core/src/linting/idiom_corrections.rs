@@ -0,0 +1,207 @@
+use super::{Lint, LintGroup, LintKind, MapPhraseLinter, PatternLinter, RuleExample, Suggestion};
+use crate::patterns::{Pattern, SequencePattern, WordSet};
+use crate::{Dialect, Inflection, Lemmatizer, PosTag, Token, TokenStringExt};
+
+/// Produce a [`LintGroup`] that corrects idioms which are commonly mangled by a misplaced or
+/// missing negative, like "could care less" or "irregardless".
+///
+/// How strongly these are frowned upon varies between dialects, so each rule adjusts its message
+/// to the dialect the group is set to.
+pub fn lint_group() -> LintGroup {
+    let mut group = LintGroup::default();
+
+    macro_rules! add_idiom_mappings {
+        ($group:expr, {
+            $($name:expr => ($input:expr, $corrections:expr, $message:expr, $american:expr, $description:expr)),+ $(,)?
+        }) => {
+            $(
+                $group.add_lazy($name, || {
+                    MapPhraseLinter::new_exact_phrases($input, $corrections, $message, $description)
+                        .with_lint_kind(LintKind::WordChoice)
+                        .with_regional_message([Dialect::American], $american)
+                });
+            )+
+        };
+    }
+
+    add_idiom_mappings!(group, {
+        // The name of the rule
+        "CouldCareLess" => (
+            // The exact phrases to look for.
+            ["could care less"],
+            // The corrections to provide.
+            ["couldn't care less"],
+            // The message to be shown with the error.
+            "The idiom is `couldn't care less`; without the negative, it says the opposite.",
+            // The message to be shown instead in American English.
+            "`Could care less` is a common informal variant, but `couldn't care less` is expected in formal writing.",
+            // A description of the rule.
+            "Corrects `could care less` to `couldn't care less`."
+        ),
+        "Irregardless" => (
+            ["irregardless"],
+            ["regardless"],
+            "`Irregardless` isn't standard English. Did you mean `regardless`?",
+            "`Irregardless` is listed as nonstandard in American dictionaries. Did you mean `regardless`?",
+            "Corrects the nonstandard `irregardless` to `regardless`."
+        ),
+    });
+
+    group.add("CantHelpBut", Box::new(CantHelpBut::default()));
+
+    group.set_all_rules_to(Some(true));
+
+    // Widely accepted, if informal, so only checked on request.
+    group.config.set_rule_enabled("CantHelpBut", false);
+
+    group
+}
+
+/// Suggests `can't help` followed by an -ing verb in place of `can't help but`, as in "I can't
+/// help noticing" rather than "I can't help but notice".
+struct CantHelpBut {
+    pattern: Box<dyn Pattern>,
+    dialect: Dialect,
+}
+
+impl Default for CantHelpBut {
+    fn default() -> Self {
+        let pattern = SequencePattern::default()
+            .then(WordSet::new(&["can't", "cannot", "couldn't"]))
+            .then_whitespace()
+            .then_any_capitalization_of("help")
+            .then_whitespace()
+            .then_any_capitalization_of("but")
+            .then_whitespace()
+            .then_any_word();
+
+        Self {
+            pattern: Box::new(pattern),
+            dialect: Dialect::default(),
+        }
+    }
+}
+
+impl PatternLinter for CantHelpBut {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Option<Lint> {
+        let (verb, rest) = matched_tokens.split_last()?;
+        let but = rest.iter().rev().find(|t| t.kind.is_word())?;
+
+        let lemma = Lemmatizer::curated()
+            .lemmatize_str(&verb.span.get_content_string(source), Some(PosTag::Verb));
+        let participle = Lemmatizer::curated().inflect(&lemma, Inflection::PresentParticiple)?;
+
+        let message = match self.dialect {
+            Dialect::American => {
+                "`Can't help but` is common in speech, but formal writing prefers `can't help` followed by an -ing verb."
+            }
+            _ => {
+                "`Can't help but` is mainly American. Elsewhere, `can't help` is followed by an -ing verb."
+            }
+        };
+
+        Some(Lint {
+            span: [*but, *verb].span()?,
+            lint_kind: LintKind::WordChoice,
+            suggestions: vec![Suggestion::replace_with(participle.chars().collect())],
+            message: message.to_string(),
+            priority: 31,
+            ..Default::default()
+        })
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggests `can't help` with a gerund, like `can't help noticing`, in place of `can't help but notice`."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "I can't help but notice the noise.",
+            "I can't help noticing the noise.",
+        )]
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::{LintGroup, Linter};
+    use crate::{Dialect, Document};
+
+    use super::{CantHelpBut, lint_group};
+
+    fn lint_group_for(dialect: Dialect) -> LintGroup {
+        let mut group = lint_group();
+        group.set_dialect(dialect);
+        group
+    }
+
+    #[test]
+    fn could_care_less() {
+        assert_suggestion_result(
+            "Honestly, I could care less.",
+            lint_group(),
+            "Honestly, I couldn't care less.",
+        );
+    }
+
+    #[test]
+    fn allows_couldnt_care_less() {
+        assert_lint_count("Honestly, I couldn't care less.", lint_group(), 0);
+    }
+
+    #[test]
+    fn irregardless() {
+        assert_suggestion_result(
+            "Irregardless of the cost, we should fix it.",
+            lint_group(),
+            "Regardless of the cost, we should fix it.",
+        );
+    }
+
+    #[test]
+    fn messages_depend_on_dialect() {
+        let message = |dialect| {
+            let document = Document::new_markdown_default_curated("I could care less.");
+            lint_group_for(dialect).lint(&document)[0].message.clone()
+        };
+
+        assert_ne!(message(Dialect::American), message(Dialect::British));
+        assert_eq!(message(Dialect::British), message(Dialect::Australian));
+    }
+
+    #[test]
+    fn cant_help_but() {
+        assert_suggestion_result(
+            "I can't help but notice the noise.",
+            CantHelpBut::default(),
+            "I can't help noticing the noise.",
+        );
+    }
+
+    #[test]
+    fn couldnt_help_but() {
+        assert_suggestion_result(
+            "She couldn't help but laugh.",
+            CantHelpBut::default(),
+            "She couldn't help laughing.",
+        );
+    }
+
+    #[test]
+    fn cant_help_but_is_off_by_default() {
+        assert_lint_count("I can't help but notice the noise.", lint_group(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, Register};
+
+/// Flags informal words and slang (like "gonna" or "dude"), which are out of place in formal
+/// writing.
+///
+/// Which words are affected is decided by [`crate::WordMetadata::register`].
+#[derive(Debug, Default)]
+pub struct InformalLanguage;
+
+/// Formal replacements for informal words, where there is an obvious one.
+fn formal_alternative(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "gonna" => "going to",
+        "wanna" => "want to",
+        "gotta" => "have to",
+        "kinda" => "kind of",
+        "sorta" => "sort of",
+        "yeah" | "yep" => "yes",
+        "nope" => "no",
+        "gimme" => "give me",
+        "lemme" => "let me",
+        "dunno" => "don't know",
+        "y'all" => "you all",
+        _ => return None,
+    })
+}
+
+impl Linter for InformalLanguage {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        // Quoted speech is often informal on purpose.
+        let depths = document.quote_depths();
+
+        document
+            .get_tokens()
+            .iter()
+            .zip(depths)
+            .filter(|(t, depth)| *depth == 0 && t.kind.is_word())
+            .map(|(t, _)| t)
+            .filter(|t| {
+                matches!(
+                    t.kind.register(),
+                    Some(Register::Informal | Register::Slang)
+                )
+            })
+            .map(|t| {
+                let word = document.get_span_content_str(t.span);
+                let lowercase = word.to_lowercase().replace('’', "'");
+
+                let suggestions = formal_alternative(&lowercase)
+                    .map(|alt| {
+                        let mut alt: Vec<char> = alt.chars().collect();
+
+                        if word.chars().next().is_some_and(char::is_uppercase) {
+                            alt[0] = alt[0].to_ascii_uppercase();
+                        }
+
+                        Suggestion::replace_with(alt)
+                    })
+                    .into_iter()
+                    .collect();
+
+                let kind = if t.kind.register() == Some(Register::Slang) {
+                    "slang"
+                } else {
+                    "informal"
+                };
+
+                Lint {
+                    span: t.span,
+                    lint_kind: LintKind::Style,
+                    suggestions,
+                    message: format!(
+                        "“{word}” is {kind}, which may be out of place in formal writing."
+                    ),
+                    priority: 127,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for informal words and slang, like `gonna` or `dude`. Enable this rule for formal writing."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "We're gonna publish the results next week.",
+            "We're going to publish the results next week.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InformalLanguage;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn replaces_gonna() {
+        assert_suggestion_result("Gonna be late.", InformalLanguage, "Going to be late.");
+    }
+
+    #[test]
+    fn replaces_dunno() {
+        assert_suggestion_result("I dunno why.", InformalLanguage, "I don't know why.");
+    }
+
+    #[test]
+    fn flags_slang_without_suggestion() {
+        assert_lint_count("The dude brought booze.", InformalLanguage, 2);
+    }
+
+    #[test]
+    fn allows_formal_text() {
+        assert_lint_count(
+            "We will publish the results next week.",
+            InformalLanguage,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_quoted_speech() {
+        assert_lint_count("He said, \"I'm gonna win.\"", InformalLanguage, 0);
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::OnceLock;
+
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::{CharString, Dialect, Document};
+
+type Builder = Box<dyn Fn() -> Box<dyn Linter> + Send + Sync>;
+
+/// A [`Linter`] that isn't constructed until it is first needed.
+///
+/// Useful for rules that are expensive to build (like those with large patterns), since a
+/// disabled rule is never built at all.
+pub struct LazyLinter {
+    build: Builder,
+    linter: OnceLock<Box<dyn Linter>>,
+    /// Passed on to the inner linter once it is built.
+    dialect: Option<Dialect>,
+}
+
+impl LazyLinter {
+    pub fn new<L: Linter + 'static>(build: impl Fn() -> L + Send + Sync + 'static) -> Self {
+        Self {
+            build: Box::new(move || Box::new(build())),
+            linter: OnceLock::new(),
+            dialect: None,
+        }
+    }
+
+    /// Whether the inner linter has been constructed yet.
+    pub fn is_built(&self) -> bool {
+        self.linter.get().is_some()
+    }
+
+    fn get(&self) -> &dyn Linter {
+        self.linter
+            .get_or_init(|| {
+                let mut linter = (self.build)();
+
+                if let Some(dialect) = self.dialect {
+                    linter.set_dialect(dialect);
+                }
+
+                linter
+            })
+            .as_ref()
+    }
+
+    fn get_mut(&mut self) -> &mut dyn Linter {
+        self.get();
+        self.linter.get_mut().unwrap().as_mut()
+    }
+}
+
+impl Linter for LazyLinter {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        self.get_mut().lint(document)
+    }
+
+    fn description(&self) -> &str {
+        self.get().description()
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        self.get().lint_kind()
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        self.get().examples()
+    }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        self.get().anchor_words()
+    }
+
+    /// Doesn't build the inner linter, since the dialect can just as well be applied later.
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = Some(dialect);
+
+        if let Some(linter) = self.linter.get_mut() {
+            linter.set_dialect(dialect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazyLinter;
+    use crate::Document;
+    use crate::linting::{Linter, RepeatedWords};
+
+    #[test]
+    fn builds_on_first_use() {
+        let mut linter = LazyLinter::new(RepeatedWords::default);
+        assert!(!linter.is_built());
+
+        let lints = linter.lint(&Document::new_plain_english_curated("This is is a test."));
+
+        assert!(linter.is_built());
+        assert_eq!(lints.len(), 1);
+    }
+}
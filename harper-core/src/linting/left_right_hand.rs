@@ -35,16 +35,21 @@ impl PatternLinter for LeftRightHand {
         Some(Lint {
             span: space.span,
             lint_kind: LintKind::Miscellaneous,
-            suggestions: vec![Suggestion::ReplaceWith(vec!['-'])],
+            suggestions: vec![Suggestion::replace_with(vec!['-'])],
             message: "Use a hyphen in `left-hand` or `right-hand` when modifying a noun."
                 .to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Ensures `left hand` and `right hand` are hyphenated when used as adjectives before a noun, such as in `left-hand side` or `right-hand corner`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
@@ -46,10 +46,15 @@ impl PatternLinter for LetUsRedundancy {
             message: "`let's` stands for `let us`, so including another pronoun is redundant."
                 .to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Many are not aware that the contraction `let's` is short for `let us`. As a result, many will incorrectly use it before a pronoun, such as in the phrase `let's us do`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Repetition)
+    }
 }
@@ -41,10 +41,15 @@ impl PatternLinter for NoContractionWithVerb {
             ],
             message: "It seems you forgot to include a subject here.".to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Make sure you include a subject when giving permission to it."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
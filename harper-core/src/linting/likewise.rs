@@ -51,6 +51,10 @@ impl PatternLinter for Likewise {
     fn description(&self) -> &'static str {
         "Looks for incorrect spacing inside the closed compound `likewise`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
@@ -43,6 +43,10 @@ impl Linter for LinkingVerbs {
     fn description(&self) -> &'static str {
         "Linking verbs connect nouns to other ideas. Make sure you do not accidentally link words that aren't nouns."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::Span;
 
-use super::{LintKind, Suggestion};
+use super::{LintKind, Severity, Suggestion, SuggestionKind};
 
 /// An error found in text.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -25,6 +25,28 @@ pub struct Lint {
     /// A numerical value for the importance of a lint.
     /// Lower = more important.
     pub priority: u8,
+    /// How prominently the lint should be displayed.
+    ///
+    /// Individual rules usually leave this as `None`.
+    /// It is filled in by [`LintGroup`](super::LintGroup) when the rule that produced the lint
+    /// has a configured severity.
+    #[serde(default)]
+    pub severity: Option<Severity>,
+    /// The name of the rule that produced the lint, as used in
+    /// [`LintGroupConfig`](super::LintGroupConfig).
+    ///
+    /// Filled in by [`LintGroup`](super::LintGroup).
+    #[serde(default)]
+    pub rule: Option<String>,
+}
+
+/// The text around a [`Lint`] before and after one of its suggestions is applied.
+///
+/// Produced by [`Lint::preview`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestionPreview {
+    pub before: String,
+    pub after: String,
 }
 
 impl Lint {
@@ -42,6 +64,82 @@ impl Lint {
 
         hasher.finish()
     }
+
+    /// The severity configured for the lint, or the default for its [`LintKind`] if there isn't
+    /// one.
+    pub fn severity_or_default(&self) -> Severity {
+        self.severity
+            .unwrap_or_else(|| self.lint_kind.default_severity())
+    }
+
+    /// Show how the text around the lint would read once the suggestion at `suggestion_index`
+    /// is applied, with up to `context` characters of surrounding text on either side.
+    ///
+    /// Returns `None` if the lint has no such suggestion.
+    pub fn preview(
+        &self,
+        source: &[char],
+        suggestion_index: usize,
+        context: usize,
+    ) -> Option<SuggestionPreview> {
+        let edits = self.suggestions.get(suggestion_index)?.to_edits(self.span);
+
+        // Edits may reach outside the lint itself, so the window has to cover them too.
+        let start = edits
+            .iter()
+            .map(|edit| edit.span.start)
+            .fold(self.span.start, usize::min)
+            .min(source.len());
+        let end = edits
+            .iter()
+            .map(|edit| edit.span.end)
+            .fold(self.span.end, usize::max)
+            .min(source.len());
+
+        let window = Span::new(
+            start.saturating_sub(context),
+            end.saturating_add(context).min(source.len()),
+        );
+
+        let before = window.get_content(source).to_vec();
+        let mut after = before.clone();
+
+        for mut edit in edits.into_iter().rev() {
+            edit.span.pull_by(window.start);
+            edit.apply(&mut after);
+        }
+
+        Some(SuggestionPreview {
+            before: before.into_iter().collect(),
+            after: after.into_iter().collect(),
+        })
+    }
+
+    /// Move the lint forward in the document, along with any absolute edits it suggests.
+    pub fn push_by(&mut self, by: usize) {
+        self.span.push_by(by);
+
+        for suggestion in &mut self.suggestions {
+            if let SuggestionKind::Edits(edits) = &mut suggestion.kind {
+                for edit in edits {
+                    edit.span.push_by(by);
+                }
+            }
+        }
+    }
+
+    /// Move the lint backward in the document, along with any absolute edits it suggests.
+    pub fn pull_by(&mut self, by: usize) {
+        self.span.pull_by(by);
+
+        for suggestion in &mut self.suggestions {
+            if let SuggestionKind::Edits(edits) = &mut suggestion.kind {
+                for edit in edits {
+                    edit.span.pull_by(by);
+                }
+            }
+        }
+    }
 }
 
 impl Default for Lint {
@@ -52,6 +150,92 @@ impl Default for Lint {
             suggestions: Default::default(),
             message: Default::default(),
             priority: 127,
+            severity: None,
+            rule: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Lint, SuggestionPreview};
+    use crate::Span;
+    use crate::linting::{Confidence, Edit, LintKind, Severity, Suggestion};
+
+    #[test]
+    fn round_trips_through_json() {
+        let lint = Lint {
+            span: Span::new(3, 9),
+            lint_kind: LintKind::Repetition,
+            suggestions: vec![
+                Suggestion::remove(),
+                Suggestion::edits(vec![Edit::delete(Span::new(4, 9))]),
+            ],
+            message: "Did you mean to repeat this word?".to_string(),
+            priority: 31,
+            severity: Some(Severity::Warning),
+            rule: Some("RepeatedWords".to_string()),
+        };
+
+        let json = serde_json::to_string(&lint).unwrap();
+        let restored: Lint = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(lint, restored);
+    }
+
+    /// Lints serialized before `severity` and `rule` existed should still be readable.
+    #[test]
+    fn deserializes_without_optional_fields() {
+        let json = r#"{
+            "span": { "start": 0, "end": 4 },
+            "lint_kind": "Spelling",
+            "suggestions": [{ "ReplaceWith": ["t", "e", "s", "t"] }],
+            "message": "Did you mean “test”?",
+            "priority": 63
+        }"#;
+
+        let lint: Lint = serde_json::from_str(json).unwrap();
+
+        assert_eq!(lint.span, Span::new(0, 4));
+        assert_eq!(lint.severity, None);
+        assert_eq!(lint.rule, None);
+        assert_eq!(lint.suggestions[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn previews_suggestion_in_context() {
+        let source: Vec<char> = "I like teh cat a lot.".chars().collect();
+        let lint = Lint {
+            span: Span::new(7, 10),
+            suggestions: vec![Suggestion::replace_with("the".chars().collect())],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            lint.preview(&source, 0, 5),
+            Some(SuggestionPreview {
+                before: "like teh cat ".to_string(),
+                after: "like the cat ".to_string(),
+            })
+        );
+        assert_eq!(lint.preview(&source, 1, 5), None);
+    }
+
+    #[test]
+    fn preview_covers_edits_outside_the_span() {
+        let source: Vec<char> = "one two three".chars().collect();
+        let lint = Lint {
+            span: Span::new(4, 7),
+            suggestions: vec![Suggestion::edits(vec![
+                Edit::delete(Span::new(0, 4)),
+                Edit::insert(13, vec!['!']),
+            ])],
+            ..Default::default()
+        };
+
+        let preview = lint.preview(&source, 0, 100).unwrap();
+
+        assert_eq!(preview.before, "one two three");
+        assert_eq!(preview.after, "two three!");
+    }
+}
@@ -6,58 +6,83 @@ use cached::proc_macro::cached;
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 
-use super::Lint;
+use super::absolute_adjectives::AbsoluteAdjectives;
 use super::an_a::AnA;
+use super::anchor_filter::AnchorFilter;
+use super::archaic_language::ArchaicLanguage;
 use super::avoid_curses::AvoidCurses;
 use super::back_in_the_day::BackInTheDay;
 use super::boring_words::BoringWords;
+use super::canonical_casing::CanonicalCasing;
+use super::capitalization_after_colon::CapitalizationAfterColon;
 use super::capitalize_personal_pronouns::CapitalizePersonalPronouns;
 use super::chock_full::ChockFull;
 use super::compound_nouns::CompoundNouns;
 use super::correct_number_suffix::CorrectNumberSuffix;
+use super::dangling_modifiers::DanglingModifiers;
 use super::despite_of::DespiteOf;
 use super::dot_initialisms::DotInitialisms;
 use super::ellipsis_length::EllipsisLength;
 use super::expand_time_shorthands::ExpandTimeShorthands;
+use super::forbidden_words::ForbiddenWords;
+use super::heading_punctuation::HeadingPunctuation;
 use super::hereby::Hereby;
 use super::hop_hope::HopHope;
 use super::hyphenate_number_day::HyphenateNumberDay;
+use super::informal_language::InformalLanguage;
 use super::left_right_hand::LeftRightHand;
 use super::lets_confusion::LetsConfusion;
 use super::likewise::Likewise;
 use super::linking_verbs::LinkingVerbs;
+use super::lint_profile::run_rule;
+use super::list_numbering::ListNumbering;
+use super::list_punctuation::ListPunctuation;
 use super::long_sentences::LongSentences;
 use super::matcher::Matcher;
 use super::merge_words::MergeWords;
+use super::misplaced_only::MisplacedOnly;
 use super::modal_of::ModalOf;
 use super::multiple_sequential_pronouns::MultipleSequentialPronouns;
 use super::nobody::Nobody;
 use super::number_suffix_capitalization::NumberSuffixCapitalization;
 use super::out_of_date::OutOfDate;
+use super::personal_voice::{DocumentGenre, PersonalVoice};
 use super::pique_interest::PiqueInterest;
 use super::plural_conjugate::PluralConjugate;
 use super::possessive_your::PossessiveYour;
 use super::pronoun_contraction::PronounContraction;
 use super::proper_noun_capitalization_linters;
 use super::repeated_words::RepeatedWords;
+use super::respectively::Respectively;
+use super::sensitive_words::SensitiveWords;
 use super::sentence_capitalization::SentenceCapitalization;
 use super::somewhat_something::SomewhatSomething;
+use super::space_after_punctuation::SpaceAfterPunctuation;
 use super::spaces::Spaces;
 use super::spell_check::SpellCheck;
 use super::spelled_numbers::SpelledNumbers;
 use super::terminating_conjunctions::TerminatingConjunctions;
+use super::terminology::Terminology;
 use super::that_which::ThatWhich;
 use super::then_than::ThenThan;
+use super::transition_overuse::TransitionOveruse;
 use super::unclosed_quotes::UnclosedQuotes;
+use super::unpaired_brackets::UnpairedBrackets;
 use super::use_genitive::UseGenitive;
 use super::was_aloud::WasAloud;
 use super::whereas::Whereas;
 use super::wordpress_dotcom::WordPressDotcom;
 use super::wrong_quotes::WrongQuotes;
-use super::{CurrencyPlacement, Linter, NoOxfordComma, OxfordComma};
-use crate::Document;
-use crate::linting::{closed_compounds, phrase_corrections};
-use crate::{Dictionary, MutableDictionary};
+use super::{
+    CurrencyConsistency, CurrencyPlacement, LazyLinter, Linter, NoOxfordComma, OxfordComma,
+};
+use super::{Lint, LintProfile, OverlapPolicy, RuleDescription, SentenceCache, Severity};
+use crate::linting::{
+    binomial_order, closed_compounds, gender_neutral_language, idiom_corrections,
+    phrase_corrections,
+};
+use crate::{Dialect, Dictionary, MutableDictionary, TitleCaseStyle};
+use crate::{Document, InlineDirectives, Span, TokenStringExt};
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 #[serde(transparent)]
@@ -127,40 +152,176 @@ impl LintGroupConfig {
     }
 }
 
+/// The [`Severity`] attached to each rule in a [`LintGroup`].
+///
+/// Rules without an entry are left alone, which allows integrations to fall back to their own
+/// default severity.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct SeverityConfig {
+    inner: HashMap<String, Severity>,
+}
+
+impl SeverityConfig {
+    pub fn set_rule_severity(&mut self, key: impl ToString, severity: Severity) {
+        self.inner.insert(key.to_string(), severity);
+    }
+
+    /// Remove any severity attached to a rule.
+    pub fn unset_rule_severity(&mut self, key: impl AsRef<str>) {
+        self.inner.remove(key.as_ref());
+    }
+
+    pub fn get_rule_severity(&self, key: &str) -> Option<Severity> {
+        self.inner.get(key).copied()
+    }
+
+    /// Merge the contents of another [`SeverityConfig`] into this one.
+    /// The other config will be left empty after this operation.
+    ///
+    /// Conflicting keys will be overridden by the value in the other config.
+    pub fn merge_from(&mut self, other: &mut SeverityConfig) {
+        self.inner.extend(other.inner.drain());
+    }
+
+    /// Clear all severities.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
 #[derive(Default)]
 pub struct LintGroup {
     pub config: LintGroupConfig,
+    pub severity_config: SeverityConfig,
+    /// Applied to the lints produced by all rules combined.
+    pub overlap_policy: OverlapPolicy,
+    /// When present, rules are run sentence by sentence and only on sentences that have changed
+    /// since the last run.
+    pub sentence_cache: Option<SentenceCache>,
+    /// When present, records how long each rule takes to run and how many lints it produces.
+    pub profile: Option<LintProfile>,
     /// We use a binary map here so the ordering is stable.
     inner: BTreeMap<String, Box<dyn Linter>>,
+    /// Used to skip rules whose anchor words don't appear in a document.
+    /// Built on first use, and again when rules are added or enabled.
+    anchor_filter: Option<AnchorFilter>,
+    /// Passed to every rule in the group.
+    dialect: Dialect,
+    /// Used by the proper noun capitalization rules, when they are rebuilt by
+    /// [`Self::set_curated_dictionary`].
+    title_case_style: TitleCaseStyle,
 }
 
 impl LintGroup {
     pub fn empty() -> Self {
         Self {
             config: LintGroupConfig::default(),
+            severity_config: SeverityConfig::default(),
+            overlap_policy: OverlapPolicy::default(),
+            sentence_cache: None,
+            profile: None,
             inner: BTreeMap::new(),
+            anchor_filter: None,
+            dialect: Dialect::default(),
+            title_case_style: TitleCaseStyle::default(),
         }
     }
 
     /// Add a [`Linter`] to the group, returning whether the operation was successful.
     /// If it returns `false`, it is because a linter with that key already existed in the group.
-    pub fn add(&mut self, name: impl AsRef<str>, linter: Box<dyn Linter>) -> bool {
+    pub fn add(&mut self, name: impl AsRef<str>, mut linter: Box<dyn Linter>) -> bool {
         if self.inner.contains_key(name.as_ref()) {
             false
         } else {
+            linter.set_dialect(self.dialect);
             self.inner.insert(name.as_ref().to_string(), linter);
+            self.anchor_filter = None;
             true
         }
     }
 
+    /// Add a rule that is only constructed once it is first needed, returning whether the
+    /// operation was successful.
+    ///
+    /// See [`LazyLinter`].
+    pub fn add_lazy<L: Linter + 'static>(
+        &mut self,
+        name: impl AsRef<str>,
+        build: impl Fn() -> L + Send + Sync + 'static,
+    ) -> bool {
+        self.add(name, Box::new(LazyLinter::new(build)))
+    }
+
     /// Merge the contents of another [`LintGroup`] into this one.
     /// The other lint group will be left empty after this operation.
     pub fn merge_from(&mut self, other: &mut LintGroup) {
         self.config.merge_from(&mut other.config);
+        self.severity_config.merge_from(&mut other.severity_config);
 
         let other_map = std::mem::take(&mut other.inner);
 
-        self.inner.extend(other_map);
+        for (key, mut linter) in other_map {
+            linter.set_dialect(self.dialect);
+            self.inner.insert(key, linter);
+        }
+        self.anchor_filter = None;
+    }
+
+    /// Put `linter` in place of the rule called `name`, taking on the group's dialect.
+    ///
+    /// The old rule's anchor words and cached lints no longer apply, so they are forgotten.
+    fn replace_rule(&mut self, name: &str, mut linter: Box<dyn Linter>) {
+        linter.set_dialect(self.dialect);
+        self.inner.insert(name.to_string(), linter);
+        self.anchor_filter = None;
+
+        if let Some(sentence_cache) = &mut self.sentence_cache {
+            sentence_cache.clear();
+        }
+    }
+
+    /// Make sure [`Self::anchor_filter`] knows about the anchor words of every enabled rule.
+    ///
+    /// Asking a [`LazyLinter`] for its anchor words constructs it, so rules that have never been
+    /// enabled are left out.
+    fn update_anchor_filter(&mut self) {
+        let up_to_date = self.anchor_filter.as_ref().is_some_and(|filter| {
+            self.inner
+                .keys()
+                .filter(|key| self.config.is_rule_enabled(key))
+                .all(|key| filter.has_considered(key))
+        });
+
+        if up_to_date {
+            return;
+        }
+
+        let previous = self.anchor_filter.take();
+        let rules = self.inner.iter().filter(|(key, _)| {
+            self.config.is_rule_enabled(key)
+                || previous
+                    .as_ref()
+                    .is_some_and(|filter| filter.has_considered(key))
+        });
+
+        self.anchor_filter =
+            AnchorFilter::new(rules.map(|(key, linter)| (key.as_str(), linter.as_ref())));
+    }
+
+    /// The dialect the group's rules check against.
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// Make every rule in the group, including those added later, check against the given
+    /// [`Dialect`].
+    pub fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+
+        for linter in self.inner.values_mut() {
+            linter.set_dialect(dialect);
+        }
     }
 
     /// Set all contained rules to a specific value.
@@ -181,14 +342,282 @@ impl LintGroup {
             .collect()
     }
 
+    /// Describe every rule in the group, ordered by name.
+    ///
+    /// See [`RuleDescription`] for what's included.
+    pub fn rule_descriptions(&self) -> Vec<RuleDescription> {
+        let curated = curated_config();
+
+        self.inner
+            .iter()
+            .map(|(key, linter)| self.describe(key, linter.as_ref(), &curated))
+            .collect()
+    }
+
+    /// Describe a single rule in the group, if it exists.
+    pub fn rule_description(&self, name: &str) -> Option<RuleDescription> {
+        let (key, linter) = self.inner.get_key_value(name)?;
+
+        Some(self.describe(key, linter.as_ref(), &curated_config()))
+    }
+
+    fn describe(
+        &self,
+        key: &str,
+        linter: &dyn Linter,
+        curated: &LintGroupConfig,
+    ) -> RuleDescription {
+        RuleDescription {
+            name: key.to_string(),
+            description: linter.description().to_string(),
+            kind: linter.lint_kind(),
+            default_enabled: curated.is_rule_enabled(key),
+            severity: self.severity_config.get_rule_severity(key),
+            examples: linter.examples(),
+        }
+    }
+
+    /// Check against the given [`Dialect`].
+    ///
+    /// See [`Self::set_dialect`].
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.set_dialect(dialect);
+        self
+    }
+
     /// Swap out [`Self::config`] with another [`LintGroupConfig`].
     pub fn with_lint_config(mut self, config: LintGroupConfig) -> Self {
         self.config = config;
         self
     }
 
+    /// Swap out [`Self::severity_config`] with another [`SeverityConfig`].
+    pub fn with_severity_config(mut self, severity_config: SeverityConfig) -> Self {
+        self.severity_config = severity_config;
+        self
+    }
+
+    /// Swap out [`Self::overlap_policy`].
+    pub fn with_overlap_policy(mut self, overlap_policy: OverlapPolicy) -> Self {
+        self.overlap_policy = overlap_policy;
+        self
+    }
+
+    /// Replace the group's list of [`ForbiddenWords`].
+    pub fn with_forbidden_words(mut self, forbidden_words: ForbiddenWords) -> Self {
+        self.replace_rule("ForbiddenWords", Box::new(forbidden_words));
+        self
+    }
+
+    /// Replace the group's [`Terminology`].
+    pub fn set_terminology(&mut self, terminology: Terminology) {
+        self.replace_rule("Terminology", Box::new(terminology));
+    }
+
+    /// See [`Self::set_terminology`].
+    pub fn with_terminology(mut self, terminology: Terminology) -> Self {
+        self.set_terminology(terminology);
+        self
+    }
+
+    /// Replace the group's [`SensitiveWords`] rule.
+    pub fn set_sensitive_words(&mut self, sensitive_words: SensitiveWords) {
+        self.replace_rule("SensitiveWords", Box::new(sensitive_words));
+    }
+
+    /// See [`Self::set_sensitive_words`].
+    pub fn with_sensitive_words(mut self, sensitive_words: SensitiveWords) -> Self {
+        self.set_sensitive_words(sensitive_words);
+        self
+    }
+
+    /// Tell the group what kind of document it is checking, which decides the pronouns
+    /// [`PersonalVoice`] flags.
+    pub fn set_document_genre(&mut self, genre: Option<DocumentGenre>) {
+        self.replace_rule("PersonalVoice", Box::new(PersonalVoice::new(genre)));
+    }
+
+    /// See [`Self::set_document_genre`].
+    pub fn with_document_genre(mut self, genre: Option<DocumentGenre>) -> Self {
+        self.set_document_genre(genre);
+        self
+    }
+
+    /// Replace the group's [`TransitionOveruse`] rule, such as with one configured for different
+    /// counts.
+    pub fn set_transition_overuse(&mut self, transition_overuse: TransitionOveruse) {
+        self.replace_rule("TransitionOveruse", Box::new(transition_overuse));
+    }
+
+    /// See [`Self::set_transition_overuse`].
+    pub fn with_transition_overuse(mut self, transition_overuse: TransitionOveruse) -> Self {
+        self.set_transition_overuse(transition_overuse);
+        self
+    }
+
+    /// Replace the group's [`SpellCheck`] rule, such as with one configured for a different
+    /// [`crate::KeyboardLayout`].
+    ///
+    /// The rule takes on the group's dialect.
+    pub fn with_spell_check<T: Dictionary + 'static>(mut self, spell_check: SpellCheck<T>) -> Self {
+        self.replace_rule("SpellCheck", Box::new(spell_check));
+        self
+    }
+
+    /// Point the curated rules that look words up in a dictionary (spell checking, canonical
+    /// casing and proper noun capitalization) at a new one.
+    ///
+    /// Those rules are rebuilt with their default settings, taking on the group's dialect.
+    /// Every other rule is kept as it is, along with the group's configuration and
+    /// [`Self::overlap_policy`].
+    pub fn set_curated_dictionary(&mut self, dictionary: Arc<impl Dictionary + 'static>) {
+        let mut rebuilt = proper_noun_capitalization_linters::lint_group_with_style(
+            dictionary.clone(),
+            self.title_case_style,
+        );
+        rebuilt.add(
+            "CanonicalCasing",
+            Box::new(CanonicalCasing::new(dictionary.clone())),
+        );
+        rebuilt.add("SpellCheck", Box::new(SpellCheck::new(dictionary)));
+
+        for (key, mut linter) in mem::take(&mut rebuilt.inner) {
+            linter.set_dialect(self.dialect);
+            self.inner.insert(key, linter);
+        }
+
+        self.anchor_filter = None;
+
+        // The cached lints were found with the old dictionary.
+        if let Some(sentence_cache) = &mut self.sentence_cache {
+            sentence_cache.clear();
+        }
+    }
+
+    /// Swap out [`Self::sentence_cache`].
+    pub fn with_sentence_cache(mut self, sentence_cache: SentenceCache) -> Self {
+        self.sentence_cache = Some(sentence_cache);
+        self
+    }
+
+    /// Swap out [`Self::profile`].
+    pub fn with_profile(mut self, profile: LintProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Apply inline directives, severities and the overlap policy to the combined output of the
+    /// group's rules.
+    fn finish_lints(&self, document: &Document, mut results: Vec<Lint>) -> Vec<Lint> {
+        let directives = InlineDirectives::parse(document.get_source(), document.get_comments());
+
+        results.retain(|lint| {
+            let key = lint.rule.as_deref().unwrap_or_default();
+            !directives.is_suppressed(key, lint.span)
+        });
+
+        for lint in &mut results {
+            let key = lint.rule.as_deref().unwrap_or_default();
+
+            if let Some(severity) = self.severity_config.get_rule_severity(key) {
+                lint.severity = Some(severity);
+            }
+        }
+
+        self.overlap_policy.resolve(&mut results);
+
+        results
+    }
+
+    /// Lint a document using every available thread.
+    ///
+    /// The document is split into paragraphs, and each enabled rule runs on them in parallel
+    /// with the other rules.
+    /// Rules cannot see past the paragraph they are given, but results are otherwise the same
+    /// as [`Linter::lint`], and in the same order.
+    #[cfg(feature = "parallel")]
+    pub fn lint_parallel(&mut self, document: &Document) -> Vec<Lint> {
+        use rayon::prelude::*;
+
+        use super::RuleProfile;
+        use super::lint_profile::run_measured;
+        use crate::TokenStringExt;
+
+        let paragraphs: Vec<_> = document.iter_paragraphs().collect();
+        let paragraphs: Vec<_> = paragraphs
+            .into_par_iter()
+            .filter_map(|paragraph| document.sub_document(paragraph))
+            .collect();
+
+        self.update_anchor_filter();
+        let hits = self
+            .anchor_filter
+            .as_ref()
+            .map(|filter| filter.search(document));
+
+        let config = &self.config;
+        let should_run = |key: &str| {
+            config.is_rule_enabled(key) && hits.as_ref().is_none_or(|h| h.may_match(key))
+        };
+        let profiling = self.profile.is_some();
+
+        let per_rule: Vec<_> = self
+            .inner
+            .par_iter_mut()
+            .filter(|(key, _)| should_run(key))
+            .map(|(key, linter)| {
+                let mut lints = Vec::new();
+                let mut measured = RuleProfile::default();
+
+                for (span, paragraph) in &paragraphs {
+                    if !hits.as_ref().is_none_or(|h| h.may_match_within(key, *span)) {
+                        continue;
+                    }
+
+                    let paragraph_lints = if profiling {
+                        let (paragraph_lints, paragraph_profile) =
+                            run_measured(linter.as_mut(), paragraph);
+                        measured += paragraph_profile;
+                        paragraph_lints
+                    } else {
+                        linter.lint(paragraph)
+                    };
+
+                    lints.extend(paragraph_lints.into_iter().map(|mut lint| {
+                        lint.push_by(span.start);
+                        lint.rule = Some(key.clone());
+                        lint
+                    }));
+                }
+
+                (key.as_str(), lints, measured)
+            })
+            .collect();
+
+        let mut results = Vec::new();
+
+        for (key, lints, measured) in per_rule {
+            if let Some(profile) = &mut self.profile {
+                profile.add(key, measured);
+            }
+
+            results.extend(lints);
+        }
+
+        self.finish_lints(document, results)
+    }
+
     pub fn new_curated(dictionary: Arc<impl Dictionary + 'static>) -> Self {
+        Self::new_curated_with_title_case_style(dictionary, TitleCaseStyle::default())
+    }
+
+    /// Create a new curated group whose capitalization rules follow the given title case style.
+    pub fn new_curated_with_title_case_style(
+        dictionary: Arc<impl Dictionary + 'static>,
+        title_case_style: TitleCaseStyle,
+    ) -> Self {
         let mut out = Self::empty();
+        out.title_case_style = title_case_style;
 
         macro_rules! insert_struct_rule {
             ($rule:ident, $default_config:expr) => {
@@ -199,10 +628,16 @@ impl LintGroup {
         }
 
         out.merge_from(&mut phrase_corrections::lint_group());
-        out.merge_from(&mut proper_noun_capitalization_linters::lint_group(
-            dictionary.clone(),
-        ));
+        out.merge_from(&mut idiom_corrections::lint_group());
+        out.merge_from(
+            &mut proper_noun_capitalization_linters::lint_group_with_style(
+                dictionary.clone(),
+                title_case_style,
+            ),
+        );
         out.merge_from(&mut closed_compounds::lint_group());
+        out.merge_from(&mut gender_neutral_language::lint_group());
+        out.merge_from(&mut binomial_order::lint_group());
 
         // Add all the more complex rules to the group.
         insert_struct_rule!(BackInTheDay, true);
@@ -224,16 +659,24 @@ impl LintGroup {
         insert_struct_rule!(AnA, true);
         insert_struct_rule!(SentenceCapitalization, true);
         insert_struct_rule!(UnclosedQuotes, true);
+        insert_struct_rule!(UnpairedBrackets, true);
+        insert_struct_rule!(ListPunctuation, true);
+        insert_struct_rule!(ListNumbering, true);
+        insert_struct_rule!(HeadingPunctuation, true);
+        insert_struct_rule!(CapitalizationAfterColon, false);
         insert_struct_rule!(WrongQuotes, false);
         insert_struct_rule!(LongSentences, true);
         insert_struct_rule!(RepeatedWords, true);
         insert_struct_rule!(Spaces, true);
+        insert_struct_rule!(SpaceAfterPunctuation, true);
         insert_struct_rule!(Matcher, true);
         insert_struct_rule!(CorrectNumberSuffix, true);
         insert_struct_rule!(NumberSuffixCapitalization, true);
         insert_struct_rule!(MultipleSequentialPronouns, true);
         insert_struct_rule!(LinkingVerbs, false);
         insert_struct_rule!(AvoidCurses, true);
+        insert_struct_rule!(InformalLanguage, false);
+        insert_struct_rule!(ArchaicLanguage, false);
         insert_struct_rule!(TerminatingConjunctions, true);
         insert_struct_rule!(EllipsisLength, true);
         insert_struct_rule!(DotInitialisms, true);
@@ -246,6 +689,7 @@ impl LintGroup {
         insert_struct_rule!(OxfordComma, true);
         insert_struct_rule!(NoOxfordComma, false);
         insert_struct_rule!(PronounContraction, true);
+        insert_struct_rule!(CurrencyConsistency, true);
         insert_struct_rule!(CurrencyPlacement, true);
         insert_struct_rule!(SomewhatSomething, true);
         insert_struct_rule!(LetsConfusion, true);
@@ -253,6 +697,21 @@ impl LintGroup {
         insert_struct_rule!(ChockFull, true);
         insert_struct_rule!(ExpandTimeShorthands, true);
         insert_struct_rule!(ModalOf, true);
+        insert_struct_rule!(ForbiddenWords, true);
+        insert_struct_rule!(Terminology, true);
+        insert_struct_rule!(SensitiveWords, false);
+        insert_struct_rule!(PersonalVoice, true);
+        insert_struct_rule!(TransitionOveruse, false);
+        insert_struct_rule!(Respectively, true);
+        insert_struct_rule!(MisplacedOnly, false);
+        insert_struct_rule!(DanglingModifiers, false);
+        insert_struct_rule!(AbsoluteAdjectives, true);
+
+        out.add(
+            "CanonicalCasing",
+            Box::new(CanonicalCasing::new(dictionary.clone())),
+        );
+        out.config.set_rule_enabled("CanonicalCasing", true);
 
         out.add("SpellCheck", Box::new(SpellCheck::new(dictionary)));
         out.config.set_rule_enabled("SpellCheck", true);
@@ -270,30 +729,188 @@ impl LintGroup {
 
 impl Linter for LintGroup {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
-        let mut results = Vec::new();
+        self.update_anchor_filter();
+        let hits = self
+            .anchor_filter
+            .as_ref()
+            .map(|filter| filter.search(document));
+
+        let config = &self.config;
+        let should_run = |key: &str| {
+            config.is_rule_enabled(key) && hits.as_ref().is_none_or(|h| h.may_match(key))
+        };
+        let mut profile = self.profile.as_mut();
 
-        for (key, linter) in &mut self.inner {
-            if self.config.is_rule_enabled(key) {
-                results.extend(linter.lint(document));
+        let results = match &mut self.sentence_cache {
+            Some(cache) => {
+                let should_run = |key: &str, span: Span| {
+                    config.is_rule_enabled(key)
+                        && hits.as_ref().is_none_or(|h| h.may_match_within(key, span))
+                };
+
+                cache.lint(document, &mut self.inner, should_run, profile)
             }
-        }
+            None => {
+                let mut results = Vec::new();
+                // Only built once a rule with anchor words needs them.
+                let mut paragraphs: Option<Vec<(Span, Document)>> = None;
 
-        results
+                for (key, linter) in &mut self.inner {
+                    if !should_run(key) {
+                        continue;
+                    }
+
+                    let lints = match hits.as_ref().filter(|h| h.is_anchored(key)) {
+                        // Rules with anchor words only match near them, so they only need the
+                        // paragraphs their anchor words were found in.
+                        Some(hits) => {
+                            let paragraphs = paragraphs.get_or_insert_with(|| {
+                                document
+                                    .iter_paragraphs()
+                                    .filter_map(|paragraph| document.sub_document(paragraph))
+                                    .collect()
+                            });
+
+                            let mut lints = Vec::new();
+
+                            for (span, paragraph) in paragraphs
+                                .iter()
+                                .filter(|(span, _)| hits.may_match_within(key, *span))
+                            {
+                                let paragraph_lints = run_rule(
+                                    linter.as_mut(),
+                                    paragraph,
+                                    key,
+                                    profile.as_deref_mut(),
+                                );
+
+                                lints.extend(paragraph_lints.into_iter().map(|mut lint| {
+                                    lint.push_by(span.start);
+                                    lint
+                                }));
+                            }
+
+                            lints
+                        }
+                        None => run_rule(linter.as_mut(), document, key, profile.as_deref_mut()),
+                    };
+
+                    results.extend(lints.into_iter().map(|mut lint| {
+                        lint.rule = Some(key.clone());
+                        lint
+                    }));
+                }
+
+                results
+            }
+        };
+
+        self.finish_lints(document, results)
     }
 
     fn description(&self) -> &str {
         "A collection of linters that can be run as one."
     }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        LintGroup::set_dialect(self, dialect);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use crate::{Document, FstDictionary, MutableDictionary, linting::Linter};
+    use crate::{
+        Dialect, Document, FstDictionary, MutableDictionary, WordMetadata,
+        linting::{
+            ForbiddenWord, ForbiddenWords, Linter, OverlapPolicy, Severity, SpellCheck, Terminology,
+        },
+    };
 
     use super::LintGroup;
 
+    /// Records the text of every document it is asked to lint.
+    #[derive(Default)]
+    struct RecordingLinter {
+        seen: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl Linter for RecordingLinter {
+        fn lint(&mut self, document: &Document) -> Vec<crate::linting::Lint> {
+            self.seen
+                .lock()
+                .unwrap()
+                .push(document.get_source().iter().collect());
+            Vec::new()
+        }
+
+        fn description(&self) -> &str {
+            "Records what it sees."
+        }
+
+        fn anchor_words(&self) -> Option<Vec<crate::CharString>> {
+            Some(vec!["tact".chars().collect()])
+        }
+    }
+
+    #[test]
+    fn anchored_rules_only_see_paragraphs_with_hits() {
+        let recorder = RecordingLinter::default();
+        let seen = recorder.seen.clone();
+
+        let mut group = LintGroup::empty();
+        group.add("Recorder", Box::new(recorder));
+        group.config.set_rule_enabled("Recorder", true);
+
+        let document = Document::new_markdown_default_curated(
+            "Nothing here.\n\nTime to change tact.\n\nOr here.",
+        );
+        group.lint(&document);
+
+        assert_eq!(*seen.lock().unwrap(), ["Time to change tact."]);
+    }
+
+    #[test]
+    fn anchored_lints_keep_their_position() {
+        let mut group = LintGroup::empty();
+        group.add(
+            "Tact",
+            Box::new(crate::linting::MapPhraseLinter::new_exact_phrase(
+                "change tact",
+                ["change tack"],
+                "",
+                "",
+            )),
+        );
+        group.config.set_rule_enabled("Tact", true);
+
+        let document =
+            Document::new_markdown_default_curated("Nothing here.\n\nTime to change tact.");
+        let lints = group.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].span.get_content_string(document.get_source()),
+            "change tact"
+        );
+    }
+
+    #[test]
+    fn replaced_rules_drop_the_old_anchor_words() {
+        let mut group = LintGroup::empty();
+        group.add("Terminology", Box::new(RecordingLinter::default()));
+        group.config.set_rule_enabled("Terminology", true);
+
+        let document =
+            Document::new_markdown_default_curated("Nothing here.\n\nOur backend uses wifi.");
+        group.lint(&document);
+
+        group.set_terminology(Terminology::new().with_term("Wi-Fi", ["wifi"]));
+
+        assert_eq!(group.lint(&document).len(), 1);
+    }
+
     #[test]
     fn can_get_all_descriptions() {
         let group = LintGroup::new_curated(Arc::new(MutableDictionary::default()));
@@ -319,4 +936,222 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn rule_descriptions_cover_every_rule() {
+        let group = LintGroup::new_curated(Arc::new(MutableDictionary::default()));
+        let descriptions = group.rule_descriptions();
+
+        assert_eq!(descriptions.len(), group.all_descriptions().len());
+        assert!(
+            descriptions
+                .iter()
+                .any(|d| d.name == "SpellCheck" && d.kind.is_some_and(|k| k.is_spelling()))
+        );
+        assert!(serde_json::to_string(&descriptions).is_ok());
+    }
+
+    #[test]
+    fn describes_a_single_rule() {
+        let group = LintGroup::new_curated(Arc::new(MutableDictionary::default()));
+
+        let description = group.rule_description("AnA").unwrap();
+
+        assert_eq!(description.name, "AnA");
+        assert!(!description.description.is_empty());
+        assert!(group.rule_description("NotARule").is_none());
+    }
+
+    #[test]
+    fn phrase_rules_enabled_later_still_run() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+        group.config.set_rule_enabled("ChangeTack", false);
+
+        let doc = Document::new_markdown_default_curated("We need to change tact.");
+        assert!(group.lint(&doc).is_empty());
+
+        group.config.set_rule_enabled("ChangeTack", true);
+        let lints = group.lint(&doc);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule.as_deref(), Some("ChangeTack"));
+    }
+
+    /// Every example a rule advertises should actually be flagged by that rule, applying the
+    /// first suggestion should produce the advertised correction, and the rule should have
+    /// nothing to say about the correction.
+    #[test]
+    fn rule_examples_are_accurate() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+        let descriptions = group.rule_descriptions();
+
+        for description in descriptions {
+            group.set_all_rules_to(Some(false));
+            group.config.set_rule_enabled(&description.name, true);
+
+            for example in description.examples {
+                let doc = Document::new_markdown_default_curated(&example.incorrect);
+                let lints = group.lint(&doc);
+
+                assert!(
+                    !lints.is_empty(),
+                    "{} did not flag {:?}",
+                    description.name,
+                    example.incorrect
+                );
+
+                let Some(corrected) = example.corrected else {
+                    continue;
+                };
+
+                let mut text: Vec<char> = example.incorrect.chars().collect();
+                let mut lints = lints;
+                lints.sort_by_key(|l| std::cmp::Reverse(l.span.start));
+
+                for lint in lints {
+                    if let Some(sug) = lint.suggestions.first() {
+                        sug.apply(lint.span, &mut text);
+                    }
+                }
+
+                assert_eq!(
+                    text.iter().collect::<String>(),
+                    corrected,
+                    "{}",
+                    description.name
+                );
+
+                let doc = Document::new_markdown_default_curated(&corrected);
+                let lints = group.lint(&doc);
+
+                assert!(
+                    lints.is_empty(),
+                    "{} flagged the corrected {:?}: {:?}",
+                    description.name,
+                    corrected,
+                    lints.iter().map(|l| &l.message).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn honors_inline_directives() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+
+        let doc = Document::new_markdown_default_curated(
+            "This is an tset.\n\n<!-- harper: disable SpellCheck -->\n\nThis is an tset.",
+        );
+        let lints = group.lint(&doc);
+
+        let (spelling, other): (Vec<_>, Vec<_>) =
+            lints.iter().partition(|l| l.lint_kind.is_spelling());
+
+        assert_eq!(spelling.len(), 1);
+        assert_eq!(other.len(), 2);
+    }
+
+    #[test]
+    fn ignores_inline_directives_outside_comments() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+
+        let plain = Document::new_markdown_default_curated("This is an tset.\n\nThis is an tset.");
+        let mentioned = Document::new_markdown_default_curated(
+            "Write `<!-- harper: disable -->` to turn it off.\n\nThis is an tset.\n\nThis is an tset.",
+        );
+
+        assert_eq!(group.lint(&mentioned).len(), group.lint(&plain).len());
+    }
+
+    #[test]
+    fn overlap_policy_removes_stacked_lints() {
+        let doc = Document::new_markdown_default_curated("baited breath");
+
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+        assert!(group.lint(&doc).len() > 1);
+
+        let mut group = group.with_overlap_policy(OverlapPolicy::KeepMostImportant);
+        assert_eq!(group.lint(&doc).len(), 1);
+    }
+
+    #[test]
+    fn applies_configured_severity() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+        group
+            .severity_config
+            .set_rule_severity("SpellCheck", Severity::Error);
+
+        let doc = Document::new_markdown_default_curated("This is an tset.");
+        let lints = group.lint(&doc);
+
+        assert!(lints.iter().any(|l| l.severity == Some(Severity::Error)));
+        assert!(
+            lints
+                .iter()
+                .filter(|l| !l.lint_kind.is_spelling())
+                .all(|l| l.severity.is_none())
+        );
+    }
+
+    #[test]
+    fn passes_dialect_to_rules() {
+        let doc = Document::new_markdown_default_curated("I like the colour.");
+
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+        assert_eq!(group.lint(&doc).len(), 1);
+
+        group.set_dialect(Dialect::British);
+        assert_eq!(group.lint(&doc).len(), 0);
+    }
+
+    #[test]
+    fn replaced_rules_take_on_dialect() {
+        let doc = Document::new_markdown_default_curated("I like the colour.");
+
+        let mut group = LintGroup::new_curated(FstDictionary::curated())
+            .with_dialect(Dialect::British)
+            .with_spell_check(SpellCheck::new(FstDictionary::curated()));
+
+        assert_eq!(group.lint(&doc).len(), 0);
+    }
+
+    #[test]
+    fn new_dictionary_keeps_the_rest_of_the_group() {
+        let doc = Document::new_markdown_default_curated(
+            "I like the colour of the blorptastic house. We utilize it.",
+        );
+
+        let mut group = LintGroup::new_curated(FstDictionary::curated())
+            .with_dialect(Dialect::British)
+            .with_overlap_policy(OverlapPolicy::KeepMostImportant)
+            .with_forbidden_words(ForbiddenWords::new().with_forbidden(
+                "utilize",
+                ForbiddenWord {
+                    replacements: vec!["use".to_string()],
+                    reason: None,
+                },
+            ));
+
+        let mut dictionary = (*MutableDictionary::curated()).clone();
+        dictionary.append_word_str("blorptastic", WordMetadata::default());
+        group.set_curated_dictionary(Arc::new(dictionary));
+
+        assert_eq!(group.overlap_policy, OverlapPolicy::KeepMostImportant);
+
+        let lints = group.lint(&doc);
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule.as_deref(), Some("ForbiddenWords"));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let document = Document::new_markdown_default_curated(
+            "This is an test.\n\nThere is is a problem. I can't beleive it!\n\nAll is well.",
+        );
+
+        let mut group = LintGroup::new_curated(FstDictionary::curated());
+
+        assert_eq!(group.lint_parallel(&document), group.lint(&document));
+    }
 }
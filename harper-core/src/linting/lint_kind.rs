@@ -3,6 +3,8 @@ use std::fmt::Display;
 use is_macro::Is;
 use serde::{Deserialize, Serialize};
 
+use super::Severity;
+
 /// The general category a [`Lint`](super::Lint) falls into.
 /// There's no reason not to add a new item here if you are adding a new rule that doesn't fit
 /// the existing categories.
@@ -17,6 +19,8 @@ pub enum LintKind {
     Enhancement,
     Readability,
     WordChoice,
+    /// Words that have been explicitly banned, usually by a style guide.
+    Forbidden,
     #[default]
     Miscellaneous,
 }
@@ -34,9 +38,28 @@ impl LintKind {
             LintKind::Enhancement => "Enhancement",
             LintKind::WordChoice => "WordChoice",
             LintKind::Style => "Style",
+            LintKind::Forbidden => "Forbidden",
         }
         .to_owned()
     }
+
+    /// The [`Severity`] of lints of this kind, for when their rule isn't given one.
+    ///
+    /// Spelling and grammar mistakes are warnings, while matters of style are only information.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            LintKind::Spelling
+            | LintKind::Capitalization
+            | LintKind::Repetition
+            | LintKind::WordChoice
+            | LintKind::Forbidden
+            | LintKind::Miscellaneous => Severity::Warning,
+            LintKind::Style
+            | LintKind::Formatting
+            | LintKind::Readability
+            | LintKind::Enhancement => Severity::Info,
+        }
+    }
 }
 
 impl Display for LintKind {
@@ -51,6 +74,7 @@ impl Display for LintKind {
             LintKind::Enhancement => "Enhancement",
             LintKind::WordChoice => "Word Choice",
             LintKind::Style => "Style",
+            LintKind::Forbidden => "Forbidden Word",
         };
 
         write!(f, "{}", s)
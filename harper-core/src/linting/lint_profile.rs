@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::ops::AddAssign;
+use std::time::{Duration, Instant};
+
+use super::{Lint, Linter};
+use crate::Document;
+
+/// Records how long each rule in a [`LintGroup`](super::LintGroup) takes to run and how many
+/// lints it produces, so slow or noisy rules can be found.
+///
+/// Profiling is opt-in, through [`LintGroup::with_profile`](super::LintGroup::with_profile).
+/// It relies on [`Instant`], so it cannot be used on `wasm32-unknown-unknown`.
+#[derive(Debug, Default, Clone)]
+pub struct LintProfile {
+    rules: BTreeMap<String, RuleProfile>,
+}
+
+/// The measurements taken for a single rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleProfile {
+    /// The number of times the rule was run.
+    ///
+    /// With a [`SentenceCache`](super::SentenceCache), rules only run on sentences they haven't
+    /// seen, and [`Self::lints`] only counts what those runs produced.
+    pub runs: usize,
+    pub time: Duration,
+    /// The number of lints produced, before inline directives and the overlap policy are
+    /// applied.
+    pub lints: usize,
+}
+
+impl RuleProfile {
+    /// The average time taken by each run, if there were any.
+    pub fn average_time(&self) -> Option<Duration> {
+        let runs = u32::try_from(self.runs).ok().filter(|runs| *runs > 0)?;
+        Some(self.time / runs)
+    }
+}
+
+impl AddAssign for RuleProfile {
+    fn add_assign(&mut self, other: Self) {
+        self.runs += other.runs;
+        self.time += other.time;
+        self.lints += other.lints;
+    }
+}
+
+impl LintProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, rule: &str) -> Option<&RuleProfile> {
+        self.rules.get(rule)
+    }
+
+    /// Iterate through the rules that have run, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RuleProfile)> {
+        self.rules
+            .iter()
+            .map(|(rule, profile)| (rule.as_str(), profile))
+    }
+
+    /// The rules that have run, slowest first.
+    pub fn slowest(&self) -> Vec<(&str, &RuleProfile)> {
+        let mut rules: Vec<_> = self.iter().collect();
+        rules.sort_by_key(|(_, profile)| std::cmp::Reverse(profile.time));
+        rules
+    }
+
+    /// The time taken by every rule combined.
+    pub fn total_time(&self) -> Duration {
+        self.rules.values().map(|profile| profile.time).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.rules.clear();
+    }
+
+    /// Add the measurements from another [`LintProfile`] to this one.
+    pub fn merge_from(&mut self, other: &LintProfile) {
+        for (rule, profile) in other.iter() {
+            self.add(rule, *profile);
+        }
+    }
+
+    /// Add measurements for a rule.
+    pub fn add(&mut self, rule: &str, profile: RuleProfile) {
+        match self.rules.get_mut(rule) {
+            Some(existing) => *existing += profile,
+            None => {
+                self.rules.insert(rule.to_string(), profile);
+            }
+        }
+    }
+}
+
+/// Run a rule on a document, recording it in `profile` if one is provided.
+pub(crate) fn run_rule(
+    linter: &mut (impl Linter + ?Sized),
+    document: &Document,
+    rule: &str,
+    profile: Option<&mut LintProfile>,
+) -> Vec<Lint> {
+    let Some(profile) = profile else {
+        return linter.lint(document);
+    };
+
+    let (lints, measured) = run_measured(linter, document);
+    profile.add(rule, measured);
+
+    lints
+}
+
+/// Run a rule on a document, measuring how long it took and how many lints it produced.
+pub(crate) fn run_measured(
+    linter: &mut (impl Linter + ?Sized),
+    document: &Document,
+) -> (Vec<Lint>, RuleProfile) {
+    let start = Instant::now();
+    let lints = linter.lint(document);
+
+    let profile = RuleProfile {
+        runs: 1,
+        time: start.elapsed(),
+        lints: lints.len(),
+    };
+
+    (lints, profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LintProfile;
+    use crate::linting::{LintGroup, Linter, SentenceCache};
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn records_enabled_rules() {
+        let mut group =
+            LintGroup::new_curated(FstDictionary::curated()).with_profile(LintProfile::new());
+        group.config.set_rule_enabled("RepeatedWords", false);
+
+        let document = Document::new_markdown_default_curated("This is an test of the the thing.");
+        group.lint(&document);
+
+        let profile = group.profile.as_ref().unwrap();
+
+        assert_eq!(
+            profile.get("AnA").map(|rule| (rule.runs, rule.lints)),
+            Some((1, 1))
+        );
+        assert!(profile.get("RepeatedWords").is_none());
+        assert!(profile.slowest().len() > 1);
+    }
+
+    #[test]
+    fn skips_cached_sentences() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated())
+            .with_sentence_cache(SentenceCache::new())
+            .with_profile(LintProfile::new());
+
+        let document = Document::new_markdown_default_curated("This is an test. Another one.");
+        group.lint(&document);
+        group.lint(&document);
+
+        let profile = group.profile.as_ref().unwrap();
+
+        assert_eq!(
+            profile.get("AnA").map(|rule| (rule.runs, rule.lints)),
+            Some((2, 1))
+        );
+    }
+
+    #[test]
+    fn merges_profiles() {
+        let mut group =
+            LintGroup::new_curated(FstDictionary::curated()).with_profile(LintProfile::new());
+        group.lint(&Document::new_markdown_default_curated("This is an test."));
+
+        let mut merged = LintProfile::new();
+        merged.merge_from(group.profile.as_ref().unwrap());
+        merged.merge_from(group.profile.as_ref().unwrap());
+
+        assert_eq!(
+            merged.get("AnA").map(|rule| (rule.runs, rule.lints)),
+            Some((2, 2))
+        );
+        assert_eq!(
+            merged.total_time(),
+            group.profile.as_ref().unwrap().total_time() * 2
+        );
+    }
+}
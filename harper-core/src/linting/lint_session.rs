@@ -0,0 +1,236 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use hashbrown::{HashMap, HashSet};
+
+use super::{Lint, Linter};
+use crate::parsers::Parser;
+use crate::{Dictionary, Document, IgnoredLints, Span, remove_overlaps};
+
+/// A document that is kept in sync with an editor through [`Self::apply_edit`].
+///
+/// Rather than re-parsing the whole document on every keystroke, only the paragraphs around each
+/// edit are parsed again.
+/// Each lint is given an ID when it is first found, and edits report which lints were added or
+/// removed, so editors can leave the rest of their decorations alone.
+pub struct LintSession<P, D, L> {
+    document: Document,
+    parser: P,
+    dictionary: D,
+    linter: L,
+    ignored_lints: IgnoredLints,
+    /// The current lints, each paired with its ID.
+    lints: Vec<(u32, Lint)>,
+    next_id: u32,
+}
+
+/// The lints added and removed by [`LintSession::apply_edit`], by ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintChanges {
+    pub added: Vec<u32>,
+    pub removed: Vec<u32>,
+}
+
+impl<P: Parser, D: Dictionary, L: Linter> LintSession<P, D, L> {
+    pub fn new(text: &str, parser: P, dictionary: D, linter: L) -> Self {
+        let document = Document::new(text, &parser, &dictionary);
+
+        let mut session = Self {
+            document,
+            parser,
+            dictionary,
+            linter,
+            ignored_lints: IgnoredLints::default(),
+            lints: Vec::new(),
+            next_id: 0,
+        };
+        session.relint_all();
+
+        session
+    }
+
+    /// Leave out lints that have been ignored.
+    pub fn with_ignored_lints(mut self, ignored_lints: IgnoredLints) -> Self {
+        self.ignored_lints = ignored_lints;
+        self.relint_all();
+        self
+    }
+
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+
+    /// The current lints, each paired with its ID.
+    pub fn lints(&self) -> &[(u32, Lint)] {
+        &self.lints
+    }
+
+    /// Find the lint with a given ID.
+    pub fn lint(&self, id: u32) -> Option<&Lint> {
+        self.lints
+            .iter()
+            .find_map(|(lint_id, lint)| (*lint_id == id).then_some(lint))
+    }
+
+    /// Replace the text in `span` with `new_text` and lint the document again.
+    ///
+    /// Lints that were not affected by the edit keep their IDs, even if the edit moved them.
+    pub fn apply_edit(&mut self, span: Span, new_text: &[char]) -> LintChanges {
+        let end = span.end.min(self.document.get_source().len());
+        let edited = Span::new(span.start.min(end), end);
+
+        self.document
+            .apply_edit(edited, new_text, &self.parser, &self.dictionary);
+
+        // Where each of the old lints would be if the edit didn't affect them.
+        let mut unaffected: HashMap<(usize, usize, u64), Vec<u32>> = HashMap::new();
+        for (id, lint) in &self.lints {
+            let moved = if lint.span.end <= edited.start {
+                lint.span
+            } else if lint.span.start >= edited.end {
+                Span::new(
+                    lint.span.start - edited.len() + new_text.len(),
+                    lint.span.end - edited.len() + new_text.len(),
+                )
+            } else {
+                continue;
+            };
+
+            unaffected
+                .entry((moved.start, moved.end, relative_hash(lint)))
+                .or_default()
+                .push(*id);
+        }
+
+        let old_ids: Vec<u32> = self.lints.iter().map(|(id, _)| *id).collect();
+
+        let mut changes = LintChanges::default();
+        let mut lints = Vec::new();
+
+        for lint in self.run_linter() {
+            let key = (lint.span.start, lint.span.end, relative_hash(&lint));
+            let id = match unaffected.get_mut(&key).and_then(|ids| ids.pop()) {
+                Some(id) => id,
+                None => {
+                    let id = self.take_id();
+                    changes.added.push(id);
+                    id
+                }
+            };
+
+            lints.push((id, lint));
+        }
+        self.lints = lints;
+
+        let kept: HashSet<u32> = self.lints.iter().map(|(id, _)| *id).collect();
+        changes.removed = old_ids
+            .into_iter()
+            .filter(|id| !kept.contains(id))
+            .collect();
+
+        changes
+    }
+
+    fn take_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn run_linter(&mut self) -> Vec<Lint> {
+        let mut lints = self.linter.lint(&self.document);
+        remove_overlaps(&mut lints);
+        self.ignored_lints
+            .remove_ignored(&mut lints, &self.document);
+        lints
+    }
+
+    /// Lint the whole document, giving every lint a new ID.
+    fn relint_all(&mut self) {
+        let lints = self.run_linter();
+        self.lints = lints
+            .into_iter()
+            .map(|lint| (self.take_id(), lint))
+            .collect();
+    }
+}
+
+/// Hash everything about a lint except its position.
+///
+/// Unlike [`Lint::spanless_hash`], this also ignores where the edits it suggests are in the
+/// document, which lets a lint be recognized after an edit earlier in the document moves it.
+fn relative_hash(lint: &Lint) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    lint.lint_kind.hash(&mut hasher);
+    lint.message.hash(&mut hasher);
+    lint.priority.hash(&mut hasher);
+
+    for suggestion in &lint.suggestions {
+        let edits = suggestion.to_edits(lint.span);
+        edits.len().hash(&mut hasher);
+
+        for edit in edits {
+            (edit.span.start as isize - lint.span.start as isize).hash(&mut hasher);
+            edit.span.len().hash(&mut hasher);
+            edit.replacement.hash(&mut hasher);
+        }
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LintSession;
+    use crate::linting::LintGroup;
+    use crate::parsers::PlainEnglish;
+    use crate::{FstDictionary, Span};
+
+    fn session(text: &str) -> LintSession<PlainEnglish, std::sync::Arc<FstDictionary>, LintGroup> {
+        let dictionary = FstDictionary::curated();
+        let linter = LintGroup::new_curated(dictionary.clone());
+
+        LintSession::new(text, PlainEnglish, dictionary, linter)
+    }
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn fixing_a_lint_only_removes_it() {
+        let mut session = session("This is a tset.\n\nThe the dog is here.");
+        assert_eq!(session.lints().len(), 2);
+
+        let changes = session.apply_edit(Span::new(10, 14), &chars("test"));
+
+        assert!(changes.added.is_empty());
+        assert_eq!(changes.removed, vec![0]);
+        assert_eq!(session.lints().len(), 1);
+    }
+
+    #[test]
+    fn moved_lints_keep_their_ids() {
+        let mut session = session("This is a tset.\n\nThe the dog is here.");
+
+        let changes = session.apply_edit(Span::new(0, 0), &chars("Hi. "));
+
+        assert!(changes.added.is_empty());
+        assert!(changes.removed.is_empty());
+        assert_eq!(
+            session.lint(1).map(|lint| lint.span),
+            Some(Span::new(21, 28))
+        );
+    }
+
+    #[test]
+    fn new_lints_are_added() {
+        let mut session = session("This is a test.");
+
+        let changes = session.apply_edit(Span::new(10, 14), &chars("tset"));
+
+        assert_eq!(changes.added.len(), 1);
+        assert!(changes.removed.is_empty());
+        assert!(session.lint(changes.added[0]).is_some());
+    }
+}
@@ -0,0 +1,196 @@
+use std::io::{self, BufRead};
+
+use super::{Lint, Linter};
+use crate::parsers::Parser;
+use crate::{Dictionary, Document, Lrc};
+
+/// Lints text from a reader one chunk at a time, so large files don't need to be read or
+/// tokenized all at once before results become available.
+///
+/// Chunks end at blank lines, so paragraphs are never split.
+/// Since each chunk is parsed and linted on its own, constructs that span a blank line (like a
+/// Markdown code block containing one) may not be parsed correctly.
+pub struct LintStream<'a, R, P, D, L: ?Sized> {
+    reader: R,
+    parser: &'a P,
+    dictionary: &'a D,
+    linter: &'a mut L,
+    /// The minimum number of characters to collect before ending a chunk.
+    chunk_len: usize,
+    offset: usize,
+    line: usize,
+    done: bool,
+}
+
+/// A piece of a [`LintStream`], along with the lints found in it.
+#[derive(Debug, Clone)]
+pub struct LintedChunk {
+    /// The character index at which the chunk starts in the stream.
+    pub offset: usize,
+    /// The zero-indexed line on which the chunk starts in the stream.
+    pub first_line: usize,
+    pub document: Document,
+    /// The lints found in the chunk.
+    /// Their spans are relative to the chunk's [`Self::document`].
+    pub lints: Vec<Lint>,
+}
+
+impl LintedChunk {
+    /// The chunk's lints, with spans relative to the start of the whole stream.
+    pub fn lints_in_stream(&self) -> impl Iterator<Item = Lint> + '_ {
+        self.lints.iter().map(|lint| {
+            let mut lint = lint.clone();
+            lint.push_by(self.offset);
+            lint
+        })
+    }
+}
+
+impl<'a, R, P, D, L> LintStream<'a, R, P, D, L>
+where
+    R: BufRead,
+    P: Parser,
+    D: Dictionary,
+    L: Linter + ?Sized,
+{
+    pub fn new(reader: R, parser: &'a P, dictionary: &'a D, linter: &'a mut L) -> Self {
+        Self {
+            reader,
+            parser,
+            dictionary,
+            linter,
+            chunk_len: 64 * 1024,
+            offset: 0,
+            line: 0,
+            done: false,
+        }
+    }
+
+    /// Set the minimum number of characters to collect before ending a chunk.
+    pub fn with_chunk_len(mut self, chunk_len: usize) -> Self {
+        self.chunk_len = chunk_len;
+        self
+    }
+
+    /// Read lines until the chunk is long enough and ends with a blank line, or the reader
+    /// runs dry.
+    fn read_chunk(&mut self) -> io::Result<Option<(Vec<char>, usize)>> {
+        let mut chunk = Vec::new();
+        let mut lines = 0;
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+
+            if self.reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            chunk.extend(line.chars());
+            lines += 1;
+
+            if line.trim().is_empty() && chunk.len() >= self.chunk_len {
+                break;
+            }
+        }
+
+        Ok((!chunk.is_empty()).then_some((chunk, lines)))
+    }
+}
+
+impl<R, P, D, L> Iterator for LintStream<'_, R, P, D, L>
+where
+    R: BufRead,
+    P: Parser,
+    D: Dictionary,
+    L: Linter + ?Sized,
+{
+    type Item = io::Result<LintedChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (source, lines) = match self.read_chunk() {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+
+        let len = source.len();
+        let document = Document::new_from_vec(Lrc::new(source), self.parser, self.dictionary);
+        let lints = self.linter.lint(&document);
+
+        let chunk = LintedChunk {
+            offset: self.offset,
+            first_line: self.line,
+            document,
+            lints,
+        };
+
+        self.offset += len;
+        self.line += lines;
+
+        Some(Ok(chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LintStream;
+    use crate::linting::{LintGroup, Linter};
+    use crate::parsers::PlainEnglish;
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let text = "This is an test.\n\nThere is is a problem.\nStill the same paragraph.\n\nLast.";
+
+        let dict = FstDictionary::curated();
+        let mut linter = LintGroup::new_curated(dict.clone());
+
+        let chunks: Vec<_> = LintStream::new(text.as_bytes(), &PlainEnglish, &dict, &mut linter)
+            .with_chunk_len(1)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1].offset, 18);
+        assert_eq!(chunks[1].first_line, 2);
+        assert_eq!(chunks[2].first_line, 5);
+        assert_eq!(
+            chunks[1].document.get_full_string(),
+            "There is is a problem.\nStill the same paragraph.\n\n"
+        );
+    }
+
+    #[test]
+    fn matches_whole_document() {
+        let text = "This is an test.\n\nThere is is a problem.\n\nI can't beleive it!";
+
+        let dict = FstDictionary::curated();
+        let mut linter = LintGroup::new_curated(dict.clone());
+
+        let mut expected = linter.lint(&Document::new_plain_english(text, &dict));
+
+        let mut streamed = Vec::new();
+        for chunk in
+            LintStream::new(text.as_bytes(), &PlainEnglish, &dict, &mut linter).with_chunk_len(1)
+        {
+            streamed.extend(chunk.unwrap().lints_in_stream());
+        }
+
+        for lints in [&mut expected, &mut streamed] {
+            lints.sort_by_key(|l| l.span.start);
+        }
+
+        assert_eq!(streamed, expected);
+    }
+}
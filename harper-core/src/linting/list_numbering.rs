@@ -0,0 +1,113 @@
+use itertools::Itertools;
+
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::Document;
+
+/// Looks for numbered lists that skip or repeat a number, like "1., 2., 2., 4.".
+///
+/// Relies on the numbers reported by the document's parser (see [`crate::ListItem::number`]), so
+/// it only checks formats that show numbers as written, like plain text and Typst.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListNumbering;
+
+impl Linter for ListNumbering {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        let lists = document
+            .get_list_items()
+            .iter()
+            .into_group_map_by(|item| item.list);
+
+        for items in lists.into_values() {
+            // Lists that mix written and automatic numbers can't be checked.
+            let Some(numbers) = items
+                .iter()
+                .map(|item| item.number)
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            let Some(first) = numbers.first() else {
+                continue;
+            };
+
+            for (offset, number) in numbers.iter().enumerate() {
+                let expected = first.value + offset;
+
+                if number.value == expected {
+                    continue;
+                }
+
+                lints.push(Lint {
+                    span: number.span,
+                    lint_kind: LintKind::Formatting,
+                    suggestions: vec![Suggestion::replace_with(
+                        expected.to_string().chars().collect(),
+                    )],
+                    message: format!("This list item should be numbered {expected}."),
+                    priority: 63,
+                    ..Default::default()
+                });
+            }
+        }
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Numbered lists should count up by one, without skipping or repeating a number."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListNumbering;
+    use crate::linting::Linter;
+    use crate::parsers::PlainEnglish;
+    use crate::{Document, FstDictionary};
+
+    fn fix_plain_text(text: &str) -> String {
+        let document = Document::new(text, &PlainEnglish, &FstDictionary::curated());
+        let lints = ListNumbering.lint(&document);
+
+        document.fix_all(&lints).text.iter().collect()
+    }
+
+    #[test]
+    fn renumbers_duplicates_and_gaps() {
+        assert_eq!(
+            fix_plain_text("1. Mix\n2. Pour\n2. Bake\n4. Eat"),
+            "1. Mix\n2. Pour\n3. Bake\n4. Eat"
+        );
+    }
+
+    #[test]
+    fn keeps_the_starting_number() {
+        assert_eq!(
+            fix_plain_text("5) Mix\n7) Pour\n8) Bake"),
+            "5) Mix\n6) Pour\n7) Bake"
+        );
+    }
+
+    #[test]
+    fn allows_correct_lists() {
+        let document =
+            Document::new_plain_english_curated("1. Mix\n\n2. Pour\n  1. Slowly\n3. Bake");
+
+        assert!(ListNumbering.lint(&document).is_empty());
+    }
+
+    #[test]
+    fn ignores_markdown() {
+        let document = Document::new_markdown_default_curated("1. Mix\n1. Pour\n1. Bake\n");
+
+        assert!(ListNumbering.lint(&document).is_empty());
+    }
+}
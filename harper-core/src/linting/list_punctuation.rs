@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, ListItem, Span};
+
+/// Looks for list items that end with a period when their siblings don't, or the other way
+/// around.
+///
+/// Relies on the list items found by the document's parser (see [`Document::get_list_items`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListPunctuation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ending {
+    Period,
+    Bare,
+    /// Items ending in other punctuation, like questions or ellipses, don't count either way.
+    Other,
+}
+
+impl Ending {
+    fn of(item: &ListItem, source: &[char]) -> Self {
+        let text = item.span.get_content(source);
+
+        match text {
+            [.., '.', '.'] | [.., '…'] => Self::Other,
+            [.., '.'] => Self::Period,
+            [.., c] if c.is_alphanumeric() || *c == ')' || *c == '`' || *c == '*' => Self::Bare,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl Linter for ListPunctuation {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let mut lints = Vec::new();
+
+        let lists = document
+            .get_list_items()
+            .iter()
+            .into_group_map_by(|item| item.list);
+
+        for items in lists.into_values() {
+            let endings = items
+                .iter()
+                .map(|item| (item.span, Ending::of(item, source)))
+                .filter(|(_, ending)| *ending != Ending::Other)
+                .collect_vec();
+
+            let periods = endings.iter().filter(|(_, e)| *e == Ending::Period).count();
+            let bare = endings.len() - periods;
+
+            if periods == 0 || bare == 0 {
+                continue;
+            }
+
+            // Follow the majority, or the first item if there isn't one.
+            let expected = match periods.cmp(&bare) {
+                Ordering::Greater => Ending::Period,
+                Ordering::Less => Ending::Bare,
+                Ordering::Equal => endings[0].1,
+            };
+
+            for (span, ending) in endings {
+                if ending == expected {
+                    continue;
+                }
+
+                let last_char = Span::new_with_len(span.end - 1, 1);
+
+                lints.push(match expected {
+                    Ending::Period => Lint {
+                        span: last_char,
+                        lint_kind: LintKind::Formatting,
+                        suggestions: vec![Suggestion::insert_after(vec!['.'])],
+                        message: "The other items in this list end with a period.".to_string(),
+                        priority: 63,
+                        ..Default::default()
+                    },
+                    _ => Lint {
+                        span: last_char,
+                        lint_kind: LintKind::Formatting,
+                        suggestions: vec![Suggestion::remove()],
+                        message: "The other items in this list don't end with a period."
+                            .to_string(),
+                        priority: 63,
+                        ..Default::default()
+                    },
+                });
+            }
+        }
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Items in the same list should either all end with a period or all end without one."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "- Install the package.\n- Run the tests\n- Open a pull request.\n",
+            "- Install the package.\n- Run the tests.\n- Open a pull request.\n",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListPunctuation;
+    use crate::Document;
+    use crate::linting::Linter;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn adds_missing_period() {
+        assert_suggestion_result(
+            "- Install the package.\n- Run the tests\n- Open a pull request.\n",
+            ListPunctuation,
+            "- Install the package.\n- Run the tests.\n- Open a pull request.\n",
+        );
+    }
+
+    #[test]
+    fn removes_stray_period() {
+        assert_suggestion_result(
+            "1. Apples\n2. Oranges.\n3. *Pears*\n",
+            ListPunctuation,
+            "1. Apples\n2. Oranges\n3. *Pears*\n",
+        );
+    }
+
+    #[test]
+    fn follows_first_item_on_ties() {
+        assert_suggestion_result(
+            "- Apples\n- Oranges.\n",
+            ListPunctuation,
+            "- Apples\n- Oranges\n",
+        );
+    }
+
+    #[test]
+    fn allows_consistent_lists() {
+        assert_lint_count("- Apples\n- Oranges\n- Pears\n", ListPunctuation, 0);
+        assert_lint_count("1. Eat.\n2. Sleep.\n", ListPunctuation, 0);
+    }
+
+    #[test]
+    fn ignores_questions_and_ellipses() {
+        assert_lint_count("- Why?\n- Because\n- And so on...\n", ListPunctuation, 0);
+    }
+
+    #[test]
+    fn compares_only_siblings() {
+        assert_lint_count(
+            "- Fruit.\n  - Apples\n  - Pears\n- Vegetables.\n\nText.\n\n- A\n- B\n",
+            ListPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn checks_plain_text_lists() {
+        let document = Document::new_plain_english_curated("- Apples.\n- Oranges\n- Pears.");
+
+        assert_eq!(ListPunctuation.lint(&document).len(), 1);
+    }
+}
@@ -29,4 +29,8 @@ impl Linter for LongSentences {
     fn description(&self) -> &'static str {
         "This rule looks for run-on sentences, which can make your work harder to grok."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Readability)
+    }
 }
@@ -1,13 +1,22 @@
-use super::{Lint, LintKind, PatternLinter};
+use super::{Lint, LintKind, PatternLinter, RuleExample};
 use crate::linting::Suggestion;
 use crate::patterns::{EitherPattern, ExactPhrase, Pattern, SimilarToPhrase};
-use crate::{Token, TokenStringExt};
+use crate::{Dialect, DialectFlags, Inflection, Lemmatizer, Span, Token, TokenStringExt};
+use hashbrown::HashMap;
 
 pub struct MapPhraseLinter {
     description: String,
     pattern: Box<dyn Pattern>,
     correct_forms: Vec<String>,
+    /// For phrases with inflected first words, the correct form to use for each inflection,
+    /// keyed by the lowercase inflected word.
+    inflected_corrections: HashMap<String, String>,
     message: String,
+    /// Messages to show in place of [`Self::message`] for text written in some dialects.
+    regional_messages: Vec<(DialectFlags, String)>,
+    dialect: Dialect,
+    lint_kind: LintKind,
+    examples: Vec<RuleExample>,
 }
 
 impl MapPhraseLinter {
@@ -21,10 +30,41 @@ impl MapPhraseLinter {
             description: description.to_string(),
             pattern,
             correct_forms: correct_forms.into_iter().map(|f| f.to_string()).collect(),
+            inflected_corrections: HashMap::new(),
             message: message.to_string(),
+            regional_messages: Vec::new(),
+            dialect: Dialect::default(),
+            lint_kind: LintKind::Miscellaneous,
+            examples: Vec::new(),
         }
     }
 
+    /// Report matches as a kind other than [`LintKind::Miscellaneous`].
+    pub fn with_lint_kind(mut self, lint_kind: LintKind) -> Self {
+        self.lint_kind = lint_kind;
+        self
+    }
+
+    /// Show a different message for text written in one of `dialects`.
+    /// The first regional message that matches the dialect wins.
+    pub fn with_regional_message(
+        mut self,
+        dialects: impl IntoIterator<Item = Dialect>,
+        message: impl ToString,
+    ) -> Self {
+        self.regional_messages
+            .push((dialects.into_iter().collect(), message.to_string()));
+        self
+    }
+
+    /// The message to show for the dialect the linter was last given.
+    fn message(&self) -> &str {
+        self.regional_messages
+            .iter()
+            .find(|(dialects, _)| dialects.contains(self.dialect))
+            .map_or(self.message.as_str(), |(_, message)| message.as_str())
+    }
+
     pub fn new_similar_to_phrase(phrase: &'static str, detectable_distance: u8) -> Self {
         Self::new(
             Box::new(SimilarToPhrase::from_phrase(phrase, detectable_distance)),
@@ -40,17 +80,35 @@ impl MapPhraseLinter {
         message: impl ToString,
         description: impl ToString,
     ) -> Self {
+        let phrases: Vec<String> = phrase.into_iter().map(|p| p.as_ref().to_string()).collect();
+
         let patterns = EitherPattern::new(
-            phrase
-                .into_iter()
+            phrases
+                .iter()
                 .map(|p| {
-                    let pattern: Box<dyn Pattern> = Box::new(ExactPhrase::from_phrase(p.as_ref()));
+                    let pattern: Box<dyn Pattern> = Box::new(ExactPhrase::from_phrase(p));
                     pattern
                 })
                 .collect(),
         );
 
-        Self::new(Box::new(patterns), correct_forms, message, description)
+        let mut linter = Self::new(Box::new(patterns), correct_forms, message, description);
+
+        if let Some(correct_form) = linter.correct_forms.first() {
+            linter.examples = phrases
+                .iter()
+                .map(|p| {
+                    let mut corrected: Vec<char> = p.chars().collect();
+                    let span = Span::new(0, corrected.len());
+                    Suggestion::replace_with_match_case(correct_form.chars().collect(), &corrected)
+                        .apply(span, &mut corrected);
+
+                    RuleExample::new(p, corrected.into_iter().collect::<String>())
+                })
+                .collect();
+        }
+
+        linter
     }
 
     pub fn new_exact_phrase(
@@ -59,12 +117,57 @@ impl MapPhraseLinter {
         message: impl ToString,
         description: impl ToString,
     ) -> Self {
-        Self::new(
-            Box::new(ExactPhrase::from_phrase(phrase.as_ref())),
-            correct_forms,
-            message,
-            description,
-        )
+        Self::new_exact_phrases([phrase], correct_forms, message, description)
+    }
+
+    /// Like [`Self::new_exact_phrase`], but also matches the phrase with its first word
+    /// inflected, correcting it to the same inflection of the correct form.
+    /// For example, "peak my interest" also matches "peaked my interest" and suggests "piqued
+    /// my interest".
+    pub fn new_inflected_phrase(
+        phrase: &str,
+        correct_form: &str,
+        message: impl ToString,
+        description: impl ToString,
+    ) -> Self {
+        let lemmatizer = Lemmatizer::curated();
+
+        let split_first = |phrase: &str| -> (String, String) {
+            match phrase.split_once(' ') {
+                Some((first, rest)) => (first.to_string(), format!(" {rest}")),
+                None => (phrase.to_string(), String::new()),
+            }
+        };
+
+        let (phrase_first, phrase_rest) = split_first(phrase);
+        let (correct_first, correct_rest) = split_first(correct_form);
+
+        let mut phrases = vec![phrase.to_string()];
+        let mut inflected_corrections = HashMap::new();
+
+        for inflection in Inflection::ALL {
+            let (Some(inflected), Some(correct_inflected)) = (
+                lemmatizer.inflect(&phrase_first, inflection),
+                lemmatizer.inflect(&correct_first, inflection),
+            ) else {
+                continue;
+            };
+
+            if inflected_corrections.contains_key(&inflected.to_lowercase()) {
+                continue;
+            }
+
+            phrases.push(format!("{inflected}{phrase_rest}"));
+            inflected_corrections.insert(
+                inflected.to_lowercase(),
+                format!("{correct_inflected}{correct_rest}"),
+            );
+        }
+
+        let mut linter = Self::new_exact_phrases(phrases, [correct_form], message, description);
+        linter.inflected_corrections = inflected_corrections;
+        linter.examples.truncate(1);
+        linter
     }
 
     pub fn new_closed_compound(phrase: impl AsRef<str>, correct_form: impl ToString) -> Self {
@@ -91,11 +194,21 @@ impl PatternLinter for MapPhraseLinter {
         let span = matched_tokens.span()?;
         let matched_text = span.get_content(source);
 
+        let first_word = matched_tokens
+            .first()?
+            .span
+            .get_content_string(source)
+            .to_lowercase();
+
+        let correct_forms = match self.inflected_corrections.get(&first_word) {
+            Some(correction) => std::slice::from_ref(correction),
+            None => self.correct_forms.as_slice(),
+        };
+
         Some(Lint {
             span,
-            lint_kind: LintKind::Miscellaneous,
-            suggestions: self
-                .correct_forms
+            lint_kind: self.lint_kind,
+            suggestions: correct_forms
                 .iter()
                 .map(|correct_form| {
                     Suggestion::replace_with_match_case(
@@ -104,12 +217,58 @@ impl PatternLinter for MapPhraseLinter {
                     )
                 })
                 .collect(),
-            message: self.message.to_string(),
+            message: self.message().to_string(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &str {
         self.description.as_str()
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(self.lint_kind)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        self.examples.clone()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MapPhraseLinter;
+    use crate::linting::tests::assert_suggestion_result;
+
+    fn peak_interest() -> MapPhraseLinter {
+        MapPhraseLinter::new_inflected_phrase(
+            "peak my interest",
+            "pique my interest",
+            "Did you mean `pique`?",
+            "Corrects `peak my interest`.",
+        )
+    }
+
+    #[test]
+    fn corrects_lemma() {
+        assert_suggestion_result(
+            "This might peak my interest.",
+            peak_interest(),
+            "This might pique my interest.",
+        );
+    }
+
+    #[test]
+    fn corrects_inflection() {
+        assert_suggestion_result(
+            "That peaked my interest.",
+            peak_interest(),
+            "That piqued my interest.",
+        );
+    }
 }
@@ -298,12 +298,15 @@ impl Linter for Matcher {
                     lints.push(Lint {
                         span,
                         lint_kind: LintKind::Miscellaneous,
-                        suggestions: vec![Suggestion::ReplaceWith(trigger.replace_with.to_owned())],
+                        suggestions: vec![Suggestion::replace_with(
+                            trigger.replace_with.to_owned(),
+                        )],
                         message: format!(
                             "Did you mean “{}”?",
                             trigger.replace_with.iter().collect::<String>()
                         ),
                         priority: 15,
+                        ..Default::default()
                     })
                 }
             }
@@ -315,4 +318,8 @@ impl Linter for Matcher {
     fn description(&self) -> &'static str {
         "A collection of curated rules. A catch-all that will be removed in the future."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
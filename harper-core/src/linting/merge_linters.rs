@@ -4,7 +4,7 @@ macro_rules! merge_linters {
 
         mod merge_rule_hidden {
             use paste::paste;
-            use crate::{Document, linting::{Lint, Linter}, remove_overlaps};
+            use crate::{Document, linting::{Lint, LintKind, Linter, RuleExample}, remove_overlaps};
 
             $(
                 use super::$linter;
@@ -34,6 +34,23 @@ macro_rules! merge_linters {
                     fn description(&self) -> &'static str {
                         $desc
                     }
+
+                    fn lint_kind(&self) -> Option<LintKind> {
+                        let kinds = [$(self.[< $linter:snake >].lint_kind()),*];
+                        let first = kinds[0]?;
+
+                        kinds.iter().all(|k| *k == Some(first)).then_some(first)
+                    }
+
+                    fn examples(&self) -> Vec<RuleExample> {
+                        let mut examples = Vec::new();
+
+                        $(
+                            examples.extend(self.[< $linter:snake >].examples());
+                        )*
+
+                        examples
+                    }
                 }
             }
         }
@@ -53,9 +53,10 @@ impl Linter for MergeWords {
                 lints.push(Lint {
                     span: Span::new(a.span.start, b.span.end),
                     lint_kind: LintKind::WordChoice,
-                    suggestions: vec![Suggestion::ReplaceWith(merged_word.to_vec())],
+                    suggestions: vec![Suggestion::replace_with(merged_word.to_vec())],
                     message: "It seems these words would go better together.".to_owned(),
                     priority: 63,
+                    ..Default::default()
                 });
             }
 
@@ -70,9 +71,10 @@ impl Linter for MergeWords {
                 lints.push(Lint {
                     span: Span::new(a.span.start, b.span.end),
                     lint_kind: LintKind::WordChoice,
-                    suggestions: vec![Suggestion::ReplaceWith(merged_word.to_vec())],
+                    suggestions: vec![Suggestion::replace_with(merged_word.to_vec())],
                     message: "It seems you intended to make this a contraction.".to_owned(),
                     priority: 63,
+                    ..Default::default()
                 });
             }
         }
@@ -83,6 +85,10 @@ impl Linter for MergeWords {
     fn description(&self) -> &str {
         "Accidentally inserting a space inside a word is common. This rule looks for valid words that are split by whitespace."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
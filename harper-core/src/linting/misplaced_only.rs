@@ -0,0 +1,178 @@
+use hashbrown::HashMap;
+
+use super::{Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{CharStringExt, Document, Lemmatizer, PosTag, Token, TokenKind};
+
+/// Words that give a quantity, which "only" usually limits.
+const QUANTITIES: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "twelve",
+    "twenty", "hundred", "thousand", "dozen", "few", "little", "once", "twice",
+];
+
+/// Looks for "only" placed before a verb when it limits the quantity after it, like "I only ate
+/// two cookies", and suggests moving it next to the quantity: "I ate only two cookies".
+///
+/// Where "only" belongs depends on what the writer meant, so this is off by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MisplacedOnly;
+
+/// The word tokens after `index`, skipping the whitespace between them.
+/// Stops at anything other than words, numbers and whitespace.
+fn following_words(tokens: &[Token], index: usize) -> impl Iterator<Item = &Token> {
+    tokens[index + 1..]
+        .iter()
+        .take_while(|t| t.kind.is_word() || t.kind.is_number() || t.kind.is_space())
+        .filter(|t| !t.kind.is_space())
+}
+
+fn is_quantity(tokens: &[&Token], source: &[char]) -> bool {
+    let text = |token: &Token| token.span.get_content_string(source).to_lowercase();
+
+    match tokens {
+        [first, ..] if first.kind.is_number() => true,
+        [first, second, ..] if text(first) == "a" => QUANTITIES.contains(&text(second).as_str()),
+        [first, ..] => QUANTITIES.contains(&text(first).as_str()),
+        [] => false,
+    }
+}
+
+impl Linter for MisplacedOnly {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let tokens = document.get_tokens();
+        let lemmatizer = Lemmatizer::curated();
+        let mut tags = None;
+        let mut lints = Vec::new();
+
+        for (index, token) in tokens.iter().enumerate() {
+            if !token.kind.is_word()
+                || !document
+                    .get_span_content_str(token.span)
+                    .eq_ignore_ascii_case("only")
+            {
+                continue;
+            }
+
+            let tags = tags.get_or_insert_with(|| {
+                document
+                    .pos_tags()
+                    .into_iter()
+                    .filter_map(|word| Some((word.token.span.start, word.tag?)))
+                    .collect::<HashMap<_, _>>()
+            });
+            let tag = |token: &Token| tags.get(&token.span.start).copied();
+
+            // "Only" has to come between a subject and its verb, as in "I only ate".
+            let Some(subject) = tokens[..index].iter().rev().find(|t| !t.kind.is_space()) else {
+                continue;
+            };
+
+            if !matches!(
+                tag(subject),
+                Some(PosTag::Pronoun | PosTag::Noun | PosTag::ProperNoun)
+            ) {
+                continue;
+            }
+
+            let after: Vec<&Token> = following_words(tokens, index).take(3).collect();
+
+            let Some((verb, object)) = after.split_first() else {
+                continue;
+            };
+
+            let verb_text = document.get_span_content(verb.span);
+
+            // Between a subject and a quantity, any word that can be a verb is one.
+            // Inflected verbs, like "ate", aren't always marked as such, but they can be
+            // lemmatized.
+            let is_verb = matches!(verb.kind, TokenKind::Word(Some(_)))
+                && (verb.kind.is_verb()
+                    || lemmatizer
+                        .lemmatize(verb_text, Some(PosTag::Verb))
+                        .as_slice()
+                        != verb_text.to_lower().as_ref());
+
+            if !is_verb || !is_quantity(object, source) {
+                continue;
+            }
+
+            let mut replacement = verb_text.to_vec();
+            replacement.push(' ');
+            replacement.extend(document.get_span_content(token.span));
+
+            lints.push(Lint {
+                span: token.span.with_len(verb.span.end - token.span.start),
+                lint_kind: LintKind::WordChoice,
+                suggestions: vec![Suggestion::replace_with(replacement)],
+                message: "“Only” usually belongs right before the quantity it limits.".to_string(),
+                priority: 63,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for “only” placed before a verb when it limits the quantity after it, and suggests moving it next to that quantity."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "I only ate two cookies.",
+            "I ate only two cookies.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MisplacedOnly;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn moves_only_to_the_quantity() {
+        assert_suggestion_result(
+            "I only ate two cookies.",
+            MisplacedOnly,
+            "I ate only two cookies.",
+        );
+    }
+
+    #[test]
+    fn handles_numbers() {
+        assert_suggestion_result(
+            "The team only found 3 bugs.",
+            MisplacedOnly,
+            "The team found only 3 bugs.",
+        );
+    }
+
+    #[test]
+    fn handles_a_few() {
+        assert_suggestion_result(
+            "We only need a few minutes.",
+            MisplacedOnly,
+            "We need only a few minutes.",
+        );
+    }
+
+    #[test]
+    fn allows_only_before_the_quantity() {
+        assert_lint_count("I ate only two cookies.", MisplacedOnly, 0);
+    }
+
+    #[test]
+    fn allows_only_without_a_quantity() {
+        assert_lint_count("I only wanted to help.", MisplacedOnly, 0);
+    }
+
+    #[test]
+    fn allows_sentence_initial_only() {
+        assert_lint_count("Only two people came.", MisplacedOnly, 0);
+    }
+}
@@ -2,24 +2,38 @@
 //!
 //! See the [`Linter`] trait and the [documentation for authoring a rule](https://writewithharper.com/docs/contributors/author-a-rule) for more information.
 
+mod absolute_adjectives;
 mod an_a;
+mod anchor_filter;
+mod archaic_language;
 mod avoid_curses;
 mod back_in_the_day;
+mod binomial_order;
 mod boring_words;
+mod canonical_casing;
+mod capitalization_after_colon;
 mod capitalize_personal_pronouns;
 mod chock_full;
 mod closed_compounds;
 mod compound_nouns;
 mod correct_number_suffix;
+mod currency_consistency;
 mod currency_placement;
+mod dangling_modifiers;
 mod dashes;
 mod despite_of;
 mod dot_initialisms;
 mod ellipsis_length;
 mod expand_time_shorthands;
+mod forbidden_words;
+mod gender_neutral_language;
+mod heading_punctuation;
 mod hereby;
 mod hop_hope;
 mod hyphenate_number_day;
+mod idiom_corrections;
+mod informal_language;
+mod lazy_linter;
 mod left_right_hand;
 mod lets_confusion;
 mod likewise;
@@ -27,99 +41,145 @@ mod linking_verbs;
 mod lint;
 mod lint_group;
 mod lint_kind;
+mod lint_profile;
+mod lint_session;
+mod lint_stream;
+mod list_numbering;
+mod list_punctuation;
 mod long_sentences;
 mod map_phrase_linter;
 mod matcher;
 mod merge_linters;
 mod merge_words;
+mod misplaced_only;
 mod modal_of;
 mod multiple_sequential_pronouns;
 mod no_oxford_comma;
 mod nobody;
 mod number_suffix_capitalization;
 mod out_of_date;
+mod overlap_policy;
 mod oxford_comma;
 mod pattern_linter;
+mod personal_voice;
 mod phrase_corrections;
 mod pique_interest;
 mod plural_conjugate;
 mod possessive_your;
 mod pronoun_contraction;
 mod proper_noun_capitalization_linters;
+mod regional_linter;
 mod repeated_words;
+mod respectively;
+mod rule_description;
+mod sensitive_words;
+mod sentence_cache;
 mod sentence_capitalization;
+mod severity;
 mod somewhat_something;
+mod space_after_punctuation;
 mod spaces;
 mod spell_check;
 mod spelled_numbers;
 mod suggestion;
 mod terminating_conjunctions;
+mod terminology;
 mod that_which;
 mod then_than;
+mod transition_overuse;
 mod unclosed_quotes;
+mod unpaired_brackets;
 mod use_genitive;
 mod was_aloud;
 mod whereas;
 mod wordpress_dotcom;
 mod wrong_quotes;
 
+pub use absolute_adjectives::AbsoluteAdjectives;
 pub use an_a::AnA;
+pub use archaic_language::ArchaicLanguage;
 pub use avoid_curses::AvoidCurses;
 pub use back_in_the_day::BackInTheDay;
 pub use boring_words::BoringWords;
+pub use canonical_casing::CanonicalCasing;
+pub use capitalization_after_colon::{CapitalizationAfterColon, ColonCapitalizationStyle};
 pub use capitalize_personal_pronouns::CapitalizePersonalPronouns;
 pub use chock_full::ChockFull;
 pub use compound_nouns::CompoundNouns;
 pub use correct_number_suffix::CorrectNumberSuffix;
+pub use currency_consistency::CurrencyConsistency;
 pub use currency_placement::CurrencyPlacement;
+pub use dangling_modifiers::DanglingModifiers;
 pub use despite_of::DespiteOf;
 pub use dot_initialisms::DotInitialisms;
 pub use ellipsis_length::EllipsisLength;
 pub use expand_time_shorthands::ExpandTimeShorthands;
+pub use forbidden_words::{ForbiddenWord, ForbiddenWords};
+pub use heading_punctuation::HeadingPunctuation;
 pub use hereby::Hereby;
 pub use hop_hope::HopHope;
 pub use hyphenate_number_day::HyphenateNumberDay;
+pub use informal_language::InformalLanguage;
+pub use lazy_linter::LazyLinter;
 pub use left_right_hand::LeftRightHand;
 pub use lets_confusion::LetsConfusion;
 pub use likewise::Likewise;
 pub use linking_verbs::LinkingVerbs;
-pub use lint::Lint;
-pub use lint_group::{LintGroup, LintGroupConfig};
+pub use lint::{Lint, SuggestionPreview};
+pub use lint_group::{LintGroup, LintGroupConfig, SeverityConfig};
 pub use lint_kind::LintKind;
+pub use lint_profile::{LintProfile, RuleProfile};
+pub use lint_session::{LintChanges, LintSession};
+pub use lint_stream::{LintStream, LintedChunk};
+pub use list_numbering::ListNumbering;
+pub use list_punctuation::ListPunctuation;
 pub use long_sentences::LongSentences;
 pub use map_phrase_linter::MapPhraseLinter;
 pub use matcher::Matcher;
 pub use merge_words::MergeWords;
+pub use misplaced_only::MisplacedOnly;
 pub use modal_of::ModalOf;
 pub use multiple_sequential_pronouns::MultipleSequentialPronouns;
 pub use no_oxford_comma::NoOxfordComma;
 pub use nobody::Nobody;
 pub use number_suffix_capitalization::NumberSuffixCapitalization;
 pub use out_of_date::OutOfDate;
+pub use overlap_policy::OverlapPolicy;
 pub use oxford_comma::OxfordComma;
 pub use pattern_linter::PatternLinter;
+pub use personal_voice::{DocumentGenre, PersonalVoice};
 pub use pique_interest::PiqueInterest;
 pub use plural_conjugate::PluralConjugate;
 pub use possessive_your::PossessiveYour;
 pub use pronoun_contraction::PronounContraction;
+pub use regional_linter::RegionalLinter;
 pub use repeated_words::RepeatedWords;
+pub use respectively::Respectively;
+pub use rule_description::{RuleDescription, RuleExample};
+pub use sensitive_words::{SensitiveWord, SensitiveWords, SensitivityTier};
+pub use sentence_cache::SentenceCache;
 pub use sentence_capitalization::SentenceCapitalization;
+pub use severity::Severity;
 pub use somewhat_something::SomewhatSomething;
+pub use space_after_punctuation::SpaceAfterPunctuation;
 pub use spaces::Spaces;
-pub use spell_check::SpellCheck;
+pub use spell_check::{IdentifierHandling, IgnorePattern, LearnableWord, SkipWords, SpellCheck};
 pub use spelled_numbers::SpelledNumbers;
-pub use suggestion::Suggestion;
+pub use suggestion::{Confidence, Edit, Suggestion, SuggestionKind};
 pub use terminating_conjunctions::TerminatingConjunctions;
+pub use terminology::Terminology;
 pub use that_which::ThatWhich;
 pub use then_than::ThenThan;
+pub use transition_overuse::TransitionOveruse;
 pub use unclosed_quotes::UnclosedQuotes;
+pub use unpaired_brackets::UnpairedBrackets;
 pub use use_genitive::UseGenitive;
 pub use was_aloud::WasAloud;
 pub use whereas::Whereas;
 pub use wordpress_dotcom::WordPressDotcom;
 pub use wrong_quotes::WrongQuotes;
 
-use crate::Document;
+use crate::{CharString, Dialect, Document};
 
 /// A __stateless__ rule that searches documents for grammatical errors.
 ///
@@ -134,6 +194,28 @@ pub trait Linter {
     /// A user-facing description of what kinds of grammatical errors this rule looks for.
     /// It is usually shown in settings menus.
     fn description(&self) -> &str;
+    /// The [`LintKind`] of the lints this rule produces, if it is always the same.
+    fn lint_kind(&self) -> Option<LintKind> {
+        None
+    }
+    /// Short snippets of text this rule flags, along with how it would correct them.
+    /// Used to render documentation for the rule.
+    fn examples(&self) -> Vec<RuleExample> {
+        Vec::new()
+    }
+    /// Words, at least one of which must begin a word token for this rule to produce any lints.
+    ///
+    /// [`LintGroup`] skips rules when none of their anchor words appear in a document, and
+    /// otherwise only runs them on the paragraphs they appear in.
+    /// See [`Pattern::anchor_words`](crate::patterns::Pattern::anchor_words).
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        None
+    }
+    /// Tell the rule which [`Dialect`] the text is written in.
+    ///
+    /// [`LintGroup`] calls this on every rule it contains.
+    /// Rules that don't vary between dialects can ignore it.
+    fn set_dialect(&mut self, _dialect: Dialect) {}
 }
 
 /// A __stateless__ rule that searches documents for grammatical errors.
@@ -149,6 +231,28 @@ pub trait Linter: Send + Sync {
     /// A user-facing description of what kinds of grammatical errors this rule looks for.
     /// It is usually shown in settings menus.
     fn description(&self) -> &str;
+    /// The [`LintKind`] of the lints this rule produces, if it is always the same.
+    fn lint_kind(&self) -> Option<LintKind> {
+        None
+    }
+    /// Short snippets of text this rule flags, along with how it would correct them.
+    /// Used to render documentation for the rule.
+    fn examples(&self) -> Vec<RuleExample> {
+        Vec::new()
+    }
+    /// Words, at least one of which must begin a word token for this rule to produce any lints.
+    ///
+    /// [`LintGroup`] skips rules when none of their anchor words appear in a document, and
+    /// otherwise only runs them on the paragraphs they appear in.
+    /// See [`Pattern::anchor_words`](crate::patterns::Pattern::anchor_words).
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        None
+    }
+    /// Tell the rule which [`Dialect`] the text is written in.
+    ///
+    /// [`LintGroup`] calls this on every rule it contains.
+    /// Rules that don't vary between dialects can ignore it.
+    fn set_dialect(&mut self, _dialect: Dialect) {}
 }
 
 #[cfg(test)]
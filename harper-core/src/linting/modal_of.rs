@@ -120,12 +120,17 @@ impl PatternLinter for ModalOf {
             )],
             message: "Use `have` rather than `of` here.".to_string(),
             priority: 126,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Detects `of` mistakenly used with `would`, `could`, `should`, etc."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
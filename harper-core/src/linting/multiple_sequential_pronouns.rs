@@ -39,10 +39,10 @@ impl PatternLinter for MultipleSequentialPronouns {
         let mut suggestions = Vec::new();
 
         if matched_tokens.len() == 3 {
-            suggestions.push(Suggestion::ReplaceWith(
+            suggestions.push(Suggestion::replace_with(
                 matched_tokens[0].span.get_content(source).to_vec(),
             ));
-            suggestions.push(Suggestion::ReplaceWith(
+            suggestions.push(Suggestion::replace_with(
                 matched_tokens[2].span.get_content(source).to_vec(),
             ));
         }
@@ -53,12 +53,17 @@ impl PatternLinter for MultipleSequentialPronouns {
             message: "There are too many personal pronouns in sequence here.".to_owned(),
             priority: 63,
             suggestions,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "When editing work to change point of view (i.e. first-person or third-person) it is common to add pronouns while neglecting to remove old ones. This rule catches cases where you have multiple disparate pronouns in sequence."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Repetition)
+    }
 }
 
 impl Default for MultipleSequentialPronouns {
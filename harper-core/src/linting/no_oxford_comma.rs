@@ -34,9 +34,10 @@ impl NoOxfordComma {
         Some(Lint {
             span: offender.span,
             lint_kind: LintKind::Style,
-            suggestions: vec![Suggestion::Remove],
+            suggestions: vec![Suggestion::remove()],
             message: "Remove the Oxford comma here.".to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 }
@@ -83,6 +84,10 @@ impl Linter for NoOxfordComma {
     fn description(&self) -> &str {
         "The Oxford comma is one of the more controversial rules in common use today. Enabling this lint checks that there is no comma before `and`, `or` or `nor` when listing out more than two ideas."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
 }
 
 #[cfg(test)]
@@ -45,6 +45,10 @@ impl PatternLinter for Nobody {
     fn description(&self) -> &'static str {
         "Looks for incorrect spacing inside the closed compound `nobody`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Lint, LintKind, Linter, Suggestion};
 use crate::{Document, Span, TokenKind};
 use crate::{Number, TokenStringExt};
 
@@ -25,9 +25,12 @@ impl Linter for NumberSuffixCapitalization {
                     span: suffix_span,
                     lint_kind: LintKind::Capitalization,
                     message: "This suffix should be lowercase".to_string(),
-                    suggestions: vec![Suggestion::ReplaceWith(
-                        chars.iter().map(|c| c.to_ascii_lowercase()).collect(),
-                    )],
+                    suggestions: vec![
+                        Suggestion::replace_with(
+                            chars.iter().map(|c| c.to_ascii_lowercase()).collect(),
+                        )
+                        .with_confidence(Confidence::High),
+                    ],
                     ..Default::default()
                 })
             }
@@ -39,6 +42,10 @@ impl Linter for NumberSuffixCapitalization {
     fn description(&self) -> &'static str {
         "You should never capitalize number suffixes."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Capitalization)
+    }
 }
 
 #[cfg(test)]
@@ -41,12 +41,17 @@ impl PatternLinter for OutOfDate {
             )],
             message: "Did you mean the compound adjective?".to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Ensures that the phrase `out of date` is written with a hyphen as `out-of-date` when used as a compound adjective."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,141 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Lint;
+
+/// How a [`LintGroup`](super::LintGroup) handles lints from different rules that cover the same
+/// text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverlapPolicy {
+    /// Report every lint, even if it overlaps with another.
+    #[default]
+    KeepAll,
+    /// When lints overlap, keep only the most important one (see [`Lint::priority`]).
+    /// If two lints are equally important, the one that contains the other wins.
+    KeepMostImportant,
+    /// Lints with identical spans are merged into one, which keeps the message of the most
+    /// important and offers the suggestions of all of them.
+    /// Any remaining overlaps are resolved as in [`Self::KeepMostImportant`].
+    Merge,
+}
+
+impl OverlapPolicy {
+    /// Apply the policy to a set of lints.
+    ///
+    /// Note: this function may change the ordering of the lints.
+    pub fn resolve(&self, lints: &mut Vec<Lint>) {
+        match self {
+            Self::KeepAll => (),
+            Self::KeepMostImportant => keep_most_important(lints),
+            Self::Merge => {
+                merge_identical_spans(lints);
+                keep_most_important(lints);
+            }
+        }
+    }
+}
+
+fn keep_most_important(lints: &mut Vec<Lint>) {
+    lints.sort_by_key(|l| (l.priority, Reverse(l.span.len()), l.span.start));
+
+    // Maps the start of each accepted span to its end.
+    // Since accepted spans never overlap, we only need to check the closest one on either side.
+    let mut accepted: BTreeMap<usize, usize> = BTreeMap::new();
+
+    lints.retain(|lint| {
+        let span = lint.span;
+
+        let prev_overlaps = accepted
+            .range(..=span.start)
+            .next_back()
+            .is_some_and(|(_, end)| *end > span.start);
+        let next_overlaps = accepted
+            .range(span.start..)
+            .next()
+            .is_some_and(|(start, _)| *start < span.end);
+
+        if prev_overlaps || next_overlaps {
+            return false;
+        }
+
+        accepted.insert(span.start, span.end);
+        true
+    });
+}
+
+fn merge_identical_spans(lints: &mut Vec<Lint>) {
+    lints.sort_by_key(|l| (l.span.start, l.span.end, l.priority));
+
+    let mut merged: Vec<Lint> = Vec::with_capacity(lints.len());
+
+    for lint in lints.drain(..) {
+        match merged.last_mut() {
+            Some(prev) if prev.span == lint.span => {
+                for suggestion in lint.suggestions {
+                    if !prev.suggestions.contains(&suggestion) {
+                        prev.suggestions.push(suggestion);
+                    }
+                }
+            }
+            _ => merged.push(lint),
+        }
+    }
+
+    *lints = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlapPolicy;
+    use crate::Span;
+    use crate::linting::{Lint, Suggestion};
+
+    fn lint(start: usize, end: usize, priority: u8, suggestion: &str) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            priority,
+            suggestions: vec![Suggestion::replace_with(suggestion.chars().collect())],
+            message: suggestion.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn keep_all_is_noop() {
+        let mut lints = vec![lint(0, 5, 10, "a"), lint(2, 4, 1, "b")];
+        OverlapPolicy::KeepAll.resolve(&mut lints);
+
+        assert_eq!(lints.len(), 2);
+    }
+
+    #[test]
+    fn drops_less_important_overlaps() {
+        let mut lints = vec![lint(0, 5, 10, "a"), lint(2, 4, 1, "b"), lint(6, 8, 10, "c")];
+        OverlapPolicy::KeepMostImportant.resolve(&mut lints);
+
+        let messages: Vec<_> = lints.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, ["b", "c"]);
+    }
+
+    #[test]
+    fn containing_lint_wins_ties() {
+        let mut lints = vec![lint(2, 4, 5, "inner"), lint(0, 6, 5, "outer")];
+        OverlapPolicy::KeepMostImportant.resolve(&mut lints);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "outer");
+    }
+
+    #[test]
+    fn merges_identical_spans() {
+        let mut lints = vec![lint(0, 4, 10, "a"), lint(0, 4, 1, "b"), lint(0, 4, 5, "b")];
+        OverlapPolicy::Merge.resolve(&mut lints);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "b");
+        assert_eq!(lints[0].suggestions.len(), 2);
+    }
+}
@@ -46,9 +46,10 @@ impl OxfordComma {
         Some(Lint {
             span: offender.span,
             lint_kind: LintKind::Style,
-            suggestions: vec![Suggestion::InsertAfter(vec![','])],
+            suggestions: vec![Suggestion::insert_after(vec![','])],
             message: "An Oxford comma is necessary here.".to_owned(),
             priority: 31,
+            ..Default::default()
         })
     }
 }
@@ -99,6 +100,10 @@ impl Linter for OxfordComma {
     fn description(&self) -> &str {
         "The Oxford comma is one of the more controversial rules in common use today. Enabling this lint checks that there is a comma before `and`, `or`, or `nor` when listing out more than two ideas."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
-use super::{Lint, Linter};
+use super::{Lint, LintKind, Linter, RuleExample};
 use crate::patterns::Pattern;
-use crate::{Token, TokenStringExt};
+use crate::{CharString, Dialect, Token, TokenStringExt};
 
 /// A trait that searches for [`Pattern`]s in [`Document`](crate::Document)s.
 ///
@@ -18,6 +18,16 @@ pub trait PatternLinter {
     /// A user-facing description of what kinds of grammatical errors this rule looks for.
     /// It is usually shown in settings menus.
     fn description(&self) -> &str;
+    /// See [`Linter::lint_kind`].
+    fn lint_kind(&self) -> Option<LintKind> {
+        None
+    }
+    /// See [`Linter::examples`].
+    fn examples(&self) -> Vec<RuleExample> {
+        Vec::new()
+    }
+    /// See [`Linter::set_dialect`].
+    fn set_dialect(&mut self, _dialect: Dialect) {}
 }
 
 /// A trait that searches for [`Pattern`]s in [`Document`](crate::Document)s.
@@ -36,6 +46,16 @@ pub trait PatternLinter: Send + Sync {
     /// A user-facing description of what kinds of grammatical errors this rule looks for.
     /// It is usually shown in settings menus.
     fn description(&self) -> &str;
+    /// See [`Linter::lint_kind`].
+    fn lint_kind(&self) -> Option<LintKind> {
+        None
+    }
+    /// See [`Linter::examples`].
+    fn examples(&self) -> Vec<RuleExample> {
+        Vec::new()
+    }
+    /// See [`Linter::set_dialect`].
+    fn set_dialect(&mut self, _dialect: Dialect) {}
 }
 
 impl<L> Linter for L
@@ -74,4 +94,20 @@ where
     fn description(&self) -> &str {
         self.description()
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        PatternLinter::lint_kind(self)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        PatternLinter::examples(self)
+    }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        self.pattern().anchor_words()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        PatternLinter::set_dialect(self, dialect);
+    }
 }
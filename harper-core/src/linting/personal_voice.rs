@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter};
+use crate::{Document, TokenStringExt};
+
+/// The kind of document being written, which decides the voice it should be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DocumentGenre {
+    /// Reference documentation, which describes its subject without a narrator, so avoids the
+    /// first-person singular.
+    Reference,
+    /// Academic writing, which avoids addressing the reader in the second person.
+    Academic,
+}
+
+impl DocumentGenre {
+    /// The pronouns that don't suit the genre.
+    fn discouraged_pronouns(self) -> &'static [&'static str] {
+        match self {
+            Self::Reference => &["i", "me", "my", "mine", "myself"],
+            Self::Academic => &["you", "your", "yours", "yourself", "yourselves"],
+        }
+    }
+
+    fn message(self, pronoun: &str) -> String {
+        match self {
+            Self::Reference => {
+                format!("Reference documentation is usually written without “{pronoun}”.")
+            }
+            Self::Academic => {
+                format!("Academic writing usually avoids addressing the reader as “{pronoun}”.")
+            }
+        }
+    }
+}
+
+/// Flags pronouns that don't suit the genre of a document, like "I" in reference documentation
+/// or "you" in academic writing.
+///
+/// Does nothing until it is given a [`DocumentGenre`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PersonalVoice {
+    genre: Option<DocumentGenre>,
+}
+
+impl PersonalVoice {
+    pub fn new(genre: Option<DocumentGenre>) -> Self {
+        Self { genre }
+    }
+}
+
+impl Linter for PersonalVoice {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let Some(genre) = self.genre else {
+            return Vec::new();
+        };
+
+        let discouraged = genre.discouraged_pronouns();
+
+        document
+            .iter_words()
+            .filter_map(|word| {
+                let text = document.get_span_content_str(word.span);
+
+                if !discouraged.contains(&text.to_lowercase().as_str()) {
+                    return None;
+                }
+
+                Some(Lint {
+                    span: word.span,
+                    lint_kind: LintKind::Style,
+                    suggestions: vec![],
+                    message: genre.message(&text),
+                    priority: 63,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn description(&self) -> &'static str {
+        match self.genre {
+            None => {
+                "Flags pronouns that don't suit the configured document genre, such as “I” in reference documentation."
+            }
+            Some(DocumentGenre::Reference) => {
+                "Reference documentation should describe its subject without using “I” or “me”."
+            }
+            Some(DocumentGenre::Academic) => {
+                "Academic writing should avoid addressing the reader as “you”."
+            }
+        }
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentGenre, PersonalVoice};
+    use crate::linting::tests::assert_lint_count;
+
+    fn reference() -> PersonalVoice {
+        PersonalVoice::new(Some(DocumentGenre::Reference))
+    }
+
+    fn academic() -> PersonalVoice {
+        PersonalVoice::new(Some(DocumentGenre::Academic))
+    }
+
+    #[test]
+    fn reference_flags_first_person_singular() {
+        assert_lint_count(
+            "I recommend setting this flag. My tests show it helps.",
+            reference(),
+            2,
+        );
+    }
+
+    #[test]
+    fn reference_allows_second_person() {
+        assert_lint_count(
+            "You can set this flag to speed up your builds.",
+            reference(),
+            0,
+        );
+    }
+
+    #[test]
+    fn academic_flags_second_person() {
+        assert_lint_count("As you can see, your results may vary.", academic(), 2);
+    }
+
+    #[test]
+    fn academic_allows_first_person() {
+        assert_lint_count(
+            "In this paper, I argue that the model is sound.",
+            academic(),
+            0,
+        );
+    }
+
+    #[test]
+    fn does_nothing_without_a_genre() {
+        assert_lint_count("I think you should try it.", PersonalVoice::default(), 0);
+    }
+}
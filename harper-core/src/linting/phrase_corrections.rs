@@ -1,7 +1,11 @@
-use super::{LintGroup, MapPhraseLinter};
+use super::{LazyLinter, LintGroup, MapPhraseLinter, RegionalLinter};
+use crate::Dialect;
 
 /// Produce a [`LintGroup`] that looks for errors in common phrases.
 /// Comes pre-configured with the recommended default settings.
+///
+/// Phrases that are only wrong in some dialects are kept in regional packs, which only run when
+/// the group is set to one of those dialects.
 pub fn lint_group() -> LintGroup {
     let mut group = LintGroup::default();
 
@@ -10,15 +14,21 @@ pub fn lint_group() -> LintGroup {
             $($name:expr => ($input:expr, $corrections:expr, $hint:expr, $description:expr)),+ $(,)?
         }) => {
             $(
-                $group.add(
-                    $name,
-                    Box::new(MapPhraseLinter::new_exact_phrases(
-                        $input,
-                        $corrections,
-                        $hint,
-                        $description,
-                    )),
-                );
+                $group.add_lazy($name, || {
+                    MapPhraseLinter::new_exact_phrases($input, $corrections, $hint, $description)
+                });
+            )+
+        };
+    }
+
+    macro_rules! add_regional_mappings {
+        ($group:expr, $dialects:expr, {
+            $($name:expr => ($input:expr, $corrections:expr, $hint:expr, $description:expr)),+ $(,)?
+        }) => {
+            $(
+                $group.add($name, Box::new(RegionalLinter::new($dialects, LazyLinter::new(|| {
+                    MapPhraseLinter::new_exact_phrases($input, $corrections, $hint, $description)
+                }))));
             )+
         };
     }
@@ -361,6 +371,32 @@ pub fn lint_group() -> LintGroup {
         ),
     });
 
+    // en-GB
+    add_regional_mappings!(group, [Dialect::British], {
+        "DifferentThan" => (
+            ["different than"],
+            ["different from", "different to"],
+            "In British English, `different from` or `different to` is preferred.",
+            "Suggests `different from` or `different to` in place of the American `different than`."
+        ),
+        "AtTheWeekend" => (
+            ["on the weekend"],
+            ["at the weekend"],
+            "In British English, this is usually `at the weekend`.",
+            "Suggests the British idiom `at the weekend` in place of `on the weekend`."
+        ),
+    });
+
+    // en-US
+    add_regional_mappings!(group, [Dialect::American], {
+        "OnTheWeekend" => (
+            ["at the weekend"],
+            ["on the weekend"],
+            "In American English, this is usually `on the weekend`.",
+            "Suggests the American idiom `on the weekend` in place of `at the weekend`."
+        ),
+    });
+
     group.set_all_rules_to(Some(true));
 
     group
@@ -368,10 +404,18 @@ pub fn lint_group() -> LintGroup {
 
 #[cfg(test)]
 mod tests {
+    use crate::Dialect;
+    use crate::linting::LintGroup;
     use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
 
     use super::lint_group;
 
+    fn lint_group_for(dialect: Dialect) -> LintGroup {
+        let mut group = lint_group();
+        group.set_dialect(dialect);
+        group
+    }
+
     // todo: 4 tests: get/gets/getting rid off
     #[test]
     fn get_rid_off() {
@@ -518,4 +562,37 @@ mod tests {
     fn point_is_moot() {
         assert_suggestion_result("Your point is mute.", lint_group(), "Your point is moot.");
     }
+
+    #[test]
+    fn different_than_in_british() {
+        assert_suggestion_result(
+            "It is different than the last one.",
+            lint_group_for(Dialect::British),
+            "It is different from the last one.",
+        );
+    }
+
+    #[test]
+    fn different_than_in_american() {
+        assert_lint_count("It is different than the last one.", lint_group(), 0);
+    }
+
+    #[test]
+    fn weekend_by_dialect() {
+        assert_suggestion_result(
+            "We met on the weekend.",
+            lint_group_for(Dialect::British),
+            "We met at the weekend.",
+        );
+        assert_suggestion_result(
+            "We met at the weekend.",
+            lint_group_for(Dialect::American),
+            "We met on the weekend.",
+        );
+        assert_lint_count(
+            "We met on the weekend.",
+            lint_group_for(Dialect::Australian),
+            0,
+        );
+    }
 }
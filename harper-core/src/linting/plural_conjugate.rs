@@ -48,15 +48,20 @@ impl PatternLinter for PluralConjugate {
         Some(Lint {
             span: matched_tokens.last()?.span,
             lint_kind: LintKind::WordChoice,
-            suggestions: vec![Suggestion::ReplaceWith(sug)],
+            suggestions: vec![Suggestion::replace_with(sug)],
             message: "Use the alternative conjugation of this verb to be consistent with the noun's plural nature.".to_owned(),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Make sure you use the correct conjugation of the verb \"to be\" in plural contexts."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
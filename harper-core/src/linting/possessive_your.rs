@@ -49,6 +49,10 @@ impl PatternLinter for PossessiveYour {
     fn description(&self) -> &'static str {
         "The possessive version of `you` is more common before nouns."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
@@ -39,10 +39,15 @@ impl PatternLinter for AvoidContraction {
             message: "It appears you intended to use the possessive version of this word"
                 .to_owned(),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "This rule looks for situations where a contraction was used where it shouldn't have been."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
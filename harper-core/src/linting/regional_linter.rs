@@ -0,0 +1,81 @@
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::{CharString, Dialect, DialectFlags, Document};
+
+/// A [`Linter`] that only runs on text written in one of a set of [`Dialect`]s.
+///
+/// Useful for rules that are only correct in some regions, like idioms that differ between
+/// British and American English.
+pub struct RegionalLinter {
+    dialects: DialectFlags,
+    dialect: Dialect,
+    inner: Box<dyn Linter>,
+}
+
+impl RegionalLinter {
+    pub fn new<L: Linter + 'static>(dialects: impl IntoIterator<Item = Dialect>, inner: L) -> Self {
+        Self {
+            dialects: dialects.into_iter().collect(),
+            dialect: Dialect::default(),
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Whether the rule applies to the dialect it was last given.
+    pub fn is_active(&self) -> bool {
+        self.dialects.allows(self.dialect)
+    }
+}
+
+impl Linter for RegionalLinter {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+
+        self.inner.lint(document)
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        self.inner.lint_kind()
+    }
+
+    /// Outside of its dialects, the rule flags nothing, so it has no examples either.
+    fn examples(&self) -> Vec<RuleExample> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+
+        self.inner.examples()
+    }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        self.inner.anchor_words()
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+        self.inner.set_dialect(dialect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegionalLinter;
+    use crate::linting::{Linter, RepeatedWords};
+    use crate::{Dialect, Document};
+
+    #[test]
+    fn only_runs_in_its_dialects() {
+        let document = Document::new_plain_english_curated("This is is a test.");
+        let mut linter = RegionalLinter::new([Dialect::British], RepeatedWords::default());
+
+        assert_eq!(linter.lint(&document).len(), 0);
+
+        linter.set_dialect(Dialect::British);
+        assert_eq!(linter.lint(&document).len(), 1);
+    }
+}
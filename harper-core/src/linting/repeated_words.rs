@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Edit, Lint, LintKind, Linter, RuleExample, Suggestion};
 use crate::TokenStringExt;
 use crate::char_string::char_string;
 use crate::{CharString, CharStringExt, Document, Span};
@@ -57,9 +57,14 @@ impl Linter for RepeatedWords {
                     lints.push(Lint {
                         span: Span::new(tok_a.span.start, tok_b.span.end),
                         lint_kind: LintKind::Repetition,
-                        suggestions: vec![Suggestion::ReplaceWith(
-                            document.get_span_content(tok_a.span).to_vec(),
-                        )],
+                        // Only remove the second occurrence, leaving the first untouched.
+                        suggestions: vec![
+                            Suggestion::edits(vec![Edit::delete(Span::new(
+                                tok_a.span.end,
+                                tok_b.span.end,
+                            ))])
+                            .with_confidence(Confidence::High),
+                        ],
                         message: "Did you mean to repeat this word?".to_string(),
                         ..Default::default()
                     })
@@ -73,6 +78,14 @@ impl Linter for RepeatedWords {
     fn description(&self) -> &'static str {
         "This rule looks for repetitions of words that are not homographs."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Repetition)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new("This is is a test", "This is a test")]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,235 @@
+use hashbrown::HashMap;
+
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::{Document, PosTag, Token, TokenKind, TokenStringExt};
+
+/// Looks for sentences that use "respectively" to pair up two lists of different lengths, like
+/// "Alice, Bob, and Carol scored 90 and 85, respectively."
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Respectively;
+
+/// One of the pieces of a clause between commas and coordinating conjunctions.
+struct Segment {
+    /// Whether the segment could be a list item on its own, like "Bob" or "the 85 points".
+    /// Segments that aren't, like "Carol scored 90", end one list and start another.
+    simple: bool,
+    /// Whether the segment comes right after "and" or "or".
+    after_conjunction: bool,
+    /// Whether the segment opens with a preposition or adverb, like "In 2020".
+    introductory: bool,
+}
+
+fn is_list_conjunction(token: &Token, source: &[char]) -> bool {
+    token.kind.is_word()
+        && matches!(
+            token
+                .span
+                .get_content_string(source)
+                .to_lowercase()
+                .as_str(),
+            "and" | "or"
+        )
+}
+
+/// Split a clause into [`Segment`]s, using the part of speech of each word.
+fn segments(tokens: &[Token], source: &[char], tags: &HashMap<usize, PosTag>) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut current: Option<Segment> = None;
+    let mut after_conjunction = false;
+
+    for token in tokens {
+        if token.kind.is_whitespace() {
+            continue;
+        }
+
+        let is_conjunction = is_list_conjunction(token, source);
+
+        if token.kind.is_comma() || is_conjunction {
+            segments.extend(current.take());
+            after_conjunction |= is_conjunction;
+            continue;
+        }
+
+        let tag = tags.get(&token.span.start).copied();
+
+        let segment = current.get_or_insert_with(|| Segment {
+            simple: true,
+            after_conjunction: std::mem::take(&mut after_conjunction),
+            introductory: matches!(tag, Some(PosTag::Preposition | PosTag::Adverb)),
+        });
+
+        if let TokenKind::Word(metadata) = token.kind {
+            // Words missing from the dictionary are usually names, but words in it without a
+            // part of speech are usually inflected verbs, like "scored".
+            segment.simple &= match tag {
+                None => metadata.is_none(),
+                Some(tag) => matches!(
+                    tag,
+                    PosTag::Article | PosTag::Adjective | PosTag::Noun | PosTag::ProperNoun
+                ),
+            };
+        }
+    }
+
+    segments.extend(current);
+    segments
+}
+
+/// The number of items in each coordinated list (like "Alice, Bob, and Carol") in a clause.
+fn list_lengths(segments: &[Segment]) -> Vec<usize> {
+    let mut lengths = Vec::new();
+    let mut count = 0;
+    let mut has_conjunction = false;
+
+    let mut close = |count: usize, has_conjunction: bool| {
+        if count >= 2 && has_conjunction {
+            lengths.push(count);
+        }
+    };
+
+    for (index, segment) in segments.iter().enumerate() {
+        if index == 0 {
+            // An introductory phrase, like "In 2020,", isn't part of a list.
+            count = usize::from(segment.simple || !segment.introductory);
+            continue;
+        }
+
+        // A list ends after the item following its conjunction.
+        if has_conjunction && !segment.after_conjunction {
+            close(count, has_conjunction);
+            count = 1;
+            has_conjunction = false;
+            continue;
+        }
+
+        count += 1;
+        has_conjunction |= segment.after_conjunction;
+
+        // The segment ends this list with its first words and starts a new one with its last.
+        if !segment.simple {
+            close(count, has_conjunction);
+            count = 1;
+            has_conjunction = false;
+        }
+    }
+
+    close(count, has_conjunction);
+    lengths
+}
+
+impl Linter for Respectively {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let mut lints = Vec::new();
+        let mut tags = None;
+
+        for sentence in document.iter_sentences() {
+            let Some(position) = sentence.iter().position(|t| {
+                t.kind.is_word()
+                    && t.span
+                        .get_content_string(source)
+                        .eq_ignore_ascii_case("respectively")
+            }) else {
+                continue;
+            };
+
+            let tags = tags.get_or_insert_with(|| {
+                document
+                    .pos_tags()
+                    .into_iter()
+                    .filter_map(|word| Some((word.token.span.start, word.tag?)))
+                    .collect::<HashMap<_, _>>()
+            });
+
+            let lengths = list_lengths(&segments(&sentence[..position], source, tags));
+
+            let [.., first, second] = lengths.as_slice() else {
+                continue;
+            };
+
+            if first == second {
+                continue;
+            }
+
+            lints.push(Lint {
+                span: sentence[position].span,
+                lint_kind: LintKind::Miscellaneous,
+                suggestions: vec![],
+                message: format!(
+                    "“Respectively” pairs up lists of the same length, but these have {first} and {second} items."
+                ),
+                priority: 63,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks that the two lists paired up by “respectively” have the same number of items."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::without_correction(
+            "Alice, Bob, and Carol scored 90 and 85, respectively.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Respectively;
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_longer_first_list() {
+        assert_lint_count(
+            "Alice, Bob, and Carol scored 90 and 85, respectively.",
+            Respectively,
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_longer_second_list() {
+        assert_lint_count(
+            "The first and second prizes went to Alice, Bob, and Carol, respectively.",
+            Respectively,
+            1,
+        );
+    }
+
+    #[test]
+    fn allows_matching_lists() {
+        assert_lint_count(
+            "Alice, Bob, and Carol scored 90, 85, and 70, respectively.",
+            Respectively,
+            0,
+        );
+        assert_lint_count(
+            "The first and second prizes went to Alice and Bob, respectively.",
+            Respectively,
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_introductory_phrases() {
+        assert_lint_count(
+            "In 2020, Alice and Bob won gold and silver, respectively.",
+            Respectively,
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_sentences_without_two_lists() {
+        assert_lint_count("Alice and Bob are 30 and 32.", Respectively, 0);
+        assert_lint_count("They scored 90 and 85, respectively.", Respectively, 0);
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::{LintKind, Severity};
+
+/// A machine-readable description of a single rule inside a [`LintGroup`](super::LintGroup).
+///
+/// Intended for editors and documentation sites that want to render a browser of the available
+/// rules.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuleDescription {
+    /// The key used to refer to the rule in a [`LintGroupConfig`](super::LintGroupConfig).
+    pub name: String,
+    /// The user-facing description of the rule.
+    pub description: String,
+    /// The category of lints the rule produces, if it is consistent.
+    pub kind: Option<LintKind>,
+    /// Whether the rule is enabled in the curated configuration.
+    pub default_enabled: bool,
+    /// The severity configured for the rule in the group it was taken from, if any.
+    pub severity: Option<Severity>,
+    pub examples: Vec<RuleExample>,
+}
+
+/// A snippet of text a rule flags, along with the result of applying its first suggestion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RuleExample {
+    pub incorrect: String,
+    /// `None` if the rule does not offer a suggestion for this text.
+    pub corrected: Option<String>,
+}
+
+impl RuleExample {
+    pub fn new(incorrect: impl ToString, corrected: impl ToString) -> Self {
+        Self {
+            incorrect: incorrect.to_string(),
+            corrected: Some(corrected.to_string()),
+        }
+    }
+
+    /// An example for which the rule produces a lint, but no suggestions.
+    pub fn without_correction(incorrect: impl ToString) -> Self {
+        Self {
+            incorrect: incorrect.to_string(),
+            corrected: None,
+        }
+    }
+}
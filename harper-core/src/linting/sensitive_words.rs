@@ -0,0 +1,219 @@
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, Severity, Suggestion};
+use crate::{Document, Register, TokenStringExt};
+
+/// How strongly a word is likely to offend, which decides the [`Severity`] of its lints.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum SensitivityTier {
+    /// Words that are merely too casual for some audiences, like "crap".
+    Mild,
+    /// Swears.
+    #[default]
+    Moderate,
+    /// Slurs and other words that should never appear in published text.
+    Severe,
+}
+
+impl SensitivityTier {
+    /// The [`Severity`] given to lints for words of this tier.
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::Mild => Severity::Info,
+            Self::Moderate => Severity::Warning,
+            Self::Severe => Severity::Error,
+        }
+    }
+
+    fn message(self, word: &str) -> String {
+        match self {
+            Self::Mild => format!("“{word}” may be too casual for some readers."),
+            Self::Moderate => format!("“{word}” is likely to offend some readers."),
+            Self::Severe => format!("“{word}” is offensive and shouldn't be published."),
+        }
+    }
+}
+
+/// A word to screen for, along with what could be used instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SensitiveWord {
+    pub tier: SensitivityTier,
+    /// The words to suggest in its place.
+    pub replacements: Vec<String>,
+}
+
+/// Screens text for profanity and other sensitive words, for copy that will be read by
+/// customers.
+///
+/// By default, this flags the swears and offensive words marked in Harper's dictionary, which
+/// can be extended or overridden with a word list of its own.
+/// Each word belongs to a [`SensitivityTier`], and words below [`Self::with_min_tier`] are not
+/// flagged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SensitiveWords {
+    builtin: bool,
+    min_tier: SensitivityTier,
+    /// Keyed by the lowercase version of each word.
+    words: HashMap<String, SensitiveWord>,
+}
+
+impl Default for SensitiveWords {
+    fn default() -> Self {
+        Self {
+            builtin: true,
+            min_tier: SensitivityTier::Mild,
+            words: HashMap::new(),
+        }
+    }
+}
+
+impl SensitiveWords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to flag the words marked in Harper's dictionary, in addition to those added.
+    pub fn with_builtin(mut self, builtin: bool) -> Self {
+        self.builtin = builtin;
+        self
+    }
+
+    /// Only flag words of this tier or above.
+    pub fn with_min_tier(mut self, min_tier: SensitivityTier) -> Self {
+        self.min_tier = min_tier;
+        self
+    }
+
+    /// Screen for a word, replacing any existing entry for it.
+    pub fn add(&mut self, word: &str, entry: SensitiveWord) {
+        self.words.insert(word.to_lowercase(), entry);
+    }
+
+    pub fn with_word(mut self, word: &str, entry: SensitiveWord) -> Self {
+        self.add(word, entry);
+        self
+    }
+
+    /// Look up the entry for an added word, in any capitalization.
+    pub fn get(&self, word: &str) -> Option<&SensitiveWord> {
+        self.words.get(&word.to_lowercase())
+    }
+}
+
+impl Linter for SensitiveWords {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for word in document.iter_words() {
+            let text = document.get_span_content_str(word.span);
+
+            let (tier, replacements) = match self.get(&text) {
+                Some(entry) => (entry.tier, entry.replacements.as_slice()),
+                None if !self.builtin => continue,
+                None if word.kind.register() == Some(Register::Offensive) => {
+                    (SensitivityTier::Severe, [].as_slice())
+                }
+                None if word.kind.is_swear() => (SensitivityTier::Moderate, [].as_slice()),
+                None => continue,
+            };
+
+            if tier < self.min_tier {
+                continue;
+            }
+
+            let original = document.get_span_content(word.span);
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::WordChoice,
+                suggestions: replacements
+                    .iter()
+                    .map(|r| Suggestion::replace_with_match_case(r.chars().collect(), original))
+                    .collect(),
+                message: tier.message(&text),
+                priority: 31,
+                severity: Some(tier.severity()),
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Screens for profanity and other sensitive words, for text that will be read by customers."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SensitiveWord, SensitiveWords, SensitivityTier};
+    use crate::Document;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::{Linter, Severity};
+
+    fn heck() -> SensitiveWord {
+        SensitiveWord {
+            tier: SensitivityTier::Mild,
+            replacements: vec!["wow".to_string()],
+        }
+    }
+
+    #[test]
+    fn flags_builtin_swears() {
+        assert_lint_count(
+            "He ate shit when he fell off the bike.",
+            SensitiveWords::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn builtin_words_can_be_disabled() {
+        assert_lint_count(
+            "He ate shit when he fell off the bike.",
+            SensitiveWords::default().with_builtin(false),
+            0,
+        );
+    }
+
+    #[test]
+    fn suggests_replacements_for_added_words() {
+        assert_suggestion_result(
+            "Heck, that was fast.",
+            SensitiveWords::default().with_word("heck", heck()),
+            "Wow, that was fast.",
+        );
+    }
+
+    #[test]
+    fn skips_tiers_below_minimum() {
+        assert_lint_count(
+            "Heck, that was fast.",
+            SensitiveWords::default()
+                .with_word("heck", heck())
+                .with_min_tier(SensitivityTier::Moderate),
+            0,
+        );
+    }
+
+    #[test]
+    fn severity_follows_tier() {
+        let document = Document::new_plain_english_curated("The vendor tried to gyp us. Heck.");
+        let lints = SensitiveWords::default()
+            .with_word("heck", heck())
+            .lint(&document);
+
+        let severities: Vec<_> = lints.iter().map(|l| l.severity).collect();
+        assert_eq!(severities, [Some(Severity::Error), Some(Severity::Info)]);
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use hashbrown::HashMap;
+
+use super::lint_profile::run_rule;
+use super::{Lint, LintProfile, Linter};
+use crate::{Document, Span, Token, TokenStringExt};
+
+/// Remembers the lints each rule produced for a sentence, so that sentences which haven't
+/// changed since the last run don't need to be linted again.
+///
+/// Rules only see one sentence at a time when linting through the cache, so problems that
+/// span several sentences will not be found.
+#[derive(Debug, Default, Clone)]
+pub struct SentenceCache {
+    /// Maps the hash of a sentence to the lints found in it, keyed by rule.
+    /// Lint spans are relative to the start of the sentence.
+    entries: HashMap<u64, BTreeMap<String, Vec<Lint>>>,
+}
+
+impl SentenceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct sentences currently cached.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Lint a document sentence by sentence, only running rules on sentences they haven't seen.
+    ///
+    /// Each returned lint has its [`Lint::rule`] set.
+    /// Sentences that no longer appear in the document are evicted.
+    /// A rule only runs on the sentences `should_run` allows it to, given the rule and the span
+    /// of the sentence.
+    /// Only the rules that actually run are recorded in `profile`.
+    pub fn lint(
+        &mut self,
+        document: &Document,
+        linters: &mut BTreeMap<String, Box<dyn Linter>>,
+        should_run: impl Fn(&str, Span) -> bool,
+        mut profile: Option<&mut LintProfile>,
+    ) -> Vec<Lint> {
+        let mut old_entries = std::mem::take(&mut self.entries);
+        let mut results = Vec::new();
+
+        for sentence in document.iter_sentences() {
+            let Some(span) = sentence.span() else {
+                continue;
+            };
+
+            let hash = hash_sentence(sentence, document.get_source());
+
+            let entry = self
+                .entries
+                .entry(hash)
+                .or_insert_with(|| old_entries.remove(&hash).unwrap_or_default());
+
+            let mut sentence_doc = None;
+
+            for (key, linter) in linters.iter_mut() {
+                if !should_run(key, span) {
+                    continue;
+                }
+
+                let lints = entry.entry(key.clone()).or_insert_with(|| {
+                    let (_, doc) = sentence_doc.get_or_insert_with(|| {
+                        document
+                            .sub_document(sentence)
+                            .expect("sentence is not empty")
+                    });
+
+                    run_rule(linter.as_mut(), doc, key, profile.as_deref_mut())
+                });
+
+                results.extend(lints.iter().map(|lint| {
+                    let mut lint = lint.clone();
+                    lint.push_by(span.start);
+                    lint.rule = Some(key.clone());
+                    lint
+                }));
+            }
+        }
+
+        results
+    }
+}
+
+fn hash_sentence(sentence: &[Token], source: &[char]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    let start = sentence.first().map(|t| t.span.start).unwrap_or_default();
+
+    for token in sentence {
+        token.kind.hash(&mut hasher);
+        token.span.pulled_by(start).hash(&mut hasher);
+        token.span.get_content(source).hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::SentenceCache;
+    use crate::linting::{Lint, LintGroup, Linter};
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn matches_uncached_lints() {
+        let document = Document::new_markdown_default_curated(
+            "This is an test. There is is a problem. I can't beleive it!",
+        );
+
+        let mut uncached = LintGroup::new_curated(FstDictionary::curated());
+        let mut cached = LintGroup::new_curated(FstDictionary::curated())
+            .with_sentence_cache(SentenceCache::new());
+
+        let mut expected = uncached.lint(&document);
+        let mut first = cached.lint(&document);
+        let mut second = cached.lint(&document);
+
+        for lints in [&mut expected, &mut first, &mut second] {
+            lints.sort_by_key(|l| (l.span.start, l.rule.clone()));
+        }
+
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+    }
+
+    /// Counts how many times it has been run.
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Linter for Counter {
+        fn lint(&mut self, _document: &Document) -> Vec<Lint> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Vec::new()
+        }
+
+        fn description(&self) -> &str {
+            "Counts how many times it has been run."
+        }
+    }
+
+    #[test]
+    fn reuses_unchanged_sentences() {
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let mut group = LintGroup::empty().with_sentence_cache(SentenceCache::new());
+        group.add("Counter", Box::new(Counter(runs.clone())));
+        group.config.set_rule_enabled("Counter", true);
+
+        group.lint(&Document::new_plain_english_curated("One. Two."));
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+
+        group.lint(&Document::new_plain_english_curated("One. Three."));
+        assert_eq!(runs.load(Ordering::Relaxed), 3);
+        assert_eq!(group.sentence_cache.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn shifts_cached_lints() {
+        let mut group = LintGroup::new_curated(FstDictionary::curated())
+            .with_sentence_cache(SentenceCache::new());
+
+        group.lint(&Document::new_plain_english_curated(" This is an test."));
+        let lints = group.lint(&Document::new_plain_english_curated(
+            "A new sentence. This is an test.",
+        ));
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span.start, 24);
+    }
+}
@@ -1,12 +1,34 @@
 use itertools::Itertools;
 
 use super::Suggestion;
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, RuleExample};
 use crate::document::Document;
 use crate::{Token, TokenKind, TokenStringExt};
 
-#[derive(Debug, Clone, Copy, Default)]
-pub struct SentenceCapitalization;
+#[derive(Debug, Clone, Copy)]
+pub struct SentenceCapitalization {
+    /// Whether sentences may start with a name the dictionary marks as case-sensitive and
+    /// lowercase, like "npm".
+    allow_lowercase_names: bool,
+}
+
+impl SentenceCapitalization {
+    /// Choose whether sentences may start with a lowercase name, like "npm" or "iOS".
+    ///
+    /// On by default, since capitalizing such a name would misspell it.
+    pub fn with_lowercase_names(mut self, allow_lowercase_names: bool) -> Self {
+        self.allow_lowercase_names = allow_lowercase_names;
+        self
+    }
+}
+
+impl Default for SentenceCapitalization {
+    fn default() -> Self {
+        Self {
+            allow_lowercase_names: true,
+        }
+    }
+}
 
 impl Linter for SentenceCapitalization {
     /// A linter that checks to make sure the first word of each sentence is
@@ -38,6 +60,11 @@ impl Linter for SentenceCapitalization {
                         continue;
                     }
 
+                    // Names like "npm" and "iOS" keep their capitalization everywhere.
+                    if self.allow_lowercase_names && first_word.kind.is_case_sensitive() {
+                        continue;
+                    }
+
                     let letters = document.get_span_content(first_word.span);
 
                     if let Some(first_letter) = letters.first() {
@@ -45,12 +72,13 @@ impl Linter for SentenceCapitalization {
                             lints.push(Lint {
                                 span: first_word.span.with_len(1),
                                 lint_kind: LintKind::Capitalization,
-                                suggestions: vec![Suggestion::ReplaceWith(
+                                suggestions: vec![Suggestion::replace_with(
                                     first_letter.to_uppercase().collect_vec(),
                                 )],
                                 priority: 31,
                                 message: "This sentence does not start with a capital letter"
                                     .to_string(),
+                                ..Default::default()
                             })
                         }
                     }
@@ -64,6 +92,17 @@ impl Linter for SentenceCapitalization {
     fn description(&self) -> &'static str {
         "The opening word of a sentence should almost always be capitalized."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Capitalization)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "there is no way she is not guilty.",
+            "There is no way she is not guilty.",
+        )]
+    }
 }
 
 fn is_full_sentence(toks: &[Token]) -> bool {
@@ -94,7 +133,7 @@ mod tests {
     fn catches_basic() {
         assert_lint_count(
             "there is no way she is not guilty.",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             1,
         )
     }
@@ -103,7 +142,7 @@ mod tests {
     fn no_period() {
         assert_lint_count(
             "there is no way she is not guilty",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             1,
         )
     }
@@ -112,7 +151,7 @@ mod tests {
     fn two_sentence() {
         assert_lint_count(
             "i have complete conviction in this. she is absolutely guilty",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             2,
         )
     }
@@ -121,7 +160,7 @@ mod tests {
     fn start_with_number() {
         assert_lint_count(
             "53 is the length of the longest word.",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             0,
         );
     }
@@ -130,7 +169,7 @@ mod tests {
     fn ignores_unlintable() {
         assert_lint_count(
             "[`misspelled_word`] is assumed to be quite small (n < 100). ",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             0,
         )
     }
@@ -139,7 +178,7 @@ mod tests {
     fn unphased_unlintable() {
         assert_lint_count(
             "the linter should not be affected by `this` unlintable.",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             1,
         )
     }
@@ -148,7 +187,7 @@ mod tests {
     fn unphased_ellipsis() {
         assert_lint_count(
             "the linter should not be affected by... that ellipsis.",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             1,
         )
     }
@@ -157,13 +196,44 @@ mod tests {
     fn unphased_comma() {
         assert_lint_count(
             "the linter should not be affected by, that comma.",
-            SentenceCapitalization,
+            SentenceCapitalization::default(),
             1,
         )
     }
 
     #[test]
     fn issue_228_allows_labels() {
-        assert_lint_count("python lsp (fork of pyright)", SentenceCapitalization, 0)
+        assert_lint_count(
+            "python lsp (fork of pyright)",
+            SentenceCapitalization::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn allows_lowercase_names() {
+        assert_lint_count(
+            "npm is the package manager that ships with Node.",
+            SentenceCapitalization::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn flags_lowercase_names_when_configured() {
+        assert_lint_count(
+            "npm is the package manager that ships with Node.",
+            SentenceCapitalization::default().with_lowercase_names(false),
+            1,
+        )
+    }
+
+    #[test]
+    fn allows_email_addresses_before_mentions() {
+        assert_lint_count(
+            "Email user@example.com or ping @octocat. Use a#b here.",
+            SentenceCapitalization::default(),
+            0,
+        )
     }
 }
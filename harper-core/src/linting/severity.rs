@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use is_macro::Is;
+use serde::{Deserialize, Serialize};
+
+/// How loudly a [`Lint`](super::Lint) should be presented to the user.
+///
+/// Integrations are expected to map these onto their own notion of severity (diagnostic
+/// severities in the case of `harper-ls`, colors in the case of `harper-cli`).
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, Is, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// Purely a nudge. Often rendered as a subtle underline, if at all.
+    Hint,
+    #[default]
+    #[serde(alias = "information")]
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Produce a string representation, which can be used as keys in a map or CSS variables.
+    pub fn to_string_key(&self) -> String {
+        match self {
+            Severity::Hint => "hint",
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+        .to_owned()
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Severity::Hint => "Hint",
+            Severity::Info => "Info",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Severity;
+
+    #[test]
+    fn ordered_by_importance() {
+        assert!(Severity::Hint < Severity::Info);
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+    }
+
+    #[test]
+    fn deserializes_lowercase() {
+        let severity: Severity = serde_json::from_str("\"warning\"").unwrap();
+        assert_eq!(severity, Severity::Warning);
+    }
+}
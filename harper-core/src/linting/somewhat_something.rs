@@ -38,12 +38,17 @@ impl PatternLinter for SomewhatSomething {
             suggestions: vec![Suggestion::replace_with_match_case_str("something", og)],
             message: "Use the traditional form.".to_owned(),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "When describing a single instance of a noun, use `something` rather than `somewhat`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,170 @@
+use super::{Confidence, Lint, LintKind, Linter, RuleExample, Suggestion};
+use crate::{Document, Punctuation, Span, Token, TokenKind};
+
+/// Detect a comma, period or colon with no space between it and the next word, like in
+/// "apples,oranges".
+///
+/// Numbers (`3,000`), URLs, domains and file names are lexed as their own kinds of tokens, so
+/// they are left alone.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpaceAfterPunctuation;
+
+impl SpaceAfterPunctuation {
+    fn lint_at(punctuation: Span) -> Lint {
+        Lint {
+            span: punctuation,
+            lint_kind: LintKind::Formatting,
+            suggestions: vec![
+                Suggestion::insert_after(vec![' ']).with_confidence(Confidence::High),
+            ],
+            message: "There should be a space after this punctuation.".to_string(),
+            priority: 31,
+            ..Default::default()
+        }
+    }
+
+    /// The lexer reads "end.Next" as a hostname, but unlike real domains and file names, the
+    /// part after the period is a capitalized word.
+    /// Returns the span of the period.
+    fn missing_space_in_hostname(token: &Token, source: &[char]) -> Option<Span> {
+        let content = token.span.get_content(source);
+        let dot = content.iter().position(|c| *c == '.')?;
+        let (before, after) = (&content[..dot], &content[dot + 1..]);
+
+        let is_word_end = before.len() > 1
+            && before.iter().all(|c| c.is_alphabetic())
+            && before.last().is_some_and(|c| c.is_lowercase());
+        let is_sentence_start = after.len() > 1
+            && after[0].is_uppercase()
+            && after[1..].iter().all(|c| c.is_lowercase());
+
+        (is_word_end && is_sentence_start).then(|| Span::new_with_len(token.span.start + dot, 1))
+    }
+}
+
+impl Linter for SpaceAfterPunctuation {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let mut lints = Vec::new();
+
+        for window in document.get_tokens().windows(3) {
+            let [before, punctuation, after] = window else {
+                continue;
+            };
+
+            let is_separator = matches!(
+                punctuation.kind,
+                TokenKind::Punctuation(
+                    Punctuation::Comma | Punctuation::Period | Punctuation::Colon
+                )
+            );
+
+            // File names, like "README.md", are split into words around the period, but their
+            // extensions aren't capitalized like the start of a sentence would be.
+            let is_file_extension = punctuation.kind.is_period()
+                && !after
+                    .span
+                    .get_content(source)
+                    .first()
+                    .is_some_and(|c| c.is_uppercase());
+
+            if is_separator
+                && !is_file_extension
+                && before.kind.is_word()
+                && after.kind.is_word()
+                && before.span.end == punctuation.span.start
+                && punctuation.span.end == after.span.start
+            {
+                lints.push(Self::lint_at(punctuation.span));
+            }
+        }
+
+        for token in document.get_tokens() {
+            if token.kind.is_hostname()
+                && let Some(period) = Self::missing_space_in_hostname(token, source)
+            {
+                lints.push(Self::lint_at(period));
+            }
+        }
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for commas, periods, and colons that run straight into the next word."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![
+            RuleExample::new(
+                "We bought apples,oranges and pears.",
+                "We bought apples, oranges and pears.",
+            ),
+            RuleExample::new(
+                "It rained all day.Then it stopped.",
+                "It rained all day. Then it stopped.",
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpaceAfterPunctuation;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn inserts_space_after_comma() {
+        assert_suggestion_result(
+            "We bought apples,oranges and pears.",
+            SpaceAfterPunctuation,
+            "We bought apples, oranges and pears.",
+        );
+    }
+
+    #[test]
+    fn inserts_space_after_colon() {
+        assert_suggestion_result(
+            "There is one rule:listen.",
+            SpaceAfterPunctuation,
+            "There is one rule: listen.",
+        );
+    }
+
+    #[test]
+    fn inserts_space_after_period() {
+        assert_suggestion_result(
+            "It rained all day.Then it stopped.",
+            SpaceAfterPunctuation,
+            "It rained all day. Then it stopped.",
+        );
+    }
+
+    #[test]
+    fn allows_numbers() {
+        assert_lint_count(
+            "It cost 3,000 dollars at 10:30 on version 1.2.",
+            SpaceAfterPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_urls_domains_and_files() {
+        assert_lint_count(
+            "See https://example.com/a,b or WordPress.com, then open config.json or README.md.",
+            SpaceAfterPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_correct_spacing() {
+        assert_lint_count("Apples, oranges. Pears: yes.", SpaceAfterPunctuation, 0);
+    }
+}
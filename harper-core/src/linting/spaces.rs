@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Confidence, Lint, LintKind, Linter, Suggestion};
 use crate::TokenStringExt;
 use crate::{Document, Token, TokenKind};
 
@@ -19,12 +19,15 @@ impl Linter for Spaces {
                     output.push(Lint {
                         span: space.span,
                         lint_kind: LintKind::Formatting,
-                        suggestions: vec![Suggestion::ReplaceWith(vec![' '])],
+                        suggestions: vec![
+                            Suggestion::replace_with(vec![' ']).with_confidence(Confidence::High),
+                        ],
                         message: format!(
                             "There are {} spaces where there should be only one.",
                             count
                         ),
                         priority: 15,
+                        ..Default::default()
                     })
                 }
             }
@@ -52,9 +55,10 @@ impl Linter for Spaces {
                         .span()
                         .unwrap(),
                     lint_kind: LintKind::Formatting,
-                    suggestions: vec![Suggestion::Remove],
+                    suggestions: vec![Suggestion::remove().with_confidence(Confidence::High)],
                     message: "Unnecessary space at the end of the sentence.".to_string(),
                     priority: 63,
+                    ..Default::default()
                 })
             }
         }
@@ -65,6 +69,10 @@ impl Linter for Spaces {
     fn description(&self) -> &'static str {
         "Words should be separated by at most one space."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
 }
 
 #[cfg(test)]
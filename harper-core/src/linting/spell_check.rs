@@ -1,17 +1,34 @@
 use hashbrown::HashMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use smallvec::ToSmallVec;
+use unicode_script::{Script, UnicodeScript};
 
-use super::Suggestion;
+use super::{Confidence, Suggestion};
 use super::{Lint, LintKind, Linter};
 use crate::document::Document;
-use crate::spell::suggest_correct_spelling;
-use crate::{CharString, CharStringExt, Dictionary, TokenStringExt};
+use crate::spell::suggest_correct_spelling_for_layout;
+use crate::{
+    CharString, CharStringExt, Dialect, Dictionary, KeyboardLayout, Punctuation, Span, Token,
+    TokenKind,
+};
 
 pub struct SpellCheck<T>
 where
     T: Dictionary,
 {
     dictionary: T,
+    keyboard_layout: KeyboardLayout,
+    /// Words tagged with other dialects (like "colour" for American English) are flagged.
+    dialect: Dialect,
+    /// Whether to accept words made by joining two dictionary words, like "cachefriendly".
+    allow_closed_compounds: bool,
+    /// When set, unknown words used at least this many times in a document are reported
+    /// together in a single lint, rather than once for each use.
+    learning_threshold: Option<usize>,
+    identifier_handling: IdentifierHandling,
+    ignore_patterns: Vec<IgnorePattern>,
+    skip_words: SkipWords,
     word_cache: HashMap<CharString, Vec<CharString>>,
 }
 
@@ -19,34 +36,525 @@ impl<T: Dictionary> SpellCheck<T> {
     pub fn new(dictionary: T) -> Self {
         Self {
             dictionary,
+            keyboard_layout: KeyboardLayout::default(),
+            dialect: Dialect::default(),
+            allow_closed_compounds: false,
+            learning_threshold: None,
+            identifier_handling: IdentifierHandling::default(),
+            ignore_patterns: Vec::new(),
+            skip_words: SkipWords::default(),
             word_cache: HashMap::new(),
         }
     }
+
+    /// Rank suggestions assuming the text was typed on the given [`KeyboardLayout`].
+    pub fn with_keyboard_layout(mut self, keyboard_layout: KeyboardLayout) -> Self {
+        self.keyboard_layout = keyboard_layout;
+        self.word_cache.clear();
+        self
+    }
+
+    /// Check spelling against the given [`Dialect`], so "colour" is only accepted in
+    /// the dialects that spell it that way.
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self.word_cache.clear();
+        self
+    }
+
+    /// Accept words made by joining two dictionary words of at least three letters each, like
+    /// "cachefriendly" or "tokenstream", which are common in technical writing.
+    pub fn with_closed_compounds(mut self, allow_closed_compounds: bool) -> Self {
+        self.allow_closed_compounds = allow_closed_compounds;
+        self
+    }
+
+    /// Report unknown words that are used at least `threshold` times in a document together,
+    /// as a single lint suggesting they be added to the dictionary.
+    ///
+    /// A word that keeps coming up is more likely to be a name or jargon than a misspelling,
+    /// and flagging each use of it buries the real mistakes.
+    /// Pass `None` (the default) to report every use on its own.
+    pub fn with_learning_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.learning_threshold = threshold;
+        self
+    }
+
+    /// Choose what to do with code identifiers mentioned in prose, like `getUserById` or
+    /// `MAX_RETRIES`.
+    pub fn with_identifier_handling(mut self, identifier_handling: IdentifierHandling) -> Self {
+        self.identifier_handling = identifier_handling;
+        self
+    }
+
+    /// Never flag words that lie entirely within text matched by one of the patterns.
+    ///
+    /// See [`IgnorePattern`].
+    pub fn with_ignore_patterns(mut self, patterns: Vec<IgnorePattern>) -> Self {
+        self.ignore_patterns = patterns;
+        self
+    }
+
+    /// Never flag the kinds of words that are enabled in `skip_words`.
+    pub fn with_skip_words(mut self, skip_words: SkipWords) -> Self {
+        self.skip_words = skip_words;
+        self
+    }
+
+    /// The unknown words that are used at least `min_occurrences` times in a document, in the
+    /// order they first appear.
+    pub fn learnable_words(
+        &self,
+        document: &Document,
+        min_occurrences: usize,
+    ) -> Vec<LearnableWord> {
+        let mut words: Vec<LearnableWord> = Vec::new();
+        let mut indices: HashMap<CharString, usize> = HashMap::new();
+        let ignored = self.ignored_spans(document);
+
+        for (index, token) in document.tokens().enumerate() {
+            if is_within(&ignored, token.span) || !self.is_misspelled(document, index) {
+                continue;
+            }
+
+            let text = document.get_span_content(token.span);
+
+            let index = *indices.entry(text.to_smallvec()).or_insert_with(|| {
+                words.push(LearnableWord {
+                    word: text.iter().collect(),
+                    occurrences: Vec::new(),
+                });
+                words.len() - 1
+            });
+
+            words[index].occurrences.push(token.span);
+        }
+
+        words.retain(|word| word.occurrences.len() >= min_occurrences.max(1));
+        words
+    }
+}
+
+/// What [`SpellCheck`] does with code identifiers in prose, like `getUserById` (camelCase),
+/// `get_user_by_id` (snake_case) or `MAX_RETRIES` (SCREAMING_CASE).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IdentifierHandling {
+    /// Don't check identifiers at all.
+    Skip,
+    /// Check each of the words an identifier is made of, so `getUsrById` is flagged but
+    /// `getUserById` isn't.
+    #[default]
+    CheckComponents,
+}
+
+/// Kinds of words [`SpellCheck`] can skip without looking them up.
+///
+/// In technical prose, these are much more often codes or identifiers than misspellings.
+/// All of them are checked by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SkipWords {
+    /// Words containing digits, like `utf8` or `h264`.
+    pub digits: bool,
+    /// Words joined to others by underscores, like the parts of `user_id`.
+    pub underscores: bool,
+    /// Words written partly in another script, such as Latin letters run together with
+    /// Cyrillic ones.
+    pub mixed_scripts: bool,
+}
+
+/// A regular expression matching text that [`SpellCheck`] should never flag, like ticket IDs
+/// (`[A-Z]{2,}-\d+`) or hexadecimal numbers (`0x[0-9a-f]+`).
+///
+/// Patterns are matched against the text of the whole document, since things like ticket IDs
+/// are split into several tokens.
+/// Words that lie entirely within a match are skipped before they are looked up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IgnorePattern(Regex);
+
+impl IgnorePattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl TryFrom<String> for IgnorePattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<IgnorePattern> for String {
+    fn from(value: IgnorePattern) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl PartialEq for IgnorePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
 }
 
+impl Eq for IgnorePattern {}
+
+/// Whether `span` lies entirely within one of the `ignored` spans.
+fn is_within(ignored: &[Span], span: Span) -> bool {
+    ignored
+        .iter()
+        .any(|ignored| ignored.start <= span.start && span.end <= ignored.end)
+}
+
+/// The spans of the words a camelCase or PascalCase identifier is made of, relative to its
+/// start.
+///
+/// Returns `None` if the word isn't made of several words.
+fn camel_case_components(word: &[char]) -> Option<Vec<Span>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+
+    for i in 1..word.len() {
+        let lower_to_upper = word[i - 1].is_lowercase() && word[i].is_uppercase();
+        // The end of an acronym, like the "P" in "JSONParser".
+        let acronym_end = word[i - 1].is_uppercase()
+            && word[i].is_uppercase()
+            && word.get(i + 1).is_some_and(|c| c.is_lowercase());
+
+        if lower_to_upper || acronym_end {
+            components.push(Span::new(start, i));
+            start = i;
+        }
+    }
+
+    if components.is_empty() {
+        return None;
+    }
+
+    components.push(Span::new(start, word.len()));
+    Some(components)
+}
+
+/// An unknown word that is used repeatedly in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LearnableWord {
+    pub word: String,
+    /// Where the word is used, in order.
+    pub occurrences: Vec<Span>,
+}
+
+/// The shortest word that may be part of a closed compound.
+/// Shorter words (like "a" or "ox") would let too many misspellings through.
+const MIN_COMPOUND_PART_LEN: usize = 3;
+
 impl<T: Dictionary> SpellCheck<T> {
+    fn is_known_word(&self, word: &[char]) -> bool {
+        (self.dictionary.contains_exact_word(word)
+            || self.dictionary.contains_exact_word(&word.to_lower()))
+            && self.is_in_dialect(word)
+    }
+
+    /// The spans of the document's text matched by any of the ignore patterns.
+    fn ignored_spans(&self, document: &Document) -> Vec<Span> {
+        if self.ignore_patterns.is_empty() {
+            return Vec::new();
+        }
+
+        let text: String = document.get_source().iter().collect();
+
+        // Regexes report byte offsets, so we need to know where each character starts.
+        let char_starts: Vec<usize> = text.char_indices().map(|(byte, _)| byte).collect();
+        let char_index = |byte: usize| char_starts.partition_point(|start| *start < byte);
+
+        self.ignore_patterns
+            .iter()
+            .flat_map(|pattern| pattern.0.find_iter(&text))
+            .map(|found| Span::new(char_index(found.start()), char_index(found.end())))
+            .collect()
+    }
+
+    /// Whether the word is used in the configured dialect.
+    /// Words the dictionary doesn't tag with a dialect are used in all of them.
+    fn is_in_dialect(&self, word: &[char]) -> bool {
+        self.dictionary
+            .get_word_metadata(word)
+            .is_none_or(|metadata| metadata.dialects.allows(self.dialect))
+    }
+
+    /// Whether the token at `index` is a word that should be flagged.
+    fn is_misspelled(&self, document: &Document, index: usize) -> bool {
+        let token = &document.get_tokens()[index];
+
+        if !token.kind.is_word() {
+            return false;
+        }
+
+        let word_chars = document.get_span_content(token.span);
+
+        if self.is_skipped(document, index) {
+            return false;
+        }
+
+        if self.is_known_word(word_chars)
+            || self.is_miscapitalized(word_chars)
+            || self.is_part_of_hyphenated_word(document, index)
+            || (self.allow_closed_compounds && self.is_closed_compound(word_chars))
+        {
+            return false;
+        }
+
+        let is_identifier = Self::is_snake_case_component(document, index)
+            || camel_case_components(word_chars).is_some();
+
+        !(is_identifier && self.identifier_handling == IdentifierHandling::Skip)
+            && !self.misspelled_spans(document, index).is_empty()
+    }
+
+    /// Whether the word at `index` is one of the kinds enabled in [`Self::skip_words`].
+    fn is_skipped(&self, document: &Document, index: usize) -> bool {
+        let tokens = document.get_tokens();
+        let token = tokens[index];
+
+        if self.skip_words.digits
+            && document
+                .get_span_content(token.span)
+                .iter()
+                .any(char::is_ascii_digit)
+        {
+            return true;
+        }
+
+        if self.skip_words.underscores && Self::is_snake_case_component(document, index) {
+            return true;
+        }
+
+        // Word tokens only contain Latin letters, so a word in mixed scripts is split into
+        // several tokens that touch one another.
+        let is_other_script = |neighbor: Option<&Token>| {
+            neighbor.is_some_and(|neighbor| {
+                (neighbor.span.end == token.span.start || neighbor.span.start == token.span.end)
+                    && document
+                        .get_span_content(neighbor.span)
+                        .iter()
+                        .any(|c| c.is_alphabetic() && c.script() != Script::Latin)
+            })
+        };
+
+        self.skip_words.mixed_scripts
+            && (is_other_script(index.checked_sub(1).and_then(|i| tokens.get(i)))
+                || is_other_script(tokens.get(index + 1)))
+    }
+
+    /// The parts of a misspelled word to flag: usually the whole thing, but only the unknown
+    /// components of camelCase identifiers.
+    fn misspelled_spans(&self, document: &Document, index: usize) -> Vec<Span> {
+        let token = &document.get_tokens()[index];
+        let word_chars = document.get_span_content(token.span);
+
+        match camel_case_components(word_chars) {
+            Some(components) => components
+                .into_iter()
+                .filter(|component| !self.is_known_word(component.get_content(word_chars)))
+                .map(|mut component| {
+                    component.push_by(token.span.start);
+                    component
+                })
+                .collect(),
+            None => vec![token.span],
+        }
+    }
+
+    /// Whether the word at `index` is joined to another by an underscore, like in
+    /// `get_user_by_id`.
+    fn is_snake_case_component(document: &Document, index: usize) -> bool {
+        let tokens = document.get_tokens();
+
+        let is_joined = |underscore: usize, word: usize| {
+            tokens
+                .get(underscore)
+                .is_some_and(|t| t.kind == TokenKind::Punctuation(Punctuation::Underscore))
+                && tokens.get(word).is_some_and(|t| t.kind.is_word())
+        };
+
+        (index >= 2 && is_joined(index - 1, index - 2)) || is_joined(index + 1, index + 2)
+    }
+
+    /// A single lint listing the words that are used so often they should probably be added to
+    /// the dictionary, placed at the first use of any of them.
+    fn learnable_words_lint(words: &[LearnableWord]) -> Option<Lint> {
+        let first = words.first()?;
+
+        let message = if let [word] = words {
+            format!(
+                "“{}” is used {} times but isn't in the dictionary. Consider adding it to your dictionary.",
+                word.word,
+                word.occurrences.len()
+            )
+        } else {
+            let list = words
+                .iter()
+                .map(|word| format!("“{}”", word.word))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!(
+                "{} words are used repeatedly but aren't in the dictionary: {list}. Consider adding them to your dictionary.",
+                words.len()
+            )
+        };
+
+        Some(Lint {
+            span: first.occurrences[0],
+            lint_kind: LintKind::Spelling,
+            message,
+            priority: 63,
+            ..Default::default()
+        })
+    }
+
+    /// Whether the word is a miscapitalized version of one that must always be written the same
+    /// way, which is left to [`super::CanonicalCasing`].
+    fn is_miscapitalized(&self, word: &[char]) -> bool {
+        self.dictionary
+            .get_word_metadata(word)
+            .is_some_and(|metadata| metadata.case_sensitive)
+    }
+
+    /// Whether a word can be split into two dictionary words.
+    fn is_closed_compound(&self, word: &[char]) -> bool {
+        (MIN_COMPOUND_PART_LEN..=word.len().saturating_sub(MIN_COMPOUND_PART_LEN)).any(|split| {
+            let (head, tail) = word.split_at(split);
+            self.is_known_word(head) && self.is_known_word(tail)
+        })
+    }
+
+    /// Whether the word at `index` is part of a hyphenated word (like "pre-empt") that is in
+    /// the dictionary once its hyphens are removed.
+    ///
+    /// The other parts of hyphenated words are checked on their own, so "cache-friendly" is
+    /// accepted because both "cache" and "friendly" are.
+    fn is_part_of_hyphenated_word(&self, document: &Document, index: usize) -> bool {
+        let tokens = document.get_tokens();
+
+        let is_joined = |hyphen: usize, word: usize| {
+            tokens[hyphen].kind.is_hyphen() && tokens[word].kind.is_word()
+        };
+
+        let mut start = index;
+        while start >= 2 && is_joined(start - 1, start - 2) {
+            start -= 2;
+        }
+
+        let mut end = index;
+        while end + 2 < tokens.len() && is_joined(end + 1, end + 2) {
+            end += 2;
+        }
+
+        if start == end {
+            return false;
+        }
+
+        let joined: CharString = tokens[start..=end]
+            .iter()
+            .step_by(2)
+            .flat_map(|token| document.get_span_content(token.span))
+            .copied()
+            .collect();
+
+        self.is_known_word(&joined)
+    }
+
     fn cached_suggest_correct_spelling(&mut self, word: &[char]) -> Vec<CharString> {
-        let word = word.to_smallvec();
+        if let Some(suggestions) = self.word_cache.get(word) {
+            return suggestions.clone();
+        }
+
+        // Back off until we find a match.
+        let mut suggestions: Vec<CharString> = Vec::new();
+        let mut dist = 2;
+
+        while suggestions.is_empty() && dist < 5 {
+            suggestions = suggest_correct_spelling_for_layout(
+                word,
+                100,
+                dist,
+                &self.dictionary,
+                self.keyboard_layout,
+            )
+            .into_iter()
+            .filter(|v| self.is_in_dialect(v))
+            .map(|v| v.to_smallvec())
+            .collect();
+
+            dist += 1;
+        }
+
+        // A word from another dialect (like "color" in British English) was most likely meant
+        // to be this dialect's spelling of it.
+        if self.dictionary.contains_word(word) {
+            suggestions.sort_by_key(|v| {
+                !self
+                    .dictionary
+                    .get_word_metadata(v)
+                    .is_some_and(|metadata| metadata.dialects.contains(self.dialect))
+            });
+        }
 
         self.word_cache
-            .entry(word.clone())
-            .or_insert_with(|| {
-                // Back off until we find a match.
-                let mut suggestions = Vec::new();
-                let mut dist = 2;
-
-                while suggestions.is_empty() && dist < 5 {
-                    suggestions = suggest_correct_spelling(&word, 100, dist, &self.dictionary)
-                        .into_iter()
-                        .map(|v| v.to_smallvec())
-                        .collect();
-
-                    dist += 1;
+            .insert(word.to_smallvec(), suggestions.clone());
+
+        suggestions
+    }
+}
+
+impl<T: Dictionary> SpellCheck<T> {
+    fn misspelling_lint(&mut self, span: Span, word_chars: &[char]) -> Lint {
+        let mut possibilities = self.cached_suggest_correct_spelling(word_chars);
+
+        if possibilities.len() > 3 {
+            possibilities.resize_with(3, || panic!());
+        }
+
+        // If the misspelled word is capitalized, capitalize the results too.
+        if let Some(mis_f) = word_chars.first() {
+            if mis_f.is_uppercase() {
+                for sug_f in possibilities.iter_mut().filter_map(|w| w.first_mut()) {
+                    *sug_f = sug_f.to_uppercase().next().unwrap();
                 }
+            }
+        }
 
-                suggestions
-            })
-            .clone()
+        let suggestions = possibilities
+            .iter()
+            .map(|word| Suggestion::replace_with(word.to_vec()).with_confidence(Confidence::Low));
+
+        // If there's only one suggestion, save the user a step in the GUI
+        let message = if suggestions.len() == 1 {
+            format!(
+                "Did you mean “{}”?",
+                possibilities.last().unwrap().iter().collect::<String>()
+            )
+        } else {
+            format!(
+                "Did you mean to spell “{}” this way?",
+                word_chars.iter().collect::<String>()
+            )
+        };
+
+        Lint {
+            span,
+            lint_kind: LintKind::Spelling,
+            suggestions: suggestions.collect(),
+            message,
+            priority: 63,
+            ..Default::default()
+        }
     }
 }
 
@@ -54,53 +562,33 @@ impl<T: Dictionary> Linter for SpellCheck<T> {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
-        for word in document.iter_words() {
-            let word_chars = document.get_span_content(word.span);
-            if self.dictionary.contains_exact_word(word_chars)
-                || self.dictionary.contains_exact_word(&word_chars.to_lower())
-            {
+        let learnable = self
+            .learning_threshold
+            .map(|threshold| self.learnable_words(document, threshold))
+            .unwrap_or_default();
+
+        lints.extend(Self::learnable_words_lint(&learnable));
+
+        let ignored = self.ignored_spans(document);
+
+        for (index, word) in document.tokens().enumerate() {
+            if is_within(&ignored, word.span) || !self.is_misspelled(document, index) {
                 continue;
             }
 
-            let mut possibilities = self.cached_suggest_correct_spelling(word_chars);
+            let word_chars = document.get_span_content(word.span);
 
-            if possibilities.len() > 3 {
-                possibilities.resize_with(3, || panic!());
+            if learnable
+                .iter()
+                .any(|learnable| learnable.word.chars().eq(word_chars.iter().copied()))
+            {
+                continue;
             }
 
-            // If the misspelled word is capitalized, capitalize the results too.
-            if let Some(mis_f) = word_chars.first() {
-                if mis_f.is_uppercase() {
-                    for sug_f in possibilities.iter_mut().filter_map(|w| w.first_mut()) {
-                        *sug_f = sug_f.to_uppercase().next().unwrap();
-                    }
-                }
+            for span in self.misspelled_spans(document, index) {
+                let lint = self.misspelling_lint(span, document.get_span_content(span));
+                lints.push(lint);
             }
-
-            let suggestions = possibilities
-                .iter()
-                .map(|word| Suggestion::ReplaceWith(word.to_vec()));
-
-            // If there's only one suggestion, save the user a step in the GUI
-            let message = if suggestions.len() == 1 {
-                format!(
-                    "Did you mean “{}”?",
-                    possibilities.last().unwrap().iter().collect::<String>()
-                )
-            } else {
-                format!(
-                    "Did you mean to spell “{}” this way?",
-                    document.get_span_content_str(word.span)
-                )
-            };
-
-            lints.push(Lint {
-                span: word.span,
-                lint_kind: LintKind::Spelling,
-                suggestions: suggestions.collect(),
-                message,
-                priority: 63,
-            })
         }
 
         lints
@@ -109,16 +597,28 @@ impl<T: Dictionary> Linter for SpellCheck<T> {
     fn description(&self) -> &'static str {
         "Looks and provides corrections for misspelled words."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Spelling)
+    }
+
+    fn set_dialect(&mut self, dialect: Dialect) {
+        if self.dialect != dialect {
+            self.dialect = dialect;
+            self.word_cache.clear();
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        FstDictionary,
+        Dialect, Document, FstDictionary,
+        linting::Linter,
         linting::tests::{assert_lint_count, assert_suggestion_result},
     };
 
-    use super::SpellCheck;
+    use super::{IdentifierHandling, IgnorePattern, SkipWords, SpellCheck};
 
     #[test]
     fn markdown_capitalized() {
@@ -137,4 +637,228 @@ mod tests {
             2,
         );
     }
+
+    #[test]
+    fn accepts_decomposed_accents() {
+        assert_lint_count(
+            "The cafe\u{0301} was nai\u{0308}ve.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_paths_mentions_and_hashtags() {
+        assert_lint_count(
+            "Ask @jdoe about #rustlang or edit ~/.confg/harpr.toml and /usr/lcl/bin.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn checks_identifier_components() {
+        assert_lint_count(
+            "Call getUserById or get_user_by_id with MAX_RETRIES.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+        let document = Document::new_plain_english_curated("Call getUsrById.");
+        let lints = SpellCheck::new(FstDictionary::curated()).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(document.get_span_content_str(lints[0].span), "Usr");
+        assert_lint_count(
+            "Call get_usr_by_id.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn can_skip_identifiers() {
+        assert_lint_count(
+            "Call getUsrById or get_usr_by_id with MAX_RETRYS.",
+            SpellCheck::new(FstDictionary::curated())
+                .with_identifier_handling(IdentifierHandling::Skip),
+            0,
+        );
+    }
+
+    #[test]
+    fn accepts_hyphenated_words() {
+        assert_lint_count(
+            "A cache-friendly, well-known and state-of-the-art layout.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn checks_each_part_of_hyphenated_words() {
+        assert_lint_count(
+            "A cache-frendly layout.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn accepts_hyphenated_spellings_of_words() {
+        assert_lint_count(
+            "We had to pre-empt the attack.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn closed_compounds_are_optional() {
+        let text = "The cachefriendly layout.";
+
+        assert_lint_count(text, SpellCheck::new(FstDictionary::curated()), 1);
+        assert_lint_count(
+            text,
+            SpellCheck::new(FstDictionary::curated()).with_closed_compounds(true),
+            0,
+        );
+    }
+
+    #[test]
+    fn closed_compounds_need_known_parts() {
+        assert_lint_count(
+            "The cachefrendly layout.",
+            SpellCheck::new(FstDictionary::curated()).with_closed_compounds(true),
+            1,
+        );
+    }
+
+    #[test]
+    fn checks_against_dialect() {
+        let text = "The colour of the theatre.";
+
+        assert_lint_count(text, SpellCheck::new(FstDictionary::curated()), 2);
+        assert_lint_count(
+            text,
+            SpellCheck::new(FstDictionary::curated()).with_dialect(Dialect::British),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_american_spellings_in_british_english() {
+        assert_lint_count(
+            "The color of the theater.",
+            SpellCheck::new(FstDictionary::curated()).with_dialect(Dialect::British),
+            2,
+        );
+    }
+
+    #[test]
+    fn derived_words_keep_dialect() {
+        assert_lint_count(
+            "They recoloured the neighbours' favourite cancelled sign.",
+            SpellCheck::new(FstDictionary::curated()).with_dialect(Dialect::Australian),
+            0,
+        );
+        assert_lint_count(
+            "They recoloured the neighbours' favourite cancelled sign.",
+            SpellCheck::new(FstDictionary::curated()),
+            4,
+        );
+    }
+
+    #[test]
+    fn words_without_dialect_pass_everywhere() {
+        assert_lint_count(
+            "We organize the analysis.",
+            SpellCheck::new(FstDictionary::curated()).with_dialect(Dialect::British),
+            0,
+        );
+    }
+
+    #[test]
+    fn suggests_spellings_from_dialect() {
+        assert_suggestion_result(
+            "The color changed.",
+            SpellCheck::new(FstDictionary::curated()).with_dialect(Dialect::British),
+            "The colour changed.",
+        );
+        assert_suggestion_result(
+            "The colour changed.",
+            SpellCheck::new(FstDictionary::curated()),
+            "The color changed.",
+        );
+    }
+
+    #[test]
+    fn reports_repeated_unknown_words_once() {
+        let text = "Zorblax is great. I use Zorblax daily, and Zorblax never fails. This is wrnog.";
+
+        assert_lint_count(text, SpellCheck::new(FstDictionary::curated()), 4);
+        assert_lint_count(
+            text,
+            SpellCheck::new(FstDictionary::curated()).with_learning_threshold(Some(3)),
+            2,
+        );
+    }
+
+    #[test]
+    fn lists_learnable_words() {
+        let document = Document::new_markdown_default_curated(
+            "Zorblax and Quuxify. Quuxify then Zorblax, then Zorblax and wrnog.",
+        );
+
+        let words = SpellCheck::new(FstDictionary::curated()).learnable_words(&document, 2);
+
+        assert_eq!(
+            words
+                .iter()
+                .map(|word| (word.word.as_str(), word.occurrences.len()))
+                .collect::<Vec<_>>(),
+            vec![("Zorblax", 3), ("Quuxify", 2)]
+        );
+    }
+
+    #[test]
+    fn skips_words_matching_ignore_patterns() {
+        let text = "Café — see QXZR-123 and 0xdeadbeef, then wrnog.";
+        let patterns = vec![
+            IgnorePattern::new(r"[A-Z]{2,}-\d+").unwrap(),
+            IgnorePattern::new("0x[0-9a-f]+").unwrap(),
+        ];
+
+        assert_lint_count(text, SpellCheck::new(FstDictionary::curated()), 2);
+        assert_lint_count(
+            text,
+            SpellCheck::new(FstDictionary::curated()).with_ignore_patterns(patterns),
+            1,
+        );
+    }
+
+    #[test]
+    fn ignore_patterns_round_trip_as_strings() {
+        let pattern: IgnorePattern = serde_json::from_str(r#""0x[0-9a-f]+""#).unwrap();
+
+        assert_eq!(pattern.as_str(), "0x[0-9a-f]+");
+        assert_eq!(serde_json::to_string(&pattern).unwrap(), r#""0x[0-9a-f]+""#);
+        assert!(serde_json::from_str::<IgnorePattern>(r#""(""#).is_err());
+    }
+
+    #[test]
+    fn skips_configured_kinds_of_words() {
+        let text = "Encode it with zqxv9, then frob_nicate the Xяzqv.";
+        let skip_all = SkipWords {
+            digits: true,
+            underscores: true,
+            mixed_scripts: true,
+        };
+
+        assert_lint_count(text, SpellCheck::new(FstDictionary::curated()), 4);
+        assert_lint_count(
+            text,
+            SpellCheck::new(FstDictionary::curated()).with_skip_words(skip_all),
+            0,
+        );
+    }
 }
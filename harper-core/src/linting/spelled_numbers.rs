@@ -1,5 +1,5 @@
 use crate::linting::{LintKind, Linter, Suggestion};
-use crate::{Document, Lint, Number, TokenStringExt};
+use crate::{Document, Lint, Number, NumberKind, TokenStringExt};
 
 /// Linter that checks to make sure small integers (< 10) are spelled
 /// out.
@@ -14,6 +14,8 @@ impl Linter for SpelledNumbers {
             let Number {
                 value,
                 suffix: None,
+                kind: NumberKind::Plain,
+                prefix: None,
                 ..
             } = number_tok.kind.number().unwrap()
             else {
@@ -25,11 +27,12 @@ impl Linter for SpelledNumbers {
                 lints.push(Lint {
                     span: number_tok.span,
                     lint_kind: LintKind::Readability,
-                    suggestions: vec![Suggestion::ReplaceWith(
+                    suggestions: vec![Suggestion::replace_with(
                         spell_out_number(value as u64).unwrap().chars().collect(),
                     )],
                     message: "Try to spell out numbers less than ten.".to_string(),
                     priority: 63,
+                    ..Default::default()
                 })
             }
         }
@@ -40,6 +43,10 @@ impl Linter for SpelledNumbers {
     fn description(&self) -> &'static str {
         "Most style guides recommend that you spell out numbers less than ten."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Readability)
+    }
 }
 
 /// Converts a number to its spelled-out variant.
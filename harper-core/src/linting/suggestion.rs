@@ -6,23 +6,153 @@ use serde::{Deserialize, Serialize};
 use crate::Span;
 
 /// A suggested edit that could resolve a [`Lint`](super::Lint).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(from = "SerializedSuggestion")]
+pub struct Suggestion {
+    /// The edit to make.
+    pub kind: SuggestionKind,
+    /// How sure the rule is that this is the edit the author wants.
+    ///
+    /// Only [`Confidence::High`] suggestions are applied without asking (see
+    /// [`crate::fixer::is_safe_fix`]).
+    pub confidence: Confidence,
+}
+
+/// The edit a [`Suggestion`] makes.
 #[derive(Debug, Clone, Serialize, Deserialize, Is, PartialEq, Eq, Hash)]
-pub enum Suggestion {
+pub enum SuggestionKind {
     /// Replace the offending text with a specific character sequence.
     ReplaceWith(Vec<char>),
+    /// Insert the provided characters _before_ the offending text.
+    InsertBefore(Vec<char>),
     /// Insert the provided characters _after_ the offending text.
     InsertAfter(Vec<char>),
     /// Remove the offending text.
     Remove,
+    /// Make several independent edits at once.
+    ///
+    /// Useful when a fix touches text that isn't contiguous, or when only part of the offending
+    /// text needs to change.
+    /// The edits must not overlap.
+    Edits(Vec<Edit>),
+}
+
+/// How sure a rule is that a [`Suggestion`] is the right fix.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Confidence {
+    /// One guess among several, like a spelling correction, for the author to choose from.
+    Low,
+    /// The usual fix, though the author may have meant something else.
+    #[default]
+    Medium,
+    /// The only reasonable fix. Applying it without asking should never make the text worse.
+    High,
+}
+
+/// Suggestions were serialized as a bare [`SuggestionKind`] before they had a confidence.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedSuggestion {
+    Full {
+        kind: SuggestionKind,
+        confidence: Confidence,
+    },
+    Kind(SuggestionKind),
+}
+
+impl From<SerializedSuggestion> for Suggestion {
+    fn from(value: SerializedSuggestion) -> Self {
+        match value {
+            SerializedSuggestion::Full { kind, confidence } => Self { kind, confidence },
+            SerializedSuggestion::Kind(kind) => kind.into(),
+        }
+    }
+}
+
+impl From<SuggestionKind> for Suggestion {
+    fn from(kind: SuggestionKind) -> Self {
+        Self {
+            kind,
+            confidence: Confidence::default(),
+        }
+    }
+}
+
+/// A single change to a document, used to build [`Suggestion::edits`].
+///
+/// Unlike other suggestions, the span is an absolute position in the source, rather than being
+/// relative to the span of the [`Lint`](super::Lint).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Edit {
+    /// The text to be replaced.
+    /// If empty, [`Self::replacement`] is inserted at its start.
+    pub span: Span,
+    pub replacement: Vec<char>,
+}
+
+impl Edit {
+    pub fn replace(span: Span, replacement: Vec<char>) -> Self {
+        Self { span, replacement }
+    }
+
+    pub fn insert(at: usize, text: Vec<char>) -> Self {
+        Self::replace(Span::new(at, at), text)
+    }
+
+    pub fn delete(span: Span) -> Self {
+        Self::replace(span, Vec::new())
+    }
+
+    /// Apply the edit to a given text.
+    ///
+    /// Any part of the span that lies past the end of the text is ignored.
+    pub fn apply(&self, source: &mut Vec<char>) {
+        let end = self.span.end.min(source.len());
+        let start = self.span.start.min(end);
+
+        source.splice(start..end, self.replacement.iter().copied());
+    }
 }
 
 impl Suggestion {
+    /// Replace the offending text with a specific character sequence.
+    pub fn replace_with(chars: Vec<char>) -> Self {
+        SuggestionKind::ReplaceWith(chars).into()
+    }
+
+    /// Insert the provided characters _before_ the offending text.
+    pub fn insert_before(chars: Vec<char>) -> Self {
+        SuggestionKind::InsertBefore(chars).into()
+    }
+
+    /// Insert the provided characters _after_ the offending text.
+    pub fn insert_after(chars: Vec<char>) -> Self {
+        SuggestionKind::InsertAfter(chars).into()
+    }
+
+    /// Remove the offending text.
+    pub fn remove() -> Self {
+        SuggestionKind::Remove.into()
+    }
+
+    /// Make several independent edits at once. See [`SuggestionKind::Edits`].
+    pub fn edits(edits: Vec<Edit>) -> Self {
+        SuggestionKind::Edits(edits).into()
+    }
+
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
     /// Variant of [`Self::replace_with_match_case`] that accepts a static string.
     pub fn replace_with_match_case_str(value: &'static str, template: &[char]) -> Self {
         Self::replace_with_match_case(value.chars().collect(), template)
     }
 
-    /// Construct an instance of [`Self::ReplaceWith`], but make the content match the case of the
+    /// Construct an instance of [`SuggestionKind::ReplaceWith`], but make the content match the case of the
     /// provided template.
     ///
     /// For example, if we want to replace "You're" with "You are", we can provide "you are" and
@@ -38,51 +168,64 @@ impl Suggestion {
             }
         }
 
-        Self::ReplaceWith(value)
+        Self::replace_with(value)
     }
 
-    /// Apply a suggestion to a given text.
-    pub fn apply(&self, span: Span, source: &mut Vec<char>) {
-        match self {
-            Self::ReplaceWith(chars) => {
-                // Avoid allocation if possible
-                if chars.len() == span.len() {
-                    for (index, c) in chars.iter().enumerate() {
-                        source[index + span.start] = *c
-                    }
-                } else {
-                    let popped = source.split_off(span.start);
+    /// Express the suggestion as a list of [`Edit`]s, given the span of the
+    /// [`Lint`](super::Lint) it belongs to.
+    ///
+    /// The edits are sorted by position and do not overlap.
+    pub fn to_edits(&self, span: Span) -> Vec<Edit> {
+        let mut edits = match &self.kind {
+            SuggestionKind::ReplaceWith(chars) => vec![Edit::replace(span, chars.clone())],
+            SuggestionKind::InsertBefore(chars) => vec![Edit::insert(span.start, chars.clone())],
+            SuggestionKind::InsertAfter(chars) => vec![Edit::insert(span.end, chars.clone())],
+            SuggestionKind::Remove => vec![Edit::delete(span)],
+            SuggestionKind::Edits(edits) => edits.clone(),
+        };
 
-                    source.extend(chars);
-                    source.extend(popped.into_iter().skip(span.len()));
-                }
-            }
-            Self::Remove => {
-                for i in span.end..source.len() {
-                    source[i - span.len()] = source[i];
-                }
+        edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+        edits
+    }
 
-                source.truncate(source.len() - span.len());
-            }
-            Self::InsertAfter(chars) => {
-                let popped = source.split_off(span.end);
-                source.extend(chars);
-                source.extend(popped);
-            }
+    /// Apply a suggestion to a given text.
+    pub fn apply(&self, span: Span, source: &mut Vec<char>) {
+        // Work backwards so earlier edits don't shift the positions of later ones.
+        for edit in self.to_edits(span).iter().rev() {
+            edit.apply(source);
         }
     }
 }
 
 impl Display for Suggestion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Suggestion::ReplaceWith(with) => {
+        match &self.kind {
+            SuggestionKind::ReplaceWith(with) => {
                 write!(f, "Replace with: “{}”", with.iter().collect::<String>())
             }
-            Suggestion::InsertAfter(with) => {
+            SuggestionKind::InsertBefore(with) | SuggestionKind::InsertAfter(with) => {
                 write!(f, "Insert “{}”", with.iter().collect::<String>())
             }
-            Suggestion::Remove => write!(f, "Remove error"),
+            SuggestionKind::Remove => write!(f, "Remove error"),
+            SuggestionKind::Edits(edits) => {
+                for (idx, edit) in edits.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    let replacement: String = edit.replacement.iter().collect();
+
+                    if edit.span.is_empty() {
+                        write!(f, "Insert “{replacement}”")?;
+                    } else if replacement.is_empty() {
+                        write!(f, "Remove text")?;
+                    } else {
+                        write!(f, "Replace with: “{replacement}”")?;
+                    }
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -91,18 +234,65 @@ impl Display for Suggestion {
 mod tests {
     use crate::Span;
 
-    use super::Suggestion;
+    use super::{Edit, Suggestion};
 
     #[test]
     fn insert_comma_after() {
         let source = "This is a test";
         let mut source_chars = source.chars().collect();
-        let sug = Suggestion::InsertAfter(vec![',']);
+        let sug = Suggestion::insert_after(vec![',']);
         sug.apply(Span::new(0, 4), &mut source_chars);
 
         assert_eq!(source_chars, "This, is a test".chars().collect::<Vec<_>>());
     }
 
+    #[test]
+    fn insert_before() {
+        let mut source_chars = "This is test".chars().collect();
+        let sug = Suggestion::insert_before("a ".chars().collect());
+        sug.apply(Span::new(8, 12), &mut source_chars);
+
+        assert_eq!(source_chars, "This is a test".chars().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn remove_span() {
+        let mut source_chars = "This is is a test".chars().collect();
+        Suggestion::remove().apply(Span::new(4, 7), &mut source_chars);
+
+        assert_eq!(source_chars, "This is a test".chars().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn compound_edits_apply_independently() {
+        let mut source_chars = "apples oranges and pears".chars().collect();
+        let sug = Suggestion::edits(vec![
+            Edit::insert(14, vec![',']),
+            Edit::replace(Span::new(0, 6), "Apples".chars().collect()),
+            Edit::delete(Span::new(18, 24)),
+        ]);
+        sug.apply(Span::new(0, 24), &mut source_chars);
+
+        assert_eq!(
+            source_chars,
+            "Apples oranges, and".chars().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn simple_suggestions_become_single_edits() {
+        let span = Span::new(3, 5);
+
+        assert_eq!(
+            Suggestion::insert_after(vec!['!']).to_edits(span),
+            vec![Edit::insert(5, vec!['!'])]
+        );
+        assert_eq!(
+            Suggestion::remove().to_edits(span),
+            vec![Edit::delete(span)]
+        );
+    }
+
     #[test]
     fn suggestion_your_match_case() {
         let template: Vec<_> = "You're".chars().collect();
@@ -112,7 +302,7 @@ mod tests {
 
         assert_eq!(
             Suggestion::replace_with_match_case(value, &template),
-            Suggestion::ReplaceWith(correct)
+            Suggestion::replace_with(correct)
         )
     }
 }
@@ -61,12 +61,17 @@ impl PatternLinter for TerminatingConjunctions {
                  clause."
             ),
             priority: 63,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Subordinating conjunctions are words that create a grammatical space for another idea or clause. As such, they should never appear at the end of a clause."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,526 @@
+use std::collections::BTreeMap;
+
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::{Document, TokenStringExt};
+
+/// How a term in a [`Terminology`] should be written.
+///
+/// Written in a config as either a list of variants or a table with the fields below.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TermEntry", into = "TermEntry")]
+pub struct Term {
+    /// Other spellings of the term, which are replaced with it.
+    pub variants: Vec<String>,
+    /// For abbreviations, what the abbreviation stands for.
+    ///
+    /// The first use in a document should be spelled out, like "application programming
+    /// interface (API)", and later uses abbreviated.
+    pub expansion: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum TermEntry {
+    Variants(Vec<String>),
+    Table(TermTable),
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TermTable {
+    variants: Vec<String>,
+    expansion: Option<String>,
+}
+
+impl From<TermEntry> for Term {
+    fn from(entry: TermEntry) -> Self {
+        match entry {
+            TermEntry::Variants(variants) => Self {
+                variants,
+                expansion: None,
+            },
+            TermEntry::Table(TermTable {
+                variants,
+                expansion,
+            }) => Self {
+                variants,
+                expansion,
+            },
+        }
+    }
+}
+
+impl From<Term> for TermEntry {
+    fn from(term: Term) -> Self {
+        match term.expansion {
+            None => Self::Variants(term.variants),
+            expansion => Self::Table(TermTable {
+                variants: term.variants,
+                expansion,
+            }),
+        }
+    }
+}
+
+/// Enforces consistent spelling of project-specific terms, like "backend" instead of "back end"
+/// or "Wi-Fi" instead of "wifi".
+///
+/// Variants are matched regardless of capitalization, and a space in a variant matches any
+/// whitespace, so "back end" also catches "Back\nend".
+/// Abbreviations with an [expansion](Term::expansion) are checked across the whole document,
+/// so each should be spelled out once before it is used on its own.
+///
+/// Serializes as a map from each preferred term to its [`Term`] entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "BTreeMap<String, Term>", into = "BTreeMap<String, Term>")]
+pub struct Terminology {
+    terms: BTreeMap<String, Term>,
+    /// Every variant alongside its preferred term, longest first, so the longest match wins.
+    variants: Vec<(Vec<char>, String)>,
+    /// Every abbreviation alongside its expansion.
+    abbreviations: Vec<(String, Vec<char>)>,
+}
+
+impl Terminology {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefer `term` over each of its `variants`, replacing any existing entry for it.
+    pub fn add_term(&mut self, term: &str, variants: impl IntoIterator<Item = impl ToString>) {
+        self.insert(
+            term,
+            Term {
+                variants: variants.into_iter().map(|v| v.to_string()).collect(),
+                expansion: None,
+            },
+        );
+    }
+
+    pub fn with_term(
+        mut self,
+        term: &str,
+        variants: impl IntoIterator<Item = impl ToString>,
+    ) -> Self {
+        self.add_term(term, variants);
+        self
+    }
+
+    /// Require `abbreviation` to be spelled out as `expansion` the first time it is used in a
+    /// document, and abbreviated afterward.
+    pub fn add_abbreviation(&mut self, abbreviation: &str, expansion: &str) {
+        self.insert(
+            abbreviation,
+            Term {
+                variants: Vec::new(),
+                expansion: Some(expansion.to_string()),
+            },
+        );
+    }
+
+    pub fn with_abbreviation(mut self, abbreviation: &str, expansion: &str) -> Self {
+        self.add_abbreviation(abbreviation, expansion);
+        self
+    }
+
+    /// Add a term, replacing any existing entry for it.
+    pub fn insert(&mut self, term: &str, entry: Term) {
+        self.terms.insert(term.to_string(), entry);
+        self.index_terms();
+    }
+
+    /// The entry for a preferred term.
+    pub fn get(&self, term: &str) -> Option<&Term> {
+        self.terms.get(term)
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Merge the terms of another [`Terminology`] into this one.
+    /// Terms in both take the other's entry.
+    /// The other will be left empty after this operation.
+    pub fn merge_from(&mut self, other: &mut Terminology) {
+        self.terms.append(&mut other.terms);
+        other.index_terms();
+        self.index_terms();
+    }
+
+    fn index_terms(&mut self) {
+        self.variants = self
+            .terms
+            .iter()
+            .flat_map(|(term, entry)| {
+                entry
+                    .variants
+                    .iter()
+                    .map(|variant| (variant.chars().collect(), term.clone()))
+            })
+            .collect();
+
+        self.variants
+            .sort_by_key(|(variant, _)| std::cmp::Reverse(variant.len()));
+
+        self.abbreviations = self
+            .terms
+            .iter()
+            .filter_map(|(term, entry)| {
+                Some((term.clone(), entry.expansion.as_ref()?.chars().collect()))
+            })
+            .collect();
+    }
+}
+
+impl From<BTreeMap<String, Term>> for Terminology {
+    fn from(terms: BTreeMap<String, Term>) -> Self {
+        let mut terminology = Self {
+            terms,
+            ..Default::default()
+        };
+        terminology.index_terms();
+        terminology
+    }
+}
+
+impl From<Terminology> for BTreeMap<String, Term> {
+    fn from(value: Terminology) -> Self {
+        value.terms
+    }
+}
+
+/// How many characters of `source`, starting at `start`, match `expected`.
+///
+/// The match has to end at the end of a word.
+fn match_len(source: &[char], start: usize, expected: &[char], ignore_case: bool) -> Option<usize> {
+    let mut cursor = start;
+    let mut expected = expected.iter().peekable();
+
+    while let Some(c) = expected.next() {
+        if c.is_whitespace() {
+            let run = source[cursor..]
+                .iter()
+                .take_while(|c| c.is_whitespace())
+                .count();
+
+            if run == 0 {
+                return None;
+            }
+
+            cursor += run;
+            while expected.next_if(|c| c.is_whitespace()).is_some() {}
+        } else {
+            let actual = source.get(cursor)?;
+
+            let matches = if ignore_case {
+                actual.to_lowercase().eq(c.to_lowercase())
+            } else {
+                actual == c
+            };
+
+            if !matches {
+                return None;
+            }
+
+            cursor += 1;
+        }
+    }
+
+    if source.get(cursor).is_some_and(|c| c.is_alphanumeric()) {
+        return None;
+    }
+
+    Some(cursor - start)
+}
+
+/// How many characters of `source`, starting at `start`, are taken up by an abbreviation in
+/// parentheses, like " (API)".
+fn parenthesized_len(source: &[char], start: usize, abbreviation: &[char]) -> Option<usize> {
+    let spaces = source[start..]
+        .iter()
+        .take_while(|c| c.is_whitespace())
+        .count();
+    let open = start + spaces;
+
+    if source.get(open) != Some(&'(') {
+        return None;
+    }
+
+    let close = open + 1 + abbreviation.len();
+
+    (source.get(open + 1..close)? == abbreviation && source.get(close) == Some(&')'))
+        .then_some(close + 1 - start)
+}
+
+/// Capitalize the replacement for text at the start of a sentence.
+fn match_sentence_case(found: &[char], mut replacement: Vec<char>) -> Vec<char> {
+    if found.first().is_some_and(|c| c.is_uppercase())
+        && replacement.first().is_some_and(|c| c.is_lowercase())
+    {
+        replacement[0] = replacement[0]
+            .to_uppercase()
+            .next()
+            .unwrap_or(replacement[0]);
+    }
+
+    replacement
+}
+
+impl Linter for Terminology {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        if self.terms.is_empty() {
+            return Vec::new();
+        }
+
+        let source = document.get_source();
+        let mut lints = Vec::new();
+        // Where the last match ended, so a match isn't reported again from inside it.
+        let mut matched_until = 0;
+        // The abbreviations that have been spelled out or used so far.
+        let mut introduced = HashSet::new();
+
+        for word in document.iter_words() {
+            let start = word.span.start;
+
+            if start < matched_until {
+                continue;
+            }
+
+            let mut abbreviation_lint = None;
+
+            for (abbreviation, expansion) in &self.abbreviations {
+                let abbreviation_chars: Vec<char> = abbreviation.chars().collect();
+
+                if let Some(len) = match_len(source, start, expansion, true) {
+                    // Include the abbreviation itself, if it follows in parentheses.
+                    let len = len
+                        + parenthesized_len(source, start + len, &abbreviation_chars).unwrap_or(0);
+                    matched_until = start + len;
+
+                    if !introduced.insert(abbreviation.as_str()) {
+                        abbreviation_lint = Some(Lint {
+                            span: word.span.with_len(len),
+                            lint_kind: LintKind::Style,
+                            suggestions: vec![Suggestion::replace_with(abbreviation_chars)],
+                            message: format!(
+                                "“{abbreviation}” has already been spelled out, so it can be abbreviated."
+                            ),
+                            priority: 31,
+                            ..Default::default()
+                        });
+                    }
+
+                    break;
+                }
+
+                if let Some(len) = match_len(source, start, &abbreviation_chars, false) {
+                    matched_until = start + len;
+
+                    if introduced.insert(abbreviation.as_str()) {
+                        let mut replacement = expansion.clone();
+                        replacement.extend(" (".chars());
+                        replacement.extend(&abbreviation_chars);
+                        replacement.push(')');
+
+                        let expansion: String = expansion.iter().collect();
+
+                        abbreviation_lint = Some(Lint {
+                            span: word.span.with_len(len),
+                            lint_kind: LintKind::Style,
+                            suggestions: vec![Suggestion::replace_with(match_sentence_case(
+                                &source[start..start + len],
+                                replacement,
+                            ))],
+                            message: format!(
+                                "Spell out “{abbreviation}” as “{expansion}” the first time it is used."
+                            ),
+                            priority: 31,
+                            ..Default::default()
+                        });
+                    }
+
+                    break;
+                }
+            }
+
+            if start < matched_until {
+                lints.extend(abbreviation_lint);
+                continue;
+            }
+
+            let Some((len, term)) = self
+                .variants
+                .iter()
+                .find_map(|(variant, term)| Some((match_len(source, start, variant, true)?, term)))
+            else {
+                continue;
+            };
+
+            let found = &source[start..start + len];
+            let replacement = match_sentence_case(found, term.chars().collect());
+
+            if found == replacement.as_slice() {
+                continue;
+            }
+
+            matched_until = start + len;
+
+            let found: String = found.iter().collect();
+
+            lints.push(Lint {
+                span: word.span.with_len(len),
+                lint_kind: LintKind::Style,
+                suggestions: vec![Suggestion::replace_with(replacement)],
+                message: format!("This project writes “{found}” as “{term}”."),
+                priority: 31,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Enforces the preferred spelling of the terms listed in your terminology settings."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Terminology;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    fn terminology() -> Terminology {
+        Terminology::new()
+            .with_term("backend", ["back end", "back-end"])
+            .with_term("Wi-Fi", ["wifi", "wi-fi"])
+    }
+
+    #[test]
+    fn replaces_multi_word_variants() {
+        assert_suggestion_result(
+            "The back end is slow.",
+            terminology(),
+            "The backend is slow.",
+        );
+    }
+
+    #[test]
+    fn keeps_sentence_capitalization() {
+        assert_suggestion_result(
+            "Back-end work is hard.",
+            terminology(),
+            "Backend work is hard.",
+        );
+    }
+
+    #[test]
+    fn enforces_capitalization() {
+        assert_suggestion_result(
+            "Connect to the wi-fi or the WIFI.",
+            terminology(),
+            "Connect to the Wi-Fi or the Wi-Fi.",
+        );
+    }
+
+    #[test]
+    fn allows_preferred_terms() {
+        assert_lint_count(
+            "The backend needs Wi-Fi. Backend code is fine.",
+            terminology(),
+            0,
+        );
+    }
+
+    #[test]
+    fn requires_whole_words() {
+        assert_lint_count("The back ends are wifis.", terminology(), 0);
+    }
+
+    #[test]
+    fn matches_across_lines() {
+        assert_lint_count("The back\nend is slow.", terminology(), 1);
+    }
+
+    #[test]
+    fn empty_by_default() {
+        assert_lint_count("The back end is slow.", Terminology::default(), 0);
+    }
+
+    #[test]
+    fn closer_terms_take_precedence() {
+        let mut base = terminology();
+        let mut closer = Terminology::new().with_term("Wi-Fi", ["WLAN"]);
+
+        base.merge_from(&mut closer);
+
+        assert_eq!(base.len(), 2);
+        assert_eq!(base.get("Wi-Fi").unwrap().variants, ["WLAN"]);
+        assert!(closer.is_empty());
+        assert_lint_count("Use the WLAN, not the wifi.", base, 1);
+    }
+
+    #[test]
+    fn deserializes_from_map() {
+        let terminology: Terminology =
+            serde_json::from_str(r#"{ "backend": ["back end"] }"#).unwrap();
+
+        assert_lint_count("The back end is slow.", terminology, 1);
+    }
+
+    fn api() -> Terminology {
+        Terminology::new().with_abbreviation("API", "application programming interface")
+    }
+
+    #[test]
+    fn spells_out_first_use() {
+        assert_suggestion_result(
+            "API keys are secret. The API is public.",
+            api(),
+            "Application programming interface (API) keys are secret. The API is public.",
+        );
+    }
+
+    #[test]
+    fn abbreviates_later_uses() {
+        assert_suggestion_result(
+            "The application programming interface (API) is public. Call the application programming interface (API) with care.",
+            api(),
+            "The application programming interface (API) is public. Call the API with care.",
+        );
+    }
+
+    #[test]
+    fn allows_introduced_abbreviations() {
+        assert_lint_count(
+            "The application programming interface is public. The API is stable. Use the API.",
+            api(),
+            0,
+        );
+    }
+
+    #[test]
+    fn deserializes_abbreviations() {
+        let terminology: Terminology = serde_json::from_str(
+            r#"{ "API": { "expansion": "application programming interface" }, "backend": ["back end"] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(terminology, api().with_term("backend", ["back end"]));
+        assert_eq!(
+            serde_json::to_string(&terminology).unwrap(),
+            r#"{"API":{"variants":[],"expansion":"application programming interface"},"backend":["back end"]}"#
+        );
+    }
+}
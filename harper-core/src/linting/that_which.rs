@@ -51,15 +51,20 @@ impl PatternLinter for ThatWhich {
         Some(Lint {
             span: matched_tokens.span()?,
             lint_kind: LintKind::Repetition,
-            suggestions: vec![Suggestion::ReplaceWith(suggestion)],
+            suggestions: vec![Suggestion::replace_with(suggestion)],
             message: "“that that” sometimes means “that which”, which is clearer.".to_string(),
             priority: 126,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Repeating the word \"that\" is often redundant. The phrase `that which` is easier to read."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Repetition)
+    }
 }
 
 #[cfg(test)]
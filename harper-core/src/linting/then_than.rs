@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, PatternLinter};
+use super::{Lint, LintKind, PatternLinter, RuleExample};
 use crate::Token;
 use crate::char_string::char_string;
 use crate::linting::Suggestion;
@@ -55,11 +55,23 @@ impl PatternLinter for ThenThan {
             )],
             message: "Did you mean `than`?".to_string(),
             priority: 31,
+            ..Default::default()
         })
     }
     fn description(&self) -> &'static str {
         "Corrects the misuse of `then` to `than`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "One was shorter then the other.",
+            "One was shorter than the other.",
+        )]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,213 @@
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::{Document, Span, Token, TokenStringExt};
+
+/// The transitions that are checked, in lowercase.
+const TRANSITIONS: &[&str] = &[
+    "additionally",
+    "also",
+    "as a result",
+    "besides",
+    "consequently",
+    "finally",
+    "for example",
+    "for instance",
+    "furthermore",
+    "hence",
+    "however",
+    "in addition",
+    "in contrast",
+    "in fact",
+    "indeed",
+    "instead",
+    "likewise",
+    "meanwhile",
+    "moreover",
+    "nevertheless",
+    "nonetheless",
+    "of course",
+    "on the other hand",
+    "similarly",
+    "still",
+    "therefore",
+    "thus",
+];
+
+/// Looks for sentence-initial transitions, like "However,", that are leaned on too often: used
+/// in too many paragraphs in a row, or more than once in the same paragraph.
+#[derive(Debug, Clone, Copy)]
+pub struct TransitionOveruse {
+    max_consecutive_paragraphs: usize,
+    max_per_paragraph: usize,
+}
+
+impl Default for TransitionOveruse {
+    fn default() -> Self {
+        Self {
+            max_consecutive_paragraphs: 2,
+            max_per_paragraph: 1,
+        }
+    }
+}
+
+impl TransitionOveruse {
+    /// Flag a transition that opens sentences in more than this many paragraphs in a row.
+    pub fn with_max_consecutive_paragraphs(mut self, max: usize) -> Self {
+        self.max_consecutive_paragraphs = max;
+        self
+    }
+
+    /// Flag a transition that opens more than this many sentences in one paragraph.
+    pub fn with_max_per_paragraph(mut self, max: usize) -> Self {
+        self.max_per_paragraph = max;
+        self
+    }
+}
+
+/// The transition a sentence opens with, like "However" in "However, it works.", as the span it
+/// covers and its lowercase text.
+fn opening_transition(sentence: &[Token], source: &[char]) -> Option<(Span, String)> {
+    let start = sentence
+        .iter()
+        .position(|t| !t.kind.is_whitespace() && !t.kind.is_paragraph_break())?;
+    let comma = sentence.iter().position(|t| t.kind.is_comma())?;
+
+    let opening = sentence.get(start..comma)?;
+
+    if !opening
+        .iter()
+        .all(|t| t.kind.is_word() || t.kind.is_whitespace())
+    {
+        return None;
+    }
+
+    let text = opening
+        .iter_words()
+        .map(|t| t.span.get_content_string(source).to_lowercase())
+        .join(" ");
+
+    if !TRANSITIONS.contains(&text.as_str()) {
+        return None;
+    }
+
+    Some((opening.span()?, text))
+}
+
+impl Linter for TransitionOveruse {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let source = document.get_source();
+        let mut lints = Vec::new();
+
+        // How many paragraphs in a row each transition has been used in.
+        let mut runs: HashMap<String, usize> = HashMap::new();
+
+        for paragraph in document.iter_paragraphs() {
+            let uses = paragraph
+                .iter_sentences()
+                .filter_map(|sentence| opening_transition(sentence, source))
+                .into_group_map_by(|(_, text)| text.clone());
+
+            runs.retain(|transition, _| uses.contains_key(transition));
+
+            for (transition, spans) in uses {
+                let run = runs.entry(transition).or_default();
+                *run += 1;
+
+                for (index, (span, _)) in spans.into_iter().enumerate() {
+                    let found = span.get_content_string(source);
+
+                    let message = if index >= self.max_per_paragraph {
+                        format!("“{found}” already opens a sentence in this paragraph.")
+                    } else if index == 0 && *run > self.max_consecutive_paragraphs {
+                        format!("“{found}” has opened a sentence in {run} paragraphs in a row.")
+                    } else {
+                        continue;
+                    };
+
+                    lints.push(Lint {
+                        span,
+                        lint_kind: LintKind::Style,
+                        suggestions: vec![],
+                        message,
+                        priority: 127,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for transitions like “However” that open too many sentences in a row, which makes writing feel repetitive."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::without_correction(
+            "However, it works.\n\nHowever, it is slow.\n\nHowever, it is free.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransitionOveruse;
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_long_runs() {
+        assert_lint_count(
+            "However, it works.\n\nHowever, it is slow.\n\nHowever, it is free.\n\nHowever, it is new.",
+            TransitionOveruse::default(),
+            2,
+        );
+    }
+
+    #[test]
+    fn allows_short_runs() {
+        assert_lint_count(
+            "However, it works.\n\nHowever, it is slow.\n\nIt is free.\n\nHowever, it is new.",
+            TransitionOveruse::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_repeats_in_one_paragraph() {
+        assert_lint_count(
+            "In addition, it is fast. It is small. In addition, it is free.",
+            TransitionOveruse::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn counts_are_configurable() {
+        let text = "Moreover, it works.\n\nMoreover, it is slow. Moreover, it is free.";
+
+        assert_lint_count(
+            text,
+            TransitionOveruse::default()
+                .with_max_consecutive_paragraphs(1)
+                .with_max_per_paragraph(2),
+            1,
+        );
+    }
+
+    #[test]
+    fn ignores_words_that_are_not_transitions() {
+        assert_lint_count(
+            "Sadly, it works.\n\nSadly, it is slow.\n\nSadly, it is free.",
+            TransitionOveruse::default(),
+            0,
+        );
+    }
+}
@@ -19,6 +19,7 @@ impl Linter for UnclosedQuotes {
                     suggestions: vec![],
                     message: "This quote has no termination.".to_string(),
                     priority: 255,
+                    ..Default::default()
                 })
             }
         }
@@ -29,4 +30,8 @@ impl Linter for UnclosedQuotes {
     fn description(&self) -> &'static str {
         "Quotation marks should always be closed. Unpaired quotation marks are a hallmark of sloppy work."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
 }
@@ -0,0 +1,258 @@
+use super::{Lint, LintKind, Linter, RuleExample};
+use crate::document::Document;
+use crate::{Punctuation, Span, Token, TokenKind};
+
+/// Looks for parentheses, square brackets and curly braces that are opened but never closed, or
+/// closed without being opened.
+///
+/// Quotations are treated as boundaries, so a parenthesis opened inside a quotation has to be
+/// closed inside it too.
+/// Quotation marks that are never closed are left to
+/// [`UnclosedQuotes`](super::UnclosedQuotes).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnpairedBrackets;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bracket {
+    Round,
+    Square,
+    Curly,
+}
+
+impl Bracket {
+    /// The kind of bracket a token is, and whether it opens.
+    fn of(kind: &TokenKind) -> Option<(Self, bool)> {
+        match kind {
+            TokenKind::Punctuation(Punctuation::OpenRound) => Some((Self::Round, true)),
+            TokenKind::Punctuation(Punctuation::CloseRound) => Some((Self::Round, false)),
+            TokenKind::Punctuation(Punctuation::OpenSquare) => Some((Self::Square, true)),
+            TokenKind::Punctuation(Punctuation::CloseSquare) => Some((Self::Square, false)),
+            TokenKind::Punctuation(Punctuation::OpenCurly) => Some((Self::Curly, true)),
+            TokenKind::Punctuation(Punctuation::CloseCurly) => Some((Self::Curly, false)),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Round => "parenthesis",
+            Self::Square => "square bracket",
+            Self::Curly => "curly brace",
+        }
+    }
+}
+
+/// Something that has been opened and not yet closed.
+enum Open {
+    Bracket(Bracket, Span),
+    Quote,
+}
+
+fn unclosed_lint(bracket: Bracket, span: Span) -> Lint {
+    Lint {
+        span,
+        lint_kind: LintKind::Formatting,
+        message: format!("This {} is never closed.", bracket.name()),
+        priority: 127,
+        ..Default::default()
+    }
+}
+
+fn unopened_lint(bracket: Bracket, span: Span) -> Lint {
+    Lint {
+        span,
+        lint_kind: LintKind::Formatting,
+        message: format!("This {} closes nothing.", bracket.name()),
+        priority: 127,
+        ..Default::default()
+    }
+}
+
+/// Closing brackets that are commonly used without an opener, like in enumerations ("1) first
+/// 2) second" or "options a), b) or c)") and emoticons (":)").
+fn is_standalone_closer(tokens: &[Token], index: usize, source: &[char]) -> bool {
+    let Some(prev) = index.checked_sub(1).map(|i| tokens[i]) else {
+        return false;
+    };
+
+    if matches!(
+        prev.kind,
+        TokenKind::Punctuation(Punctuation::Colon | Punctuation::Semicolon)
+    ) {
+        return true;
+    }
+
+    let is_marker = prev.kind.is_number() || (prev.kind.is_word() && prev.span.len() == 1);
+
+    // The marker has to stand on its own, so "word)" and "(a b)" are still checked.
+    let stands_alone = index < 2 || {
+        let before = tokens[index - 2].kind;
+
+        before.is_whitespace() || before.is_comma() || before.is_sentence_terminator()
+    };
+
+    is_marker && stands_alone && prev.span.get_content(source).len() <= 3
+}
+
+impl Linter for UnpairedBrackets {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let tokens = document.get_tokens();
+        let source = document.get_source();
+
+        let mut lints = Vec::new();
+        let mut stack = Vec::new();
+
+        for (index, token) in tokens.iter().enumerate() {
+            if let Some(quote) = token.kind.as_quote() {
+                match quote.twin_loc {
+                    Some(twin) if twin > index => stack.push(Open::Quote),
+                    Some(_) => {
+                        // Close everything opened since the quotation started.
+                        while let Some(open) = stack.pop() {
+                            match open {
+                                Open::Bracket(bracket, span) => {
+                                    lints.push(unclosed_lint(bracket, span))
+                                }
+                                Open::Quote => break,
+                            }
+                        }
+                    }
+                    None => (),
+                }
+
+                continue;
+            }
+
+            let Some((bracket, opens)) = Bracket::of(&token.kind) else {
+                continue;
+            };
+
+            if opens {
+                stack.push(Open::Bracket(bracket, token.span));
+                continue;
+            }
+
+            // Look for the matching opener, without leaving the current quotation.
+            let opener = stack
+                .iter()
+                .rev()
+                .take_while(|open| !matches!(open, Open::Quote))
+                .position(|open| matches!(open, Open::Bracket(b, _) if *b == bracket));
+
+            match opener {
+                Some(depth) => {
+                    // Anything opened after the matching opener was never closed.
+                    for open in stack.drain(stack.len() - depth..) {
+                        if let Open::Bracket(bracket, span) = open {
+                            lints.push(unclosed_lint(bracket, span));
+                        }
+                    }
+
+                    stack.pop();
+                }
+                None => {
+                    if !is_standalone_closer(tokens, index, source) {
+                        lints.push(unopened_lint(bracket, token.span));
+                    }
+                }
+            }
+        }
+
+        lints.extend(stack.into_iter().filter_map(|open| match open {
+            Open::Bracket(bracket, span) => Some(unclosed_lint(bracket, span)),
+            Open::Quote => None,
+        }));
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    fn description(&self) -> &'static str {
+        "Parentheses, square brackets, and curly braces should come in pairs."
+    }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Formatting)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::without_correction(
+            "The results (shown below are promising.",
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnpairedBrackets;
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn allows_balanced_brackets() {
+        assert_lint_count(
+            "The results (shown in [the table] below) are promising.",
+            UnpairedBrackets,
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_unclosed_opener() {
+        assert_lint_count(
+            "The results (shown below are promising.",
+            UnpairedBrackets,
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_unopened_closer() {
+        assert_lint_count(
+            "The results shown below) are promising.",
+            UnpairedBrackets,
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_mismatched_brackets() {
+        assert_lint_count(
+            "The results (shown [below) are promising.",
+            UnpairedBrackets,
+            1,
+        );
+    }
+
+    #[test]
+    fn brackets_do_not_cross_quotations() {
+        assert_lint_count(
+            "She said \"the results (shown below\" are promising).",
+            UnpairedBrackets,
+            2,
+        );
+    }
+
+    #[test]
+    fn allows_list_markers_and_emoticons() {
+        assert_lint_count(
+            "Do this first.\n1) Open the box.\na) Take out the manual :)",
+            UnpairedBrackets,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_inline_letter_markers() {
+        assert_lint_count("Pick one of options a), b) or c).", UnpairedBrackets, 0);
+    }
+
+    #[test]
+    fn allows_inline_number_markers() {
+        assert_lint_count("Do this: 1) first 2) second.", UnpairedBrackets, 0);
+    }
+
+    #[test]
+    fn flags_closer_after_long_word() {
+        assert_lint_count("Pick one of the options) today.", UnpairedBrackets, 1);
+    }
+}
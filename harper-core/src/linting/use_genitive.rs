@@ -62,15 +62,20 @@ impl PatternLinter for UseGenitive {
         Some(Lint {
             span: matched_tokens[2].span,
             lint_kind: LintKind::Miscellaneous,
-            suggestions: vec![Suggestion::ReplaceWith(vec!['t', 'h', 'e', 'i', 'r'])],
+            suggestions: vec![Suggestion::replace_with(vec!['t', 'h', 'e', 'i', 'r'])],
             message: "Use the genitive case.".to_string(),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Looks for situations where the genitive case of \"there\" should be used."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 impl Default for UseGenitive {
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, PatternLinter};
+use super::{Lint, LintKind, PatternLinter, RuleExample};
 use crate::Token;
 use crate::TokenStringExt;
 use crate::linting::Suggestion;
@@ -38,12 +38,24 @@ impl PatternLinter for WasAloud {
             )],
             message: format!("Did you mean `{verb} allowed`?"),
             priority: 31,
+            ..Default::default()
         })
     }
 
     fn description(&self) -> &'static str {
         "Ensures `was aloud` and `were aloud` are corrected to `was allowed` or `were allowed` when referring to permission."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
+
+    fn examples(&self) -> Vec<RuleExample> {
+        vec![RuleExample::new(
+            "He was aloud to enter the room.",
+            "He was allowed to enter the room.",
+        )]
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +46,10 @@ impl PatternLinter for Whereas {
     fn description(&self) -> &'static str {
         "The Whereas rule is designed to identify instances where the phrase `where as` is used in text and suggests replacing it with the single word `whereas`."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::WordChoice)
+    }
 }
 
 #[cfg(test)]
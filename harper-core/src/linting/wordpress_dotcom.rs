@@ -19,10 +19,11 @@ impl Linter for WordPressDotcom {
                 lints.push(Lint {
                     span: hostname.span,
                     lint_kind: LintKind::Style,
-                    suggestions: vec![Suggestion::ReplaceWith(correct.to_vec())],
+                    suggestions: vec![Suggestion::replace_with(correct.to_vec())],
                     message: "The WordPress hosting provider should be stylized as `WordPress.com`"
                         .to_owned(),
                     priority: 31,
+                    ..Default::default()
                 });
             }
         }
@@ -33,6 +34,10 @@ impl Linter for WordPressDotcom {
     fn description(&self) -> &str {
         "Ensures correct capitalization of WordPress.com. This rule verifies that the official stylization of WordPress.com is used when referring to the hosting provider."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Style)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use super::{Lint, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, Suggestion};
 use crate::document::Document;
 use crate::{Token, TokenStringExt};
 
@@ -17,6 +17,10 @@ impl Linter for WrongQuotes {
     fn description(&self) -> &'static str {
         "The key on the keyboard often used as a quotation mark is actually a double-apostrophe. Use the correct character."
     }
+
+    fn lint_kind(&self) -> Option<LintKind> {
+        Some(LintKind::Miscellaneous)
+    }
 }
 
 fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Option<Lint> {
@@ -32,7 +36,7 @@ fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Opti
     if quote_char != should_be {
         Some(Lint {
             span: quote_token.span,
-            suggestions: vec![Suggestion::ReplaceWith(vec![should_be])],
+            suggestions: vec![Suggestion::replace_with(vec![should_be])],
             message: "Use the better-formatted quote character.".to_string(),
             ..Default::default()
         })
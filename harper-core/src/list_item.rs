@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Span;
+
+/// A single item of a bulleted or numbered list, as found by a [`Parser`](crate::parsers::Parser)
+/// for a format that has lists, like Markdown or Typst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListItem {
+    /// The location of the item's text in the source, without its bullet or number.
+    ///
+    /// If the item contains a nested list, the span may cover it too, but the items of the
+    /// nested list are reported separately.
+    pub span: Span,
+    /// Identifies the list the item belongs to.
+    /// Items with the same `list` are siblings.
+    pub list: usize,
+    /// The number written before the item, if it is part of a numbered list whose numbers are
+    /// shown as written.
+    ///
+    /// `None` for bulleted items, and for formats like Markdown that renumber lists
+    /// automatically.
+    pub number: Option<ItemNumber>,
+}
+
+/// The number written before an item of a numbered list, like the `2` in `2. Preheat the oven`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemNumber {
+    pub value: usize,
+    /// The location of the digits in the source.
+    pub span: Span,
+}
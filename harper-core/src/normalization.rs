@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+use std::iter;
+
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
+
+use crate::Token;
+
+/// Whether NFC can never combine a character with the ones before it, so the text on either
+/// side of it can be normalized separately.
+fn is_boundary(c: char) -> bool {
+    canonical_combining_class(c) == 0 && is_nfc_quick(iter::once(c)) == IsNormalized::Yes
+}
+
+/// Text in Unicode Normalization Form C, along with where each of its characters came from in
+/// the original.
+pub(crate) struct Normalized {
+    pub chars: Vec<char>,
+    /// The index in the original of each character, plus the original's length at the end.
+    original_indices: Vec<usize>,
+}
+
+impl Normalized {
+    /// Move a token parsed from the normalized text back onto the original.
+    pub fn map_token_back(&self, mut token: Token) -> Token {
+        token.span.start = self.original_indices[token.span.start];
+        token.span.end = self.original_indices[token.span.end];
+        token
+    }
+}
+
+/// Normalize text to NFC, so decomposed characters like `e` followed by a combining acute
+/// accent become the single character `é`.
+///
+/// Compatibility characters, like the ligature `ﬁ`, are left alone, since replacing them (as
+/// NFKC does) can change the meaning of the text.
+///
+/// Returns `None` if the text is already normalized, which is almost always the case.
+pub(crate) fn normalize(source: &[char]) -> Option<Normalized> {
+    if is_nfc_quick(source.iter().copied()) == IsNormalized::Yes {
+        return None;
+    }
+
+    let mut chars = Vec::with_capacity(source.len());
+    let mut original_indices = Vec::with_capacity(source.len() + 1);
+
+    // Normalize the text between each boundary on its own, so every character it produces can
+    // be traced back to where it started.
+    let mut start = 0;
+
+    while start < source.len() {
+        let end = source[start + 1..]
+            .iter()
+            .position(|c| is_boundary(*c))
+            .map_or(source.len(), |i| start + 1 + i);
+
+        for c in source[start..end].iter().copied().nfc() {
+            chars.push(c);
+            original_indices.push(start);
+        }
+
+        start = end;
+    }
+
+    original_indices.push(source.len());
+
+    if chars == source {
+        return None;
+    }
+
+    Some(Normalized {
+        chars,
+        original_indices,
+    })
+}
+
+/// Normalize a single word, for comparing against words that are already normalized.
+pub(crate) fn normalize_word(word: &[char]) -> Cow<'_, [char]> {
+    match normalize(word) {
+        Some(normalized) => Cow::Owned(normalized.chars),
+        None => Cow::Borrowed(word),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+    use crate::{Span, Token, TokenKind};
+
+    fn normalized(text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        normalize(&chars).unwrap().chars.into_iter().collect()
+    }
+
+    #[test]
+    fn composes_accents() {
+        assert_eq!(normalized("cafe\u{0301}"), "café");
+        assert_eq!(normalized("nai\u{0308}ve"), "naïve");
+        // Two marks on the same letter.
+        assert_eq!(normalized("a\u{0302}\u{0301}"), "ấ");
+    }
+
+    #[test]
+    fn leaves_ligatures_alone() {
+        let chars: Vec<char> = "ﬁnal".chars().collect();
+        assert!(normalize(&chars).is_none());
+    }
+
+    #[test]
+    fn composes_beyond_latin() {
+        // Greek alpha with a combining acute accent.
+        assert_eq!(normalized("\u{03B1}\u{0301}"), "ά");
+        // Hangul jamo, which compose into a syllable.
+        assert_eq!(normalized("\u{1100}\u{1161}"), "가");
+    }
+
+    #[test]
+    fn leaves_normalized_text_alone() {
+        let chars: Vec<char> = "café".chars().collect();
+        assert!(normalize(&chars).is_none());
+    }
+
+    #[test]
+    fn maps_spans_back() {
+        let chars: Vec<char> = "cafe\u{0301} fine".chars().collect();
+        let normalized = normalize(&chars).unwrap();
+
+        let token = normalized.map_token_back(Token::new(Span::new(5, 9), TokenKind::Unlintable));
+        assert_eq!(token.span, Span::new(6, 10));
+
+        let token = normalized.map_token_back(Token::new(Span::new(0, 4), TokenKind::Unlintable));
+        assert_eq!(token.span, Span::new(0, 5));
+    }
+}
@@ -10,14 +10,63 @@ pub struct Number {
     pub suffix: Option<NumberSuffix>,
     pub radix: u32,
     pub precision: usize,
+    #[serde(default)]
+    pub kind: NumberKind,
+    #[serde(default)]
+    pub prefix: Option<NumberPrefix>,
+}
+
+/// What a [`Number`] represents, which decides how its [`Number::value`] should be read.
+#[derive(
+    Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy, Is, Hash, Eq,
+)]
+pub enum NumberKind {
+    #[default]
+    Plain,
+    /// Like `3/4`. The value is the result of the division.
+    Fraction { denominator: u32 },
+    /// Like `50%`. The value is the number before the percent sign.
+    Percentage,
+    /// Like `6.02e23`.
+    Scientific,
+    /// Like `1.2.3`. The value is the major version.
+    Version { minor: u32, patch: Option<u32> },
+}
+
+/// A symbol written before a number.
+#[derive(Debug, Serialize, Deserialize, PartialEq, PartialOrd, Clone, Copy, Is, Hash, Eq)]
+pub enum NumberPrefix {
+    /// Like `v1.2`.
+    V,
+    /// Like `#42`.
+    Hash,
 }
 
 impl Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.radix == 16 {
-            write!(f, "0x{:X}", self.value.0 as u64)?;
-        } else {
-            write!(f, "{:.*}", self.precision, self.value.0)?;
+        match self.prefix {
+            Some(NumberPrefix::V) => write!(f, "v")?,
+            Some(NumberPrefix::Hash) => write!(f, "#")?,
+            None => (),
+        }
+
+        match self.kind {
+            _ if self.radix == 16 => write!(f, "0x{:X}", self.value.0 as u64)?,
+            NumberKind::Plain => write!(f, "{:.*}", self.precision, self.value.0)?,
+            NumberKind::Fraction { denominator } => write!(
+                f,
+                "{}/{denominator}",
+                (self.value.0 * denominator as f64).round()
+            )?,
+            NumberKind::Percentage => write!(f, "{:.*}%", self.precision, self.value.0)?,
+            NumberKind::Scientific => write!(f, "{:e}", self.value.0)?,
+            NumberKind::Version { minor, patch } => {
+                write!(f, "{}.{minor}", self.value.0)?;
+
+                if let Some(patch) = patch {
+                    write!(f, ".{patch}")?;
+                }
+            }
         }
 
         if let Some(suffix) = self.suffix {
@@ -123,7 +172,8 @@ mod tests {
                 value: OrderedFloat(15.0),
                 suffix: None,
                 radix: 16,
-                precision: 0
+                precision: 0,
+                ..Default::default()
             }
             .to_string(),
             "0xF"
@@ -137,7 +187,8 @@ mod tests {
                 value: OrderedFloat(15.0),
                 suffix: None,
                 radix: 10,
-                precision: 0
+                precision: 0,
+                ..Default::default()
             }
             .to_string(),
             "15"
@@ -151,13 +202,50 @@ mod tests {
                 value: OrderedFloat(15.0),
                 suffix: Some(NumberSuffix::Th),
                 radix: 10,
-                precision: 0
+                precision: 0,
+                ..Default::default()
             }
             .to_string(),
             "15th"
         )
     }
 
+    #[test]
+    fn displays_richer_kinds() {
+        use super::{NumberKind, NumberPrefix};
+
+        let number = |value: f64, kind: NumberKind| Number {
+            value: OrderedFloat(value),
+            radix: 10,
+            kind,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            number(0.75, NumberKind::Fraction { denominator: 4 }).to_string(),
+            "3/4"
+        );
+        assert_eq!(number(50.0, NumberKind::Percentage).to_string(), "50%");
+        assert_eq!(
+            number(6.02e23, NumberKind::Scientific).to_string(),
+            "6.02e23"
+        );
+        assert_eq!(
+            Number {
+                prefix: Some(NumberPrefix::V),
+                ..number(
+                    1.0,
+                    NumberKind::Version {
+                        minor: 2,
+                        patch: Some(3)
+                    }
+                )
+            }
+            .to_string(),
+            "v1.2.3"
+        );
+    }
+
     #[test]
     fn decimal_fifteen_and_a_half() {
         assert_eq!(
@@ -165,7 +253,8 @@ mod tests {
                 value: OrderedFloat(15.5),
                 suffix: None,
                 radix: 10,
-                precision: 2
+                precision: 2,
+                ..Default::default()
             }
             .to_string(),
             "15.50"
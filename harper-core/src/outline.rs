@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Span;
+
+/// A single heading in a document's outline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heading {
+    /// The text of the heading, without any markup.
+    pub text: String,
+    /// How deeply the heading is nested, starting at `1` for top-level headings.
+    pub level: usize,
+    /// The location of the entire heading, including its markup, in the source.
+    pub span: Span,
+}
+
+/// A heading and the headings nested beneath it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutlineNode {
+    pub heading: Heading,
+    pub children: Vec<OutlineNode>,
+}
+
+/// Implemented by parsers for formats that have headings, like Markdown or Typst.
+pub trait Outliner {
+    /// Extract the headings of a document, in the order they appear.
+    fn outline(&self, source: &[char]) -> Vec<Heading>;
+}
+
+/// Arrange a flat list of headings (as from [`Outliner::outline`]) into a hierarchy.
+///
+/// Each heading becomes a child of the closest preceding heading with a lower level.
+/// Skipped levels (like an `h3` directly under an `h1`) are tolerated.
+pub fn nest_headings(headings: impl IntoIterator<Item = Heading>) -> Vec<OutlineNode> {
+    let mut roots = Vec::new();
+    // The chain of currently open headings, from outermost to innermost.
+    let mut open: Vec<OutlineNode> = Vec::new();
+
+    for heading in headings {
+        while open
+            .last()
+            .is_some_and(|n| n.heading.level >= heading.level)
+        {
+            close_last(&mut open, &mut roots);
+        }
+
+        open.push(OutlineNode {
+            heading,
+            children: Vec::new(),
+        });
+    }
+
+    while !open.is_empty() {
+        close_last(&mut open, &mut roots);
+    }
+
+    roots
+}
+
+fn close_last(open: &mut Vec<OutlineNode>, roots: &mut Vec<OutlineNode>) {
+    let Some(node) = open.pop() else {
+        return;
+    };
+
+    match open.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None => roots.push(node),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Heading, nest_headings};
+    use crate::Span;
+
+    fn heading(text: &str, level: usize) -> Heading {
+        Heading {
+            text: text.to_string(),
+            level,
+            span: Span::default(),
+        }
+    }
+
+    #[test]
+    fn nests_headings() {
+        let tree = nest_headings([
+            heading("Intro", 1),
+            heading("Background", 2),
+            heading("Details", 3),
+            heading("Goals", 2),
+            heading("Usage", 1),
+        ]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].children.len(), 2);
+        assert_eq!(tree[0].children[0].children[0].heading.text, "Details");
+        assert_eq!(tree[1].heading.text, "Usage");
+    }
+
+    #[test]
+    fn tolerates_skipped_levels() {
+        let tree = nest_headings([heading("Title", 3), heading("Intro", 1), heading("A", 3)]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[1].children[0].heading.text, "A");
+    }
+}
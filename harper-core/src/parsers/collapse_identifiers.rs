@@ -5,7 +5,7 @@ use itertools::Itertools;
 
 use super::Parser;
 use crate::patterns::{PatternExt, SequencePattern};
-use crate::{Dictionary, Lrc, Span, Token, TokenKind, VecExt};
+use crate::{Dictionary, Heading, ListItem, Lrc, Span, Token, TokenKind, VecExt};
 
 /// A parser that wraps any other parser to collapse token strings that match
 /// the pattern `word_word` or `word-word`.
@@ -55,6 +55,18 @@ impl Parser for CollapseIdentifiers {
 
         tokens
     }
+
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        self.inner.list_items(source)
+    }
+
+    fn headings(&self, source: &[char]) -> Vec<Heading> {
+        self.inner.headings(source)
+    }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.inner.comments(source)
+    }
 }
 
 #[cfg(test)]
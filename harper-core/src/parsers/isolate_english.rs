@@ -1,4 +1,4 @@
-use crate::{Dictionary, language_detection::is_likely_english};
+use crate::{Dictionary, Heading, ListItem, Span, language_detection::is_likely_english};
 
 use super::{Parser, Token, TokenStringExt};
 
@@ -32,6 +32,18 @@ impl<D: Dictionary> Parser for IsolateEnglish<D> {
 
         english_tokens
     }
+
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        self.inner.list_items(source)
+    }
+
+    fn headings(&self, source: &[char]) -> Vec<Heading> {
+        self.inner.headings(source)
+    }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.inner.comments(source)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,119 @@
+use crate::language_detection::non_english_confidence;
+use crate::{Heading, ListItem, Span, TokenKind};
+
+use super::{Parser, Token};
+
+/// A parser that wraps another, marking paragraphs that are confidently identified as being
+/// written in another language as [`TokenKind::Unlintable`].
+///
+/// Unlike [`super::IsolateEnglish`], this keeps the rest of the document intact and only
+/// affects paragraphs the detector is sure about, so English with many unusual words is left
+/// alone.
+pub struct MarkNonEnglish {
+    inner: Box<dyn Parser>,
+    min_confidence: f32,
+}
+
+impl MarkNonEnglish {
+    pub fn new(inner: Box<dyn Parser>) -> Self {
+        Self {
+            inner,
+            min_confidence: 0.8,
+        }
+    }
+
+    /// Set how confident the detector must be (from `0.0` to `1.0`) that a paragraph isn't
+    /// English before it is marked.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+}
+
+impl Parser for MarkNonEnglish {
+    fn parse(&self, source: &[char]) -> Vec<Token> {
+        let tokens = self.inner.parse(source);
+        let mut output = Vec::with_capacity(tokens.len());
+
+        for paragraph in
+            tokens.split_inclusive(|t| matches!(t.kind, TokenKind::Newline(n) if n >= 2))
+        {
+            let (content, trailing) = match paragraph.split_last() {
+                Some((last, content)) if last.kind.is_newline() => (content, Some(*last)),
+                _ => (paragraph, None),
+            };
+
+            match (content.first(), content.last()) {
+                (Some(first), Some(last))
+                    if non_english_confidence(content, source) >= self.min_confidence =>
+                {
+                    output.push(Token::new(
+                        Span::new(first.span.start, last.span.end),
+                        TokenKind::Unlintable,
+                    ));
+                }
+                _ => output.extend_from_slice(content),
+            }
+
+            output.extend(trailing);
+        }
+
+        output
+    }
+
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        self.inner.list_items(source)
+    }
+
+    fn headings(&self, source: &[char]) -> Vec<Heading> {
+        self.inner.headings(source)
+    }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.inner.comments(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linting::{LintGroup, Linter};
+    use crate::parsers::PlainEnglish;
+    use crate::{Document, FstDictionary, TokenStringExt};
+
+    use super::MarkNonEnglish;
+
+    fn parse(text: &str) -> Document {
+        Document::new(
+            text,
+            &MarkNonEnglish::new(Box::new(PlainEnglish)),
+            &FstDictionary::curated(),
+        )
+    }
+
+    #[test]
+    fn marks_spanish_paragraph() {
+        let document =
+            parse("This is the English section.\n\nEsto es muy bueno y también está en el README.");
+
+        assert_eq!(document.iter_unlintables().count(), 1);
+        assert_eq!(
+            document.get_span_content_str(document.first_unlintable().unwrap().span),
+            "Esto es muy bueno y también está en el README."
+        );
+    }
+
+    #[test]
+    fn keeps_english_with_jargon() {
+        let document = parse("Run cargo clippy before you push the crate.");
+
+        assert_eq!(document.iter_unlintables().count(), 0);
+    }
+
+    #[test]
+    fn no_spelling_lints_in_german() {
+        let document = parse("Das ist nicht gut, und wir werden es auch sehen.");
+        let mut linter = LintGroup::new_curated(FstDictionary::curated());
+
+        assert!(linter.lint(&document).is_empty());
+    }
+}
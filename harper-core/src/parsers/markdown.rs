@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use serde::{Deserialize, Serialize};
 
 use super::{Parser, PlainEnglish};
-use crate::{Span, Token, TokenKind, TokenStringExt, VecExt};
+use crate::{Heading, ListItem, Outliner, Span, Token, TokenKind, TokenStringExt, VecExt};
 
 /// A parser that wraps the [`PlainEnglish`] parser that allows one to parse
 /// CommonMark files.
@@ -188,10 +188,17 @@ impl Parser for Markdown {
                     });
                 }
                 pulldown_cmark::Event::Start(pulldown_cmark::Tag::List(v)) => {
-                    tokens.push(Token {
-                        span: Span::new_with_len(traversed_chars, 0),
-                        kind: TokenKind::Newline(2),
-                    });
+                    // Separates a nested list from the text of the item it belongs to.
+                    // Other blocks already end with a break of their own.
+                    if stack
+                        .iter()
+                        .any(|tag| matches!(tag, pulldown_cmark::Tag::Item))
+                    {
+                        tokens.push(Token {
+                            span: Span::new_with_len(traversed_chars, 0),
+                            kind: TokenKind::Newline(2),
+                        });
+                    }
                     stack.push(pulldown_cmark::Tag::List(v));
                 }
                 pulldown_cmark::Event::Start(tag) => stack.push(tag),
@@ -199,8 +206,16 @@ impl Parser for Markdown {
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::Item)
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::Heading(_))
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::TableCell) => {
+                    // Placed after the block's content, so the tokens stay in source order.
+                    let block_end = source_str[..range.end]
+                        .trim_end()
+                        .len()
+                        .max(traversed_bytes);
+                    let end_chars =
+                        traversed_chars + source_str[traversed_bytes..block_end].chars().count();
+
                     tokens.push(Token {
-                        span: Span::new_with_len(traversed_chars, 0),
+                        span: Span::new_with_len(end_chars, 0),
                         kind: TokenKind::Newline(2),
                     });
                     stack.pop();
@@ -273,13 +288,12 @@ impl Parser for Markdown {
             }
         }
 
-        if matches!(
-            tokens.last(),
-            Some(Token {
-                kind: TokenKind::Newline(_),
-                ..
-            })
-        ) && source.last() != Some(&'\n')
+        // A break at the very end of the source, with no newline after it, isn't needed.
+        if let Some(Token {
+            kind: TokenKind::Newline(_),
+            span,
+        }) = tokens.last()
+            && !source[span.start..].contains(&'\n')
         {
             tokens.pop();
         }
@@ -289,13 +303,220 @@ impl Parser for Markdown {
 
         tokens
     }
+
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        use pulldown_cmark::{Event, Tag, TagEnd};
+
+        let source_str: String = source.iter().collect();
+        let md_parser = pulldown_cmark::Parser::new_ext(
+            &source_str,
+            pulldown_cmark::Options::all()
+                .difference(pulldown_cmark::Options::ENABLE_SMART_PUNCTUATION),
+        );
+
+        let mut items = Vec::new();
+        let mut list_count = 0;
+        // The lists currently open, innermost last, along with the text read so far of the item
+        // each one is on.
+        let mut open: Vec<(usize, Option<Span>)> = Vec::new();
+        let mut in_code_block = false;
+
+        let mut traversed_bytes = 0;
+        let mut traversed_chars = 0;
+
+        for (event, range) in md_parser.into_offset_iter() {
+            if range.start > traversed_bytes {
+                traversed_chars += source_str[traversed_bytes..range.start].chars().count();
+                traversed_bytes = range.start;
+            }
+
+            match event {
+                Event::Start(Tag::List(_)) => {
+                    open.push((list_count, None));
+                    list_count += 1;
+                }
+                Event::End(TagEnd::List(_)) => {
+                    open.pop();
+                }
+                Event::Start(Tag::Item) => {
+                    if let Some((_, text)) = open.last_mut() {
+                        *text = None;
+                    }
+                }
+                Event::End(TagEnd::Item) => {
+                    if let Some((list, Some(span))) = open.last_mut().map(|(l, t)| (*l, t.take())) {
+                        items.push(ListItem {
+                            span,
+                            list,
+                            number: None,
+                        });
+                    }
+                }
+                Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+                Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                Event::Text(_) | Event::Code(_) | Event::InlineMath(_) if !in_code_block => {
+                    let Some((_, text)) = open.last_mut() else {
+                        continue;
+                    };
+
+                    let end = traversed_chars + source_str[range].chars().count();
+
+                    *text = Some(match text {
+                        Some(span) => Span::new(span.start, end),
+                        None => Span::new(traversed_chars, end),
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        items.sort_by_key(|item| item.span.start);
+        items
+    }
+
+    fn headings(&self, source: &[char]) -> Vec<Heading> {
+        self.outline(source)
+    }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        use pulldown_cmark::Event;
+
+        let source_str: String = source.iter().collect();
+        let md_parser = pulldown_cmark::Parser::new_ext(
+            &source_str,
+            pulldown_cmark::Options::all()
+                .difference(pulldown_cmark::Options::ENABLE_SMART_PUNCTUATION),
+        );
+
+        // Runs of raw HTML. A comment in an HTML block is split into an event per line.
+        let mut html: Vec<Span> = Vec::new();
+
+        let mut traversed_bytes = 0;
+        let mut traversed_chars = 0;
+
+        for (event, range) in md_parser.into_offset_iter() {
+            if range.start > traversed_bytes {
+                traversed_chars += source_str[traversed_bytes..range.start].chars().count();
+                traversed_bytes = range.start;
+            }
+
+            if !matches!(event, Event::Html(_) | Event::InlineHtml(_)) {
+                continue;
+            }
+
+            let span = Span::new_with_len(traversed_chars, source_str[range].chars().count());
+
+            match html.last_mut() {
+                Some(last)
+                    if Span::new(last.end.min(span.start), span.start)
+                        .get_content(source)
+                        .iter()
+                        .all(|c| c.is_whitespace()) =>
+                {
+                    last.end = span.end
+                }
+                _ => html.push(span),
+            }
+        }
+
+        html.into_iter()
+            .flat_map(|span| html_comments(source, span))
+            .collect()
+    }
+}
+
+/// Find the `<!-- ... -->` comments in a run of raw HTML.
+/// A comment that isn't closed runs to the end of the HTML.
+fn html_comments(source: &[char], html: Span) -> Vec<Span> {
+    const OPEN: [char; 4] = ['<', '!', '-', '-'];
+    const CLOSE: [char; 3] = ['-', '-', '>'];
+
+    let content = html.get_content(source);
+    let mut comments = Vec::new();
+    let mut cursor = 0;
+
+    while let Some(start) = content[cursor..]
+        .windows(OPEN.len())
+        .position(|w| w == OPEN)
+        .map(|start| cursor + start)
+    {
+        let end = content[start + OPEN.len()..]
+            .windows(CLOSE.len())
+            .position(|w| w == CLOSE)
+            .map_or(content.len(), |end| start + OPEN.len() + end + CLOSE.len());
+
+        comments.push(Span::new(start, end).pushed_by(html.start));
+        cursor = end;
+    }
+
+    comments
+}
+
+impl Outliner for Markdown {
+    fn outline(&self, source: &[char]) -> Vec<Heading> {
+        use pulldown_cmark::{Event, Tag, TagEnd};
+
+        let source_str: String = source.iter().collect();
+        let md_parser = pulldown_cmark::Parser::new_ext(
+            &source_str,
+            pulldown_cmark::Options::all()
+                .difference(pulldown_cmark::Options::ENABLE_SMART_PUNCTUATION),
+        );
+
+        let mut headings = Vec::new();
+        // The heading being read, if any.
+        let mut current: Option<Heading> = None;
+
+        let mut traversed_bytes = 0;
+        let mut traversed_chars = 0;
+
+        for (event, range) in md_parser.into_offset_iter() {
+            if range.start > traversed_bytes {
+                traversed_chars += source_str[traversed_bytes..range.start].chars().count();
+                traversed_bytes = range.start;
+            }
+
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    let heading_str = source_str[range].trim_end();
+
+                    current = Some(Heading {
+                        text: String::new(),
+                        level: level as usize,
+                        span: Span::new_with_len(traversed_chars, heading_str.chars().count()),
+                    });
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some(mut heading) = current.take() {
+                        heading.text = heading.text.trim().to_string();
+                        headings.push(heading);
+                    }
+                }
+                Event::Text(text) | Event::Code(text) | Event::InlineMath(text) => {
+                    if let Some(heading) = &mut current {
+                        heading.text.push_str(&text);
+                    }
+                }
+                Event::SoftBreak | Event::HardBreak => {
+                    if let Some(heading) = &mut current {
+                        heading.text.push(' ');
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        headings
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::StrParser;
+    use super::super::{Parser, StrParser};
     use super::Markdown;
-    use crate::{Punctuation, TokenKind, TokenStringExt, parsers::markdown::MarkdownOptions};
+    use crate::{
+        Outliner, Punctuation, Span, TokenKind, TokenStringExt, parsers::markdown::MarkdownOptions,
+    };
 
     #[test]
     fn survives_emojis() {
@@ -503,4 +724,66 @@ mod tests {
             ]
         ));
     }
+
+    #[test]
+    fn extracts_outline() {
+        let source: Vec<_> =
+            "# The `harper` Book\n\nIntro.\n\n## Getting *Started*\n\nSetup\n-----\n"
+                .chars()
+                .collect();
+
+        let outline = Markdown::default().outline(&source);
+
+        assert_eq!(outline.len(), 3);
+        assert_eq!(outline[0].text, "The harper Book");
+        assert_eq!(outline[0].level, 1);
+        assert_eq!(outline[0].span, Span::new(0, 19));
+        assert_eq!(outline[1].text, "Getting Started");
+        assert_eq!(outline[1].level, 2);
+        assert_eq!(
+            outline[1].span.get_content_string(&source),
+            "## Getting *Started*"
+        );
+        assert_eq!(outline[2].text, "Setup");
+        assert_eq!(outline[2].level, 2);
+    }
+
+    #[test]
+    fn extracts_comments() {
+        let source: Vec<_> =
+            "Some <!-- inline --> text.\n\n<!--\nharper: disable\n-->\n\nNot `<!-- code -->`.\n"
+                .chars()
+                .collect();
+
+        let comments: Vec<_> = Markdown::default()
+            .comments(&source)
+            .into_iter()
+            .map(|span| span.get_content_string(&source))
+            .collect();
+
+        assert_eq!(comments, ["<!-- inline -->", "<!--\nharper: disable\n-->"]);
+    }
+
+    #[test]
+    fn extracts_list_items() {
+        let source: Vec<_> = "- Apples\n- *Oranges*.\n  1. Navel\n\nText.\n\n- `code`\n"
+            .chars()
+            .collect();
+
+        let items = Markdown::default().list_items(&source);
+        let texts: Vec<_> = items
+            .iter()
+            .map(|item| (item.span.get_content_string(&source), item.list))
+            .collect();
+
+        assert_eq!(
+            texts,
+            [
+                ("Apples".to_string(), 0),
+                ("Oranges*.".to_string(), 0),
+                ("Navel".to_string(), 1),
+                ("`code`".to_string(), 2)
+            ]
+        );
+    }
 }
@@ -10,6 +10,8 @@ where
 {
     pub masker: M,
     pub parser: P,
+    /// Whether every chunk is its own paragraph, rather than only those on separate lines.
+    separate_chunks: bool,
 }
 
 impl<M, P> Mask<M, P>
@@ -18,7 +20,20 @@ where
     P: Parser,
 {
     pub fn new(masker: M, parser: P) -> Self {
-        Self { masker, parser }
+        Self {
+            masker,
+            parser,
+            separate_chunks: false,
+        }
+    }
+
+    /// Treat every chunk as its own paragraph, even when chunks share a line.
+    ///
+    /// Useful when the chunks are unrelated snippets, like the string literals in a line of
+    /// code.
+    pub fn with_separate_chunks(mut self) -> Self {
+        self.separate_chunks = true;
+        self
     }
 }
 
@@ -39,7 +54,7 @@ where
             if let Some(last_allowed) = last_allowed {
                 let intervening = Span::new(last_allowed.end, span.start);
 
-                if intervening.get_content(source).contains(&'\n') {
+                if self.separate_chunks || intervening.get_content(source).contains(&'\n') {
                     tokens.push(Token::new(intervening, TokenKind::ParagraphBreak))
                 }
             }
@@ -56,4 +71,17 @@ where
 
         tokens
     }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        let mask = self.masker.create_mask(source);
+
+        mask.iter_allowed(source)
+            .flat_map(|(span, content)| {
+                self.parser
+                    .comments(content)
+                    .into_iter()
+                    .map(move |comment| comment.pushed_by(span.start))
+            })
+            .collect()
+    }
 }
@@ -1,5 +1,6 @@
 mod collapse_identifiers;
 mod isolate_english;
+mod mark_non_english;
 mod markdown;
 mod mask;
 mod plain_english;
@@ -7,22 +8,71 @@ mod plain_english;
 use blanket::blanket;
 pub use collapse_identifiers::CollapseIdentifiers;
 pub use isolate_english::IsolateEnglish;
+pub use mark_non_english::MarkNonEnglish;
 pub use markdown::{Markdown, MarkdownOptions};
 pub use mask::Mask;
 pub use plain_english::PlainEnglish;
 
-use crate::{Token, TokenStringExt};
+use crate::{Heading, ListItem, Span, Token, TokenStringExt};
 
 #[cfg(not(feature = "concurrent"))]
 #[blanket(derive(Box, Rc))]
 pub trait Parser {
     fn parse(&self, source: &[char]) -> Vec<Token>;
+
+    /// Find the items of the bulleted and numbered lists in the source, in the order they
+    /// appear.
+    ///
+    /// Formats without lists don't have to implement this.
+    fn list_items(&self, _source: &[char]) -> Vec<ListItem> {
+        Vec::new()
+    }
+
+    /// Find the headings in the source, in the order they appear.
+    ///
+    /// Formats without headings don't have to implement this.
+    fn headings(&self, _source: &[char]) -> Vec<Heading> {
+        Vec::new()
+    }
+
+    /// Find the comments in the source, like `<!-- ... -->` in Markdown, in the order they
+    /// appear.
+    /// Only comments can hold [`InlineDirectives`](crate::InlineDirectives).
+    ///
+    /// Formats without comments don't have to implement this.
+    fn comments(&self, _source: &[char]) -> Vec<Span> {
+        Vec::new()
+    }
 }
 
 #[cfg(feature = "concurrent")]
 #[blanket(derive(Box, Arc))]
 pub trait Parser: Send + Sync {
     fn parse(&self, source: &[char]) -> Vec<Token>;
+
+    /// Find the items of the bulleted and numbered lists in the source, in the order they
+    /// appear.
+    ///
+    /// Formats without lists don't have to implement this.
+    fn list_items(&self, _source: &[char]) -> Vec<ListItem> {
+        Vec::new()
+    }
+
+    /// Find the headings in the source, in the order they appear.
+    ///
+    /// Formats without headings don't have to implement this.
+    fn headings(&self, _source: &[char]) -> Vec<Heading> {
+        Vec::new()
+    }
+
+    /// Find the comments in the source, like `<!-- ... -->` in Markdown, in the order they
+    /// appear.
+    /// Only comments can hold [`InlineDirectives`](crate::InlineDirectives).
+    ///
+    /// Formats without comments don't have to implement this.
+    fn comments(&self, _source: &[char]) -> Vec<Span> {
+        Vec::new()
+    }
 }
 
 pub trait StrParser {
@@ -1,6 +1,6 @@
 use super::Parser;
 use crate::lexing::{FoundToken, lex_token};
-use crate::{Span, Token};
+use crate::{ItemNumber, ListItem, Span, Token};
 
 /// A parser that will attempt to lex as many tokens as possible,
 /// without discrimination and until the end of input.
@@ -19,7 +19,9 @@ impl Parser for PlainEnglish {
                 return tokens;
             }
 
-            if let Some(FoundToken { token, next_index }) = lex_token(&source[cursor..]) {
+            let previous = cursor.checked_sub(1).map(|i| source[i]);
+
+            if let Some(FoundToken { token, next_index }) = lex_token(&source[cursor..], previous) {
                 tokens.push(Token {
                     span: Span::new(cursor, cursor + next_index),
                     kind: token,
@@ -30,4 +32,214 @@ impl Parser for PlainEnglish {
             }
         }
     }
+
+    /// Lines that start with a bullet (`-`, `*` or `•`) or a number (like `1.` or `1)`) are list
+    /// items.
+    ///
+    /// Consecutive items with the same indentation and kind of marker form a list, even if
+    /// there are blank lines between them.
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        let mut items = Vec::new();
+        let mut list_count = 0;
+        // The lists currently open, from least to most indented.
+        let mut open: Vec<(usize, Marker, usize)> = Vec::new();
+
+        let mut line_start = 0;
+
+        for line in source.split(|c| *c == '\n') {
+            let indent = line
+                .iter()
+                .take_while(|c| **c == ' ' || **c == '\t')
+                .count();
+            let content = &line[indent..];
+
+            if content.iter().all(|c| c.is_whitespace()) {
+                line_start += line.len() + 1;
+                continue;
+            }
+
+            let found = Marker::parse(content);
+
+            // Lists nested deeper than this line have ended, as has any list at the same depth
+            // that this line doesn't continue.
+            open.retain(|(open_indent, open_marker, _)| {
+                *open_indent < indent
+                    || (*open_indent == indent
+                        && found
+                            .as_ref()
+                            .is_some_and(|found| found.marker == *open_marker))
+            });
+
+            if let Some(ItemStart {
+                marker,
+                text_start,
+                number,
+            }) = found
+            {
+                let list = match open.last() {
+                    Some((open_indent, _, list)) if *open_indent == indent => *list,
+                    _ => {
+                        open.push((indent, marker, list_count));
+                        list_count += 1;
+                        list_count - 1
+                    }
+                };
+
+                let text = &content[text_start..];
+                let text_len =
+                    text.len() - text.iter().rev().take_while(|c| c.is_whitespace()).count();
+                let content_start = line_start + indent;
+
+                items.push(ListItem {
+                    span: Span::new_with_len(content_start + text_start, text_len),
+                    list,
+                    number: number.map(|(value, len)| ItemNumber {
+                        value,
+                        span: Span::new_with_len(content_start, len),
+                    }),
+                });
+            }
+
+            line_start += line.len() + 1;
+        }
+
+        items
+    }
+}
+
+/// The start of a line that begins a list item.
+struct ItemStart {
+    marker: Marker,
+    /// Where the item's text starts.
+    text_start: usize,
+    /// For numbered items, the number and how many digits it has.
+    number: Option<(usize, usize)>,
+}
+
+/// The kind of marker that starts a plain-text list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bullet(char),
+    /// A number, followed by the given delimiter.
+    Number(char),
+}
+
+impl Marker {
+    /// Read the marker at the start of a line.
+    fn parse(line: &[char]) -> Option<ItemStart> {
+        let (marker, marker_len, number) = match line.first()? {
+            c @ ('-' | '*' | '•') => (Self::Bullet(*c), 1, None),
+            _ => {
+                let digits = line.iter().take_while(|c| c.is_ascii_digit()).count();
+
+                // Longer numbers are more likely to be years or amounts than list items.
+                if digits == 0 || digits > 3 {
+                    return None;
+                }
+
+                let delimiter = *line.get(digits).filter(|c| matches!(c, '.' | ')'))?;
+                let value = line[..digits].iter().collect::<String>().parse().ok()?;
+
+                (Self::Number(delimiter), digits + 1, Some((value, digits)))
+            }
+        };
+
+        let spaces = line[marker_len..]
+            .iter()
+            .take_while(|c| **c == ' ' || **c == '\t')
+            .count();
+
+        // The marker has to be followed by a space and then some text.
+        if spaces == 0 || line.len() == marker_len + spaces {
+            return None;
+        }
+
+        Some(ItemStart {
+            marker,
+            text_start: marker_len + spaces,
+            number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlainEnglish;
+    use crate::TokenKind;
+    use crate::parsers::Parser;
+
+    /// The text and kind of each token that isn't whitespace.
+    fn tokens(text: &str) -> Vec<(String, TokenKind)> {
+        let source: Vec<_> = text.chars().collect();
+
+        PlainEnglish
+            .parse(&source)
+            .into_iter()
+            .filter(|token| !token.kind.is_whitespace())
+            .map(|token| (token.span.get_content_string(&source), token.kind))
+            .collect()
+    }
+
+    fn list_items(text: &str) -> Vec<(String, usize, Option<usize>)> {
+        let source: Vec<_> = text.chars().collect();
+
+        PlainEnglish
+            .list_items(&source)
+            .into_iter()
+            .map(|item| {
+                (
+                    item.span.get_content_string(&source),
+                    item.list,
+                    item.number.map(|n| n.value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_numbered_items() {
+        assert_eq!(
+            list_items("Steps:\n1. Preheat the oven.\n\n2) Bake \n  - Wait\n3. Eat"),
+            [
+                ("Preheat the oven.".to_string(), 0, Some(1)),
+                ("Bake".to_string(), 1, Some(2)),
+                ("Wait".to_string(), 2, None),
+                ("Eat".to_string(), 3, Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn continues_lists_across_blank_lines() {
+        let items = list_items("- Apples\n\n- Pears\nSome text.\n- Plums");
+
+        assert_eq!(items[0].1, items[1].1);
+        assert_ne!(items[1].1, items[2].1);
+    }
+
+    #[test]
+    fn ignores_other_numbers_and_dashes() {
+        assert!(list_items("2024. A good year.\n-5 degrees\n1.5 liters\n--- \n").is_empty());
+    }
+
+    #[test]
+    fn email_addresses_are_not_mentions() {
+        let tokens = tokens("Email user@example.com or ping @octocat.");
+
+        assert!(tokens.contains(&("user@example.com".to_string(), TokenKind::EmailAddress)));
+        assert!(tokens.contains(&("@octocat".to_string(), TokenKind::Mention)));
+    }
+
+    #[test]
+    fn tags_start_at_word_boundaries() {
+        let tokens = tokens("Use a#b and c@d here, but not #rust or (@octocat).");
+
+        let tags: Vec<_> = tokens
+            .iter()
+            .filter(|(_, kind)| kind.is_mention() || kind.is_hashtag())
+            .map(|(text, _)| text.as_str())
+            .collect();
+
+        assert_eq!(tags, ["#rust", "@octocat"]);
+    }
 }
@@ -1,6 +1,6 @@
-use crate::Token;
+use crate::{CharString, Token};
 
-use super::Pattern;
+use super::{Pattern, most_selective_anchors};
 
 /// A [`Pattern`] that consumes a list of other patterns and only
 /// matches if all the child patterns do.
@@ -39,4 +39,8 @@ impl Pattern for All {
 
         max
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        most_selective_anchors(self.children.iter().map(|pat| pat.anchor_words()))
+    }
 }
@@ -42,4 +42,8 @@ impl Pattern for AnyCapitalization {
 
         if partial_match { 1 } else { 0 }
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        Some(vec![self.word.clone()])
+    }
 }
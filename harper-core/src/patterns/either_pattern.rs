@@ -1,4 +1,4 @@
-use crate::Token;
+use crate::{CharString, Token};
 
 use super::Pattern;
 
@@ -32,4 +32,14 @@ impl Pattern for EitherPattern {
 
         longest
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        let mut words = Vec::new();
+
+        for pattern in &self.patterns {
+            words.extend(pattern.anchor_words()?);
+        }
+
+        Some(words)
+    }
 }
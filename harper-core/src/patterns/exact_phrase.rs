@@ -1,4 +1,4 @@
-use crate::{Document, Token, TokenKind};
+use crate::{CharString, Document, Token, TokenKind};
 
 use super::{AnyCapitalization, Pattern, SequencePattern};
 
@@ -43,4 +43,8 @@ impl Pattern for ExactPhrase {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
         self.inner.matches(tokens, source)
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        self.inner.anchor_words()
+    }
 }
@@ -1,33 +1,87 @@
-use crate::{Dictionary, Token, TokenStringExt, make_title_case};
+use crate::title_case::possessive_stem_len;
+use crate::{
+    CharString, Dictionary, TitleCaseStyle, Token, TokenStringExt, make_title_case_with_style,
+};
 
 use super::Pattern;
 
+/// How many tokens ahead to look for a possessive form of the wrapped pattern.
+const POSSESSIVE_WINDOW: usize = 12;
+
 /// Will match full length of wrapped pattern only if the matched
 /// text is not already title case.
+///
+/// The last word of the wrapped pattern may also be possessive, like "New Zealand's".
 pub struct IsNotTitleCase<D: Dictionary> {
     inner: Box<dyn Pattern>,
     dict: D,
+    style: TitleCaseStyle,
 }
 
 impl<D: Dictionary> IsNotTitleCase<D> {
     pub fn new(inner: Box<dyn Pattern>, dict: D) -> Self {
-        Self { inner, dict }
+        Self::new_with_style(inner, dict, TitleCaseStyle::default())
+    }
+
+    pub fn new_with_style(inner: Box<dyn Pattern>, dict: D, style: TitleCaseStyle) -> Self {
+        Self { inner, dict, style }
+    }
+
+    /// Match the wrapped pattern, allowing its last word to be possessive.
+    fn inner_matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        let direct = self.inner.matches(tokens, source);
+
+        if direct != 0 {
+            return direct;
+        }
+
+        let window = &tokens[..tokens.len().min(POSSESSIVE_WINDOW)];
+
+        for (idx, tok) in window.iter().enumerate() {
+            if !tok.kind.is_word() {
+                continue;
+            }
+
+            let content = tok.span.get_content(source);
+            let stem_len = possessive_stem_len(content);
+
+            if stem_len == content.len() {
+                continue;
+            }
+
+            // Try again as if the suffix weren't there.
+            let mut trimmed = window[..=idx].to_vec();
+            trimmed[idx].span.end = trimmed[idx].span.start + stem_len;
+
+            if self.inner.matches(&trimmed, source) == idx + 1 {
+                return idx + 1;
+            }
+        }
+
+        0
     }
 }
 
 impl<D: Dictionary> Pattern for IsNotTitleCase<D> {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
-        let inner_match = self.inner.matches(tokens, source);
+        let inner_match = self.inner_matches(tokens, source);
 
         if inner_match == 0 {
             return 0;
         }
 
         let matched_chars = tokens[0..inner_match].span().unwrap().get_content(source);
-        if make_title_case(&tokens[0..inner_match], source, &self.dict) != matched_chars {
+        if make_title_case_with_style(&tokens[0..inner_match], source, &self.dict, self.style)
+            != matched_chars
+        {
             inner_match
         } else {
             0
         }
     }
+
+    /// The inner pattern's anchors still begin the matched tokens when they are possessive.
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        self.inner.anchor_words()
+    }
 }
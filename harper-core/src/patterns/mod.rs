@@ -7,7 +7,7 @@
 
 use std::collections::VecDeque;
 
-use crate::{Document, Span, Token, VecExt};
+use crate::{CharString, Document, Span, Token, VecExt};
 
 mod all;
 mod any_capitalization;
@@ -59,12 +59,28 @@ pub use word_set::WordSet;
 #[blanket(derive(Rc, Arc))]
 pub trait Pattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize;
+    /// Words, at least one of which must begin a word token for this pattern to match.
+    ///
+    /// Lets callers skip a pattern that can't match a document without running it.
+    /// Capitalization is ignored when searching for them.
+    /// The default, `None`, makes no promises.
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        None
+    }
 }
 
 #[cfg(feature = "concurrent")]
 #[blanket(derive(Arc))]
 pub trait Pattern: Send + Sync {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize;
+    /// Words, at least one of which must begin a word token for this pattern to match.
+    ///
+    /// Lets callers skip a pattern that can't match a document without running it.
+    /// Capitalization is ignored when searching for them.
+    /// The default, `None`, makes no promises.
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        None
+    }
 }
 
 pub trait PatternExt {
@@ -154,6 +170,23 @@ where
     }
 }
 
+/// Pick the most selective of several sets of anchor words, each of which must be present for
+/// a pattern to match.
+///
+/// See [`Pattern::anchor_words`].
+fn most_selective_anchors(
+    candidates: impl IntoIterator<Item = Option<Vec<CharString>>>,
+) -> Option<Vec<CharString>> {
+    candidates.into_iter().flatten().min_by_key(|words| {
+        let shortest = words
+            .iter()
+            .map(|word| word.len())
+            .min()
+            .unwrap_or_default();
+        (words.len(), std::cmp::Reverse(shortest))
+    })
+}
+
 pub trait DocPattern {
     fn find_all_matches_in_doc(&self, document: &Document) -> Vec<Span>;
 }
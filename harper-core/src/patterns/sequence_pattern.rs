@@ -3,8 +3,9 @@ use paste::paste;
 use super::whitespace_pattern::WhitespacePattern;
 use super::{
     AnyCapitalization, AnyPattern, IndefiniteArticle, Pattern, RepeatingPattern, SingularSubject,
+    most_selective_anchors,
 };
-use crate::{Token, TokenKind};
+use crate::{CharString, Token, TokenKind};
 
 /// A pattern that checks that a sequence of other patterns match.
 /// There are specific extension methods available, but you can also use [`Self::then`] to add
@@ -85,6 +86,11 @@ impl SequencePattern {
     gen_then_from_is!(article);
     gen_then_from_is!(proper_noun);
     gen_then_from_is!(preposition);
+    gen_then_from_is!(url);
+    gen_then_from_is!(email_address);
+    gen_then_from_is!(path);
+    gen_then_from_is!(mention);
+    gen_then_from_is!(hashtag);
 
     pub fn then_indefinite_article(self) -> Self {
         self.then(IndefiniteArticle::default())
@@ -195,6 +201,10 @@ impl Pattern for SequencePattern {
 
         tok_cursor
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        most_selective_anchors(self.token_patterns.iter().map(|pat| pat.anchor_words()))
+    }
 }
 
 #[cfg(test)]
@@ -56,4 +56,8 @@ where
 
         inner_pattern.matches(tokens, source)
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        Some(self.patterns.keys().cloned().collect())
+    }
 }
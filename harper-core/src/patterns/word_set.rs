@@ -1,7 +1,7 @@
 use super::Pattern;
 use smallvec::SmallVec;
 
-use crate::{CharString, Token};
+use crate::{CharString, Lemmatizer, Token};
 
 // A [`Pattern`] that matches against any of a set of provided words.
 // For small sets of short words, it doesn't allocate.
@@ -9,16 +9,32 @@ use crate::{CharString, Token};
 // Note that any capitalization of the contained words will result in a match.
 #[derive(Debug, Default, Clone)]
 pub struct WordSet {
-    words: SmallVec<[CharString; 4]>,
+    /// Each word paired with its [`fold_hash`], sorted by hash so a candidate can be found with
+    /// a binary search.
+    words: SmallVec<[(u64, CharString); 4]>,
 }
 
 impl WordSet {
     pub fn add(&mut self, word: &str) {
-        let chars = word.chars().collect();
+        let chars: CharString = word.chars().collect();
+        let hash = fold_hash(&chars);
 
-        if !self.words.contains(&chars) {
-            self.words.push(chars);
+        if self.candidates(hash).any(|existing| existing == &chars) {
+            return;
         }
+
+        let index = self.words.partition_point(|(other, _)| *other < hash);
+        self.words.insert(index, (hash, chars));
+    }
+
+    /// The words with a given hash.
+    fn candidates(&self, hash: u64) -> impl Iterator<Item = &CharString> {
+        let start = self.words.partition_point(|(other, _)| *other < hash);
+
+        self.words[start..]
+            .iter()
+            .take_while(move |(other, _)| *other == hash)
+            .map(|(_, word)| word)
     }
 
     /// Create a new word set that matches against any word in the provided list.
@@ -31,6 +47,21 @@ impl WordSet {
 
         set
     }
+
+    /// Create a new word set that matches against any inflection of the provided lemmas, so
+    /// `run` also matches "runs", "ran" and "running".
+    pub fn new_with_inflections(lemmas: &[&str]) -> Self {
+        let lemmatizer = Lemmatizer::curated();
+        let mut set = Self::default();
+
+        for lemma in lemmas {
+            for form in lemmatizer.all_forms(lemma) {
+                set.add(&form);
+            }
+        }
+
+        set
+    }
 }
 
 impl Pattern for WordSet {
@@ -45,7 +76,7 @@ impl Pattern for WordSet {
 
         let tok_chars = tok.span.get_content(source);
 
-        for word in &self.words {
+        for word in self.candidates(fold_hash(tok_chars)) {
             if tok_chars.len() != word.len() {
                 continue;
             }
@@ -62,6 +93,24 @@ impl Pattern for WordSet {
 
         0
     }
+
+    fn anchor_words(&self) -> Option<Vec<CharString>> {
+        Some(self.words.iter().map(|(_, word)| word.clone()).collect())
+    }
+}
+
+/// Hash a word in a way that ignores ASCII capitalization, matching
+/// [`char::eq_ignore_ascii_case`].
+fn fold_hash(word: &[char]) -> u64 {
+    // FNV-1a, which is fast for the short inputs typical of words.
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for c in word {
+        hash ^= u64::from(c.to_ascii_lowercase());
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash
 }
 
 #[cfg(test)]
@@ -91,4 +140,37 @@ mod tests {
 
         assert_eq!(matches, vec![Span::new(6, 7), Span::new(12, 13)]);
     }
+
+    #[test]
+    fn many_words() {
+        let mut set = WordSet::new(&["Anzac", "ANZAC"]);
+
+        for i in 0..200 {
+            set.add(&format!("word{i}"));
+        }
+
+        let doc =
+            Document::new_markdown_default_curated("See WORD17, word199 and anzac, not word200.");
+
+        let matches = set.find_all_matches_in_doc(&doc);
+
+        assert_eq!(
+            matches,
+            vec![Span::new(2, 3), Span::new(5, 6), Span::new(9, 10)]
+        );
+    }
+
+    #[test]
+    fn inflections() {
+        let set = WordSet::new_with_inflections(&["run"]);
+
+        let doc = Document::new_markdown_default_curated("She ran, he runs and they are running.");
+
+        let matches = set.find_all_matches_in_doc(&doc);
+
+        assert_eq!(
+            matches,
+            vec![Span::new(2, 3), Span::new(7, 8), Span::new(15, 16)]
+        );
+    }
 }
@@ -0,0 +1,220 @@
+use is_macro::Is;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::{Document, Token, TokenKind, WordMetadata};
+
+/// The part of speech a word plays in a specific sentence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Is)]
+pub enum PosTag {
+    Article,
+    Preposition,
+    Conjunction,
+    Pronoun,
+    ProperNoun,
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+}
+
+/// A word token along with the part of speech it was resolved to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaggedWord {
+    pub token: Token,
+    /// `None` if the word isn't in the dictionary, so nothing is known about it.
+    pub tag: Option<PosTag>,
+}
+
+/// Every part of speech the dictionary allows for a word, most likely first.
+///
+/// The order of [`PosTag`]'s variants decides which is most likely when the context doesn't.
+fn candidates(metadata: &WordMetadata) -> SmallVec<[PosTag; 4]> {
+    let mut tags = SmallVec::new();
+
+    if metadata.article {
+        tags.push(PosTag::Article);
+    }
+    if metadata.preposition {
+        tags.push(PosTag::Preposition);
+    }
+    if metadata.is_conjunction() {
+        tags.push(PosTag::Conjunction);
+    }
+    if metadata.is_pronoun_noun() {
+        tags.push(PosTag::Pronoun);
+    } else if metadata.is_proper_noun() {
+        tags.push(PosTag::ProperNoun);
+    } else if metadata.is_noun() {
+        tags.push(PosTag::Noun);
+    }
+    if metadata.is_verb() {
+        tags.push(PosTag::Verb);
+    }
+    if metadata.is_adjective() {
+        tags.push(PosTag::Adjective);
+    }
+    if metadata.is_adverb() {
+        tags.push(PosTag::Adverb);
+    }
+
+    tags
+}
+
+/// The word immediately before another in the same clause.
+struct Previous<'a> {
+    tag: Option<PosTag>,
+    metadata: Option<&'a WordMetadata>,
+    text: &'a [char],
+}
+
+/// Pick the part of speech of a word that could be several, based on the words around it.
+fn disambiguate(
+    options: &[PosTag],
+    previous: Option<&Previous>,
+    next: &[PosTag],
+) -> Option<PosTag> {
+    let has = |tag| options.contains(&tag);
+    let default = options.first().copied();
+
+    let Some(previous) = previous else {
+        return default;
+    };
+
+    let is_to = previous
+        .text
+        .iter()
+        .map(|c| c.to_ascii_lowercase())
+        .eq("to".chars());
+    let is_auxiliary = previous
+        .metadata
+        .is_some_and(|metadata| metadata.is_auxiliary_verb());
+
+    match previous.tag {
+        // "to run", "will run"
+        _ if (is_to || is_auxiliary) && has(PosTag::Verb) => Some(PosTag::Verb),
+        // "they run", but not "their run"
+        Some(PosTag::Pronoun)
+            if has(PosTag::Verb)
+                && !previous
+                    .metadata
+                    .is_some_and(|metadata| metadata.is_possessive_noun()) =>
+        {
+            Some(PosTag::Verb)
+        }
+        // "the green light", "the run"
+        Some(PosTag::Article | PosTag::Adjective | PosTag::Preposition) => {
+            if has(PosTag::Adjective) && next.contains(&PosTag::Noun) {
+                Some(PosTag::Adjective)
+            } else if has(PosTag::Noun) {
+                Some(PosTag::Noun)
+            } else {
+                default
+            }
+        }
+        // "the dog runs away"
+        Some(PosTag::Noun | PosTag::ProperNoun) if has(PosTag::Verb) => Some(PosTag::Verb),
+        _ => default,
+    }
+}
+
+impl Document {
+    /// The part of speech of each word in the document, in order.
+    ///
+    /// Many words can be more than one part of speech (like "run", which can be a noun or a
+    /// verb), so the neighboring words are used to decide which one each word is in context.
+    pub fn pos_tags(&self) -> Vec<TaggedWord> {
+        let tokens = self.get_tokens();
+        let mut tagged = Vec::new();
+        let mut previous: Option<Previous> = None;
+
+        for (index, token) in tokens.iter().enumerate() {
+            match &token.kind {
+                TokenKind::Word(metadata) => {
+                    let options = metadata.as_ref().map(candidates).unwrap_or_default();
+
+                    let next = tokens[index + 1..]
+                        .iter()
+                        .find(|t| !t.kind.is_whitespace())
+                        .and_then(|t| t.kind.as_word())
+                        .and_then(|metadata| metadata.as_ref())
+                        .map(candidates)
+                        .unwrap_or_default();
+
+                    let tag = if options.len() > 1 {
+                        disambiguate(&options, previous.as_ref(), &next)
+                    } else {
+                        options.first().copied()
+                    };
+
+                    tagged.push(TaggedWord { token: *token, tag });
+
+                    previous = Some(Previous {
+                        tag,
+                        metadata: metadata.as_ref(),
+                        text: self.get_span_content(token.span),
+                    });
+                }
+                TokenKind::Space(_) => (),
+                _ => previous = None,
+            }
+        }
+
+        tagged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PosTag;
+    use crate::Document;
+
+    fn tags(text: &str) -> Vec<(String, Option<PosTag>)> {
+        let document = Document::new_plain_english_curated(text);
+
+        document
+            .pos_tags()
+            .into_iter()
+            .map(|word| (document.get_span_content_str(word.token.span), word.tag))
+            .collect()
+    }
+
+    fn tag_of(text: &str, word: &str) -> Option<PosTag> {
+        tags(text)
+            .into_iter()
+            .find(|(w, _)| w == word)
+            .and_then(|(_, tag)| tag)
+    }
+
+    #[test]
+    fn run_after_article_is_noun() {
+        assert_eq!(tag_of("The run was long.", "run"), Some(PosTag::Noun));
+    }
+
+    #[test]
+    fn run_after_pronoun_is_verb() {
+        assert_eq!(tag_of("They run every day.", "run"), Some(PosTag::Verb));
+    }
+
+    #[test]
+    fn run_after_to_is_verb() {
+        assert_eq!(tag_of("I want to run.", "run"), Some(PosTag::Verb));
+    }
+
+    #[test]
+    fn modifiers_before_nouns_are_adjectives() {
+        assert_eq!(
+            tags("The green light"),
+            vec![
+                ("The".to_string(), Some(PosTag::Article)),
+                ("green".to_string(), Some(PosTag::Adjective)),
+                ("light".to_string(), Some(PosTag::Noun)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_words_have_no_tag() {
+        assert_eq!(tag_of("The zorblax is here.", "zorblax"), None);
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use super::Dictionary;
+use crate::{CharString, WordMetadata};
+
+/// The differences between two dictionaries.
+///
+/// Words are compared with their exact capitalization, so a word whose capitalization
+/// changed shows up as both removed and added.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DictionaryDiff {
+    /// Words only in the newer dictionary, sorted.
+    pub added: Vec<CharString>,
+    /// Words only in the older dictionary, sorted.
+    pub removed: Vec<CharString>,
+    /// Words in both dictionaries whose metadata differs, sorted by word.
+    pub changed: Vec<MetadataChange>,
+}
+
+/// A word whose metadata differs between two dictionaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataChange {
+    pub word: CharString,
+    pub before: WordMetadata,
+    pub after: WordMetadata,
+}
+
+impl DictionaryDiff {
+    /// Find what changed going from `before` to `after`.
+    pub fn between(before: &impl Dictionary, after: &impl Dictionary) -> Self {
+        let mut diff = Self::default();
+
+        for word in before.words_iter() {
+            if !after.contains_exact_word(word) {
+                diff.removed.push(word.into());
+                continue;
+            }
+
+            let old_metadata = before.get_word_metadata(word).unwrap_or_default();
+            let new_metadata = after.get_word_metadata(word).unwrap_or_default();
+
+            if old_metadata != new_metadata {
+                diff.changed.push(MetadataChange {
+                    word: word.into(),
+                    before: old_metadata,
+                    after: new_metadata,
+                });
+            }
+        }
+
+        diff.added = after
+            .words_iter()
+            .filter(|word| !before.contains_exact_word(word))
+            .map(CharString::from)
+            .collect();
+
+        diff.added.sort_unstable();
+        diff.removed.sort_unstable();
+        diff.changed.sort_unstable_by(|a, b| a.word.cmp(&b.word));
+
+        diff
+    }
+
+    /// Whether the two dictionaries are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Lists the differences one word per line, like a unified diff: `+` for added words, `-`
+/// for removed ones and `~` for ones whose metadata changed.
+impl Display for DictionaryDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for word in &self.added {
+            writeln!(f, "+{}", word.iter().collect::<String>())?;
+        }
+
+        for word in &self.removed {
+            writeln!(f, "-{}", word.iter().collect::<String>())?;
+        }
+
+        for change in &self.changed {
+            writeln!(f, "~{}", change.word.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DictionaryDiff;
+    use crate::spell::MutableDictionary;
+    use crate::{CharString, WordMetadata};
+
+    fn dictionary(words: &[(&str, WordMetadata)]) -> MutableDictionary {
+        let mut dictionary = MutableDictionary::new();
+        dictionary.extend_words(
+            words
+                .iter()
+                .map(|(word, metadata)| (word.chars().collect::<CharString>(), *metadata)),
+        );
+        dictionary
+    }
+
+    #[test]
+    fn finds_added_removed_and_changed_words() {
+        let common = WordMetadata {
+            common: true,
+            ..Default::default()
+        };
+
+        let before = dictionary(&[
+            ("apple", WordMetadata::default()),
+            ("banana", WordMetadata::default()),
+            ("github", WordMetadata::default()),
+        ]);
+        let after = dictionary(&[
+            ("apple", common),
+            ("cherry", WordMetadata::default()),
+            ("GitHub", WordMetadata::default()),
+        ]);
+
+        let diff = DictionaryDiff::between(&before, &after);
+
+        assert_eq!(
+            diff.to_string(),
+            "+GitHub\n+cherry\n-banana\n-github\n~apple\n"
+        );
+        assert_eq!(diff.changed[0].after, common);
+    }
+
+    #[test]
+    fn identical_dictionaries_have_no_diff() {
+        let words = [("apple", WordMetadata::default())];
+
+        assert!(DictionaryDiff::between(&dictionary(&words), &dictionary(&words)).is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+use super::Dictionary;
+use crate::{CharString, Tense, WordMetadata};
+
+/// The words of a dictionary, sorted and separated by newlines.
+///
+/// This is the same format [`super::UserDictionary`] saves to, so the result can be used
+/// as a user dictionary directly.
+pub fn export_word_list(dictionary: &impl Dictionary) -> String {
+    let mut out = String::new();
+
+    for word in sorted_words(dictionary) {
+        out.extend(word);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// A dictionary written as a Hunspell `.dic`/`.aff` pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HunspellExport {
+    pub dic: String,
+    pub aff: String,
+}
+
+/// Write a dictionary in Hunspell's format, so it can be read by other spell checkers (or by
+/// [`super::MutableDictionary::from_hunspell`]).
+///
+/// Every word is written out in full rather than as a stem with affixes.
+/// Metadata is kept as `po:` and `is:` morphological fields, where Hunspell has a way of
+/// expressing it.
+pub fn export_hunspell(dictionary: &impl Dictionary) -> HunspellExport {
+    let words = sorted_words(dictionary);
+    let mut dic = format!("{}\n", words.len());
+
+    for word in words {
+        for c in word.iter() {
+            if *c == '/' {
+                dic.push('\\');
+            }
+            dic.push(*c);
+        }
+
+        if let Some(metadata) = dictionary.get_word_metadata(&word) {
+            for field in morphological_fields(&metadata) {
+                dic.push(' ');
+                dic.push_str(field);
+            }
+        }
+
+        dic.push('\n');
+    }
+
+    HunspellExport {
+        dic,
+        aff: "SET UTF-8\n".to_string(),
+    }
+}
+
+fn sorted_words(dictionary: &impl Dictionary) -> Vec<CharString> {
+    let mut words: Vec<CharString> = dictionary.words_iter().map(CharString::from).collect();
+    words.sort_unstable();
+    words
+}
+
+/// The Hunspell morphological fields that describe the metadata.
+fn morphological_fields(metadata: &WordMetadata) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+
+    if let Some(noun) = metadata.noun {
+        fields.push(if noun.is_proper == Some(true) {
+            "po:propn"
+        } else if noun.is_pronoun == Some(true) {
+            "po:pron"
+        } else {
+            "po:noun"
+        });
+
+        if noun.is_plural == Some(true) {
+            fields.push("is:plural");
+        }
+
+        if noun.is_possessive == Some(true) {
+            fields.push("is:possessive");
+        }
+    }
+
+    if let Some(verb) = metadata.verb {
+        fields.push("po:verb");
+
+        if verb.tense == Some(Tense::Past) {
+            fields.push("is:past");
+        }
+    }
+
+    for (present, field) in [
+        (metadata.adjective.is_some(), "po:adj"),
+        (metadata.adverb.is_some(), "po:adv"),
+        (metadata.conjunction.is_some(), "po:conj"),
+        (metadata.preposition, "po:prep"),
+        (metadata.article, "po:art"),
+    ] {
+        if present {
+            fields.push(field);
+        }
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export_hunspell, export_word_list};
+    use crate::spell::{Dictionary, MutableDictionary};
+    use crate::{NounData, WordMetadata};
+
+    fn test_dictionary() -> MutableDictionary {
+        let mut dictionary = MutableDictionary::new();
+
+        dictionary.extend_words([
+            ("zebra".chars().collect::<Vec<_>>(), WordMetadata::default()),
+            (
+                "cats".chars().collect(),
+                WordMetadata {
+                    noun: Some(NounData {
+                        is_plural: Some(true),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ),
+            ("and/or".chars().collect(), WordMetadata::default()),
+        ]);
+
+        dictionary
+    }
+
+    #[test]
+    fn word_list_is_sorted() {
+        assert_eq!(
+            export_word_list(&test_dictionary()),
+            "and/or\ncats\nzebra\n"
+        );
+    }
+
+    #[test]
+    fn hunspell_keeps_metadata() {
+        let export = export_hunspell(&test_dictionary());
+
+        assert_eq!(export.dic, "3\nand\\/or\ncats po:noun is:plural\nzebra\n");
+    }
+
+    #[test]
+    fn hunspell_round_trips() {
+        let original = test_dictionary();
+        let export = export_hunspell(&original);
+        let parsed = MutableDictionary::from_hunspell(&export.dic, &export.aff).unwrap();
+
+        assert_eq!(export_word_list(&parsed), export_word_list(&original));
+        assert_eq!(
+            parsed.get_word_metadata_str("cats"),
+            original.get_word_metadata_str("cats")
+        );
+    }
+}
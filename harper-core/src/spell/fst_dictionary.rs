@@ -1,14 +1,23 @@
 use super::{
     MutableDictionary,
-    hunspell::{parse_default_attribute_list, parse_default_word_list},
+    hunspell::{
+        self, parse_attribute_list, parse_default_attribute_list, parse_default_word_list,
+        word_list::parse_word_list,
+    },
+    phonetic::{MIN_CODE_LEN, PhoneticIndex, metaphone},
     seq_to_normalized,
+    word_frequency::apply_default_frequency_ranks,
 };
 use fst::{IntoStreamer, Map as FstMap, Streamer, map::StreamWithState};
 use hashbrown::HashMap;
 use lazy_static::lazy_static;
 use levenshtein_automata::{DFA, LevenshteinAutomatonBuilder};
-use std::{cell::RefCell, sync::Arc};
+use std::{
+    cell::RefCell,
+    sync::{Arc, OnceLock},
+};
 
+use crate::edit_distance::edit_distance_min_alloc;
 use crate::{CharString, CharStringExt, WordMetadata};
 
 use super::Dictionary;
@@ -22,9 +31,31 @@ pub struct FstDictionary {
     /// Underlying [`super::MutableDictionary`] used for everything except fuzzy finding
     full_dict: Arc<MutableDictionary>,
     /// Used for fuzzy-finding the index of words or metadata
-    word_map: FstMap<Vec<u8>>,
+    word_map: FstMap<FstBytes>,
     /// Used for fuzzy-finding the index of words or metadata
     words: Vec<(CharString, WordMetadata)>,
+    /// Used for finding words that sound like a misspelling, keyed by index into `words`.
+    /// Built the first time it is needed.
+    phonetic_index: OnceLock<PhoneticIndex>,
+}
+
+/// The storage behind an [`FstDictionary`]'s FST.
+enum FstBytes {
+    Owned(Vec<u8>),
+    /// An FST file mapped into memory, so its pages may be shared between processes. The file
+    /// starts with a header, which is skipped.
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl AsRef<[u8]> for FstBytes {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            FstBytes::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            FstBytes::Mapped(map) => &map[mapped::HEADER_LEN..],
+        }
+    }
 }
 
 /// The uncached function that is used to produce the original copy of the
@@ -36,7 +67,20 @@ fn uncached_inner_new() -> Arc<FstDictionary> {
     // There will be at _least_ this number of words
     let mut word_map = HashMap::with_capacity(word_list.len());
     attr_list.expand_marked_words(word_list, &mut word_map);
+    apply_default_frequency_ranks(&mut word_map);
 
+    #[cfg(feature = "mmap")]
+    {
+        let words = FstDictionary::sort_words(word_map);
+
+        let word_map = mapped::default_path(&words)
+            .and_then(|path| mapped::open_or_create(&path, &words).ok())
+            .unwrap_or_else(|| FstMap::new(FstDictionary::build_fst(&words)).unwrap());
+
+        Arc::new(FstDictionary::from_parts(words, word_map))
+    }
+
+    #[cfg(not(feature = "mmap"))]
     Arc::new(FstDictionary::new(word_map))
 }
 
@@ -67,15 +111,49 @@ impl PartialEq for FstDictionary {
 impl FstDictionary {
     /// Create a dictionary from the curated dictionary included
     /// in the Harper binary.
+    ///
+    /// Without the `embedded-dictionary` feature, this is empty until the word list is installed
+    /// with [`hunspell::install_default_word_list`].
     pub fn curated() -> Arc<Self> {
+        if hunspell::default_word_list().is_none() {
+            return Arc::new(Self::new(HashMap::new()));
+        }
+
         (*DICT).clone()
     }
 
     pub fn new(new_words: HashMap<CharString, WordMetadata>) -> Self {
-        let mut words: Vec<(CharString, WordMetadata)> = new_words.into_iter().collect();
+        let words = Self::sort_words(new_words);
+        let word_map = FstMap::new(Self::build_fst(&words)).expect("Unable to build FST map.");
+
+        Self::from_parts(words, word_map)
+    }
+
+    /// Create a dictionary from a word list and an attribute list, in the same formats as the
+    /// curated `dictionary.dict` and `affixes.json`.
+    ///
+    /// This allows the curated dictionary to be loaded at runtime, rather than using the copy
+    /// included in the binary.
+    pub fn from_word_list(word_list: &str, attribute_list: &str) -> Result<Self, hunspell::Error> {
+        let word_list = parse_word_list(word_list)?;
+        let attr_list = parse_attribute_list(attribute_list)?;
+
+        let mut word_map = HashMap::with_capacity(word_list.len());
+        attr_list.expand_marked_words(word_list, &mut word_map);
+        apply_default_frequency_ranks(&mut word_map);
+
+        Ok(Self::new(word_map))
+    }
+
+    fn sort_words(words: HashMap<CharString, WordMetadata>) -> Vec<(CharString, WordMetadata)> {
+        let mut words: Vec<(CharString, WordMetadata)> = words.into_iter().collect();
         words.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
         words.dedup_by(|(a, _), (b, _)| a == b);
+        words
+    }
 
+    /// Build an FST that maps each word in a sorted list to its index.
+    fn build_fst(words: &[(CharString, WordMetadata)]) -> FstBytes {
         let mut builder = fst::MapBuilder::memory();
         for (index, (word, _)) in words.iter().enumerate() {
             let word = word.iter().collect::<String>();
@@ -84,18 +162,141 @@ impl FstDictionary {
                 .expect("Insertion not in lexicographical order!");
         }
 
+        FstBytes::Owned(builder.into_inner().unwrap())
+    }
+
+    fn from_parts(words: Vec<(CharString, WordMetadata)>, word_map: FstMap<FstBytes>) -> Self {
         let mut full_dict = MutableDictionary::new();
         full_dict.extend_words(words.iter().cloned());
 
-        let fst_bytes = builder.into_inner().unwrap();
-        let word_map = FstMap::new(fst_bytes).expect("Unable to build FST map.");
-
         FstDictionary {
             full_dict: Arc::new(full_dict),
             word_map,
             words,
+            phonetic_index: OnceLock::new(),
+        }
+    }
+
+    fn phonetic_index(&self) -> &PhoneticIndex {
+        self.phonetic_index
+            .get_or_init(|| PhoneticIndex::new(self.words.iter().map(|(word, _)| word.as_slice())))
+    }
+}
+
+#[cfg(feature = "mmap")]
+mod mapped {
+    use std::fs::{self, File, OpenOptions};
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    use std::io::{self, Write};
+    use std::path::{Path, PathBuf};
+
+    use fst::Map as FstMap;
+
+    use super::{FstBytes, FstDictionary};
+    use crate::{CharString, WordMetadata};
+
+    /// Marks the start of an FST file written by [`open_or_create`].
+    const MAGIC: &[u8; 8] = b"HARPFST1";
+
+    /// The magic, the checksum of the word list and the checksum of the FST, in that order.
+    pub const HEADER_LEN: usize = 24;
+
+    /// Where the FST for `words` is kept: the user's cache directory, under a name derived from
+    /// the word list so a stale file is never picked up.
+    pub fn default_path(words: &[(CharString, WordMetadata)]) -> Option<PathBuf> {
+        let dir = dirs::cache_dir()?.join("harper");
+        fs::create_dir_all(&dir).ok()?;
+
+        Some(dir.join(format!("curated-{:016x}.fst", checksum(words))))
+    }
+
+    fn checksum(value: &(impl Hash + ?Sized)) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Map the FST stored at `path` into memory, first writing it there if it is missing or
+    /// wasn't built from `words`.
+    pub fn open_or_create(
+        path: &Path,
+        words: &[(CharString, WordMetadata)],
+    ) -> io::Result<FstMap<FstBytes>> {
+        let words_checksum = checksum(words);
+
+        if let Ok(map) = open(path, words_checksum)
+            && map.len() == words.len()
+        {
+            return Ok(map);
+        }
+
+        let fst = FstDictionary::build_fst(words);
+        let fst = fst.as_ref();
+
+        // Write to a file no one else can have opened, then move it into place, so other
+        // processes never see a partial FST.
+        let (temp, mut file) = create_temp(path)?;
+
+        let written = file
+            .write_all(MAGIC)
+            .and_then(|_| file.write_all(&words_checksum.to_le_bytes()))
+            .and_then(|_| file.write_all(&checksum(fst).to_le_bytes()))
+            .and_then(|_| file.write_all(fst))
+            .and_then(|_| file.sync_all())
+            .and_then(|_| fs::rename(&temp, path));
+
+        if let Err(err) = written {
+            let _ = fs::remove_file(&temp);
+            return Err(err);
+        }
+
+        open(path, words_checksum)
+    }
+
+    fn create_temp(path: &Path) -> io::Result<(PathBuf, File)> {
+        let mut attempt = 0;
+
+        loop {
+            let temp = path.with_extension(format!("{}-{attempt}.tmp", std::process::id()));
+
+            match OpenOptions::new().write(true).create_new(true).open(&temp) {
+                Ok(file) => return Ok((temp, file)),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists && attempt < 16 => {
+                    attempt += 1
+                }
+                Err(err) => return Err(err),
+            }
         }
     }
+
+    /// Map the FST at `path`, as long as its header says it was built from the word list with
+    /// `words_checksum` and the FST itself matches the checksum stored alongside it.
+    fn open(path: &Path, words_checksum: u64) -> io::Result<FstMap<FstBytes>> {
+        let file = File::open(path)?;
+
+        // SAFETY: Harper never writes to this file after it has been renamed into place, only
+        // replaces it with a new one, which leaves this mapping intact. Anything else that
+        // modifies it in place while it is mapped is undefined behaviour; the checks below only
+        // catch files that were already corrupt or stale when they were opened.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Invalid curated FST file.");
+
+        if map.len() < HEADER_LEN || &map[..8] != MAGIC {
+            return Err(invalid());
+        }
+
+        let stored_words_checksum = u64::from_le_bytes(map[8..16].try_into().unwrap());
+        let stored_fst_checksum = u64::from_le_bytes(map[16..24].try_into().unwrap());
+
+        if stored_words_checksum != words_checksum
+            || stored_fst_checksum != checksum(&map[HEADER_LEN..])
+        {
+            return Err(invalid());
+        }
+
+        FstMap::new(FstBytes::Mapped(map)).map_err(io::Error::other)
+    }
 }
 
 fn build_dfa(max_distance: u8, query: &str) -> DFA {
@@ -166,7 +367,14 @@ impl Dictionary for FstDictionary {
         let upper_dists = stream_distances_vec(&mut word_indexes_stream, &dfa);
         let lower_dists = stream_distances_vec(&mut word_indexes_lowercase_stream, &dfa_lowercase);
 
-        let mut merged = Vec::with_capacity(upper_dists.len());
+        let code = metaphone(word);
+        let sound_alikes = if code.len() >= MIN_CODE_LEN {
+            self.phonetic_index().get(&code)
+        } else {
+            &[]
+        };
+
+        let mut merged = Vec::with_capacity(upper_dists.len() + sound_alikes.len());
 
         // Merge the two results
         for ((i_u, dist_u), (i_l, dist_l)) in upper_dists.into_iter().zip(lower_dists.into_iter()) {
@@ -176,18 +384,51 @@ impl Dictionary for FstDictionary {
                 (i_l, dist_l)
             };
 
-            let (word, metadata) = &self.words[chosen_index as usize];
+            // The FST may have been read from disk, so don't trust its indices.
+            let Some((word, metadata)) = self.words.get(chosen_index as usize) else {
+                continue;
+            };
 
             merged.push(FuzzyMatchResult {
                 word,
                 edit_distance,
                 metadata: *metadata,
+                phonetic: sound_alikes.binary_search(&(chosen_index as u32)).is_ok(),
             })
         }
 
-        merged.sort_unstable_by_key(|v| v.word);
+        // Words that sound alike may be too far away for the automaton to find.
+        let misspelled_word_lowercase = misspelled_word_charslice.to_lower();
+        let mut buf_a = Vec::with_capacity(53);
+        let mut buf_b = Vec::with_capacity(53);
+
+        for &index in sound_alikes {
+            let (word, metadata) = &self.words[index as usize];
+
+            let edit_distance =
+                edit_distance_min_alloc(&misspelled_word_charslice, word, &mut buf_a, &mut buf_b)
+                    .min(edit_distance_min_alloc(
+                        &misspelled_word_lowercase,
+                        word,
+                        &mut buf_a,
+                        &mut buf_b,
+                    ));
+
+            let result = FuzzyMatchResult {
+                word,
+                edit_distance,
+                metadata: *metadata,
+                phonetic: true,
+            };
+
+            if result.score() <= max_distance {
+                merged.push(result);
+            }
+        }
+
+        merged.sort_unstable_by_key(|v| (v.word, v.rank_key()));
         merged.dedup_by_key(|v| v.word);
-        merged.sort_unstable_by_key(|v| v.edit_distance);
+        merged.sort_unstable_by_key(FuzzyMatchResult::rank_key);
         merged.truncate(max_results);
 
         merged
@@ -233,6 +474,42 @@ impl Dictionary for FstDictionary {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mapped_fst_matches_owned() {
+        use super::{FstDictionary, mapped};
+        use crate::Dictionary;
+
+        let curated = FstDictionary::curated();
+        let words = curated.words.clone();
+
+        let path = std::env::temp_dir().join(format!("harper-test-{}.fst", std::process::id()));
+        let word_map = mapped::open_or_create(&path, &words).unwrap();
+        let reopened = mapped::open_or_create(&path, &words).unwrap();
+
+        // A corrupted file is replaced rather than trusted.
+        let mut bytes = std::fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 0xff;
+        // Replace the file rather than writing over the one that is still mapped.
+        std::fs::remove_file(&path).unwrap();
+        std::fs::write(&path, bytes).unwrap();
+        let rebuilt = mapped::open_or_create(&path, &words).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        let dict = FstDictionary::from_parts(words, word_map);
+
+        assert_eq!(reopened.len(), dict.words.len());
+        assert_eq!(
+            rebuilt.as_fst().as_bytes(),
+            dict.word_map.as_fst().as_bytes()
+        );
+        assert_eq!(
+            dict.fuzzy_match_str("hellp", 2, 10),
+            curated.fuzzy_match_str("hellp", 2, 10)
+        );
+    }
+
     use itertools::Itertools;
 
     use crate::CharStringExt;
@@ -289,6 +566,35 @@ mod tests {
         assert!(is_sorted_by_dist)
     }
 
+    #[test]
+    fn finds_words_that_sound_alike() {
+        let dict = FstDictionary::curated();
+
+        let results = dict.fuzzy_match_str("fizikal", 2, 10);
+
+        assert!(
+            results
+                .iter()
+                .any(|fm| fm.word == ['p', 'h', 'y', 's', 'i', 'c', 'a', 'l'] && fm.phonetic)
+        );
+    }
+
+    #[test]
+    fn loads_curated_word_list_at_runtime() {
+        let dict = FstDictionary::from_word_list(
+            include_str!("../../dictionary.dict"),
+            include_str!("../../affixes.json"),
+        )
+        .unwrap();
+
+        assert!(dict == *FstDictionary::curated());
+    }
+
+    #[test]
+    fn rejects_malformed_attribute_list() {
+        assert!(FstDictionary::from_word_list("1\nword", "{").is_err());
+    }
+
     #[test]
     fn curated_contains_no_duplicates() {
         let dict = FstDictionary::curated();
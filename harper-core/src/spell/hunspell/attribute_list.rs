@@ -6,7 +6,7 @@ use super::Error;
 use super::affix_replacement::AffixReplacement;
 use super::expansion::{Expansion, HumanReadableExpansion};
 use super::word_list::MarkedWord;
-use crate::{CharString, Span, WordMetadata};
+use crate::{CharString, DialectFlags, Span, WordMetadata};
 
 #[derive(Debug, Clone)]
 pub struct AttributeList {
@@ -29,11 +29,46 @@ impl AttributeList {
     ///
     /// Will append to the given `dest`;
     ///
+    /// The dialects a word is tagged with are passed on to every word derived from it, so
+    /// "colours" is as British as "colour".
+    ///
     /// In the future, I want to make this function cleaner and faster.
     pub fn expand_marked_word(
         &self,
         word: MarkedWord,
         dest: &mut HashMap<CharString, WordMetadata>,
+    ) {
+        let dialects = word
+            .attributes
+            .iter()
+            .filter_map(|attr| self.affixes.get(attr))
+            .fold(DialectFlags::empty(), |acc, expansion| {
+                acc.union(expansion.gifts_metadata.dialects)
+            });
+
+        if dialects.is_empty() {
+            self.expand_marked_word_untagged(word, dest);
+            return;
+        }
+
+        let mut expanded = HashMap::new();
+        self.expand_marked_word_untagged(word, &mut expanded);
+
+        for (key, mut value) in expanded {
+            value.dialects = value.dialects.union(dialects);
+
+            if let Some(val) = dest.get_mut(&key) {
+                val.append(&value);
+            } else {
+                dest.insert(key, value);
+            }
+        }
+    }
+
+    fn expand_marked_word_untagged(
+        &self,
+        word: MarkedWord,
+        dest: &mut HashMap<CharString, WordMetadata>,
     ) {
         dest.reserve(word.attributes.len() + 1);
         let mut gifted_metadata = WordMetadata::default();
@@ -71,7 +106,7 @@ impl AttributeList {
                 }
 
                 for (new_word, metadata) in new_words {
-                    self.expand_marked_word(
+                    self.expand_marked_word_untagged(
                         MarkedWord {
                             letters: new_word.clone(),
                             attributes: opp_attr.clone(),
@@ -170,7 +205,7 @@ impl AttributeList {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HumanReadableAttributeList {
-    affixes: HashMap<char, HumanReadableExpansion>,
+    pub(super) affixes: HashMap<char, HumanReadableExpansion>,
 }
 
 impl HumanReadableAttributeList {
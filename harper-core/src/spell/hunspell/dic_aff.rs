@@ -0,0 +1,437 @@
+use hashbrown::HashMap;
+
+use super::Error;
+use super::affix_replacement::HumanReadableAffixReplacement;
+use super::attribute_list::{AttributeList, HumanReadableAttributeList};
+use super::expansion::HumanReadableExpansion;
+use super::word_list::MarkedWord;
+use crate::{CharString, NounData, Tense, VerbData, WordMetadata};
+
+/// The first character used to represent flags that can't be represented as a single
+/// character themselves (long and numeric flags).
+/// Sits at the start of the supplementary private use areas, so it can't collide with real
+/// single-character flags in practice.
+const FIRST_INTERNED_FLAG: u32 = 0xF0000;
+
+/// Parse a Hunspell `.dic`/`.aff` pair, expanding each word with its affixes.
+///
+/// Part-of-speech information is pulled from any `po:` and `is:` morphological fields on
+/// words and affix rules.
+/// Directives Harper has no use for (like `TRY`, `REP` or compounding rules) are ignored,
+/// as are continuation flags on affix rules.
+pub fn parse_hunspell(dic: &str, aff: &str) -> Result<HashMap<CharString, WordMetadata>, Error> {
+    let (attributes, mut flags) = parse_aff(aff)?;
+    let (words, word_metadata) = parse_dic(dic, &mut flags)?;
+
+    let mut expanded = HashMap::new();
+    attributes.expand_marked_words(words, &mut expanded);
+
+    for (word, metadata) in word_metadata {
+        expanded.entry(word).or_default().append(&metadata);
+    }
+
+    Ok(expanded)
+}
+
+/// How affix flags are written, as set by the `FLAG` directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagType {
+    /// Each character is a flag.
+    Char,
+    /// Each pair of characters is a flag.
+    Long,
+    /// Flags are comma-separated decimal numbers.
+    Num,
+}
+
+/// Reads flags written in a file's [`FlagType`], mapping each to a single character so they
+/// can be stored in a [`MarkedWord`].
+#[derive(Debug)]
+struct Flags {
+    flag_type: FlagType,
+    /// Sets of flags defined with the `AF` directive.
+    /// Words may refer to these by their one-based index.
+    aliases: Vec<Vec<char>>,
+    interned: HashMap<String, char>,
+}
+
+impl Flags {
+    fn new() -> Self {
+        Self {
+            flag_type: FlagType::Char,
+            aliases: Vec::new(),
+            interned: HashMap::new(),
+        }
+    }
+
+    fn intern(&mut self, flag: &str) -> Result<char, Error> {
+        if let Some(c) = self.interned.get(flag) {
+            return Ok(*c);
+        }
+
+        let c = char::from_u32(FIRST_INTERNED_FLAG + self.interned.len() as u32)
+            .ok_or(Error::TooManyFlags)?;
+        self.interned.insert(flag.to_string(), c);
+
+        Ok(c)
+    }
+
+    /// Read a single flag, like the one in an affix header or rule.
+    fn parse_flag(&mut self, source: &str) -> Result<char, Error> {
+        let flags = self.parse_flags(source)?;
+
+        match flags.as_slice() {
+            [flag] => Ok(*flag),
+            _ => Err(Error::MultiCharacterFlag),
+        }
+    }
+
+    /// Read a list of flags, like those following a word.
+    fn parse_flags(&mut self, source: &str) -> Result<Vec<char>, Error> {
+        match self.flag_type {
+            FlagType::Char => Ok(source.chars().collect()),
+            FlagType::Long => {
+                let chars: Vec<char> = source.chars().collect();
+
+                if !chars.len().is_multiple_of(2) {
+                    return Err(Error::MalformedFlag);
+                }
+
+                chars
+                    .chunks(2)
+                    .map(|pair| self.intern(&pair.iter().collect::<String>()))
+                    .collect()
+            }
+            FlagType::Num => source
+                .split(',')
+                .map(|flag| {
+                    let flag = flag.trim();
+
+                    if flag.is_empty() || !flag.chars().all(|c| c.is_ascii_digit()) {
+                        return Err(Error::MalformedFlag);
+                    }
+
+                    self.intern(flag)
+                })
+                .collect(),
+        }
+    }
+
+    /// Read the flags following a word, resolving `AF` aliases.
+    fn parse_word_flags(&mut self, source: &str) -> Result<Vec<char>, Error> {
+        if self.aliases.is_empty() {
+            return self.parse_flags(source);
+        }
+
+        let index: usize = source.parse().map_err(|_| Error::UnknownFlagAlias)?;
+
+        index
+            .checked_sub(1)
+            .and_then(|i| self.aliases.get(i))
+            .cloned()
+            .ok_or(Error::UnknownFlagAlias)
+    }
+}
+
+fn parse_aff(source: &str) -> Result<(AttributeList, Flags), Error> {
+    let mut flags = Flags::new();
+    let mut affixes: HashMap<char, HumanReadableExpansion> = HashMap::new();
+    // The number of rules each affix header promised that haven't been read yet.
+    let mut remaining_rules: HashMap<char, usize> = HashMap::new();
+    let mut read_alias_count = false;
+
+    for line in source.trim_start_matches('\u{feff}').lines() {
+        let mut parts = line.split_whitespace();
+
+        let Some(directive) = parts.next() else {
+            continue;
+        };
+
+        match directive {
+            "FLAG" => {
+                flags.flag_type = match parts.next() {
+                    Some("long") => FlagType::Long,
+                    Some("num") => FlagType::Num,
+                    Some("UTF-8") => FlagType::Char,
+                    _ => return Err(Error::UnknownFlagType),
+                }
+            }
+            "AF" => {
+                let value = parts.next().ok_or(Error::UnexpectedEndOfLine)?;
+
+                if !read_alias_count {
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| Error::MalformedItemCount)?;
+                    read_alias_count = true;
+                } else {
+                    let alias = flags.parse_flags(value)?;
+                    flags.aliases.push(alias);
+                }
+            }
+            "PFX" | "SFX" => {
+                let flag = flags.parse_flag(parts.next().ok_or(Error::UnexpectedEndOfLine)?)?;
+                let remaining = remaining_rules.entry(flag).or_default();
+
+                if *remaining == 0 {
+                    let cross_product = match parts.next() {
+                        Some("Y") => true,
+                        Some("N") => false,
+                        _ => return Err(Error::ExpectedBoolean),
+                    };
+
+                    *remaining = parts
+                        .next()
+                        .ok_or(Error::UnexpectedEndOfLine)?
+                        .parse()
+                        .map_err(|_| Error::ExpectedUnsignedInteger)?;
+
+                    affixes.insert(
+                        flag,
+                        HumanReadableExpansion {
+                            suffix: directive == "SFX",
+                            cross_product,
+                            replacements: Vec::new(),
+                            adds_metadata: WordMetadata::default(),
+                            gifts_metadata: WordMetadata::default(),
+                        },
+                    );
+                } else {
+                    *remaining -= 1;
+
+                    let remove = parts.next().ok_or(Error::UnexpectedEndOfLine)?;
+                    let add = parts.next().ok_or(Error::UnexpectedEndOfLine)?;
+                    // Continuation flags aren't supported.
+                    let add = add.split_once('/').map_or(add, |(add, _)| add);
+                    let condition = parts.next().unwrap_or(".");
+
+                    let expansion = affixes.get_mut(&flag).expect("Header was read.");
+                    expansion.adds_metadata.append(&parse_morphology(parts));
+                    expansion.replacements.push(HumanReadableAffixReplacement {
+                        remove: empty_if_zero(remove).to_string(),
+                        add: empty_if_zero(add).to_string(),
+                        condition: condition.to_string(),
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let attributes = HumanReadableAttributeList { affixes }.into_normal()?;
+
+    Ok((attributes, flags))
+}
+
+/// Words from a `.dic` file that were given metadata directly.
+type WordMetadataList = Vec<(CharString, WordMetadata)>;
+
+/// Parse a `.dic` file, returning its words along with any metadata given for them directly.
+fn parse_dic(
+    source: &str,
+    flags: &mut Flags,
+) -> Result<(Vec<MarkedWord>, WordMetadataList), Error> {
+    let mut lines = source.trim_start_matches('\u{feff}').lines();
+
+    let approx_item_count = lines
+        .next()
+        .ok_or(Error::MalformedItemCount)?
+        .trim()
+        .parse()
+        .map_err(|_| Error::MalformedItemCount)?;
+
+    let mut words = Vec::with_capacity(approx_item_count);
+    let mut word_metadata = Vec::new();
+
+    for line in lines {
+        // Lines starting with a tab are comments.
+        if line.trim().is_empty() || line.starts_with('\t') {
+            continue;
+        }
+
+        let (entry, morphology) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let (letters, attributes) = split_entry(entry);
+        let attributes = match attributes {
+            Some(attributes) => flags.parse_word_flags(attributes)?,
+            None => Vec::new(),
+        };
+
+        let metadata = parse_morphology(morphology.split_whitespace());
+
+        if metadata != WordMetadata::default() {
+            word_metadata.push((letters.clone(), metadata));
+        }
+
+        words.push(MarkedWord {
+            letters,
+            attributes,
+        });
+    }
+
+    Ok((words, word_metadata))
+}
+
+/// Split a `.dic` entry into the word and its flags at the first unescaped slash.
+fn split_entry(entry: &str) -> (CharString, Option<&str>) {
+    let mut letters = CharString::new();
+    let mut chars = entry.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\\' if chars.peek().is_some_and(|(_, next)| *next == '/') => {
+                letters.push('/');
+                chars.next();
+            }
+            '/' => return (letters, Some(&entry[idx + 1..])),
+            _ => letters.push(c),
+        }
+    }
+
+    (letters, None)
+}
+
+/// Hunspell uses `0` to denote an empty strip or append string.
+fn empty_if_zero(source: &str) -> &str {
+    if source == "0" { "" } else { source }
+}
+
+/// Read what we can from Hunspell morphological fields (like `po:noun` or `is:plural`).
+fn parse_morphology<'a>(fields: impl IntoIterator<Item = &'a str>) -> WordMetadata {
+    let mut metadata = WordMetadata::default();
+
+    for field in fields {
+        match field {
+            "po:noun" | "po:n" => metadata.noun = Some(metadata.noun.unwrap_or_default()),
+            "po:propn" | "po:pnoun" => {
+                metadata.noun = Some(NounData {
+                    is_proper: Some(true),
+                    ..metadata.noun.unwrap_or_default()
+                })
+            }
+            "po:pron" => {
+                metadata.noun = Some(NounData {
+                    is_pronoun: Some(true),
+                    ..metadata.noun.unwrap_or_default()
+                })
+            }
+            "po:verb" | "po:v" => metadata.verb = Some(metadata.verb.unwrap_or_default()),
+            "po:adj" | "po:adjective" => metadata.adjective = Some(Default::default()),
+            "po:adv" | "po:adverb" => metadata.adverb = Some(Default::default()),
+            "po:conj" => metadata.conjunction = Some(Default::default()),
+            "po:prep" => metadata.preposition = true,
+            "po:art" | "po:det" => metadata.article = true,
+            "is:plural" | "is:pl" => {
+                metadata.noun = Some(NounData {
+                    is_plural: Some(true),
+                    ..metadata.noun.unwrap_or_default()
+                })
+            }
+            "is:possessive" | "is:poss" => {
+                metadata.noun = Some(NounData {
+                    is_possessive: Some(true),
+                    ..metadata.noun.unwrap_or_default()
+                })
+            }
+            "is:past" => {
+                metadata.verb = Some(VerbData {
+                    tense: Some(Tense::Past),
+                    ..metadata.verb.unwrap_or_default()
+                })
+            }
+            _ => (),
+        }
+    }
+
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_hunspell;
+    use crate::CharString;
+
+    const AFF: &str = "SET UTF-8
+TRY esianrtolcdugmphbyfvkwzESIANRTOLCDUGMPHBYFVKWZ'
+
+PFX A Y 1
+PFX A   0     re         .
+
+SFX D Y 4
+SFX D   0     d          e
+SFX D   y     ied        [^aeiou]y
+SFX D   0     ed         [^ey]
+SFX D   0     ed         [aeiou]y
+
+SFX S Y 2
+SFX S   y     ies        [^aeiou]y  is:plural
+SFX S   0     s          [^y]       is:plural
+";
+
+    const DIC: &str = "4
+work/ADS po:verb
+try/D
+city/S po:noun
+and\\/or
+";
+
+    fn split(text: &str) -> CharString {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn expands_affixes() {
+        let words = parse_hunspell(DIC, AFF).unwrap();
+
+        for word in [
+            "work", "rework", "worked", "reworked", "works", "reworks", "try", "tried", "city",
+            "cities", "and/or",
+        ] {
+            assert!(words.contains_key(&split(word)), "Missing {word}");
+        }
+
+        assert!(!words.contains_key(&split("tryed")));
+    }
+
+    #[test]
+    fn reads_morphology() {
+        let words = parse_hunspell(DIC, AFF).unwrap();
+
+        assert!(words[&split("work")].is_verb());
+        assert!(words[&split("city")].is_noun());
+        assert!(words[&split("cities")].is_plural_noun());
+    }
+
+    #[test]
+    fn reads_long_flags_and_aliases() {
+        let aff = "FLAG long
+AF 2
+AF AaBb
+AF Bb
+
+PFX Aa Y 1
+PFX Aa 0 un .
+
+SFX Bb Y 1
+SFX Bb 0 s .
+";
+        let dic = "2\ndo/1\nlock/2\n";
+
+        let words = parse_hunspell(dic, aff).unwrap();
+
+        for word in ["do", "undo", "dos", "undos", "lock", "locks"] {
+            assert!(words.contains_key(&split(word)), "Missing {word}");
+        }
+
+        assert!(!words.contains_key(&split("unlock")));
+    }
+
+    #[test]
+    fn reads_numeric_flags() {
+        let aff = "FLAG num\nSFX 101 N 1\nSFX 101 0 ing .\n";
+        let dic = "1\nsing/101\n";
+
+        let words = parse_hunspell(dic, aff).unwrap();
+
+        assert!(words.contains_key(&split("singing")));
+    }
+}
@@ -12,6 +12,18 @@ pub enum Error {
     ExpectedUnsignedInteger,
     #[error("Could not parse because we encountered the end of the line.")]
     UnexpectedEndOfLine,
+    #[error("Unsupported `FLAG` type. Expected `long`, `num` or `UTF-8`.")]
+    UnknownFlagType,
+    #[error("An affix flag was malformed for the file's `FLAG` type.")]
+    MalformedFlag,
+    #[error("The file uses more distinct affix flags than can be represented.")]
+    TooManyFlags,
+    #[error("A word referenced a flag alias that was never defined.")]
+    UnknownFlagAlias,
+    #[error("The attribute list was not valid JSON.")]
+    MalformedAttributeList,
+    #[error("The curated word list has already been installed.")]
+    WordListAlreadyInstalled,
     #[error("An error occured with a condition: {0}")]
     Matcher(#[from] matcher::Error),
 }
@@ -23,9 +23,10 @@ impl Matcher {
 
             match c {
                 '[' => {
-                    let close_idx = source[idx..]
-                        .find(']')
-                        .ok_or(Error::UnmatchedBracket { index: idx })?;
+                    let close_idx = idx
+                        + source[idx..]
+                            .find(']')
+                            .ok_or(Error::UnmatchedBracket { index: idx })?;
 
                     let bracket_contents = &source[idx + 1..close_idx];
 
@@ -156,6 +157,15 @@ mod tests {
         assert!(matcher.matches(&['u']));
     }
 
+    #[test]
+    fn parses_bracket_after_literal() {
+        let matcher = Matcher::parse("a[^y]").unwrap();
+        assert_eq!(
+            matcher.operators,
+            vec![Operator::Literal('a'), Operator::MatchNone(vec!['y'])]
+        )
+    }
+
     #[test]
     fn round_trip() {
         let source = "[^aeiou]a.s";
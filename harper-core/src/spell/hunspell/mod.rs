@@ -1,5 +1,6 @@
 mod affix_replacement;
 mod attribute_list;
+mod dic_aff;
 mod error;
 mod expansion;
 mod matcher;
@@ -7,13 +8,54 @@ pub mod word_list;
 
 pub use attribute_list::AttributeList;
 use attribute_list::HumanReadableAttributeList;
+pub use dic_aff::parse_hunspell;
 pub use error::Error;
 
 pub use self::word_list::MarkedWord;
 use self::word_list::parse_word_list;
 
+#[cfg(not(feature = "embedded-dictionary"))]
+static DEFAULT_WORD_LIST: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// The curated word list, in the format of `dictionary.dict`.
+///
+/// Without the `embedded-dictionary` feature, this is `None` until the word list is provided with
+/// [`install_default_word_list`], and the curated dictionaries are empty until then.
+pub fn default_word_list() -> Option<&'static str> {
+    #[cfg(feature = "embedded-dictionary")]
+    return Some(include_str!("../../../dictionary.dict"));
+
+    #[cfg(not(feature = "embedded-dictionary"))]
+    DEFAULT_WORD_LIST.get().map(String::as_str)
+}
+
+/// Provide the curated word list at runtime, for builds without the `embedded-dictionary`
+/// feature, such as a web page that fetches `dictionary.dict` separately from the rest of Harper.
+///
+/// It can only be installed once.
+/// Curated dictionaries requested before this point are empty, so anything built from them
+/// should be rebuilt.
+#[cfg(not(feature = "embedded-dictionary"))]
+pub fn install_default_word_list(source: String) -> Result<(), Error> {
+    parse_word_list(&source)?;
+
+    DEFAULT_WORD_LIST
+        .set(source)
+        .map_err(|_| Error::WordListAlreadyInstalled)
+}
+
+/// Parse the curated word list, which is empty if it is not available yet (see
+/// [`default_word_list`]).
 pub fn parse_default_word_list() -> Result<Vec<MarkedWord>, Error> {
-    parse_word_list(include_str!("../../../dictionary.dict"))
+    default_word_list().map_or(Ok(Vec::new()), parse_word_list)
+}
+
+/// Parse an attribute list in the same JSON format as the curated `affixes.json`.
+pub fn parse_attribute_list(source: &str) -> Result<AttributeList, Error> {
+    let human_readable: HumanReadableAttributeList =
+        serde_json::from_str(source).map_err(|_| Error::MalformedAttributeList)?;
+
+    human_readable.into_normal()
 }
 
 pub fn parse_default_attribute_list() -> AttributeList {
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+/// The arrangement of keys on the user's keyboard, used to tell which typos are likely.
+///
+/// Hitting a key next to the intended one is one of the most common typing errors, so
+/// suggestions that only differ from a misspelling by such a slip are ranked higher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Qwertz,
+    Azerty,
+    Dvorak,
+    Colemak,
+}
+
+/// How far each row of keys is shifted to the right of the one above it, in key widths.
+const ROW_OFFSETS: [f32; 3] = [0.0, 0.25, 0.75];
+
+/// The furthest apart (in key widths) the centers of two keys can be while still counting as
+/// adjacent. Large enough to include the diagonal neighbors on staggered rows.
+const MAX_ADJACENT_DISTANCE: f32 = 1.3;
+
+impl KeyboardLayout {
+    /// The letter rows of the layout, from top to bottom.
+    fn rows(self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Qwertz => ["qwertzuiop", "asdfghjkl", "yxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Dvorak => ["',.pyfgcrl", "aoeuidhtns", ";qjkxbmwvz"],
+            KeyboardLayout::Colemak => ["qwfpgjluy;", "arstdhneio", "zxcvbkm"],
+        }
+    }
+
+    /// Where the center of a key is, in key widths.
+    fn key_position(self, key: char) -> Option<(f32, f32)> {
+        let key = key.to_ascii_lowercase();
+
+        self.rows()
+            .iter()
+            .zip(ROW_OFFSETS)
+            .enumerate()
+            .find_map(|(y, (row, offset))| {
+                let x = row.chars().position(|c| c == key)?;
+                Some((x as f32 + offset, y as f32))
+            })
+    }
+
+    /// Whether two different keys are next to each other, including diagonally.
+    pub fn are_adjacent(self, a: char, b: char) -> bool {
+        let (Some((ax, ay)), Some((bx, by))) = (self.key_position(a), self.key_position(b)) else {
+            return false;
+        };
+
+        let distance = ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt();
+
+        distance > 0.0 && distance <= MAX_ADJACENT_DISTANCE
+    }
+
+    /// An edit distance between a misspelling and a candidate correction where substituting
+    /// one key for an adjacent one costs half as much as any other edit.
+    ///
+    /// Leaving out (or adding) an apostrophe is just as common a slip, so it is also discounted.
+    ///
+    /// Measured in half-edits, so a single ordinary edit has a cost of two.
+    /// Letter case is ignored.
+    pub fn typo_distance(self, misspelled: &[char], candidate: &[char]) -> u16 {
+        let indel_cost = |c: char| if matches!(c, '\'' | '’') { 1 } else { 2 };
+
+        let mut previous_row = Vec::with_capacity(misspelled.len() + 1);
+        previous_row.push(0);
+        for source in misspelled {
+            previous_row.push(previous_row.last().unwrap() + indel_cost(*source));
+        }
+        let mut current_row = vec![0; misspelled.len() + 1];
+
+        for target in candidate {
+            current_row[0] = previous_row[0] + indel_cost(*target);
+
+            for (i, source) in misspelled.iter().enumerate() {
+                let cost = if source.to_lowercase().eq(target.to_lowercase()) {
+                    0
+                } else if self.are_adjacent(*source, *target) {
+                    1
+                } else {
+                    2
+                };
+
+                current_row[i + 1] = (previous_row[i + 1] + indel_cost(*target))
+                    .min(current_row[i] + indel_cost(*source))
+                    .min(previous_row[i] + cost);
+            }
+
+            std::mem::swap(&mut previous_row, &mut current_row);
+        }
+
+        previous_row[misspelled.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardLayout;
+
+    fn split(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn qwerty_neighbors() {
+        let layout = KeyboardLayout::Qwerty;
+
+        assert!(layout.are_adjacent('e', 'w'));
+        assert!(layout.are_adjacent('g', 'B'));
+        assert!(layout.are_adjacent('s', 'x'));
+        assert!(!layout.are_adjacent('q', 'p'));
+        assert!(!layout.are_adjacent('a', 'a'));
+        assert!(!layout.are_adjacent('1', '2'));
+    }
+
+    #[test]
+    fn layouts_differ() {
+        assert!(!KeyboardLayout::Qwerty.are_adjacent('t', 'z'));
+        assert!(KeyboardLayout::Qwertz.are_adjacent('t', 'z'));
+        assert!(KeyboardLayout::Dvorak.are_adjacent('o', 'e'));
+    }
+
+    #[test]
+    fn adjacent_substitutions_are_cheaper() {
+        let layout = KeyboardLayout::Qwerty;
+
+        assert_eq!(layout.typo_distance(&split("hwllo"), &split("hello")), 1);
+        assert_eq!(layout.typo_distance(&split("hpllo"), &split("hello")), 2);
+        assert_eq!(layout.typo_distance(&split("helo"), &split("hello")), 2);
+        assert_eq!(layout.typo_distance(&split("Hello"), &split("hello")), 0);
+        assert_eq!(layout.typo_distance(&split("Im"), &split("I'm")), 1);
+    }
+}
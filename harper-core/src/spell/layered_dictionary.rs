@@ -0,0 +1,346 @@
+use std::sync::Arc;
+
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+use super::{FuzzyMatchResult, dictionary::Dictionary};
+use crate::{CharString, CharStringExt, WordMetadata};
+
+/// A stack of dictionaries, where each layer takes precedence over those beneath it.
+///
+/// Unlike [`super::MergedDictionary`], which combines the metadata of every dictionary that
+/// contains a word, the topmost layer that knows about a word decides everything about it:
+/// its metadata, its capitalization, and whether it is allowed at all.
+/// A layer can forbid words, hiding them from every layer beneath it.
+///
+/// A typical stack is the curated dictionary at the bottom, followed by workspace and user
+/// dictionaries.
+#[derive(Clone, Default)]
+pub struct LayeredDictionary {
+    /// Ordered from the bottom of the stack to the top.
+    layers: Vec<DictionaryLayer>,
+}
+
+/// One level of a [`LayeredDictionary`].
+#[derive(Clone)]
+pub struct DictionaryLayer {
+    dictionary: Arc<dyn Dictionary>,
+    /// Lowercase versions of the words this layer forbids.
+    forbidden: HashSet<CharString>,
+}
+
+impl DictionaryLayer {
+    pub fn new(dictionary: Arc<dyn Dictionary>) -> Self {
+        Self {
+            dictionary,
+            forbidden: HashSet::new(),
+        }
+    }
+
+    /// Forbid words (in any capitalization), overriding any layers beneath this one.
+    pub fn with_forbidden_words(
+        mut self,
+        words: impl IntoIterator<Item = impl AsRef<[char]>>,
+    ) -> Self {
+        self.forbidden.extend(
+            words
+                .into_iter()
+                .map(|word| word.as_ref().to_lower().into_owned().into()),
+        );
+        self
+    }
+
+    /// Whether this layer has an opinion on a word, and if so, whether it allows it.
+    fn verdict(&self, lowercase: &[char], word: &[char]) -> Option<bool> {
+        if self.forbidden.contains(lowercase) {
+            Some(false)
+        } else if self.dictionary.contains_word(word) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+impl LayeredDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer on top of the existing ones.
+    pub fn push_layer(&mut self, layer: DictionaryLayer) {
+        self.layers.push(layer);
+    }
+
+    /// Add a dictionary on top of the existing layers.
+    pub fn push_dictionary(&mut self, dictionary: Arc<dyn Dictionary>) {
+        self.push_layer(DictionaryLayer::new(dictionary));
+    }
+
+    pub fn with_layer(mut self, layer: DictionaryLayer) -> Self {
+        self.push_layer(layer);
+        self
+    }
+
+    pub fn with_dictionary(mut self, dictionary: Arc<dyn Dictionary>) -> Self {
+        self.push_dictionary(dictionary);
+        self
+    }
+
+    /// Whether a word has been forbidden by a layer above any that contain it.
+    pub fn is_forbidden(&self, word: &[char]) -> bool {
+        matches!(self.verdict(word), Some(false))
+    }
+
+    /// Whether the two stacks hold the same words, layer for layer.
+    ///
+    /// Unlike `==`, which only checks that the layers are the same dictionaries, this compares
+    /// what is in them.
+    /// It is slower, but also recognizes dictionaries that were loaded from the same file twice.
+    pub fn has_same_words(&self, other: &Self) -> bool {
+        self.layers.len() == other.layers.len()
+            && self.layers.iter().zip(&other.layers).all(|(a, b)| {
+                a.forbidden == b.forbidden
+                    && (Arc::ptr_eq(&a.dictionary, &b.dictionary)
+                        || (a.dictionary.word_count() == b.dictionary.word_count()
+                            && a.dictionary.words_iter().all(|word| {
+                                b.dictionary.contains_exact_word(word)
+                                    && a.dictionary.get_word_metadata(word)
+                                        == b.dictionary.get_word_metadata(word)
+                            })))
+            })
+    }
+
+    /// The decision of the topmost layer with an opinion on a word.
+    fn verdict(&self, word: &[char]) -> Option<bool> {
+        let lowercase = word.to_lower();
+
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.verdict(&lowercase, word))
+    }
+
+    /// The topmost layer that contains a word, if it isn't forbidden.
+    fn deciding_dictionary(&self, word: &[char]) -> Option<&dyn Dictionary> {
+        let lowercase = word.to_lower();
+
+        for layer in self.layers.iter().rev() {
+            match layer.verdict(&lowercase, word) {
+                Some(true) => return Some(layer.dictionary.as_ref()),
+                Some(false) => return None,
+                None => (),
+            }
+        }
+
+        None
+    }
+}
+
+impl PartialEq for LayeredDictionary {
+    fn eq(&self, other: &Self) -> bool {
+        self.layers.len() == other.layers.len()
+            && self.layers.iter().zip(&other.layers).all(|(a, b)| {
+                Arc::ptr_eq(&a.dictionary, &b.dictionary) && a.forbidden == b.forbidden
+            })
+    }
+}
+
+impl Dictionary for LayeredDictionary {
+    fn contains_word(&self, word: &[char]) -> bool {
+        matches!(self.verdict(word), Some(true))
+    }
+
+    fn contains_word_str(&self, word: &str) -> bool {
+        let chars: CharString = word.chars().collect();
+        self.contains_word(&chars)
+    }
+
+    fn contains_exact_word(&self, word: &[char]) -> bool {
+        self.deciding_dictionary(word)
+            .is_some_and(|dict| dict.contains_exact_word(word))
+    }
+
+    fn contains_exact_word_str(&self, word: &str) -> bool {
+        let chars: CharString = word.chars().collect();
+        self.contains_exact_word(&chars)
+    }
+
+    fn fuzzy_match(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        self.layers
+            .iter()
+            .flat_map(|layer| {
+                layer
+                    .dictionary
+                    .fuzzy_match(word, max_distance, max_results)
+            })
+            .filter(|result| self.contains_word(result.word))
+            .sorted_by_key(FuzzyMatchResult::rank_key)
+            .unique_by(|result| result.word)
+            .take(max_results)
+            .map(|result| FuzzyMatchResult {
+                metadata: self.get_word_metadata(result.word).unwrap_or_default(),
+                ..result
+            })
+            .collect()
+    }
+
+    fn fuzzy_match_str(
+        &self,
+        word: &str,
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let chars: CharString = word.chars().collect();
+        self.fuzzy_match(&chars, max_distance, max_results)
+    }
+
+    fn get_correct_capitalization_of(&self, word: &[char]) -> Option<&'_ [char]> {
+        self.deciding_dictionary(word)?
+            .get_correct_capitalization_of(word)
+    }
+
+    fn get_word_metadata(&self, word: &[char]) -> Option<WordMetadata> {
+        self.deciding_dictionary(word)?.get_word_metadata(word)
+    }
+
+    fn get_word_metadata_str(&self, word: &str) -> Option<WordMetadata> {
+        let chars: CharString = word.chars().collect();
+        self.get_word_metadata(&chars)
+    }
+
+    fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        Box::new(
+            self.layers
+                .iter()
+                .flat_map(|layer| layer.dictionary.words_iter())
+                .filter(|word| self.contains_word(word))
+                .unique(),
+        )
+    }
+
+    fn word_count(&self) -> usize {
+        self.words_iter().count()
+    }
+
+    fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        Box::new(
+            self.layers
+                .iter()
+                .flat_map(move |layer| layer.dictionary.words_with_len_iter(len))
+                .filter(|word| self.contains_word(word))
+                .unique(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{DictionaryLayer, LayeredDictionary};
+    use crate::{CharString, Dictionary, FstDictionary, MutableDictionary, WordMetadata};
+
+    fn split(text: &str) -> CharString {
+        text.chars().collect()
+    }
+
+    fn dict_of(words: &[(&str, WordMetadata)]) -> Arc<MutableDictionary> {
+        let mut dict = MutableDictionary::new();
+        dict.extend_words(words.iter().map(|(word, meta)| (split(word), *meta)));
+        Arc::new(dict)
+    }
+
+    #[test]
+    fn later_layers_override_metadata() {
+        let noun = WordMetadata {
+            noun: Some(Default::default()),
+            ..Default::default()
+        };
+        let verb = WordMetadata {
+            verb: Some(Default::default()),
+            ..Default::default()
+        };
+
+        let dict = LayeredDictionary::new()
+            .with_dictionary(dict_of(&[("blorb", noun)]))
+            .with_dictionary(dict_of(&[("blorb", verb)]));
+
+        let metadata = dict.get_word_metadata_str("blorb").unwrap();
+        assert!(metadata.is_verb());
+        assert!(!metadata.is_noun());
+    }
+
+    #[test]
+    fn later_layers_forbid_words() {
+        let dict = LayeredDictionary::new()
+            .with_dictionary(FstDictionary::curated())
+            .with_layer(
+                DictionaryLayer::new(Arc::new(MutableDictionary::new()))
+                    .with_forbidden_words([split("irregardless")]),
+            );
+
+        assert!(dict.is_forbidden(&split("Irregardless")));
+        assert!(!dict.contains_word_str("irregardless"));
+        assert!(dict.get_word_metadata_str("irregardless").is_none());
+        assert!(dict.contains_word_str("regardless"));
+        assert!(
+            dict.fuzzy_match_str("irregardles", 2, 10)
+                .iter()
+                .all(|result| result.word != split("irregardless").as_slice())
+        );
+    }
+
+    #[test]
+    fn later_layers_can_allow_forbidden_words() {
+        let dict = LayeredDictionary::new()
+            .with_layer(
+                DictionaryLayer::new(Arc::new(MutableDictionary::new()))
+                    .with_forbidden_words([split("blorb")]),
+            )
+            .with_dictionary(dict_of(&[("blorb", WordMetadata::default())]));
+
+        assert!(!dict.is_forbidden(&split("blorb")));
+        assert!(dict.contains_word_str("blorb"));
+    }
+
+    #[test]
+    fn words_are_not_repeated() {
+        let dict = LayeredDictionary::new()
+            .with_dictionary(dict_of(&[("one", WordMetadata::default())]))
+            .with_dictionary(dict_of(&[
+                ("one", WordMetadata::default()),
+                ("two", WordMetadata::default()),
+            ]));
+
+        assert_eq!(dict.word_count(), 2);
+        assert_eq!(dict.words_with_len_iter(3).count(), 2);
+    }
+
+    #[test]
+    fn compares_words_rather_than_dictionaries() {
+        let curated = FstDictionary::curated();
+        let layered = |words: &[&str]| {
+            let words: Vec<_> = words
+                .iter()
+                .map(|w| (*w, WordMetadata::default()))
+                .collect();
+            LayeredDictionary::new()
+                .with_dictionary(curated.clone())
+                .with_dictionary(dict_of(&words))
+        };
+
+        let a = layered(&["blorb", "florp"]);
+        let b = layered(&["florp", "blorb"]);
+
+        assert!(a != b);
+        assert!(a.has_same_words(&b));
+        assert!(!a.has_same_words(&layered(&["blorb"])));
+        assert!(!a.has_same_words(&layered(&["blorb", "glorp"])));
+    }
+}
@@ -118,7 +118,7 @@ impl Dictionary for MergedDictionary {
         self.children
             .iter()
             .flat_map(|d| d.fuzzy_match(word, max_distance, max_results))
-            .sorted_by_key(|r| r.edit_distance)
+            .sorted_by_key(FuzzyMatchResult::rank_key)
             .take(max_results)
             .collect()
     }
@@ -132,7 +132,7 @@ impl Dictionary for MergedDictionary {
         self.children
             .iter()
             .flat_map(|d| d.fuzzy_match_str(word, max_distance, max_results))
-            .sorted_by_key(|r| r.edit_distance)
+            .sorted_by_key(FuzzyMatchResult::rank_key)
             .take(max_results)
             .collect()
     }
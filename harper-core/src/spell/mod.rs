@@ -2,24 +2,39 @@ use std::borrow::Cow;
 
 use itertools::{Itertools, MinMaxResult};
 
+use crate::normalization::normalize_word;
 use crate::{CharString, CharStringExt, WordMetadata};
 
 pub use self::dictionary::Dictionary;
+pub use self::diff::{DictionaryDiff, MetadataChange};
+pub use self::export::{HunspellExport, export_hunspell, export_word_list};
 pub use self::fst_dictionary::FstDictionary;
+pub use self::keyboard::KeyboardLayout;
+pub use self::layered_dictionary::{DictionaryLayer, LayeredDictionary};
 pub use self::merged_dictionary::MergedDictionary;
 pub use self::mutable_dictionary::MutableDictionary;
+pub use self::user_dictionary::UserDictionary;
 
 mod dictionary;
+mod diff;
+mod export;
 mod fst_dictionary;
 pub mod hunspell;
+mod keyboard;
+mod layered_dictionary;
 mod merged_dictionary;
 mod mutable_dictionary;
+mod phonetic;
+mod user_dictionary;
+mod word_frequency;
 
 #[derive(PartialEq, Debug, Hash, Eq)]
 pub struct FuzzyMatchResult<'a> {
     pub word: &'a [char],
     pub edit_distance: u8,
     pub metadata: WordMetadata,
+    /// Whether the word sounds like the one being matched against.
+    pub phonetic: bool,
 }
 
 impl PartialOrd for FuzzyMatchResult<'_> {
@@ -28,8 +43,35 @@ impl PartialOrd for FuzzyMatchResult<'_> {
     }
 }
 
+impl FuzzyMatchResult<'_> {
+    /// How far the word is from the one being matched against, where lower is closer.
+    ///
+    /// Words that sound alike are easily confused even when they are spelled very differently
+    /// (like "fizikal" and "physical"), so phonetic matches count for half their edit distance.
+    pub fn score(&self) -> u8 {
+        if self.phonetic {
+            (self.edit_distance / 2).max(1)
+        } else {
+            self.edit_distance
+        }
+    }
+
+    /// Orders results by [`Self::score`], preferring more common words when the scores are
+    /// equal.
+    pub(crate) fn rank_key(&self) -> (u8, u32) {
+        (
+            self.score(),
+            self.metadata.frequency_rank.unwrap_or(u32::MAX),
+        )
+    }
+}
+
 /// Order the suggestions to be shown to the user.
-fn order_suggestions(matches: Vec<FuzzyMatchResult>) -> Vec<&[char]> {
+fn order_suggestions<'a>(
+    misspelled_word: &[char],
+    matches: Vec<FuzzyMatchResult<'a>>,
+    layout: KeyboardLayout,
+) -> Vec<&'a [char]> {
     let mut found: Vec<&FuzzyMatchResult> = Vec::with_capacity(matches.len());
     // Often the longest and the shortest words are the most helpful, so let's push
     // them first.
@@ -67,26 +109,65 @@ fn order_suggestions(matches: Vec<FuzzyMatchResult>) -> Vec<&[char]> {
         found.swap(2, noun_index + 3);
     }
 
-    // Make commonality relevant
-    found.sort_by_key(|fmr| if fmr.metadata.common { 0 } else { 1 });
+    // Make commonality relevant, then put likely typos first among those with the same edit
+    // distance, such as those that only differ by a neighboring key. Ties go to the more
+    // frequently used word.
+    found.sort_by_cached_key(|fmr| {
+        let (score, frequency_rank) = fmr.rank_key();
+
+        let typo_distance = if fmr.phonetic {
+            // Sound-alikes are likely mistakes even when the keys are far apart.
+            u16::from(score) * 2
+        } else {
+            layout.typo_distance(misspelled_word, fmr.word)
+        };
+
+        (!fmr.metadata.common, score, typo_distance, frequency_rank)
+    });
 
-    found.into_iter().map(|fmr| fmr.word).collect()
+    // Capitalizations of the same word share a rank, so only keep the first to leave room for
+    // other words.
+    found
+        .into_iter()
+        .unique_by(|fmr| fmr.word.to_lower())
+        .map(|fmr| fmr.word)
+        .collect()
 }
 
 /// Get the closest matches in the provided [`Dictionary`] and rank them
 /// Implementation is left up to the underlying dictionary.
+///
+/// Typos are assumed to have been made on a [`KeyboardLayout::Qwerty`] keyboard.
 pub fn suggest_correct_spelling<'a>(
     misspelled_word: &[char],
     result_limit: usize,
     max_edit_dist: u8,
     dictionary: &'a impl Dictionary,
+) -> Vec<&'a [char]> {
+    suggest_correct_spelling_for_layout(
+        misspelled_word,
+        result_limit,
+        max_edit_dist,
+        dictionary,
+        KeyboardLayout::default(),
+    )
+}
+
+/// Like [`suggest_correct_spelling`], but ranks the matches assuming they were typed on the
+/// given [`KeyboardLayout`].
+pub fn suggest_correct_spelling_for_layout<'a>(
+    misspelled_word: &[char],
+    result_limit: usize,
+    max_edit_dist: u8,
+    dictionary: &'a impl Dictionary,
+    layout: KeyboardLayout,
 ) -> Vec<&'a [char]> {
     let matches: Vec<FuzzyMatchResult> = dictionary
         .fuzzy_match(misspelled_word, max_edit_dist, result_limit)
         .into_iter()
         .collect();
 
-    order_suggestions(matches)
+    order_suggestions(misspelled_word, matches, layout)
 }
 
 /// Convenience function over [`suggest_correct_spelling`] that does conversions
@@ -107,10 +188,12 @@ pub fn suggest_correct_spelling_str(
 /// Convert a given character sequence to the standard character set
 /// the dictionary is in.
 fn seq_to_normalized(seq: &[char]) -> Cow<'_, [char]> {
+    let seq = normalize_word(seq);
+
     if seq.iter().any(|c| char_to_normalized(*c) != *c) {
         Cow::Owned(seq.iter().copied().map(char_to_normalized).collect())
     } else {
-        Cow::Borrowed(seq)
+        seq
     }
 }
 
@@ -127,11 +210,12 @@ fn char_to_normalized(c: char) -> char {
 mod tests {
     use itertools::Itertools;
 
+    use crate::WordMetadata;
     use crate::spell::FuzzyMatchResult;
 
     use super::{
-        Dictionary, FstDictionary, MutableDictionary, order_suggestions, seq_to_normalized,
-        suggest_correct_spelling_str,
+        Dictionary, FstDictionary, KeyboardLayout, MutableDictionary, order_suggestions,
+        seq_to_normalized, suggest_correct_spelling_str,
     };
 
     const RESULT_LIMIT: usize = 100;
@@ -171,6 +255,7 @@ mod tests {
                         word,
                         edit_distance: 0,
                         metadata,
+                        phonetic: false,
                     })
                 } else {
                     None
@@ -189,6 +274,7 @@ mod tests {
                         word,
                         edit_distance: 0,
                         metadata,
+                        phonetic: false,
                     })
                 }
             })
@@ -196,7 +282,7 @@ mod tests {
         // Feed the common and uncommon words into the ordering function, starting with uncommon
         // words
         let words = uncommon_words.merge(common_words).collect();
-        let suggestions = order_suggestions(words);
+        let suggestions = order_suggestions(&[], words, KeyboardLayout::default());
 
         // Asserts that the ordering prioritizes common words
         let common_first = suggestions
@@ -207,6 +293,82 @@ mod tests {
         assert!(common_first);
     }
 
+    #[test]
+    fn frequent_words_first() {
+        let ranked = |word, frequency_rank| FuzzyMatchResult {
+            word,
+            edit_distance: 1,
+            metadata: WordMetadata {
+                frequency_rank,
+                ..Default::default()
+            },
+            phonetic: false,
+        };
+
+        let suggestions = order_suggestions(
+            &['c', 'a', 't'],
+            vec![
+                ranked(&['b', 'a', 't'], None),
+                ranked(&['c', 'a', 't', 's'], Some(900)),
+                ranked(&['c', 'a', 'r'], Some(300)),
+            ],
+            KeyboardLayout::default(),
+        );
+
+        assert_eq!(
+            suggestions,
+            vec![
+                &['c', 'a', 'r'][..],
+                &['c', 'a', 't', 's'],
+                &['b', 'a', 't']
+            ]
+        );
+    }
+
+    #[test]
+    fn neighboring_keys_first() {
+        let result = |word, frequency_rank| FuzzyMatchResult {
+            word,
+            edit_distance: 1,
+            metadata: WordMetadata {
+                frequency_rank,
+                ..Default::default()
+            },
+            phonetic: false,
+        };
+
+        let hello = ['h', 'e', 'l', 'l', 'o'];
+        let jello = ['j', 'e', 'l', 'l', 'o'];
+        let matches = || vec![result(&jello, Some(100)), result(&hello, Some(900))];
+
+        // "w" is next to "e" on a QWERTY keyboard, but "k" is next to "j".
+        let misspelled = ['h', 'w', 'l', 'l', 'o'];
+        assert_eq!(
+            order_suggestions(&misspelled, matches(), KeyboardLayout::Qwerty)[0],
+            hello
+        );
+
+        let misspelled = ['k', 'e', 'l', 'l', 'o'];
+        assert_eq!(
+            order_suggestions(&misspelled, matches(), KeyboardLayout::Qwerty)[0],
+            jello
+        );
+    }
+
+    #[test]
+    fn phonetic_correction() {
+        let results = suggest_correct_spelling_str(
+            "fizikal",
+            RESULT_LIMIT,
+            MAX_EDIT_DIST,
+            &FstDictionary::curated(),
+        );
+
+        dbg!(&results);
+
+        assert!(results.iter().take(3).contains(&"physical".to_string()));
+    }
+
     #[test]
     fn this_correction() {
         let results = suggest_correct_spelling_str(
@@ -1,6 +1,7 @@
 use super::{
-    hunspell::{parse_default_attribute_list, parse_default_word_list},
+    hunspell::{self, parse_default_attribute_list, parse_default_word_list},
     seq_to_normalized,
+    word_frequency::apply_default_frequency_ranks,
 };
 use crate::edit_distance::edit_distance_min_alloc;
 use hashbrown::HashMap;
@@ -55,6 +56,7 @@ fn uncached_inner_new() -> Arc<MutableDictionary> {
     let mut word_map = HashMap::with_capacity(word_list.len());
 
     attr_list.expand_marked_words(word_list, &mut word_map);
+    apply_default_frequency_ranks(&mut word_map);
 
     let mut words: Vec<CharString> = word_map.iter().map(|(v, _)| v.clone()).collect();
 
@@ -92,10 +94,26 @@ impl MutableDictionary {
     /// Create a dictionary from the curated dictionary included
     /// in the Harper binary.
     /// Consider using [`super::FstDictionary::curated()`] instead, as it is more performant for spellchecking.
+    ///
+    /// Without the `embedded-dictionary` feature, this is empty until the word list is installed
+    /// with [`hunspell::install_default_word_list`].
     pub fn curated() -> Arc<Self> {
+        if hunspell::default_word_list().is_none() {
+            return Arc::new(Self::new());
+        }
+
         (*DICT).clone()
     }
 
+    /// Create a dictionary from a Hunspell `.dic` file and its accompanying `.aff` file.
+    ///
+    /// See [`hunspell::parse_hunspell`] for what is and isn't supported.
+    pub fn from_hunspell(dic: &str, aff: &str) -> Result<Self, hunspell::Error> {
+        let mut dict = Self::new();
+        dict.extend_words(hunspell::parse_hunspell(dic, aff)?);
+        Ok(dict)
+    }
+
     /// Appends words to the dictionary.
     /// It is significantly faster to append many words with one call than many
     /// distinct calls to this function.
@@ -137,6 +155,24 @@ impl MutableDictionary {
         self.append_word(word.chars().collect::<Vec<_>>(), metadata)
     }
 
+    /// Remove a word (in its exact capitalization) from the dictionary.
+    ///
+    /// Returns whether the word was present.
+    pub fn remove_word(&mut self, word: impl AsRef<[char]>) -> bool {
+        let word = word.as_ref();
+
+        if self.word_map.remove(word).is_none() {
+            return false;
+        }
+
+        self.words.retain(|w| w.as_slice() != word);
+        self.word_len_starts = Self::create_len_starts(&self.words);
+
+        self.word_map_lowercase.remove(word.to_lower().as_ref());
+
+        true
+    }
+
     /// Create a lookup table for finding words of a specific length in a word
     /// list.
     fn create_len_starts(words: &[CharString]) -> Vec<usize> {
@@ -245,13 +281,15 @@ impl Dictionary for MutableDictionary {
                     None
                 }
             })
-            .sorted_unstable_by_key(|a| a.1)
-            .take(max_results)
             .map(|(word, edit_distance)| FuzzyMatchResult {
                 word,
                 edit_distance,
                 metadata: self.get_word_metadata(word).unwrap(),
+                phonetic: false,
             })
+            // Break ties by the word itself, so the results don't depend on the order of the word map.
+            .sorted_unstable_by_key(|result| (result.rank_key(), result.word))
+            .take(max_results)
             .collect()
     }
 
@@ -314,6 +352,19 @@ mod tests {
         assert!(words_with_same_len.contains(&&word[..]));
     }
 
+    #[test]
+    fn removes_words() {
+        let mut dict = MutableDictionary::new();
+        dict.append_word_str("Harper", Default::default());
+        dict.append_word_str("lint", Default::default());
+
+        assert!(!dict.remove_word(['h', 'a', 'r', 'p', 'e', 'r']));
+        assert!(dict.remove_word(['H', 'a', 'r', 'p', 'e', 'r']));
+        assert!(!dict.contains_word_str("harper"));
+        assert_eq!(dict.words_with_len_iter(4).count(), 1);
+        assert_eq!(dict.word_count(), 1);
+    }
+
     #[test]
     fn curated_contains_no_duplicates() {
         let dict = MutableDictionary::curated();
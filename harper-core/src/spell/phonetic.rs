@@ -0,0 +1,191 @@
+use hashbrown::HashMap;
+
+/// Codes shorter than this match too many words to say anything useful about how a word
+/// sounds, so they aren't indexed.
+pub(crate) const MIN_CODE_LEN: usize = 3;
+
+/// Maps the [`metaphone`] code of each word in a dictionary to the indices of the words with
+/// that code.
+#[derive(Debug, Default)]
+pub(crate) struct PhoneticIndex {
+    codes: HashMap<String, Vec<u32>>,
+}
+
+impl PhoneticIndex {
+    pub fn new<'a>(words: impl IntoIterator<Item = &'a [char]>) -> Self {
+        let mut codes: HashMap<String, Vec<u32>> = HashMap::new();
+
+        for (index, word) in words.into_iter().enumerate() {
+            let code = metaphone(word);
+
+            if code.len() >= MIN_CODE_LEN {
+                codes.entry(code).or_default().push(index as u32);
+            }
+        }
+
+        Self { codes }
+    }
+
+    /// The indices of the words that sound like `code`.
+    pub fn get(&self, code: &str) -> &[u32] {
+        self.codes.get(code).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Encode a word by how it is pronounced, using (a slightly simplified version of) Lawrence
+/// Philips' original Metaphone algorithm.
+///
+/// Words that sound alike, like "fizikal" and "physical", produce the same code.
+/// Anything other than ASCII letters is ignored.
+pub(crate) fn metaphone(word: &[char]) -> String {
+    let mut letters: Vec<char> = word
+        .iter()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    // Doubled letters sound the same as single ones, except for "cc" (as in "accent").
+    letters.dedup_by(|a, b| a == b && *a != 'c');
+
+    // Some leading letters are silent.
+    match letters.as_slice() {
+        ['k', 'n', ..] | ['g', 'n', ..] | ['p', 'n', ..] | ['a', 'e', ..] | ['w', 'r', ..] => {
+            letters.remove(0);
+        }
+        ['x', ..] => letters[0] = 's',
+        ['w', 'h', ..] => {
+            letters.remove(1);
+        }
+        _ => (),
+    }
+
+    let is_vowel = |c: Option<&char>| matches!(c, Some('a' | 'e' | 'i' | 'o' | 'u'));
+    let is_front_vowel = |c: Option<&char>| matches!(c, Some('e' | 'i' | 'y'));
+
+    let mut code = String::with_capacity(letters.len());
+
+    for (i, &c) in letters.iter().enumerate() {
+        let prev = i.checked_sub(1).and_then(|i| letters.get(i));
+        let next = letters.get(i + 1);
+        let after_next = letters.get(i + 2);
+
+        match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => {
+                if i == 0 {
+                    code.push('A');
+                }
+            }
+            'b' => {
+                // Silent in words ending with "mb", like "dumb".
+                if !(prev == Some(&'m') && next.is_none()) {
+                    code.push('B');
+                }
+            }
+            'c' => {
+                if next == Some(&'i') && after_next == Some(&'a') {
+                    code.push('X');
+                } else if next == Some(&'h') {
+                    code.push(if prev == Some(&'s') { 'K' } else { 'X' });
+                } else if is_front_vowel(next) {
+                    if prev != Some(&'s') {
+                        code.push('S');
+                    }
+                } else {
+                    code.push('K');
+                }
+            }
+            'd' => {
+                if next == Some(&'g') && is_front_vowel(after_next) {
+                    code.push('J');
+                } else {
+                    code.push('T');
+                }
+            }
+            'g' => {
+                let silent_gh = next == Some(&'h') && !is_vowel(after_next);
+                let silent_gn = next == Some(&'n')
+                    && (after_next.is_none() || letters.get(i + 2..) == Some(&['e', 'd'][..]));
+                let soft = is_front_vowel(next) && prev != Some(&'g');
+
+                if silent_gh || silent_gn || (prev == Some(&'d') && is_front_vowel(next)) {
+                } else if soft {
+                    code.push('J');
+                } else {
+                    code.push('K');
+                }
+            }
+            'h' => {
+                let after_modifier = matches!(prev, Some('c' | 's' | 'p' | 't' | 'g'));
+
+                if !after_modifier && (is_vowel(next) || !is_vowel(prev)) && next.is_some() {
+                    code.push('H');
+                }
+            }
+            'k' => {
+                if prev != Some(&'c') {
+                    code.push('K');
+                }
+            }
+            'p' => code.push(if next == Some(&'h') { 'F' } else { 'P' }),
+            'q' => code.push('K'),
+            's' => {
+                if next == Some(&'h')
+                    || (next == Some(&'i') && matches!(after_next, Some('o' | 'a')))
+                {
+                    code.push('X');
+                } else {
+                    code.push('S');
+                }
+            }
+            't' => {
+                if next == Some(&'i') && matches!(after_next, Some('o' | 'a')) {
+                    code.push('X');
+                } else if next == Some(&'h') {
+                    code.push('0');
+                } else if !(next == Some(&'c') && after_next == Some(&'h')) {
+                    code.push('T');
+                }
+            }
+            'v' => code.push('F'),
+            'w' | 'y' => {
+                if is_vowel(next) {
+                    code.push(c.to_ascii_uppercase());
+                }
+            }
+            'x' => code.push_str("KS"),
+            'z' => code.push('S'),
+            _ => code.push(c.to_ascii_uppercase()),
+        }
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::metaphone;
+
+    fn code(word: &str) -> String {
+        metaphone(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn sound_alikes_match() {
+        for (a, b) in [
+            ("fizikal", "physical"),
+            ("nite", "night"),
+            ("nolij", "knowledge"),
+            ("foto", "photo"),
+            ("thru", "through"),
+            ("rite", "write"),
+        ] {
+            assert_eq!(code(a), code(b), "{a} and {b} should sound alike");
+        }
+    }
+
+    #[test]
+    fn different_sounds_differ() {
+        assert_ne!(code("physical"), code("musical"));
+        assert_ne!(code("cat"), code("cut's"));
+    }
+}
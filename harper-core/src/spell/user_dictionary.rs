@@ -0,0 +1,242 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::{Dictionary, FuzzyMatchResult, LayeredDictionary, MutableDictionary};
+use crate::{CharString, WordMetadata};
+
+/// A dictionary of words the user has chosen to accept, like those added through an editor's
+/// "Add to dictionary" action.
+///
+/// Words can be added and removed at runtime and are saved as a plain, line-separated list, so
+/// the same file can be shared between Harper's integrations.
+/// Use [`Self::layered_over`] to check text against both the user's words and another
+/// dictionary (usually [`super::FstDictionary::curated()`]).
+#[derive(Debug, Clone, Default)]
+pub struct UserDictionary {
+    /// Where the dictionary is saved, if anywhere.
+    path: Option<PathBuf>,
+    words: MutableDictionary,
+}
+
+impl UserDictionary {
+    /// Create an empty dictionary that only lives in memory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The platform-appropriate location of the user dictionary shared by Harper's
+    /// integrations (for example, `~/.config/harper-ls/dictionary.txt` on Linux).
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("harper-ls/dictionary.txt"))
+    }
+
+    /// Load the dictionary saved at `path`.
+    /// If nothing has been saved there yet, the dictionary starts out empty.
+    ///
+    /// Changes are written back to `path` by [`Self::save`].
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+
+        let mut words = MutableDictionary::new();
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => words.extend_words(
+                contents
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(|line| {
+                        (
+                            line.chars().collect::<CharString>(),
+                            WordMetadata::default(),
+                        )
+                    }),
+            ),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        Ok(Self {
+            path: Some(path),
+            words,
+        })
+    }
+
+    /// Load the dictionary saved at [`Self::default_path`].
+    pub fn open_default() -> io::Result<Self> {
+        let path = Self::default_path().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "Unable to find a configuration directory for the user dictionary.",
+            )
+        })?;
+
+        Self::open(path)
+    }
+
+    /// Where the dictionary is saved, if anywhere.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Add a word to the dictionary.
+    ///
+    /// Returns whether the word was new.
+    /// Only one capitalization of each word is kept.
+    pub fn add_word(&mut self, word: &str) -> bool {
+        if self.words.contains_word_str(word) {
+            return false;
+        }
+
+        self.words.append_word_str(word, WordMetadata::default());
+        true
+    }
+
+    /// Remove a word (in its exact capitalization) from the dictionary.
+    ///
+    /// Returns whether the word was present.
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        self.words.remove_word(word.chars().collect::<CharString>())
+    }
+
+    /// Write the dictionary back to the path it was opened from, creating any missing
+    /// directories.
+    /// Does nothing for dictionaries that only live in memory.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut words: Vec<String> = self
+            .words
+            .words_iter()
+            .map(|word| word.iter().collect())
+            .collect();
+        words.sort();
+
+        let mut contents = words.join("\n");
+        contents.push('\n');
+
+        fs::write(path, contents)
+    }
+
+    /// Create a view that accepts words from both `base` and this dictionary, with this
+    /// dictionary taking precedence.
+    ///
+    /// The view is a snapshot: words added afterwards won't appear in it.
+    pub fn layered_over(&self, base: Arc<dyn Dictionary>) -> LayeredDictionary {
+        LayeredDictionary::new()
+            .with_dictionary(base)
+            .with_dictionary(Arc::new(self.words.clone()))
+    }
+}
+
+impl Dictionary for UserDictionary {
+    fn contains_word(&self, word: &[char]) -> bool {
+        self.words.contains_word(word)
+    }
+
+    fn contains_word_str(&self, word: &str) -> bool {
+        self.words.contains_word_str(word)
+    }
+
+    fn contains_exact_word(&self, word: &[char]) -> bool {
+        self.words.contains_exact_word(word)
+    }
+
+    fn contains_exact_word_str(&self, word: &str) -> bool {
+        self.words.contains_exact_word_str(word)
+    }
+
+    fn fuzzy_match(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        self.words.fuzzy_match(word, max_distance, max_results)
+    }
+
+    fn fuzzy_match_str(
+        &self,
+        word: &str,
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        self.words.fuzzy_match_str(word, max_distance, max_results)
+    }
+
+    fn get_correct_capitalization_of(&self, word: &[char]) -> Option<&'_ [char]> {
+        self.words.get_correct_capitalization_of(word)
+    }
+
+    fn get_word_metadata(&self, word: &[char]) -> Option<WordMetadata> {
+        self.words.get_word_metadata(word)
+    }
+
+    fn get_word_metadata_str(&self, word: &str) -> Option<WordMetadata> {
+        self.words.get_word_metadata_str(word)
+    }
+
+    fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        self.words.words_iter()
+    }
+
+    fn word_count(&self) -> usize {
+        self.words.word_count()
+    }
+
+    fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        self.words.words_with_len_iter(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::UserDictionary;
+    use crate::{Dictionary, FstDictionary};
+
+    #[test]
+    fn survives_reopening() {
+        let path = std::env::temp_dir()
+            .join(format!("harper-user-dict-test-{}", std::process::id()))
+            .join("dictionary.txt");
+
+        let mut dict = UserDictionary::open(&path).unwrap();
+        assert_eq!(dict.word_count(), 0);
+
+        assert!(dict.add_word("Harperize"));
+        assert!(dict.add_word("blorb"));
+        assert!(!dict.add_word("blorb"));
+        dict.save().unwrap();
+
+        let mut reopened = UserDictionary::open(&path).unwrap();
+        assert!(reopened.contains_exact_word_str("Harperize"));
+        assert!(reopened.remove_word("blorb"));
+        reopened.save().unwrap();
+
+        let reopened = UserDictionary::open(&path).unwrap();
+        assert!(!reopened.contains_word_str("blorb"));
+        assert_eq!(reopened.word_count(), 1);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn layers_over_curated() {
+        let mut dict = UserDictionary::new();
+        dict.add_word("blorb");
+
+        let merged = dict.layered_over(FstDictionary::curated());
+
+        assert!(merged.contains_word_str("blorb"));
+        assert!(merged.contains_word_str("hello"));
+    }
+}
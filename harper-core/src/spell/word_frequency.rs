@@ -0,0 +1,44 @@
+use hashbrown::HashMap;
+
+use crate::{CharString, WordMetadata};
+
+/// Set [`WordMetadata::frequency_rank`] for each word in `word_map` that appears in the
+/// built-in frequency list.
+///
+/// The list is lowercase, so every capitalization of a word receives the same rank.
+pub(crate) fn apply_default_frequency_ranks(word_map: &mut HashMap<CharString, WordMetadata>) {
+    let ranks: HashMap<&str, u32> = include_str!("../../word_frequency.txt")
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .zip(1..)
+        .collect();
+
+    let mut lowercase = String::new();
+
+    for (word, metadata) in word_map.iter_mut() {
+        lowercase.clear();
+        lowercase.extend(word.iter().flat_map(|c| c.to_lowercase()));
+
+        if let Some(rank) = ranks.get(lowercase.as_str()) {
+            metadata.frequency_rank = Some(*rank);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dictionary, FstDictionary};
+
+    #[test]
+    fn common_words_outrank_obscure_ones() {
+        let dict = FstDictionary::curated();
+
+        let the = dict.get_word_metadata_str("the").unwrap().frequency_rank;
+        let house = dict.get_word_metadata_str("house").unwrap().frequency_rank;
+        let abjure = dict.get_word_metadata_str("abjure").unwrap().frequency_rank;
+
+        assert_eq!(the, Some(1));
+        assert!(house.unwrap() < 1000);
+        assert!(abjure.is_none_or(|rank| rank > house.unwrap()));
+    }
+}
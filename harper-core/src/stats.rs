@@ -0,0 +1,289 @@
+//! Measure the prose in a [`Document`], such as how long its sentences are and how hard it is to
+//! read.
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::linting::Lint;
+use crate::{Document, Token, TokenStringExt};
+
+/// The forms of "to be", which introduce the passive voice.
+const BE_FORMS: &[&str] = &["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// Common past participles that don't end in "-ed".
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "begun",
+    "bitten",
+    "bought",
+    "brought",
+    "built",
+    "caught",
+    "chosen",
+    "done",
+    "drawn",
+    "driven",
+    "eaten",
+    "fed",
+    "felt",
+    "forgotten",
+    "found",
+    "given",
+    "gone",
+    "grown",
+    "heard",
+    "held",
+    "hidden",
+    "kept",
+    "known",
+    "laid",
+    "led",
+    "left",
+    "lost",
+    "made",
+    "meant",
+    "met",
+    "paid",
+    "read",
+    "run",
+    "said",
+    "seen",
+    "sent",
+    "set",
+    "shown",
+    "sold",
+    "spent",
+    "spoken",
+    "stolen",
+    "taken",
+    "taught",
+    "thought",
+    "told",
+    "understood",
+    "won",
+    "worn",
+    "written",
+];
+
+/// Counts taken from the prose of one or more documents.
+///
+/// Combine the counts of several documents with [`Self::merge`] to measure them as a whole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentStats {
+    pub words: usize,
+    /// Sentences that contain at least one word.
+    pub sentences: usize,
+    /// An estimate of the number of syllables in all the words.
+    /// See [`count_syllables`].
+    pub syllables: usize,
+    /// Sentences that appear to use the passive voice, like "The cake was eaten."
+    pub passive_sentences: usize,
+}
+
+impl DocumentStats {
+    pub fn new(document: &Document) -> Self {
+        let mut stats = Self::default();
+
+        for sentence in document.iter_sentences() {
+            let words: Vec<&Token> = sentence.iter().filter(|tok| tok.kind.is_word()).collect();
+
+            if words.is_empty() {
+                continue;
+            }
+
+            stats.sentences += 1;
+            stats.words += words.len();
+            stats.syllables += words
+                .iter()
+                .map(|word| count_syllables(document.get_span_content(word.span)))
+                .sum::<usize>();
+
+            if is_passive(&words, document) {
+                stats.passive_sentences += 1;
+            }
+        }
+
+        stats
+    }
+
+    /// Add the counts from another [`DocumentStats`] to this one.
+    pub fn merge(&mut self, other: &Self) {
+        self.words += other.words;
+        self.sentences += other.sentences;
+        self.syllables += other.syllables;
+        self.passive_sentences += other.passive_sentences;
+    }
+
+    /// The average number of words in each sentence.
+    /// `None` if there are no sentences.
+    pub fn words_per_sentence(&self) -> Option<f64> {
+        (self.sentences > 0).then(|| self.words as f64 / self.sentences as f64)
+    }
+
+    /// The [Flesch reading-ease score](https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests).
+    /// Higher scores are easier to read, with most plain English scoring between 60 and 70.
+    pub fn flesch_reading_ease(&self) -> Option<f64> {
+        let words_per_sentence = self.words_per_sentence()?;
+        let syllables_per_word = self.syllables as f64 / self.words as f64;
+
+        Some(206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word)
+    }
+
+    /// The [Flesch–Kincaid grade level](https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests),
+    /// which approximates the U.S. school grade needed to understand the text.
+    pub fn flesch_kincaid_grade(&self) -> Option<f64> {
+        let words_per_sentence = self.words_per_sentence()?;
+        let syllables_per_word = self.syllables as f64 / self.words as f64;
+
+        Some(0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59)
+    }
+
+    /// The percentage of sentences that appear to use the passive voice.
+    pub fn passive_percentage(&self) -> Option<f64> {
+        (self.sentences > 0).then(|| self.passive_sentences as f64 / self.sentences as f64 * 100.0)
+    }
+}
+
+/// Estimate the number of syllables in a word by counting groups of vowels.
+///
+/// A trailing silent "e" (as in "make") is ignored, but every word has at least one syllable.
+pub fn count_syllables(word: &[char]) -> usize {
+    let is_vowel = |c: &char| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut count = 0;
+    let mut prev_vowel = false;
+
+    for c in word {
+        let vowel = is_vowel(c);
+
+        if vowel && !prev_vowel {
+            count += 1;
+        }
+
+        prev_vowel = vowel;
+    }
+
+    let lower: String = word.iter().map(|c| c.to_ascii_lowercase()).collect();
+
+    if count > 1 && lower.ends_with('e') && !lower.ends_with("le") && !lower.ends_with("ee") {
+        count -= 1;
+    }
+
+    count.max(1)
+}
+
+/// Count the lints produced by each rule, keyed by [`Lint::rule`].
+/// Lints that don't record a rule are left out.
+pub fn count_rules<'a>(lints: impl IntoIterator<Item = &'a Lint>) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+
+    for lint in lints {
+        if let Some(rule) = &lint.rule {
+            *counts.entry(rule.clone()).or_default() += 1;
+        }
+    }
+
+    counts
+}
+
+/// Whether a sentence contains a form of "to be" followed by a past participle, optionally with
+/// an adverb between them, like "was quickly eaten".
+///
+/// Since the dictionary rarely knows which words are participles, words ending in "-ed" are
+/// assumed to be.
+fn is_passive(words: &[&Token], document: &Document) -> bool {
+    let text = |tok: &Token| document.get_span_content_str(tok.span).to_lowercase();
+
+    words.iter().enumerate().any(|(idx, word)| {
+        if !BE_FORMS.contains(&text(word).as_str()) {
+            return false;
+        }
+
+        let mut rest = words[idx + 1..].iter();
+        let Some(mut next) = rest.next() else {
+            return false;
+        };
+
+        if next.kind.is_adverb() || text(next).ends_with("ly") {
+            let Some(after) = rest.next() else {
+                return false;
+            };
+            next = after;
+        }
+
+        let next_text = text(next);
+
+        IRREGULAR_PARTICIPLES.contains(&next_text.as_str())
+            || (next_text.len() > 3 && next_text.ends_with("ed"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentStats, count_rules, count_syllables};
+    use crate::Document;
+    use crate::linting::Lint;
+
+    fn syllables(word: &str) -> usize {
+        count_syllables(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn counts_syllables() {
+        assert_eq!(syllables("cat"), 1);
+        assert_eq!(syllables("make"), 1);
+        assert_eq!(syllables("table"), 2);
+        assert_eq!(syllables("readability"), 5);
+        assert_eq!(syllables("rhythm"), 1);
+    }
+
+    #[test]
+    fn counts_words_and_sentences() {
+        let stats = DocumentStats::new(&Document::new_plain_english_curated(
+            "The cat sat. The dog ran away quickly.",
+        ));
+
+        assert_eq!(stats.words, 8);
+        assert_eq!(stats.sentences, 2);
+        assert_eq!(stats.words_per_sentence(), Some(4.0));
+    }
+
+    #[test]
+    fn detects_passive_voice() {
+        let stats = DocumentStats::new(&Document::new_plain_english_curated(
+            "The cake was eaten. The report was quickly reviewed. We baked bread.",
+        ));
+
+        assert_eq!(stats.passive_sentences, 2);
+    }
+
+    #[test]
+    fn simple_text_is_easy_to_read() {
+        let stats = DocumentStats::new(&Document::new_plain_english_curated(
+            "The cat sat on the mat. It was a good day.",
+        ));
+
+        assert!(stats.flesch_reading_ease().unwrap() > 90.0);
+        assert!(stats.flesch_kincaid_grade().unwrap() < 2.0);
+    }
+
+    #[test]
+    fn empty_document_has_no_scores() {
+        let stats = DocumentStats::new(&Document::new_plain_english_curated(""));
+
+        assert_eq!(stats.flesch_reading_ease(), None);
+        assert_eq!(stats.passive_percentage(), None);
+    }
+
+    #[test]
+    fn counts_lints_by_rule() {
+        let lint = |rule: &str| Lint {
+            rule: Some(rule.to_string()),
+            ..Default::default()
+        };
+
+        let counts = count_rules(&[lint("SpellCheck"), lint("AnA"), lint("SpellCheck")]);
+
+        assert_eq!(counts["SpellCheck"], 2);
+        assert_eq!(counts["AnA"], 1);
+    }
+}
@@ -1,30 +1,76 @@
 use crate::Lrc;
 use crate::Token;
 use crate::TokenKind;
+use crate::WordMetadata;
 use hashbrown::HashSet;
 use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
 
 use crate::{CharStringExt, Dictionary, Document, TokenStringExt, parsers::Parser};
 
+/// A style guide's rules for which words of a title are left lowercase.
+///
+/// In every style, the first and last words of a title are capitalized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TitleCaseStyle {
+    /// The Chicago Manual of Style.
+    /// Lowercases articles, prepositions of four letters or fewer and the conjunctions
+    /// "and", "but", "for", "or" and "nor".
+    #[default]
+    Chicago,
+    /// The Associated Press Stylebook.
+    /// Lowercases articles, along with prepositions and conjunctions of three letters or fewer.
+    Ap,
+    /// The American Psychological Association's style.
+    /// Lowercases articles, along with prepositions and coordinating conjunctions of three
+    /// letters or fewer.
+    Apa,
+    /// The Modern Language Association's style.
+    /// Lowercases articles, every preposition and all coordinating conjunctions.
+    Mla,
+}
+
 /// A helper function for [`make_title_case`] that uses Strings instead of char buffers.
 pub fn make_title_case_str(source: &str, parser: &impl Parser, dict: &impl Dictionary) -> String {
+    make_title_case_str_with_style(source, parser, dict, TitleCaseStyle::default())
+}
+
+/// A helper function for [`make_title_case_with_style`] that uses Strings instead of char buffers.
+pub fn make_title_case_str_with_style(
+    source: &str,
+    parser: &impl Parser,
+    dict: &impl Dictionary,
+    style: TitleCaseStyle,
+) -> String {
     let source: Vec<char> = source.chars().collect();
 
-    make_title_case_chars(Lrc::new(source), parser, dict).to_string()
+    make_title_case_chars(Lrc::new(source), parser, dict, style).to_string()
 }
 
-// Make a given string [title case](https://en.wikipedia.org/wiki/Title_case) following the Chicago Manual of Style.
+// Make a given string [title case](https://en.wikipedia.org/wiki/Title_case) following the given style.
 pub fn make_title_case_chars(
     source: Lrc<Vec<char>>,
     parser: &impl Parser,
     dict: &impl Dictionary,
+    style: TitleCaseStyle,
 ) -> Vec<char> {
     let document = Document::new_from_vec(source.clone(), parser, dict);
 
-    make_title_case(document.get_tokens(), source.as_slice(), dict)
+    make_title_case_with_style(document.get_tokens(), source.as_slice(), dict, style)
 }
 
+/// Make a series of tokens title case, following the Chicago Manual of Style.
 pub fn make_title_case(toks: &[Token], source: &[char], dict: &impl Dictionary) -> Vec<char> {
+    make_title_case_with_style(toks, source, dict, TitleCaseStyle::default())
+}
+
+/// Make a series of tokens title case, following the given style.
+pub fn make_title_case_with_style(
+    toks: &[Token],
+    source: &[char],
+    dict: &impl Dictionary,
+    style: TitleCaseStyle,
+) -> Vec<char> {
     if toks.is_empty() {
         return Vec::new();
     }
@@ -35,57 +81,185 @@ pub fn make_title_case(toks: &[Token], source: &[char], dict: &impl Dictionary)
     let mut output = toks.span().unwrap().get_content(source).to_vec();
 
     while let Some((index, word)) = word_likes.next() {
-        if let Some(Some(metadata)) = word.kind.as_word() {
-            if metadata.is_proper_noun() {
-                // Replace it with the dictionary entry verbatim.
-                let orig_text = word.span.get_content(source);
-
-                if let Some(correct_caps) = dict.get_correct_capitalization_of(orig_text) {
-                    // It should match the dictionary verbatim
-                    output[word.span.start - start_index..word.span.end - start_index]
-                        .iter_mut()
-                        .enumerate()
-                        .for_each(|(idx, c)| *c = correct_caps[idx]);
-                    continue;
-                }
-            }
-        };
+        let range = word.span.start - start_index..word.span.end - start_index;
+        let (stem, metadata) = possessive_stem(&word, source, dict);
+        let stem_range = range.start..range.start + stem.len();
 
-        let should_capitalize = should_capitalize_token(&word, source, dict)
+        // The possessive suffix is always lowercase.
+        for v in &mut output[stem_range.end..range.end] {
+            *v = to_lowercase(*v);
+        }
+
+        // Acronyms, like "IT", that share their spelling with another word.
+        if stem.len() > 1 && stem.iter().all(|c| c.is_uppercase()) && dict.contains_exact_word(stem)
+        {
+            continue;
+        }
+
+        if let Some(metadata) = metadata
+            && let Some(correct_caps) = dict.get_correct_capitalization_of(stem)
+            && correct_caps.len() == stem.len()
+            && (metadata.is_proper_noun()
+                || metadata.case_sensitive
+                || correct_caps.iter().skip(1).any(|c| c.is_uppercase()))
+        {
+            // It should match the dictionary verbatim
+            output[stem_range].copy_from_slice(correct_caps);
+            continue;
+        }
+
+        let should_capitalize = should_capitalize_token(&word, source, dict, style)
             || index == 0
             || word_likes.peek().is_none();
 
+        // The whole word should be lowercase, except maybe the first letter.
+        for v in &mut output[range.clone()] {
+            *v = to_lowercase(*v);
+        }
+
         if should_capitalize {
-            output[word.span.start - start_index] =
-                output[word.span.start - start_index].to_ascii_uppercase();
+            output[range.start] = to_uppercase(output[range.start]);
+        }
+    }
 
-            // The rest of the word should be lowercase.
-            for v in &mut output[word.span.start + 1 - start_index..word.span.end - start_index] {
-                *v = v.to_ascii_lowercase();
-            }
-        } else {
-            // The whole word should be lowercase.
-            for i in word.span {
-                output[i - start_index] = output[i - start_index].to_ascii_lowercase();
-            }
+    output
+}
+
+/// A helper function for [`make_sentence_case`] that uses Strings instead of char buffers.
+pub fn make_sentence_case_str(
+    source: &str,
+    parser: &impl Parser,
+    dict: &impl Dictionary,
+) -> String {
+    let source: Vec<char> = source.chars().collect();
+    let source = Lrc::new(source);
+    let document = Document::new_from_vec(source.clone(), parser, dict);
+
+    make_sentence_case(document.get_tokens(), source.as_slice(), dict).to_string()
+}
+
+/// Make a series of tokens sentence case: only the first word and proper nouns are capitalized.
+///
+/// Proper nouns and other words the dictionary capitalizes are written as they appear in the
+/// dictionary.
+/// Words written entirely in uppercase, like acronyms, are left alone.
+pub fn make_sentence_case(toks: &[Token], source: &[char], dict: &impl Dictionary) -> Vec<char> {
+    if toks.is_empty() {
+        return Vec::new();
+    }
+
+    let start_index = toks.first().unwrap().span.start;
+
+    let mut output = toks.span().unwrap().get_content(source).to_vec();
+
+    for (index, word) in toks.iter_word_likes().enumerate() {
+        let range = word.span.start - start_index..word.span.end - start_index;
+        let (stem, metadata) = possessive_stem(&word, source, dict);
+        let stem_range = range.start..range.start + stem.len();
+
+        // The possessive suffix is always lowercase.
+        for v in &mut output[stem_range.end..range.end] {
+            *v = to_lowercase(*v);
+        }
+
+        if let Some(metadata) = metadata
+            && let Some(correct_caps) = dict.get_correct_capitalization_of(stem)
+            && correct_caps.len() == stem.len()
+            && (metadata.is_proper_noun()
+                || metadata.case_sensitive
+                || correct_caps.iter().any(|c| c.is_uppercase()))
+        {
+            // It should match the dictionary verbatim
+            output[stem_range].copy_from_slice(correct_caps);
+            continue;
+        }
+
+        // Acronyms, as well as the pronoun "I".
+        if stem.iter().all(|c| !c.is_lowercase()) && (stem.len() > 1 || stem == ['I']) {
+            continue;
+        }
+
+        for v in &mut output[stem_range] {
+            *v = to_lowercase(*v);
+        }
+
+        if index == 0 {
+            output[range.start] = to_uppercase(output[range.start]);
         }
     }
 
     output
 }
 
+/// The length of a word without its possessive suffix ("'s"), if it has one.
+pub(crate) fn possessive_stem_len(word: &[char]) -> usize {
+    match word {
+        [stem @ .., '\'' | '’', 's' | 'S'] if !stem.is_empty() => stem.len(),
+        _ => word.len(),
+    }
+}
+
+/// Get a word without its possessive suffix, alongside the metadata of that stem.
+fn possessive_stem<'a>(
+    word: &Token,
+    source: &'a [char],
+    dict: &impl Dictionary,
+) -> (&'a [char], Option<WordMetadata>) {
+    let text = word.span.get_content(source);
+    let stem = &text[..possessive_stem_len(text)];
+
+    if stem.len() == text.len() {
+        (stem, word.kind.as_word().copied().flatten())
+    } else {
+        (stem, dict.get_word_metadata(stem))
+    }
+}
+
+/// The uppercase form of a character.
+///
+/// Characters whose uppercase form is more than one character (like `ß`) are left alone, so the
+/// output stays aligned with the source.
+fn to_uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+/// The lowercase form of a character.
+/// See [`to_uppercase`].
+fn to_lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
 /// Determines whether a token should be capitalized.
 /// Is not responsible for capitalization requirements that are dependent on token position.
-fn should_capitalize_token(tok: &Token, source: &[char], dict: &impl Dictionary) -> bool {
+fn should_capitalize_token(
+    tok: &Token,
+    source: &[char],
+    dict: &impl Dictionary,
+    style: TitleCaseStyle,
+) -> bool {
     match tok.kind {
         TokenKind::Word(Some(mut metadata)) => {
-            // Only specific conjunctions are not capitalized.
             lazy_static! {
                 static ref SPECIAL_CONJUNCTIONS: HashSet<Vec<char>> =
                     ["and", "but", "for", "or", "nor"]
                         .iter()
                         .map(|v| v.chars().collect())
                         .collect();
+                static ref COORDINATING_CONJUNCTIONS: HashSet<Vec<char>> =
+                    ["and", "but", "for", "or", "nor", "so", "yet"]
+                        .iter()
+                        .map(|v| v.chars().collect())
+                        .collect();
             }
 
             let chars = tok.span.get_content(source);
@@ -93,11 +267,31 @@ fn should_capitalize_token(tok: &Token, source: &[char], dict: &impl Dictionary)
 
             metadata = metadata.or(&dict.get_word_metadata(&chars_lower).unwrap_or_default());
 
-            let is_short_preposition = metadata.preposition && tok.span.len() <= 4;
+            if metadata.article {
+                return false;
+            }
+
+            let len = tok.span.len();
 
-            !is_short_preposition
-                && !metadata.article
-                && !SPECIAL_CONJUNCTIONS.contains(chars_lower.as_ref())
+            match style {
+                TitleCaseStyle::Chicago => {
+                    // Only specific conjunctions are not capitalized.
+                    !(metadata.preposition && len <= 4)
+                        && !SPECIAL_CONJUNCTIONS.contains(chars_lower.as_ref())
+                }
+                TitleCaseStyle::Ap => {
+                    !((metadata.preposition || metadata.conjunction.is_some()) && len <= 3)
+                }
+                TitleCaseStyle::Apa => {
+                    !((metadata.preposition
+                        || COORDINATING_CONJUNCTIONS.contains(chars_lower.as_ref()))
+                        && len <= 3)
+                }
+                TitleCaseStyle::Mla => {
+                    !metadata.preposition
+                        && !COORDINATING_CONJUNCTIONS.contains(chars_lower.as_ref())
+                }
+            }
         }
         _ => true,
     }
@@ -109,7 +303,9 @@ mod tests {
     use quickcheck::TestResult;
     use quickcheck_macros::quickcheck;
 
-    use super::make_title_case_str;
+    use super::{
+        TitleCaseStyle, make_sentence_case_str, make_title_case_str, make_title_case_str_with_style,
+    };
     use crate::{
         FstDictionary,
         parsers::{Markdown, PlainEnglish},
@@ -253,4 +449,179 @@ mod tests {
             "United States"
         )
     }
+
+    fn assert_title_case(source: &str, style: TitleCaseStyle, expected: &str) {
+        assert_eq!(
+            make_title_case_str_with_style(source, &PlainEnglish, &FstDictionary::curated(), style),
+            expected
+        )
+    }
+
+    #[test]
+    fn chicago_lowercases_short_prepositions() {
+        assert_title_case(
+            "a walk into the woods with friends",
+            TitleCaseStyle::Chicago,
+            "A Walk into the Woods with Friends",
+        )
+    }
+
+    #[test]
+    fn ap_capitalizes_long_prepositions() {
+        assert_title_case(
+            "a walk into the woods with friends",
+            TitleCaseStyle::Ap,
+            "A Walk Into the Woods With Friends",
+        )
+    }
+
+    #[test]
+    fn apa_lowercases_short_words() {
+        assert_title_case(
+            "a walk in the woods and fields",
+            TitleCaseStyle::Apa,
+            "A Walk in the Woods and Fields",
+        )
+    }
+
+    #[test]
+    fn mla_lowercases_all_prepositions() {
+        assert_title_case(
+            "a walk through the woods and across the fields",
+            TitleCaseStyle::Mla,
+            "A Walk through the Woods and across the Fields",
+        )
+    }
+
+    #[test]
+    fn sentence_case_lowercases_words() {
+        assert_eq!(
+            make_sentence_case_str(
+                "The Best Way To Learn",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "The best way to learn"
+        )
+    }
+
+    #[test]
+    fn sentence_case_keeps_proper_nouns() {
+        assert_eq!(
+            make_sentence_case_str(
+                "Why I Moved To Paris On A Monday",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "Why I moved to Paris on a Monday"
+        )
+    }
+
+    #[test]
+    fn sentence_case_keeps_acronyms() {
+        assert_eq!(
+            make_sentence_case_str(
+                "using NASA Data With Care",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "Using NASA data with care"
+        )
+    }
+
+    #[test]
+    fn keeps_acronyms() {
+        assert_eq!(
+            make_title_case_str(
+                "nasa and the IT department",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "NASA and the IT Department"
+        )
+    }
+
+    #[test]
+    fn keeps_mixed_case_brands() {
+        assert_eq!(
+            make_title_case_str(
+                "my IPHONE runs on macos",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "My iPhone Runs on macOS"
+        )
+    }
+
+    #[test]
+    fn capitalizes_non_ascii_letters() {
+        assert_eq!(
+            make_title_case_str(
+                "école in são paulo",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "École in São Paulo"
+        )
+    }
+
+    #[test]
+    fn lowercases_non_ascii_letters() {
+        assert_eq!(
+            make_title_case_str("ÉLAN ÜBER ALLES", &PlainEnglish, &FstDictionary::curated()),
+            "Élan Über Alles"
+        )
+    }
+
+    #[test]
+    fn leaves_expanding_letters_alone() {
+        assert_eq!(
+            make_title_case_str("straße", &PlainEnglish, &FstDictionary::curated()),
+            "Straße"
+        )
+    }
+
+    #[quickcheck]
+    fn non_ascii_first_letter_is_upcase(first: usize, rest: String) -> TestResult {
+        const LETTERS: [char; 8] = ['é', 'ø', 'ñ', 'ş', 'ç', 'ü', 'å', 'č'];
+
+        if rest.is_empty() || rest.chars().any(|c| !c.is_ascii_lowercase()) {
+            return TestResult::discard();
+        }
+
+        let first = LETTERS[first % LETTERS.len()];
+
+        let title_case: Vec<_> = make_title_case_str(
+            &format!("{first}{rest}"),
+            &PlainEnglish,
+            &FstDictionary::curated(),
+        )
+        .chars()
+        .collect();
+
+        TestResult::from_bool(title_case.first() == first.to_uppercase().next().as_ref())
+    }
+
+    #[quickcheck]
+    fn keeps_char_count(text: String) -> TestResult {
+        if text.chars().any(|c| c.is_control() || c.is_whitespace()) {
+            return TestResult::discard();
+        }
+
+        let title_case = make_title_case_str(&text, &PlainEnglish, &FstDictionary::curated());
+
+        TestResult::from_bool(title_case.chars().count() == text.chars().count())
+    }
+
+    #[test]
+    fn keeps_possessive_suffix() {
+        assert_eq!(
+            make_title_case_str(
+                "NASA'S view of the iphone's screen",
+                &PlainEnglish,
+                &FstDictionary::curated()
+            ),
+            "NASA's View of the iPhone's Screen"
+        )
+    }
 }
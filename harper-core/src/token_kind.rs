@@ -1,7 +1,9 @@
 use is_macro::Is;
 use serde::{Deserialize, Serialize};
 
-use crate::{ConjunctionData, NounData, Number, Punctuation, Quote, WordMetadata};
+use crate::{
+    ConjunctionData, CurrencyAmount, NounData, Number, Punctuation, Quote, Register, WordMetadata,
+};
 
 #[derive(
     Debug, Is, Clone, Copy, Serialize, Deserialize, Default, PartialOrd, Hash, Eq, PartialEq,
@@ -13,6 +15,8 @@ pub enum TokenKind {
     Punctuation(Punctuation),
     Decade,
     Number(Number),
+    /// An amount of money, like `$1,000` or `50 EUR`.
+    CurrencyAmount(CurrencyAmount),
     /// A sequence of " " spaces.
     Space(usize),
     /// A sequence of "\n" newlines
@@ -20,6 +24,12 @@ pub enum TokenKind {
     EmailAddress,
     Url,
     Hostname,
+    /// A file path, like `/usr/bin/env` or `src/main.rs`.
+    Path,
+    /// A mention of a user, like `@octocat`.
+    Mention,
+    /// A hashtag, like `#rustlang`.
+    Hashtag,
     /// A special token used for things like inline code blocks that should be
     /// ignored by all linters.
     #[default]
@@ -50,6 +60,7 @@ impl TokenKind {
                 | TokenKind::Hostname
                 | TokenKind::Decade
                 | TokenKind::Number(..)
+                | TokenKind::CurrencyAmount(..)
         )
     }
 
@@ -118,6 +129,19 @@ impl TokenKind {
         }
     }
 
+    /// Whether this token separates clauses within a sentence.
+    pub(crate) fn is_clause_separator(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Punctuation(
+                Punctuation::Comma
+                    | Punctuation::Semicolon
+                    | Punctuation::Colon
+                    | Punctuation::EmDash
+            )
+        )
+    }
+
     pub(crate) fn is_sentence_terminator(&self) -> bool {
         match self {
             TokenKind::Punctuation(punct) => [
@@ -190,6 +214,26 @@ impl TokenKind {
         )
     }
 
+    /// The [`Register`] of the word, if the token is a word that has one.
+    pub fn register(&self) -> Option<Register> {
+        match self {
+            TokenKind::Word(Some(metadata)) => metadata.register,
+            _ => None,
+        }
+    }
+
+    /// Whether the token is a word that must always be written with the same capitalization.
+    /// See [`WordMetadata::case_sensitive`].
+    pub fn is_case_sensitive(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Word(Some(WordMetadata {
+                case_sensitive: true,
+                ..
+            }))
+        )
+    }
+
     /// Checks that `self` is the same enum variant as `other`, regardless of
     /// whether the inner metadata is also equal.
     pub fn matches_variant_of(&self, other: &Self) -> bool {
@@ -204,6 +248,7 @@ impl TokenKind {
             TokenKind::Word(_) => TokenKind::Word(Default::default()),
             TokenKind::Punctuation(_) => TokenKind::Punctuation(Default::default()),
             TokenKind::Number(..) => TokenKind::Number(Default::default()),
+            TokenKind::CurrencyAmount(..) => TokenKind::CurrencyAmount(Default::default()),
             TokenKind::Space(_) => TokenKind::Space(Default::default()),
             TokenKind::Newline(_) => TokenKind::Newline(Default::default()),
             _ => *self,
@@ -307,6 +352,16 @@ impl TokenKind {
         metadata.is_plural_noun()
     }
 
+    /// Whether the token is an adjective that can't be graded, like "unique".
+    /// See [`WordMetadata::is_absolute_adjective`].
+    pub fn is_absolute_adjective(&self) -> bool {
+        let TokenKind::Word(Some(metadata)) = self else {
+            return false;
+        };
+
+        metadata.is_absolute_adjective()
+    }
+
     pub fn is_noun(&self) -> bool {
         let TokenKind::Word(Some(metadata)) = self else {
             return false;
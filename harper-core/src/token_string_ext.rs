@@ -95,6 +95,13 @@ pub trait TokenStringExt {
     /// Get an iterator over token slices that represent the individual
     /// sentences in a document.
     fn iter_sentences(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over token slices that represent the individual
+    /// clauses in a document.
+    ///
+    /// Each sentence is split after its commas, semicolons, colons and em dashes,
+    /// so a clause never crosses a sentence boundary.
+    fn iter_clauses(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
 }
 
 impl TokenStringExt for [Token] {
@@ -209,26 +216,59 @@ impl TokenStringExt for [Token] {
     }
 
     fn iter_sentences(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
-        let first_sentence = self
-            .iter_sentence_terminator_indices()
-            .next()
-            .map(|first_term| &self[0..=first_term]);
+        let mut sentences = Vec::new();
+        let mut start = 0;
 
-        let rest = self
-            .iter_sentence_terminator_indices()
-            .tuple_windows()
-            .map(move |(a, b)| &self[a + 1..=b]);
+        for end in sentence_end_indices(self) {
+            sentences.push(&self[start..=end]);
+            start = end + 1;
+        }
 
-        let last_sentence = if let Some(last_i) = self.last_sentence_terminator_index() {
-            if last_i + 1 < self.len() {
-                Some(&self[last_i + 1..])
-            } else {
-                None
-            }
-        } else {
-            Some(self)
-        };
+        if start < self.len() {
+            sentences.push(&self[start..]);
+        }
+
+        sentences.into_iter()
+    }
+
+    fn iter_clauses(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.iter_sentences()
+            .flat_map(|sentence| sentence.split_inclusive(|t| t.kind.is_clause_separator()))
+    }
+}
+
+/// The index of the last token of each sentence.
+///
+/// Usually that's a sentence terminator, but a quotation that ends with one ends the sentence
+/// at its closing quotation mark instead.
+/// If the quotation ends with an exclamation or question mark and is followed by more words,
+/// like in `"Stop!" she said.`, those words are its attribution, so the sentence continues.
+fn sentence_end_indices(tokens: &[Token]) -> Vec<usize> {
+    let mut ends = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if !token.kind.is_sentence_terminator() {
+            continue;
+        }
+
+        let closes_quote = !token.kind.is_paragraph_break()
+            && tokens.get(index + 1).is_some_and(|t| t.kind.is_quote());
+
+        if !closes_quote {
+            ends.push(index);
+            continue;
+        }
 
-        first_sentence.into_iter().chain(rest).chain(last_sentence)
+        let is_period = token.kind.is_period();
+        let has_attribution = tokens[index + 2..]
+            .iter()
+            .find(|t| !t.kind.is_space())
+            .is_some_and(|t| t.kind.is_word());
+
+        if is_period || !has_attribution {
+            ends.push(index + 1);
+        }
     }
+
+    ends
 }
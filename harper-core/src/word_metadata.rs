@@ -2,6 +2,8 @@ use is_macro::Is;
 use paste::paste;
 use serde::{Deserialize, Serialize};
 
+use crate::DialectFlags;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Hash)]
 pub struct WordMetadata {
     pub noun: Option<NounData>,
@@ -19,6 +21,22 @@ pub struct WordMetadata {
     /// Whether the word is considered especially common.
     #[serde(default = "default_false")]
     pub common: bool,
+    /// Whether the word must always be written with the capitalization it has in the
+    /// dictionary, like "iOS" or "npm".
+    #[serde(default = "default_false")]
+    pub case_sensitive: bool,
+    /// The dialects the word is used in.
+    /// Empty for words used in every dialect.
+    #[serde(default)]
+    pub dialects: DialectFlags,
+    /// The [`Register`] the word belongs to, if it is only appropriate in some kinds of writing.
+    #[serde(default)]
+    pub register: Option<Register>,
+    /// Where the word ranks among all words by how often it is used, with `1` being the most
+    /// common.
+    /// `None` for words too rare to be ranked.
+    #[serde(default)]
+    pub frequency_rank: Option<u32>,
 }
 
 /// Needed for `serde`
@@ -98,6 +116,13 @@ impl WordMetadata {
             article: self.article || other.article,
             preposition: self.preposition || other.preposition,
             common: self.common || other.common,
+            case_sensitive: self.case_sensitive || other.case_sensitive,
+            dialects: self.dialects.union(other.dialects),
+            register: self.register.or(other.register),
+            frequency_rank: match (self.frequency_rank, other.frequency_rank) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
         }
     }
 
@@ -105,7 +130,7 @@ impl WordMetadata {
         noun has proper, plural, possessive, pronoun.
         verb has linking, auxiliary.
         conjunction has.
-        adjective has.
+        adjective has absolute.
         adverb has
     );
 
@@ -121,6 +146,19 @@ impl WordMetadata {
     }
 }
 
+/// How formal a word is, or how it comes across to readers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
+pub enum Register {
+    /// Fine in conversation, but out of place in formal writing, like "gonna".
+    Informal,
+    /// Even more casual than [`Self::Informal`], like "dude".
+    Slang,
+    /// No longer in everyday use, like "thou".
+    Archaic,
+    /// Likely to offend, like slurs.
+    Offensive,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
 pub enum Tense {
     Past,
@@ -167,12 +205,18 @@ impl NounData {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Hash, Default)]
-pub struct AdjectiveData {}
+pub struct AdjectiveData {
+    /// Whether the adjective describes an absolute quality, which can't be had to a greater or
+    /// lesser degree, like "unique" or "perfect".
+    pub is_absolute: Option<bool>,
+}
 
 impl AdjectiveData {
     /// Produce a copy of `self` with the known properties of `other` set.
-    pub fn or(&self, _other: &Self) -> Self {
-        Self {}
+    pub fn or(&self, other: &Self) -> Self {
+        Self {
+            is_absolute: self.is_absolute.or(other.is_absolute),
+        }
     }
 }
 
@@ -1,17 +1,23 @@
 use harper_core::parsers::{self, Parser, PlainEnglish};
-use harper_core::{Token, TokenKind};
+use harper_core::{Masker, Span, Token, TokenKind};
 use harper_tree_sitter::TreeSitterMasker;
 use tree_sitter::Node;
 
 pub struct HtmlParser {
     /// Used to grab the text nodes.
     inner: parsers::Mask<TreeSitterMasker, PlainEnglish>,
+    /// Used to grab the comment nodes.
+    comments: TreeSitterMasker,
 }
 
 impl HtmlParser {
     fn node_condition(n: &Node) -> bool {
         n.kind() == "text"
     }
+
+    fn is_comment(n: &Node) -> bool {
+        n.kind() == "comment"
+    }
 }
 
 impl Default for HtmlParser {
@@ -21,6 +27,7 @@ impl Default for HtmlParser {
                 TreeSitterMasker::new(tree_sitter_html::language(), Self::node_condition),
                 PlainEnglish,
             ),
+            comments: TreeSitterMasker::new(tree_sitter_html::language(), Self::is_comment),
         }
     }
 }
@@ -37,4 +44,12 @@ impl Parser for HtmlParser {
 
         tokens
     }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.comments
+            .create_mask(source)
+            .iter_allowed(source)
+            .map(|(span, _)| span)
+            .collect()
+    }
 }
@@ -1,6 +1,6 @@
 use harper_comments::CommentParser;
 use harper_core::{
-    Lrc, Masker, MutableDictionary, Token,
+    Lrc, Masker, MutableDictionary, Span, Token,
     parsers::{Markdown, MarkdownOptions, Mask, Parser},
 };
 
@@ -44,4 +44,8 @@ impl Parser for LiterateHaskellParser {
     fn parse(&self, source: &[char]) -> Vec<Token> {
         Mask::new(LiterateHaskellMasker::text_only(), self.inner.clone()).parse(source)
     }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        Mask::new(LiterateHaskellMasker::text_only(), self.inner.clone()).comments(source)
+    }
 }
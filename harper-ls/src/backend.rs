@@ -1,13 +1,19 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
 use harper_comments::CommentParser;
-use harper_core::linting::{LintGroup, LintGroupConfig};
-use harper_core::parsers::{CollapseIdentifiers, IsolateEnglish, Markdown, Parser, PlainEnglish};
+use harper_core::linting::{LintGroup, TransitionOveruse};
+use harper_core::parsers::{
+    CollapseIdentifiers, IsolateEnglish, MarkNonEnglish, Markdown, Parser, PlainEnglish,
+};
 use harper_core::{
-    Dictionary, Document, FstDictionary, MergedDictionary, MutableDictionary, WordMetadata,
+    Dictionary, Document, FstDictionary, HarperConfig, HarperIgnore, LayeredDictionary, LineIndex,
+    Lrc, MutableDictionary, UserDictionary, WordMetadata,
 };
 use harper_html::HtmlParser;
 use harper_literate_haskell::LiterateHaskellParser;
@@ -15,29 +21,92 @@ use harper_typst::Typst;
 use serde_json::Value;
 use tokio::sync::{Mutex, RwLock};
 use tower_lsp::jsonrpc::Result as JsonResult;
-use tower_lsp::lsp_types::notification::PublishDiagnostics;
+use tower_lsp::lsp_types::notification::{Progress, PublishDiagnostics};
+use tower_lsp::lsp_types::request::WorkDoneProgressCreate;
 use tower_lsp::lsp_types::{
-    CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
-    ConfigurationItem, Diagnostic, DidChangeConfigurationParams, DidChangeTextDocumentParams,
-    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
-    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-    ExecuteCommandOptions, ExecuteCommandParams, FileChangeType, FileSystemWatcher, GlobPattern,
-    InitializeParams, InitializeResult, InitializedParams, MessageType, PublishDiagnosticsParams,
-    Range, Registration, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
-    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Url, WatchKind,
+    CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
+    DiagnosticOptions, DiagnosticServerCapabilities, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentDiagnosticParams,
+    DocumentDiagnosticReport, DocumentDiagnosticReportKind, DocumentDiagnosticReportResult,
+    ExecuteCommandOptions, ExecuteCommandParams, FileChangeType, FileSystemWatcher,
+    FullDocumentDiagnosticReport, GlobPattern, Hover, HoverParams, HoverProviderCapability,
+    InitializeParams, InitializeResult, InitializedParams, MessageType, NumberOrString,
+    ProgressParams, ProgressParamsValue, PublishDiagnosticsParams, Range, Registration,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    ServerCapabilities, TextDocumentContentChangeEvent, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions,
+    UnchangedDocumentDiagnosticReport, Url, WatchKind, WorkDoneProgress, WorkDoneProgressBegin,
+    WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
+    WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult,
+    WorkspaceDocumentDiagnosticReport, WorkspaceEdit, WorkspaceFullDocumentDiagnosticReport,
+    WorkspaceUnchangedDocumentDiagnosticReport,
 };
 use tower_lsp::{Client, LanguageServer};
 use tracing::{error, info, warn};
 
 use crate::config::Config;
+use crate::diagnostics::SOURCE_FIX_ALL_HARPER;
 use crate::dictionary_io::{file_dict_name, load_dict, save_dict};
 use crate::document_state::DocumentState;
 use crate::git_commit_parser::GitCommitParser;
+use crate::linter_cache::LinterCache;
+use crate::pos_conv::range_to_span;
+use crate::settings_cache::SettingsCache;
+use crate::workspace_scan::find_workspace_files;
 
 pub struct Backend {
     client: Client,
     config: RwLock<Config>,
     doc_state: Mutex<HashMap<Url, DocumentState>>,
+    /// Whether the client requests diagnostics itself (LSP 3.17's pull model), rather than
+    /// waiting for them to be published.
+    pull_diagnostics: AtomicBool,
+    /// Whether the client can be asked to request diagnostics again.
+    diagnostic_refresh: AtomicBool,
+    /// The root directories of the workspace, as given by the client.
+    workspace_roots: RwLock<Vec<PathBuf>>,
+    /// Held while a document is opened or its edits are applied.
+    edit_lock: Mutex<()>,
+    /// The diagnostics of files linted by [`Self::lint_workspace`] that aren't open.
+    ///
+    /// Only kept for clients that pull diagnostics, and only the diagnostics: the documents
+    /// themselves are dropped once they have been linted.
+    scanned: Mutex<HashMap<Url, Vec<Diagnostic>>>,
+    /// Linters shared between documents.
+    linters: Mutex<LinterCache>,
+    /// The settings files that apply to each directory.
+    /// Cleared by [`Self::reload_open_documents`].
+    settings: Mutex<SettingsCache>,
+}
+
+/// The files whose contents affect how every document in a workspace is linted.
+const SETTINGS_FILES: [&str; 3] = [
+    HarperConfig::FILE_NAME,
+    HarperIgnore::FILE_NAME,
+    HarperConfig::WORKSPACE_DICTIONARY,
+];
+
+/// How long to wait for more edits before linting a document, so rapid typing in a large
+/// document doesn't queue a full lint pass for every keystroke.
+///
+/// Small documents are linted right away.
+fn debounce_delay(len: usize) -> Duration {
+    // Roughly a millisecond for every thousand characters, up to half a second.
+    Duration::from_millis((len / 1000).min(500) as u64)
+}
+
+/// Identify a set of diagnostics, so clients that pull them can be told when they haven't
+/// changed.
+fn diagnostic_result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(diagnostics)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+
+    format!("{:x}", hasher.finish())
 }
 
 impl Backend {
@@ -45,10 +114,47 @@ impl Backend {
         Self {
             client,
             config: RwLock::new(config),
+            pull_diagnostics: AtomicBool::new(false),
+            diagnostic_refresh: AtomicBool::new(false),
+            workspace_roots: RwLock::new(Vec::new()),
+            edit_lock: Mutex::new(()),
+            scanned: Mutex::new(HashMap::new()),
+            linters: Mutex::new(LinterCache::default()),
+            settings: Mutex::new(SettingsCache::default()),
             doc_state: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Find the `harper.toml` settings that apply to a document.
+    async fn load_harper_config(&self, url: &Url) -> Lrc<HarperConfig> {
+        let Some(dir) = url
+            .to_file_path()
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+        else {
+            return Lrc::default();
+        };
+
+        self.settings.lock().await.get_or_load(&dir).harper_config
+    }
+
+    /// Whether a document is listed in a `.harperignore` file, and so shouldn't be linted.
+    async fn is_ignored(&self, url: &Url) -> bool {
+        let Ok(path) = url.to_file_path() else {
+            return false;
+        };
+        let Some(dir) = path.parent() else {
+            return false;
+        };
+
+        self.settings
+            .lock()
+            .await
+            .get_or_load(dir)
+            .ignore
+            .is_ignored(&path, false)
+    }
+
     /// Load a specific file's dictionary
     async fn load_file_dictionary(&self, url: &Url) -> anyhow::Result<MutableDictionary> {
         let path = self
@@ -69,6 +175,30 @@ impl Backend {
         Ok(config.file_dict_path.join(file_dict_name(url)?))
     }
 
+    /// Load the words to ignore in a specific file.
+    async fn load_file_ignore_list(&self, url: &Url) -> anyhow::Result<MutableDictionary> {
+        let path = self
+            .get_file_ignore_list_path(url)
+            .await
+            .context("Unable to get the file path.")?;
+
+        load_dict(path)
+            .await
+            .map_err(|err| info!("{err}"))
+            .or(Ok(MutableDictionary::new()))
+    }
+
+    /// Compute the location of the file's list of ignored words, which is kept beside the
+    /// file-specific dictionaries.
+    async fn get_file_ignore_list_path(&self, url: &Url) -> anyhow::Result<PathBuf> {
+        let config = self.config.read().await;
+
+        Ok(config
+            .file_dict_path
+            .join("ignored")
+            .join(file_dict_name(url)?))
+    }
+
     async fn save_file_dictionary(&self, url: &Url, dict: impl Dictionary) -> Result<()> {
         save_dict(
             self.get_file_dict_path(url)
@@ -80,32 +210,20 @@ impl Backend {
         .context("Unable to save the dictionary to path.")
     }
 
-    async fn load_user_dictionary(&self) -> MutableDictionary {
+    async fn load_user_dictionary(&self) -> UserDictionary {
         let config = self.config.read().await;
 
-        load_dict(&config.user_dict_path)
-            .await
+        UserDictionary::open(&config.user_dict_path)
             .map_err(|err| info!("{err}"))
-            .unwrap_or(MutableDictionary::new())
+            .unwrap_or_default()
     }
 
-    async fn save_user_dictionary(&self, dict: impl Dictionary) -> Result<()> {
-        let config = self.config.read().await;
-
-        save_dict(&config.user_dict_path, dict)
-            .await
-            .map_err(|err| anyhow!("Unable to save the dictionary to file: {err}"))
-    }
-
-    async fn generate_global_dictionary(&self) -> Result<MergedDictionary> {
-        let mut dict = MergedDictionary::new();
-        dict.add_dictionary(FstDictionary::curated());
+    async fn generate_global_dictionary(&self) -> Result<LayeredDictionary> {
         let user_dict = self.load_user_dictionary().await;
-        dict.add_dictionary(Arc::new(user_dict));
-        Ok(dict)
+        Ok(user_dict.layered_over(FstDictionary::curated()))
     }
 
-    async fn generate_file_dictionary(&self, url: &Url) -> Result<MergedDictionary> {
+    async fn generate_file_dictionary(&self, url: &Url) -> Result<LayeredDictionary> {
         let (global_dictionary, file_dictionary) = tokio::join!(
             self.generate_global_dictionary(),
             self.load_file_dictionary(url)
@@ -113,13 +231,79 @@ impl Backend {
 
         let mut global_dictionary =
             global_dictionary.context("Unable to load the global dictionary.")?;
-        global_dictionary.add_dictionary(Arc::new(
+        global_dictionary.push_dictionary(Arc::new(
             file_dictionary.context("Unable to load the file dictionary.")?,
         ));
 
         Ok(global_dictionary)
     }
 
+    /// Find the linter for a document that is checked against `dict`, building one if it can't
+    /// share another document's.
+    ///
+    /// Returns the dictionary to use along with it.
+    async fn linter_for(
+        &self,
+        harper_config: &HarperConfig,
+        dict: Arc<LayeredDictionary>,
+    ) -> (Arc<LayeredDictionary>, Arc<std::sync::Mutex<LintGroup>>) {
+        // Copy necessary configuration to avoid holding lock.
+        let (
+            mut lint_config,
+            mut severity_config,
+            overlap_policy,
+            forbidden_words,
+            mut spell_check_config,
+            editor_settings_id,
+        ) = {
+            let config = self.config.read().await;
+            (
+                config.lint_config.clone(),
+                config.severity_config.clone(),
+                config.overlap_policy,
+                config.forbidden_words.clone(),
+                config.spell_check_config.clone(),
+                config.settings_id,
+            )
+        };
+
+        // Settings from `harper.toml` files take precedence over the editor's.
+        lint_config.merge_from(&mut harper_config.rules.clone());
+        severity_config.merge_from(&mut harper_config.severities.clone());
+        spell_check_config.layer_harper_config(harper_config);
+
+        let mut hasher = DefaultHasher::new();
+        editor_settings_id.hash(&mut hasher);
+        serde_json::to_value(harper_config)
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+            .hash(&mut hasher);
+        let settings_id = hasher.finish();
+
+        self.linters.lock().await.get_or_build(
+            spell_check_config.dialect,
+            &dict,
+            settings_id,
+            || {
+                LintGroup::new_curated(dict.clone())
+                    .with_lint_config(lint_config)
+                    .with_severity_config(severity_config)
+                    .with_overlap_policy(overlap_policy)
+                    .with_forbidden_words(forbidden_words)
+                    .with_terminology(harper_config.terminology.clone())
+                    .with_document_genre(harper_config.genre)
+                    .with_transition_overuse(
+                        harper_config
+                            .thresholds
+                            .layer_transition_overuse(TransitionOveruse::default()),
+                    )
+                    .with_sensitive_words(harper_config.screening.build_sensitive_words())
+                    .with_spell_check(spell_check_config.build(dict.clone()))
+                    .with_dialect(spell_check_config.dialect)
+            },
+        )
+    }
+
     async fn update_document_from_file(&self, url: &Url, language_id: Option<&str>) -> Result<()> {
         let content = tokio::fs::read_to_string(
             url.to_file_path()
@@ -137,28 +321,49 @@ impl Backend {
         text: &str,
         language_id: Option<&str>,
     ) -> Result<()> {
+        if self.is_ignored(url).await {
+            self.doc_state.lock().await.remove(url);
+            return Ok(());
+        }
+
         self.pull_config().await;
 
         // Copy necessary configuration to avoid holding lock.
-        let (lint_config, markdown_options, isolate_english) = {
+        let (markdown_options, isolate_english, skip_non_english, file_types, source_code) = {
             let config = self.config.read().await;
             (
-                config.lint_config.clone(),
                 config.markdown_options,
                 config.isolate_english,
+                config.skip_non_english,
+                config.file_types.clone(),
+                config.source_code.clone(),
             )
         };
 
-        let dict = Arc::new(
-            self.generate_file_dictionary(url)
+        let path = url.to_file_path().ok();
+        let language_id = file_types.resolve(path.as_deref(), language_id);
+
+        let harper_config = self.load_harper_config(url).await;
+
+        let mut dict = self
+            .generate_file_dictionary(url)
+            .await
+            .context("Unable to generate the file dictionary.")?;
+        harper_config
+            .push_dictionaries(&mut dict)
+            .context("Unable to load the dictionaries listed in harper.toml.")?;
+        let (dict, linter) = self.linter_for(&harper_config, Arc::new(dict)).await;
+        let ignored_words = Arc::new(
+            self.load_file_ignore_list(url)
                 .await
-                .context("Unable to generate the file dictionary.")?,
+                .context("Unable to load the file's ignored words.")?,
         );
 
         let mut doc_lock = self.doc_state.lock().await;
 
         let doc_state = doc_lock.entry(url.clone()).or_insert(DocumentState {
-            linter: LintGroup::new_curated(dict.clone()).with_lint_config(lint_config.clone()),
+            linter: linter.clone(),
+            ignored_words: ignored_words.clone(),
             language_id: language_id.map(|v| v.to_string()),
             dict: dict.clone(),
             url: url.clone(),
@@ -167,10 +372,13 @@ impl Backend {
 
         if doc_state.dict != dict {
             doc_state.dict = dict.clone();
-            doc_state.linter =
-                LintGroup::new_curated(dict.clone()).with_lint_config(lint_config.clone());
+            // The identifiers were layered into the old dictionary, so they need to be again.
+            doc_state.ident_dict = Arc::default();
+            doc_state.linter = linter;
         }
 
+        doc_state.ignored_words = ignored_words;
+
         let Some(language_id) = &doc_state.language_id else {
             doc_lock.remove(url);
             return Ok(());
@@ -182,18 +390,19 @@ impl Backend {
             parser: impl Parser + 'static,
             url: &'a Url,
             doc_state: &'a mut DocumentState,
-            lint_config: &LintGroupConfig,
         ) -> Result<Box<dyn Parser>> {
             if doc_state.ident_dict != new_dict {
                 doc_state.ident_dict = new_dict.clone();
 
+                let harper_config = backend.load_harper_config(url).await;
+
                 let mut merged = backend.generate_file_dictionary(url).await?;
-                merged.add_dictionary(new_dict);
-                let merged = Arc::new(merged);
+                harper_config.push_dictionaries(&mut merged)?;
+                merged.push_dictionary(new_dict);
 
-                doc_state.linter =
-                    LintGroup::new_curated(merged.clone()).with_lint_config(lint_config.clone());
-                doc_state.dict = merged.clone();
+                let (merged, linter) = backend.linter_for(&harper_config, Arc::new(merged)).await;
+                doc_state.linter = linter;
+                doc_state.dict = merged;
             }
 
             Ok(Box::new(CollapseIdentifiers::new(
@@ -202,24 +411,26 @@ impl Backend {
             )))
         }
 
-        let source: Vec<char> = text.chars().collect();
-        let ts_parser = CommentParser::new_from_language_id(language_id, markdown_options);
+        // Collected once and shared with the document, rather than copied for each use.
+        let source: Arc<Vec<char>> = Arc::new(text.chars().collect());
+        // Code and markup like Typst can't be parsed a paragraph at a time.
+        let incremental = matches!(language_id.as_str(), "markdown" | "mail" | "plaintext");
+        let code_scope = source_code.scope(language_id);
+        let ts_parser = CommentParser::new_from_language_id_with_scope(
+            language_id,
+            markdown_options,
+            code_scope,
+        );
         let parser: Option<Box<dyn Parser>> = match language_id.as_str() {
             _ if ts_parser.is_some() => {
                 let ts_parser = ts_parser.unwrap();
 
-                if let Some(new_dict) = ts_parser.create_ident_dict(&Arc::new(source)) {
-                    Some(
-                        use_ident_dict(
-                            self,
-                            Arc::new(new_dict),
-                            ts_parser,
-                            url,
-                            doc_state,
-                            &lint_config,
-                        )
-                        .await?,
-                    )
+                // Identifiers can't be checked if they're all considered correct.
+                if code_scope.identifiers {
+                    doc_state.ident_dict = Arc::default();
+                    Some(Box::new(ts_parser))
+                } else if let Some(new_dict) = ts_parser.create_ident_dict(&source) {
+                    Some(use_ident_dict(self, Arc::new(new_dict), ts_parser, url, doc_state).await?)
                 } else {
                     Some(Box::new(ts_parser))
                 }
@@ -227,20 +438,8 @@ impl Backend {
             "literate haskell" | "lhaskell" => {
                 let parser = LiterateHaskellParser::new_markdown(markdown_options);
 
-                if let Some(new_dict) =
-                    parser.create_ident_dict(&Arc::new(source), markdown_options)
-                {
-                    Some(
-                        use_ident_dict(
-                            self,
-                            Arc::new(new_dict),
-                            parser,
-                            url,
-                            doc_state,
-                            &lint_config,
-                        )
-                        .await?,
-                    )
+                if let Some(new_dict) = parser.create_ident_dict(&source, markdown_options) {
+                    Some(use_ident_dict(self, Arc::new(new_dict), parser, url, doc_state).await?)
                 } else {
                     Some(Box::new(parser))
                 }
@@ -264,24 +463,177 @@ impl Backend {
                     parser = Box::new(IsolateEnglish::new(parser, doc_state.dict.clone()));
                 }
 
-                doc_state.document = Document::new(text, &parser, &doc_state.dict);
+                if skip_non_english {
+                    parser = Box::new(MarkNonEnglish::new(parser));
+                }
+
+                doc_state.document = Document::new_from_vec(source, &parser, &doc_state.dict);
+                doc_state.lints = None;
+                doc_state.parser = Some(parser);
+                doc_state.incremental = incremental;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ask the client to apply every safe fix in a document.
+    /// The client reports the change back through `textDocument/didChange`, like any other edit.
+    async fn fix_on_save(&self, url: &Url) {
+        let edit = {
+            let mut doc_lock = self.doc_state.lock().await;
+            let Some(doc_state) = doc_lock.get_mut(url) else {
+                return;
+            };
+
+            doc_state.safe_fix_edit()
+        };
+
+        let Some(edit) = edit else {
+            return;
+        };
+
+        let result = self
+            .client
+            .apply_edit(WorkspaceEdit {
+                changes: Some(HashMap::from([(url.clone(), vec![edit])])),
+                document_changes: None,
+                change_annotations: None,
+            })
+            .await;
+
+        match result {
+            Ok(response) if !response.applied => warn!(
+                "The client declined to apply fixes: {}",
+                response.failure_reason.unwrap_or_default()
+            ),
+            Ok(_) => (),
+            Err(err) => error!("Unable to apply fixes: {err}"),
+        }
+    }
+
+    /// Apply a client's edits to a document.
+    ///
+    /// When the document's language allows it, only the paragraphs an edit touches are
+    /// re-parsed and re-linted.
+    /// Otherwise, the edits are applied to the text and the document is rebuilt.
+    async fn apply_changes(
+        &self,
+        url: &Url,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Result<()> {
+        let mut doc_lock = self.doc_state.lock().await;
+
+        let Some(doc_state) = doc_lock.get_mut(url) else {
+            drop(doc_lock);
+
+            // Without the previous text, only a replacement of the whole document can be used.
+            if let Some(last) = changes.last()
+                && last.range.is_none()
+            {
+                self.update_document(url, &last.text, None).await?;
+            }
+
+            return Ok(());
+        };
+
+        let mut full_text: Option<Vec<char>> = None;
+
+        for change in changes {
+            let new_text: Vec<char> = change.text.chars().collect();
+
+            let Some(range) = change.range else {
+                full_text = Some(new_text);
+                continue;
+            };
+
+            if let Some(text) = &mut full_text {
+                let span = range_to_span(&LineIndex::new(text), range);
+                text.splice(span.start..span.end, new_text);
+            } else if doc_state.incremental && doc_state.parser.is_some() {
+                let span = range_to_span(&LineIndex::new(doc_state.document.get_source()), range);
+                doc_state.apply_edit(span, &new_text);
+            } else {
+                let mut text = doc_state.document.get_source().to_vec();
+                let span = range_to_span(&LineIndex::new(&text), range);
+                text.splice(span.start..span.end, new_text);
+                full_text = Some(text);
             }
         }
 
+        drop(doc_lock);
+
+        if let Some(text) = full_text {
+            self.update_document(url, &text.into_iter().collect::<String>(), None)
+                .await?;
+        }
+
         Ok(())
     }
 
+    /// Rebuild every open document from its current text, so changes to the settings and
+    /// dictionaries they share are reflected in their diagnostics.
+    ///
+    /// `changed` is reloaded from disk too, in case it isn't open yet.
+    async fn reload_open_documents(&self, changed: Option<&Url>) {
+        self.settings.lock().await.clear();
+
+        let documents: Vec<(Url, String)> = self
+            .doc_state
+            .lock()
+            .await
+            .iter()
+            .map(|(url, state)| (url.clone(), state.document.get_full_string()))
+            .collect();
+
+        let mut urls = Vec::new();
+
+        if let Some(url) = changed
+            && !documents.iter().any(|(open, _)| open == url)
+        {
+            self.update_document_from_file(url, None)
+                .await
+                .map_err(|err| error!("{err}"))
+                .err();
+            urls.push(url.clone());
+        }
+
+        for (url, text) in documents {
+            self.update_document(&url, &text, None)
+                .await
+                .map_err(|err| error!("{err}"))
+                .err();
+            urls.push(url);
+        }
+
+        if self.pull_diagnostics.load(Ordering::Relaxed) {
+            self.refresh_diagnostics().await;
+            return;
+        }
+
+        // Documents that are now ignored have been removed, so this clears their diagnostics.
+        for url in urls {
+            self.publish_diagnostics(&url).await;
+        }
+    }
+
     async fn generate_code_actions(
         &self,
         url: &Url,
         range: Range,
+        only: Option<&[CodeActionKind]>,
     ) -> JsonResult<Vec<CodeActionOrCommand>> {
         let (config, mut doc_states) = tokio::join!(self.config.read(), self.doc_state.lock());
         let Some(doc_state) = doc_states.get_mut(url) else {
             return Ok(Vec::new());
         };
 
-        Ok(doc_state.generate_code_actions(range, &config.code_action_config))
+        Ok(doc_state.generate_code_actions(
+            range,
+            only,
+            &config.code_action_config,
+            &config.spell_check_config,
+        ))
     }
 
     async fn generate_diagnostics(&self, url: &Url) -> Vec<Diagnostic> {
@@ -293,15 +645,55 @@ impl Backend {
 
         let mut doc_states = self.doc_state.lock().await;
         let Some(doc_state) = doc_states.get_mut(url) else {
-            return Vec::new();
+            return self
+                .scanned
+                .lock()
+                .await
+                .get(url)
+                .cloned()
+                .unwrap_or_default();
         };
 
         doc_state.generate_diagnostics(diagnostic_severity)
     }
 
+    /// Produce a report of a document's diagnostics for a client that pulls them.
+    ///
+    /// If they haven't changed since the report identified by `previous_result_id`, the report
+    /// just says so.
+    async fn generate_diagnostic_report(
+        &self,
+        url: &Url,
+        previous_result_id: Option<&str>,
+    ) -> DocumentDiagnosticReportKind {
+        let diagnostics = self.generate_diagnostics(url).await;
+        let result_id = diagnostic_result_id(&diagnostics);
+
+        if previous_result_id == Some(result_id.as_str()) {
+            return UnchangedDocumentDiagnosticReport { result_id }.into();
+        }
+
+        FullDocumentDiagnosticReport {
+            result_id: Some(result_id),
+            items: diagnostics,
+        }
+        .into()
+    }
+
+    /// Send the current diagnostics of a document to the client.
+    ///
+    /// Clients that pull diagnostics are instead asked to request them again.
     async fn publish_diagnostics(&self, url: &Url) {
+        if self.pull_diagnostics.load(Ordering::Relaxed) {
+            self.refresh_diagnostics().await;
+            return;
+        }
+
         let diagnostics = self.generate_diagnostics(url).await;
+        self.send_diagnostics(url, diagnostics).await;
+    }
 
+    async fn send_diagnostics(&self, url: &Url, diagnostics: Vec<Diagnostic>) {
         let result = PublishDiagnosticsParams {
             uri: url.clone(),
             diagnostics,
@@ -313,6 +705,137 @@ impl Backend {
             .await;
     }
 
+    /// Ask a client that pulls diagnostics to request them again, if it supports doing so.
+    async fn refresh_diagnostics(&self) {
+        if !self.diagnostic_refresh.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            warn!("Unable to refresh diagnostics: {err}");
+        }
+    }
+
+    /// Lint every file in the workspace that `harper-ls` supports, reporting progress to the
+    /// client as it goes.
+    ///
+    /// Files that are already open are left as they are, since they may have unsaved changes.
+    /// The others are dropped as soon as they have been linted, keeping only their diagnostics
+    /// for clients that pull them (see [`Self::scanned`]).
+    async fn lint_workspace(&self) {
+        let roots = self.workspace_roots.read().await.clone();
+        let file_types = self.config.read().await.file_types.clone();
+
+        let files =
+            match tokio::task::spawn_blocking(move || find_workspace_files(&roots, &file_types))
+                .await
+            {
+                Ok(files) => files,
+                Err(err) => {
+                    error!("Unable to search the workspace: {err}");
+                    return;
+                }
+            };
+
+        let pull_diagnostics = self.pull_diagnostics.load(Ordering::Relaxed);
+
+        let token = self.begin_progress("Linting workspace").await;
+
+        for (index, (path, language_id)) in files.iter().enumerate() {
+            if let Some(token) = &token {
+                self.send_progress(
+                    token,
+                    WorkDoneProgress::Report(WorkDoneProgressReport {
+                        cancellable: Some(false),
+                        message: Some(format!("{}/{}", index + 1, files.len())),
+                        percentage: Some((index * 100 / files.len()) as u32),
+                    }),
+                )
+                .await;
+            }
+
+            let Ok(url) = Url::from_file_path(path) else {
+                continue;
+            };
+
+            // Keep the file from being opened while it is linted, so the open document isn't
+            // dropped along with the scanned one.
+            let _edit_guard = self.edit_lock.lock().await;
+
+            if self.doc_state.lock().await.contains_key(&url) {
+                continue;
+            }
+
+            if let Err(err) = self
+                .update_document_from_file(&url, Some(language_id))
+                .await
+            {
+                error!("{err}");
+                continue;
+            }
+
+            let diagnostics = self.generate_diagnostics(&url).await;
+            self.doc_state.lock().await.remove(&url);
+
+            if pull_diagnostics {
+                self.scanned.lock().await.insert(url, diagnostics);
+            } else {
+                self.send_diagnostics(&url, diagnostics).await;
+            }
+        }
+
+        if let Some(token) = &token {
+            self.send_progress(
+                token,
+                WorkDoneProgress::End(WorkDoneProgressEnd {
+                    message: Some(format!("Linted {} files", files.len())),
+                }),
+            )
+            .await;
+        }
+
+        if pull_diagnostics {
+            self.refresh_diagnostics().await;
+        }
+    }
+
+    /// Start reporting the progress of a task through `$/progress`.
+    ///
+    /// Returns `None` if the client doesn't support it.
+    async fn begin_progress(&self, title: &str) -> Option<NumberOrString> {
+        let token = NumberOrString::String(format!("harper-ls/{title}"));
+
+        self.client
+            .send_request::<WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+            .map_err(|err| warn!("Unable to report progress: {err}"))
+            .ok()?;
+
+        self.send_progress(
+            &token,
+            WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.to_owned(),
+                cancellable: Some(false),
+                message: None,
+                percentage: Some(0),
+            }),
+        )
+        .await;
+
+        Some(token)
+    }
+
+    async fn send_progress(&self, token: &NumberOrString, progress: WorkDoneProgress) {
+        self.client
+            .send_notification::<Progress>(ProgressParams {
+                token: token.clone(),
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
+    }
+
     /// Update the configuration of the server and publish document updates that
     /// match it.
     async fn update_config_from_obj(&self, json_obj: Value) {
@@ -340,24 +863,74 @@ impl Backend {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> JsonResult<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> JsonResult<InitializeResult> {
+        let capabilities = &params.capabilities;
+
+        let roots: Vec<&Url> = match &params.workspace_folders {
+            Some(folders) => folders.iter().map(|folder| &folder.uri).collect(),
+            None => params.root_uri.iter().collect(),
+        };
+        *self.workspace_roots.write().await = roots
+            .into_iter()
+            .filter_map(|uri| uri.to_file_path().ok())
+            .collect();
+
+        self.pull_diagnostics.store(
+            capabilities
+                .text_document
+                .as_ref()
+                .is_some_and(|text_document| text_document.diagnostic.is_some()),
+            Ordering::Relaxed,
+        );
+        self.diagnostic_refresh.store(
+            capabilities
+                .workspace
+                .as_ref()
+                .and_then(|workspace| workspace.diagnostic.as_ref())
+                .and_then(|diagnostic| diagnostic.refresh_support)
+                .unwrap_or(false),
+            Ordering::Relaxed,
+        );
+
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
-                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
+                    DiagnosticOptions {
+                        identifier: Some("harper".to_owned()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: true,
+                        ..Default::default()
+                    },
+                )),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::SOURCE_FIX_ALL,
+                            CodeActionKind::from(SOURCE_FIX_ALL_HARPER),
+                        ]),
+                        ..Default::default()
+                    },
+                )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
                 execute_command_provider: Some(ExecuteCommandOptions {
                     commands: vec![
                         "HarperAddToUserDict".to_owned(),
                         "HarperAddToFileDict".to_owned(),
+                        "HarperAddToWorkspaceDict".to_owned(),
+                        "HarperAddWordsToUserDict".to_owned(),
                         "HarperOpen".to_owned(),
                         "HarperIgnoreLint".to_owned(),
+                        "HarperIgnoreWordInFile".to_owned(),
+                        "HarperLintWorkspace".to_owned(),
                     ],
                     ..Default::default()
                 }),
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
                     TextDocumentSyncOptions {
                         open_close: Some(true),
-                        change: Some(TextDocumentSyncKind::FULL),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
                         will_save: None,
                         will_save_wait_until: None,
                         save: Some(TextDocumentSyncSaveOptions::Supported(true)),
@@ -380,10 +953,15 @@ impl LanguageServer for Backend {
             method: "workspace/didChangeWatchedFiles".to_owned(),
             register_options: Some(
                 serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
-                    watchers: vec![FileSystemWatcher {
+                    watchers: std::iter::once(FileSystemWatcher {
                         glob_pattern: GlobPattern::String("**/*".to_owned()),
                         kind: Some(WatchKind::Delete),
-                    }],
+                    })
+                    .chain(SETTINGS_FILES.iter().map(|file| FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!("**/{file}")),
+                        kind: Some(WatchKind::Create | WatchKind::Change | WatchKind::Delete),
+                    }))
+                    .collect(),
                 })
                 .unwrap(),
             ),
@@ -403,40 +981,173 @@ impl LanguageServer for Backend {
             .map_err(|err| error!("{err}"))
             .err();
 
-        self.publish_diagnostics(&params.text_document.uri).await;
+        if self.config.read().await.fix_on_save {
+            self.fix_on_save(&params.text_document.uri).await;
+        }
+
+        // Clients that pull diagnostics request them after every edit on their own.
+        if !self.pull_diagnostics.load(Ordering::Relaxed) {
+            self.publish_diagnostics(&params.text_document.uri).await;
+        }
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
-        self.update_document(
-            &params.text_document.uri,
-            &params.text_document.text,
-            Some(&params.text_document.language_id),
-        )
-        .await
-        .map_err(|err| error!("{err}"))
-        .err();
+        {
+            let _edit_guard = self.edit_lock.lock().await;
 
-        self.publish_diagnostics(&params.text_document.uri).await;
+            self.scanned.lock().await.remove(&params.text_document.uri);
+
+            self.update_document(
+                &params.text_document.uri,
+                &params.text_document.text,
+                Some(&params.text_document.language_id),
+            )
+            .await
+            .map_err(|err| error!("{err}"))
+            .err();
+        }
+
+        // Clients that pull diagnostics request them after every edit on their own.
+        if !self.pull_diagnostics.load(Ordering::Relaxed) {
+            self.publish_diagnostics(&params.text_document.uri).await;
+        }
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let Some(last) = params.content_changes.last() else {
-            return;
+        let url = params.text_document.uri;
+
+        let (generation, len) = {
+            // Edits build on each other, so they must be applied in the order they were made,
+            // even though the server handles several messages at once.
+            let _edit_guard = self.edit_lock.lock().await;
+
+            if let Err(err) = self.apply_changes(&url, params.content_changes).await {
+                error!("{err}")
+            }
+
+            let mut doc_lock = self.doc_state.lock().await;
+            let Some(doc_state) = doc_lock.get_mut(&url) else {
+                return;
+            };
+
+            doc_state.generation += 1;
+            (doc_state.generation, doc_state.document.get_source().len())
         };
 
-        if let Err(err) = self
-            .update_document(&params.text_document.uri, &last.text, None)
-            .await
-        {
-            error!("{err}")
+        // Clients that pull diagnostics request them after every edit on their own.
+        if self.pull_diagnostics.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let delay = debounce_delay(len);
+
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+
+            // Another edit arrived while waiting, and will publish diagnostics itself.
+            let superseded = self
+                .doc_state
+                .lock()
+                .await
+                .get(&url)
+                .is_none_or(|doc_state| doc_state.generation != generation);
+
+            if superseded {
+                return;
+            }
         }
 
-        self.publish_diagnostics(&params.text_document.uri).await;
+        self.publish_diagnostics(&url).await;
     }
 
     async fn did_close(&self, _params: DidCloseTextDocumentParams) {}
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> JsonResult<DocumentDiagnosticReportResult> {
+        let report = match self
+            .generate_diagnostic_report(
+                &params.text_document.uri,
+                params.previous_result_id.as_deref(),
+            )
+            .await
+        {
+            DocumentDiagnosticReportKind::Full(full_document_diagnostic_report) => {
+                DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                    related_documents: None,
+                    full_document_diagnostic_report,
+                })
+            }
+            DocumentDiagnosticReportKind::Unchanged(unchanged_document_diagnostic_report) => {
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report,
+                })
+            }
+        };
+
+        Ok(report.into())
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> JsonResult<WorkspaceDiagnosticReportResult> {
+        let previous: HashMap<Url, String> = params
+            .previous_result_ids
+            .into_iter()
+            .map(|previous| (previous.uri, previous.value))
+            .collect();
+
+        let mut urls: Vec<Url> = self.doc_state.lock().await.keys().cloned().collect();
+        urls.extend(self.scanned.lock().await.keys().cloned());
+
+        let mut items = Vec::with_capacity(urls.len());
+
+        for uri in urls {
+            let report = self
+                .generate_diagnostic_report(&uri, previous.get(&uri).map(String::as_str))
+                .await;
+
+            items.push(match report {
+                DocumentDiagnosticReportKind::Full(full_document_diagnostic_report) => {
+                    WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                        uri,
+                        version: None,
+                        full_document_diagnostic_report,
+                    })
+                }
+                DocumentDiagnosticReportKind::Unchanged(unchanged_document_diagnostic_report) => {
+                    WorkspaceDocumentDiagnosticReport::Unchanged(
+                        WorkspaceUnchangedDocumentDiagnosticReport {
+                            uri,
+                            version: None,
+                            unchanged_document_diagnostic_report,
+                        },
+                    )
+                }
+            });
+        }
+
+        Ok(WorkspaceDiagnosticReport { items }.into())
+    }
+
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let settings_changed = params.changes.iter().any(|change| {
+            change
+                .uri
+                .to_file_path()
+                .ok()
+                .and_then(|path| path.file_name().map(|name| name.to_owned()))
+                .is_some_and(|name| SETTINGS_FILES.iter().any(|file| name == *file))
+        });
+
+        if settings_changed {
+            info!("Settings files changed, reloading open documents.");
+            self.reload_open_documents(None).await;
+        }
+
         let mut doc_lock = self.doc_state.lock().await;
         let mut urls_to_clear = Vec::new();
 
@@ -445,6 +1156,12 @@ impl LanguageServer for Backend {
                 continue;
             }
 
+            // `change.uri` could be a directory so use `starts_with` instead of `==`.
+            self.scanned
+                .lock()
+                .await
+                .retain(|url, _| !url.as_str().starts_with(change.uri.as_str()));
+
             doc_lock.retain(|url, _| {
                 // `change.uri` could be a directory so use `starts_with` instead of `==`.
                 let to_remove = url.as_str().starts_with(change.uri.as_str());
@@ -469,6 +1186,12 @@ impl LanguageServer for Backend {
     }
 
     async fn execute_command(&self, params: ExecuteCommandParams) -> JsonResult<Option<Value>> {
+        // The only command that doesn't take arguments.
+        if params.command == "HarperLintWorkspace" {
+            self.lint_workspace().await;
+            return Ok(None);
+        }
+
         let mut string_args = params
             .arguments
             .iter()
@@ -482,8 +1205,6 @@ impl LanguageServer for Backend {
 
         match params.command.as_str() {
             "HarperAddToUserDict" => {
-                let word = &first.chars().collect::<Vec<_>>();
-
                 let Some(second) = string_args.next() else {
                     return Ok(None);
                 };
@@ -491,16 +1212,41 @@ impl LanguageServer for Backend {
                 let file_url = second.parse().unwrap();
 
                 let mut dict = self.load_user_dictionary().await;
-                dict.append_word(word, WordMetadata::default());
-                self.save_user_dictionary(dict)
-                    .await
-                    .map_err(|err| error!("{err}"))
+                dict.add_word(&first);
+                dict.save()
+                    .map_err(|err| error!("Unable to save the dictionary to file: {err}"))
                     .err();
-                self.update_document_from_file(&file_url, None)
-                    .await
-                    .map_err(|err| error!("{err}"))
+                self.reload_open_documents(Some(&file_url)).await;
+            }
+            "HarperAddToWorkspaceDict" => {
+                let Some(second) = string_args.next() else {
+                    return Ok(None);
+                };
+
+                let file_url: Url = second.parse().unwrap();
+
+                let Some(dir) = file_url
+                    .to_file_path()
+                    .ok()
+                    .and_then(|path| path.parent().map(Path::to_path_buf))
+                else {
+                    error!("Only files on disk have a workspace dictionary.");
+                    return Ok(None);
+                };
+
+                let mut dict =
+                    match UserDictionary::open(HarperConfig::workspace_dictionary_path(dir)) {
+                        Ok(dict) => dict,
+                        Err(err) => {
+                            error!("Unable to open the workspace dictionary: {err}");
+                            return Ok(None);
+                        }
+                    };
+                dict.add_word(&first);
+                dict.save()
+                    .map_err(|err| error!("Unable to save the dictionary to file: {err}"))
                     .err();
-                self.publish_diagnostics(&file_url).await;
+                self.reload_open_documents(Some(&file_url)).await;
             }
             "HarperAddToFileDict" => {
                 let word = &first.chars().collect::<Vec<_>>();
@@ -533,6 +1279,55 @@ impl LanguageServer for Backend {
                     .err();
                 self.publish_diagnostics(&file_url).await;
             }
+            "HarperIgnoreWordInFile" => {
+                let word = &first.chars().collect::<Vec<_>>();
+
+                let Some(second) = string_args.next() else {
+                    return Ok(None);
+                };
+
+                let file_url = second.parse().unwrap();
+
+                let mut ignored = match self
+                    .load_file_ignore_list(&file_url)
+                    .await
+                    .map_err(|err| error!("{err}"))
+                {
+                    Ok(ignored) => ignored,
+                    Err(_) => {
+                        return Ok(None);
+                    }
+                };
+                ignored.append_word(word, WordMetadata::default());
+
+                match self.get_file_ignore_list_path(&file_url).await {
+                    Ok(path) => save_dict(path, ignored)
+                        .await
+                        .map_err(|err| error!("{err}"))
+                        .err(),
+                    Err(err) => {
+                        error!("{err}");
+                        return Ok(None);
+                    }
+                };
+                self.update_document_from_file(&file_url, None)
+                    .await
+                    .map_err(|err| error!("{err}"))
+                    .err();
+                self.publish_diagnostics(&file_url).await;
+            }
+            "HarperAddWordsToUserDict" => {
+                let file_url = first.parse().unwrap();
+
+                let mut dict = self.load_user_dictionary().await;
+                for word in string_args {
+                    dict.add_word(&word);
+                }
+                dict.save()
+                    .map_err(|err| error!("Unable to save the dictionary to file: {err}"))
+                    .err();
+                self.reload_open_documents(Some(&file_url)).await;
+            }
             "HarperOpen" => match open::that(&first) {
                 Ok(()) => {
                     let message = format!(r#"Opened "{}""#, first);
@@ -584,26 +1379,18 @@ impl LanguageServer for Backend {
 
     async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
         self.update_config_from_obj(params.settings).await;
+        self.reload_open_documents(None).await;
+    }
 
-        let urls: Vec<Url> = {
-            let mut doc_lock = self.doc_state.lock().await;
-            let config_lock = self.config.read().await;
-
-            for doc in doc_lock.values_mut() {
-                doc.linter = LintGroup::new_curated(doc.dict.clone())
-                    .with_lint_config(config_lock.lint_config.clone());
-            }
+    async fn hover(&self, params: HoverParams) -> JsonResult<Option<Hover>> {
+        let params = params.text_document_position_params;
 
-            doc_lock.keys().cloned().collect()
+        let mut doc_states = self.doc_state.lock().await;
+        let Some(doc_state) = doc_states.get_mut(&params.text_document.uri) else {
+            return Ok(None);
         };
 
-        for url in urls {
-            self.update_document_from_file(&url, None)
-                .await
-                .map_err(|err| error!("{err}"))
-                .err();
-            self.publish_diagnostics(&url).await;
-        }
+        Ok(doc_state.hover(params.position))
     }
 
     async fn code_action(
@@ -611,7 +1398,11 @@ impl LanguageServer for Backend {
         params: CodeActionParams,
     ) -> JsonResult<Option<CodeActionResponse>> {
         let actions = self
-            .generate_code_actions(&params.text_document.uri, params.range)
+            .generate_code_actions(
+                &params.text_document.uri,
+                params.range,
+                params.context.only.as_deref(),
+            )
             .await?;
 
         Ok(Some(actions))
@@ -1,12 +1,22 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::PathBuf;
 
 use anyhow::{Result, bail};
-use dirs::{config_dir, data_local_dir};
-use harper_core::{linting::LintGroupConfig, parsers::MarkdownOptions};
+use dirs::data_local_dir;
+use harper_comments::CodeScope;
+use harper_core::linting::{
+    ForbiddenWords, IdentifierHandling, IgnorePattern, LintGroupConfig, OverlapPolicy,
+    SeverityConfig, SkipWords, SpellCheck,
+};
+use harper_core::parsers::MarkdownOptions;
+use harper_core::{Dialect, Dictionary, HarperConfig, KeyboardLayout, UserDictionary};
 use resolve_path::PathResolveExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::file_types::FileTypeMap;
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum DiagnosticSeverity {
@@ -61,15 +71,97 @@ impl CodeActionConfig {
     }
 }
 
+/// Which parts of each language's source code are checked, besides comments, as set by the
+/// `sourceCode` setting.
+///
+/// Keyed by language ID.
+/// Languages that aren't listed only have their comments checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct SourceCodeConfig {
+    inner: HashMap<String, CodeScope>,
+}
+
+impl SourceCodeConfig {
+    pub fn scope(&self, language_id: &str) -> CodeScope {
+        self.inner.get(language_id).copied().unwrap_or_default()
+    }
+}
+
+/// Options for the [`SpellCheck`] rule.
+#[derive(Debug, Clone, Default)]
+pub struct SpellCheckConfig {
+    /// Used to rank spelling suggestions by how likely they are to be typos.
+    pub keyboard_layout: KeyboardLayout,
+    /// The variety of English to check spelling against.
+    pub dialect: Dialect,
+    /// Whether to accept words made by joining two dictionary words.
+    pub allow_closed_compounds: bool,
+    /// When set, unknown words used at least this many times in a document are reported
+    /// together, so they can be added to the dictionary at once.
+    pub learning_threshold: Option<usize>,
+    /// Whether code identifiers in prose are skipped or have their words checked.
+    pub identifier_handling: IdentifierHandling,
+    /// Regular expressions for text that is never spell checked.
+    pub ignore_patterns: Vec<IgnorePattern>,
+    /// Kinds of words that are never spell checked.
+    pub skip_words: SkipWords,
+}
+
+impl SpellCheckConfig {
+    pub fn build<T: Dictionary>(&self, dictionary: T) -> SpellCheck<T> {
+        SpellCheck::new(dictionary)
+            .with_keyboard_layout(self.keyboard_layout)
+            .with_dialect(self.dialect)
+            .with_closed_compounds(self.allow_closed_compounds)
+            .with_learning_threshold(self.learning_threshold)
+            .with_identifier_handling(self.identifier_handling)
+            .with_ignore_patterns(self.ignore_patterns.clone())
+            .with_skip_words(self.skip_words)
+    }
+
+    /// Take on the settings from a `harper.toml` file, where they were set.
+    pub fn layer_harper_config(&mut self, harper_config: &HarperConfig) {
+        if let Some(dialect) = harper_config.dialect {
+            self.dialect = dialect;
+        }
+
+        if let Some(learning) = harper_config.thresholds.learning {
+            self.learning_threshold = Some(learning);
+        }
+
+        self.ignore_patterns
+            .extend(harper_config.spelling.ignore_patterns.iter().cloned());
+        self.skip_words = harper_config.spelling.layer_skip_words(self.skip_words);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub user_dict_path: PathBuf,
     pub file_dict_path: PathBuf,
     pub lint_config: LintGroupConfig,
+    /// Per-rule severities, which take precedence over [`Self::diagnostic_severity`].
+    pub severity_config: SeverityConfig,
     pub diagnostic_severity: DiagnosticSeverity,
+    /// How lints from different rules that cover the same text are reconciled.
+    pub overlap_policy: OverlapPolicy,
+    /// Words banned by the user's style guide.
+    pub forbidden_words: ForbiddenWords,
+    pub spell_check_config: SpellCheckConfig,
     pub code_action_config: CodeActionConfig,
+    /// Whether safe fixes are applied automatically when a document is saved.
+    pub fix_on_save: bool,
     pub isolate_english: bool,
+    /// Whether paragraphs confidently identified as another language are left unlinted.
+    pub skip_non_english: bool,
     pub markdown_options: MarkdownOptions,
+    /// Extra file types to lint, and how to parse them.
+    pub file_types: FileTypeMap,
+    pub source_code: SourceCodeConfig,
+    /// Identifies the settings this was read from, so linters built from equal settings can be
+    /// shared.
+    pub settings_id: u64,
 }
 
 impl Config {
@@ -84,6 +176,10 @@ impl Config {
             bail!("Settings must contain a \"harper-ls\" key.");
         };
 
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        base.settings_id = hasher.finish();
+
         if let Some(v) = value.get("userDictPath") {
             if !v.is_string() {
                 bail!("userDict path must be a string.");
@@ -110,14 +206,70 @@ impl Config {
             base.lint_config = serde_json::from_value(v.clone())?;
         }
 
+        if let Some(v) = value.get("linterSeverities") {
+            base.severity_config = serde_json::from_value(v.clone())?;
+        }
+
         if let Some(v) = value.get("diagnosticSeverity") {
             base.diagnostic_severity = serde_json::from_value(v.clone())?;
         }
 
+        if let Some(v) = value.get("overlapPolicy") {
+            base.overlap_policy = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("forbiddenWords") {
+            base.forbidden_words = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("keyboardLayout") {
+            base.spell_check_config.keyboard_layout = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("dialect") {
+            if let Value::String(v) = v {
+                base.spell_check_config.dialect = v.parse().map_err(anyhow::Error::msg)?;
+            } else {
+                bail!("dialect must be a string.");
+            }
+        }
+
+        if let Some(v) = value.get("allowClosedCompounds") {
+            if let Value::Bool(v) = v {
+                base.spell_check_config.allow_closed_compounds = *v;
+            } else {
+                bail!("allowClosedCompounds must be a boolean.");
+            }
+        }
+
+        if let Some(v) = value.get("learningThreshold") {
+            base.spell_check_config.learning_threshold = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("identifiers") {
+            base.spell_check_config.identifier_handling = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("ignorePatterns") {
+            base.spell_check_config.ignore_patterns = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("skipWords") {
+            base.spell_check_config.skip_words = serde_json::from_value(v.clone())?;
+        }
+
         if let Some(v) = value.get("codeActions") {
             base.code_action_config = CodeActionConfig::from_lsp_config(v.clone())?;
         }
 
+        if let Some(v) = value.get("fixOnSave") {
+            if let Value::Bool(v) = v {
+                base.fix_on_save = *v;
+            } else {
+                bail!("fixOnSave must be a boolean.");
+            }
+        }
+
         if let Some(v) = value.get("isolateEnglish") {
             if let Value::Bool(v) = v {
                 base.isolate_english = *v;
@@ -126,6 +278,22 @@ impl Config {
             }
         }
 
+        if let Some(v) = value.get("skipNonEnglish") {
+            if let Value::Bool(v) = v {
+                base.skip_non_english = *v;
+            } else {
+                bail!("skipNonEnglish must be a boolean.");
+            }
+        }
+
+        if let Some(v) = value.get("fileTypes") {
+            base.file_types = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("sourceCode") {
+            base.source_code = serde_json::from_value(v.clone())?;
+        }
+
         if let Some(v) = value.get("markdown") {
             if let Some(v) = v.get("IgnoreLinkTitle") {
                 base.markdown_options.ignore_link_title = serde_json::from_value(v.clone())?;
@@ -139,15 +307,24 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
-            user_dict_path: config_dir().unwrap().join("harper-ls/dictionary.txt"),
+            user_dict_path: UserDictionary::default_path().unwrap(),
             file_dict_path: data_local_dir()
                 .unwrap()
                 .join("harper-ls/file_dictionaries/"),
             lint_config: LintGroupConfig::default(),
+            severity_config: SeverityConfig::default(),
             diagnostic_severity: DiagnosticSeverity::Hint,
+            overlap_policy: OverlapPolicy::default(),
+            forbidden_words: ForbiddenWords::default(),
+            spell_check_config: SpellCheckConfig::default(),
             code_action_config: CodeActionConfig::default(),
+            fix_on_save: false,
             isolate_english: false,
+            skip_non_english: true,
             markdown_options: MarkdownOptions::default(),
+            file_types: FileTypeMap::default(),
+            source_code: SourceCodeConfig::default(),
+            settings_id: 0,
         }
     }
 }
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use harper_core::CharStringExt;
-use harper_core::linting::{Lint, Suggestion};
+use harper_core::fixer::{FixResult, fix_safe, is_safe_fix};
+use harper_core::linting::{Lint, RuleDescription, Severity};
+use harper_core::{CharStringExt, LineIndex, Span};
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, TextEdit, Url,
     WorkspaceEdit,
@@ -10,14 +11,19 @@ use tower_lsp::lsp_types::{
 use crate::config::{CodeActionConfig, DiagnosticSeverity};
 use crate::pos_conv::span_to_range;
 
+/// The kind of the code action that applies every safe fix in a document.
+pub const SOURCE_FIX_ALL_HARPER: &str = "source.fixAll.harper";
+
 pub fn lints_to_diagnostics(
     source: &[char],
     lints: &[Lint],
     severity: DiagnosticSeverity,
 ) -> Vec<Diagnostic> {
+    let line_index = LineIndex::new(source);
+
     lints
         .iter()
-        .map(|lint| lint_to_diagnostic(lint, source, severity))
+        .map(|lint| lint_to_diagnostic(lint, &line_index, severity))
         .collect()
 }
 
@@ -25,6 +31,7 @@ pub fn lint_to_code_actions<'a>(
     lint: &'a Lint,
     url: &'a Url,
     source: &'a [char],
+    line_index: &LineIndex,
     config: &CodeActionConfig,
 ) -> Vec<CodeActionOrCommand> {
     let mut results = Vec::new();
@@ -33,30 +40,21 @@ pub fn lint_to_code_actions<'a>(
         lint.suggestions
             .iter()
             .flat_map(|suggestion| {
-                let range = span_to_range(source, lint.span);
-
-                let replace_string = match suggestion {
-                    Suggestion::ReplaceWith(with) => with.iter().collect(),
-                    Suggestion::Remove => "".to_string(),
-                    Suggestion::InsertAfter(with) => format!(
-                        "{}{}",
-                        lint.span.get_content_string(source),
-                        with.to_string()
-                    ),
-                };
+                let edits = suggestion
+                    .to_edits(lint.span)
+                    .into_iter()
+                    .map(|edit| TextEdit {
+                        range: span_to_range(line_index, edit.span),
+                        new_text: edit.replacement.to_string(),
+                    })
+                    .collect();
 
                 Some(CodeAction {
                     title: suggestion.to_string(),
                     kind: Some(CodeActionKind::QUICKFIX),
                     diagnostics: None,
                     edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            url.clone(),
-                            vec![TextEdit {
-                                range,
-                                new_text: replace_string,
-                            }],
-                        )])),
+                        changes: Some(HashMap::from([(url.clone(), edits)])),
                         document_changes: None,
                         change_annotations: None,
                     }),
@@ -87,9 +85,21 @@ pub fn lint_to_code_actions<'a>(
             Some(vec![orig.clone().into(), url.to_string().into()]),
         )));
 
+        results.push(CodeActionOrCommand::Command(Command::new(
+            format!("Add \"{}\" to the workspace dictionary.", orig),
+            "HarperAddToWorkspaceDict".to_string(),
+            Some(vec![orig.clone().into(), url.to_string().into()]),
+        )));
+
         results.push(CodeActionOrCommand::Command(Command::new(
             format!("Add \"{}\" to the file dictionary.", orig),
             "HarperAddToFileDict".to_string(),
+            Some(vec![orig.clone().into(), url.to_string().into()]),
+        )));
+
+        results.push(CodeActionOrCommand::Command(Command::new(
+            format!("Ignore \"{}\" in this file.", orig),
+            "HarperIgnoreWordInFile".to_string(),
             Some(vec![orig.into(), url.to_string().into()]),
         )));
     }
@@ -101,12 +111,190 @@ pub fn lint_to_code_actions<'a>(
     results
 }
 
-fn lint_to_diagnostic(lint: &Lint, source: &[char], severity: DiagnosticSeverity) -> Diagnostic {
-    let range = span_to_range(source, lint.span);
+/// A `source.fixAll.harper` action that applies every fix in the document that doesn't need a
+/// human to choose it.
+pub fn fix_all_code_action(
+    lints: &[Lint],
+    url: &Url,
+    source: &[char],
+    line_index: &LineIndex,
+) -> Option<CodeActionOrCommand> {
+    let result = fix_safe(source, lints);
+
+    fix_to_code_action(
+        format!("Fix all {} Harper issues in this file.", result.applied),
+        CodeActionKind::from(SOURCE_FIX_ALL_HARPER),
+        &result,
+        url,
+        source,
+        line_index,
+    )
+}
+
+/// For each rule with a lint in `lints_in_range`, an action that fixes every occurrence of it in
+/// the document.
+///
+/// Rules with only one safe fix in the document are left out, since the quick fix already
+/// covers them.
+pub fn fix_rule_code_actions(
+    lints: &[Lint],
+    lints_in_range: &[Lint],
+    url: &Url,
+    source: &[char],
+    line_index: &LineIndex,
+) -> Vec<CodeActionOrCommand> {
+    let mut rules: Vec<&str> = lints_in_range
+        .iter()
+        .filter(|lint| is_safe_fix(lint))
+        .filter_map(|lint| lint.rule.as_deref())
+        .collect();
+    rules.sort_unstable();
+    rules.dedup();
+
+    rules
+        .into_iter()
+        .filter_map(|rule| {
+            let rule_lints: Vec<Lint> = lints
+                .iter()
+                .filter(|lint| lint.rule.as_deref() == Some(rule))
+                .cloned()
+                .collect();
+
+            let result = fix_safe(source, &rule_lints);
+
+            if result.applied < 2 {
+                return None;
+            }
+
+            fix_to_code_action(
+                format!("Fix all {} {rule} issues in this file.", result.applied),
+                CodeActionKind::QUICKFIX,
+                &result,
+                url,
+                source,
+                line_index,
+            )
+        })
+        .collect()
+}
+
+/// Turn the result of the fixer into a code action.
+fn fix_to_code_action(
+    title: String,
+    kind: CodeActionKind,
+    result: &FixResult,
+    url: &Url,
+    source: &[char],
+    line_index: &LineIndex,
+) -> Option<CodeActionOrCommand> {
+    if result.applied == 0 {
+        return None;
+    }
+
+    Some(CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(kind),
+        diagnostics: None,
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                url.clone(),
+                vec![fix_to_text_edit(result, source, line_index)],
+            )])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    }))
+}
+
+/// Turn the result of the fixer into a single edit of `source`.
+///
+/// Rather than replacing the whole document, the edit only covers the region between the first
+/// and last changed characters, so the client can keep the cursor where it is.
+pub fn fix_to_text_edit(result: &FixResult, source: &[char], line_index: &LineIndex) -> TextEdit {
+    let prefix = source
+        .iter()
+        .zip(&result.text)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = source[prefix..]
+        .iter()
+        .rev()
+        .zip(result.text[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    TextEdit {
+        range: span_to_range(line_index, Span::new(prefix, source.len() - suffix)),
+        new_text: result.text[prefix..result.text.len() - suffix]
+            .iter()
+            .collect(),
+    }
+}
+
+/// Explain a lint in Markdown, for display when hovering over it.
+///
+/// Includes the rule's full description and examples when they are known.
+pub fn lint_to_hover_markdown(lint: &Lint, rule: Option<&RuleDescription>) -> String {
+    let mut markdown = format!("**{}**", lint.message);
+
+    let Some(rule) = rule else {
+        return markdown;
+    };
+
+    markdown.push_str("\n\n");
+    markdown.push_str(&rule.description);
+
+    if !rule.examples.is_empty() {
+        markdown.push_str("\n\nExamples:\n");
+
+        for example in &rule.examples {
+            match &example.corrected {
+                Some(corrected) => {
+                    markdown.push_str(&format!("\n- `{}` → `{corrected}`", example.incorrect))
+                }
+                None => markdown.push_str(&format!("\n- `{}`", example.incorrect)),
+            }
+        }
+    }
+
+    markdown.push_str(&format!(
+        "\n\nRule: [`{}`](https://writewithharper.com/docs/rules)",
+        rule.name
+    ));
+
+    markdown
+}
+
+/// Converts a rule's configured [`Severity`] to the equivalent LSP type.
+fn severity_to_lsp(severity: Severity) -> tower_lsp::lsp_types::DiagnosticSeverity {
+    match severity {
+        Severity::Error => tower_lsp::lsp_types::DiagnosticSeverity::ERROR,
+        Severity::Warning => tower_lsp::lsp_types::DiagnosticSeverity::WARNING,
+        Severity::Info => tower_lsp::lsp_types::DiagnosticSeverity::INFORMATION,
+        Severity::Hint => tower_lsp::lsp_types::DiagnosticSeverity::HINT,
+    }
+}
+
+fn lint_to_diagnostic(
+    lint: &Lint,
+    line_index: &LineIndex,
+    severity: DiagnosticSeverity,
+) -> Diagnostic {
+    let range = span_to_range(line_index, lint.span);
+
+    // A severity configured for the specific rule wins over the server-wide default.
+    let severity = lint
+        .severity
+        .map(severity_to_lsp)
+        .unwrap_or_else(|| severity.to_lsp());
 
     Diagnostic {
         range,
-        severity: Some(severity.to_lsp()),
+        severity: Some(severity),
         code: None,
         code_description: None,
         source: Some("Harper".to_string()),
@@ -1,19 +1,47 @@
-use crate::config::{CodeActionConfig, DiagnosticSeverity};
-use crate::diagnostics::{lint_to_code_actions, lints_to_diagnostics};
-use crate::pos_conv::range_to_span;
+use std::sync::Mutex;
+
+use crate::config::{CodeActionConfig, DiagnosticSeverity, SpellCheckConfig};
+use crate::diagnostics::{
+    SOURCE_FIX_ALL_HARPER, fix_all_code_action, fix_rule_code_actions, fix_to_text_edit,
+    lint_to_code_actions, lint_to_hover_markdown, lints_to_diagnostics,
+};
+use crate::pos_conv::{range_to_span, span_to_range};
+use harper_core::fixer::fix_safe;
 use harper_core::linting::{Lint, LintGroup, Linter};
-use harper_core::{Document, IgnoredLints, MergedDictionary, MutableDictionary, TokenKind};
+use harper_core::parsers::Parser;
+use harper_core::{
+    Dictionary, Document, IgnoredLints, LayeredDictionary, LineIndex, MutableDictionary, Span,
+    TokenKind,
+};
 use harper_core::{Lrc, Token};
-use tower_lsp::lsp_types::{CodeActionOrCommand, Command, Diagnostic, Range, Url};
+use tower_lsp::lsp_types::{
+    CodeActionKind, CodeActionOrCommand, Command, Diagnostic, Hover, HoverContents, MarkupContent,
+    MarkupKind, Position, Range, TextEdit, Url,
+};
 
 pub struct DocumentState {
     pub document: Document,
     pub ident_dict: Lrc<MutableDictionary>,
-    pub dict: Lrc<MergedDictionary>,
-    pub linter: LintGroup,
+    pub dict: Lrc<LayeredDictionary>,
+    /// Shared with other documents that are linted with the same dialect, dictionary and
+    /// settings.
+    pub linter: Lrc<Mutex<LintGroup>>,
     pub language_id: Option<String>,
     pub ignored_lints: IgnoredLints,
+    /// Words whose lints are left out in this document only.
+    pub ignored_words: Lrc<MutableDictionary>,
     pub url: Url,
+    /// The parser the document was last built with.
+    pub parser: Option<Box<dyn Parser>>,
+    /// Whether edits can be applied with [`Document::apply_edit`], which re-parses only the
+    /// paragraphs they touch.
+    /// Only true for languages whose paragraphs can be parsed on their own.
+    pub incremental: bool,
+    /// Incremented on every edit, so work that an edit makes obsolete can be skipped.
+    pub generation: u64,
+    /// What the linter last found in the document, before ignored lints are removed.
+    /// `None` when the whole document needs to be linted again.
+    pub lints: Option<Vec<Lint>>,
 }
 
 impl DocumentState {
@@ -21,51 +49,224 @@ impl DocumentState {
         self.ignored_lints.ignore_lint(lint, &self.document);
     }
 
-    pub fn generate_diagnostics(&mut self, severity: DiagnosticSeverity) -> Vec<Diagnostic> {
-        let temp = self.linter.config.clone();
-        self.linter.config.fill_with_curated();
+    /// Apply an edit to the document, re-parsing and re-linting only the paragraphs it touches.
+    ///
+    /// Lints elsewhere are kept, so rules that look across paragraphs are only brought up to
+    /// date when the whole document is linted again.
+    pub fn apply_edit(&mut self, span: Span, new_text: &[char]) {
+        let Some(parser) = &self.parser else {
+            return;
+        };
+
+        let region = self
+            .document
+            .apply_edit(span, new_text, parser, self.dict.as_ref());
+
+        let Some(lints) = self.lints.take() else {
+            return;
+        };
+
+        if region == Span::new(0, self.document.get_source().len()) {
+            return;
+        }
 
-        let mut lints = self.linter.lint(&self.document);
+        // Where the region ended before the edit.
+        let old_region_end = region.end + span.len() - new_text.len();
 
-        self.linter.config = temp;
+        let mut lints: Vec<Lint> = lints
+            .into_iter()
+            .filter_map(|mut lint| {
+                if lint.span.end <= region.start {
+                    Some(lint)
+                } else if lint.span.start >= old_region_end {
+                    if new_text.len() >= span.len() {
+                        lint.push_by(new_text.len() - span.len());
+                    } else {
+                        lint.pull_by(span.len() - new_text.len());
+                    }
+                    Some(lint)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if let Some((sub_span, sub_document)) = self.document.sub_document_within(region) {
+            let new_lints = Self::run_linter(&self.linter, &sub_document);
+            lints.extend(new_lints.into_iter().map(|mut lint| {
+                lint.push_by(sub_span.start);
+                lint
+            }));
+        }
+
+        lints.sort_by_key(|lint| lint.span.start);
+        self.lints = Some(lints);
+    }
+
+    /// Lint the document, leaving out the lints the user has ignored.
+    fn lint(&mut self) -> Vec<Lint> {
+        let mut lints = match &self.lints {
+            Some(lints) => lints.clone(),
+            None => {
+                let lints = Self::run_linter(&self.linter, &self.document);
+                self.lints = Some(lints.clone());
+                lints
+            }
+        };
 
         self.ignored_lints
             .remove_ignored(&mut lints, &self.document);
+        lints.retain(|lint| {
+            !self
+                .ignored_words
+                .contains_word(lint.span.get_content(self.document.get_source()))
+        });
+
+        lints
+    }
+
+    fn run_linter(linter: &Mutex<LintGroup>, document: &Document) -> Vec<Lint> {
+        let mut linter = linter.lock().unwrap();
+
+        let temp = linter.config.clone();
+        linter.config.fill_with_curated();
+
+        let lints = linter.lint(document);
+
+        linter.config = temp;
+
+        lints
+    }
+
+    /// An edit that applies every fix in the document that is safe to make without asking the
+    /// user (see [`harper_core::fixer::is_safe_fix`]).
+    /// `None` if there is nothing to fix.
+    pub fn safe_fix_edit(&mut self) -> Option<TextEdit> {
+        let lints = self.lint();
+        let source = self.document.get_full_content();
+        let result = fix_safe(source, &lints);
+
+        if result.applied == 0 {
+            return None;
+        }
+
+        Some(fix_to_text_edit(&result, source, &LineIndex::new(source)))
+    }
+
+    /// Explain the lints at a position, including the full description of the rules that
+    /// produced them.
+    pub fn hover(&mut self, position: Position) -> Option<Hover> {
+        let lints = self.lint();
+
+        let line_index = LineIndex::new(self.document.get_full_content());
+        let span = range_to_span(
+            &line_index,
+            Range {
+                start: position,
+                end: position,
+            },
+        )
+        .with_len(1);
+
+        let lints: Vec<&Lint> = lints
+            .iter()
+            .filter(|lint| lint.span.overlaps_with(span))
+            .collect();
+
+        let first = lints.first()?;
+
+        let linter = self.linter.lock().unwrap();
+        let sections: Vec<String> = lints
+            .iter()
+            .map(|lint| {
+                let rule = lint
+                    .rule
+                    .as_deref()
+                    .and_then(|rule| linter.rule_description(rule));
+
+                lint_to_hover_markdown(lint, rule.as_ref())
+            })
+            .collect();
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: sections.join("\n\n---\n\n"),
+            }),
+            range: Some(span_to_range(&line_index, first.span)),
+        })
+    }
+
+    pub fn generate_diagnostics(&mut self, severity: DiagnosticSeverity) -> Vec<Diagnostic> {
+        let lints = self.lint();
 
         lints_to_diagnostics(self.document.get_full_content(), &lints, severity)
     }
 
     /// Generate code actions results for a selected area.
+    ///
+    /// If `only` is given, just the actions of those kinds are returned, as the client requested.
     pub fn generate_code_actions(
         &mut self,
         range: Range,
+        only: Option<&[CodeActionKind]>,
         code_action_config: &CodeActionConfig,
+        spell_check_config: &SpellCheckConfig,
     ) -> Vec<CodeActionOrCommand> {
-        let temp = self.linter.config.clone();
-        self.linter.config.fill_with_curated();
+        let mut lints = self.lint();
 
-        let mut lints = self.linter.lint(&self.document);
+        lints.sort_by_key(|l| l.priority);
 
-        self.linter.config = temp;
+        let source_chars = self.document.get_full_content();
+        let line_index = LineIndex::new(source_chars);
 
-        self.ignored_lints
-            .remove_ignored(&mut lints, &self.document);
+        let wants =
+            |kind: &str| only.is_none_or(|only| only.iter().any(|k| kind.starts_with(k.as_str())));
 
-        lints.sort_by_key(|l| l.priority);
+        let mut actions = Vec::new();
 
-        let source_chars = self.document.get_full_content();
+        if wants(SOURCE_FIX_ALL_HARPER) {
+            actions.extend(fix_all_code_action(
+                &lints,
+                &self.url,
+                source_chars,
+                &line_index,
+            ));
+        }
+
+        if !wants(CodeActionKind::QUICKFIX.as_str()) {
+            return actions;
+        }
 
         // Find lints whole span overlaps with range
-        let span = range_to_span(source_chars, range).with_len(1);
+        let span = range_to_span(&line_index, range).with_len(1);
 
-        let mut actions: Vec<CodeActionOrCommand> = lints
-            .into_iter()
+        let lints_in_range: Vec<Lint> = lints
+            .iter()
             .filter(|lint| lint.span.overlaps_with(span))
-            .flat_map(|lint| {
-                lint_to_code_actions(&lint, &self.url, source_chars, code_action_config)
-            })
+            .cloned()
             .collect();
 
+        let learnable_words = self.learnable_words_at(&lints_in_range, spell_check_config);
+
+        actions.extend(fix_rule_code_actions(
+            &lints,
+            &lints_in_range,
+            &self.url,
+            source_chars,
+            &line_index,
+        ));
+
+        actions.extend(lints_in_range.into_iter().flat_map(|lint| {
+            lint_to_code_actions(
+                &lint,
+                &self.url,
+                source_chars,
+                &line_index,
+                code_action_config,
+            )
+        }));
+
         if let Some(Token {
             kind: TokenKind::Url,
             span,
@@ -79,8 +280,52 @@ impl DocumentState {
             )))
         }
 
+        if learnable_words.len() > 1 {
+            let mut arguments = vec![self.url.to_string().into()];
+            arguments.extend(learnable_words.into_iter().map(Into::into));
+
+            actions.push(CodeActionOrCommand::Command(Command::new(
+                format!(
+                    "Add all {} repeated words to the global dictionary.",
+                    arguments.len() - 1
+                ),
+                "HarperAddWordsToUserDict".to_string(),
+                Some(arguments),
+            )));
+        }
+
         actions
     }
+
+    /// If learning from documents is enabled and one of the given lints is about a word that
+    /// is used repeatedly, every such word in the document, so they can be added to the
+    /// dictionary in one go.
+    fn learnable_words_at(
+        &self,
+        lints: &[Lint],
+        spell_check_config: &SpellCheckConfig,
+    ) -> Vec<String> {
+        let Some(threshold) = spell_check_config.learning_threshold else {
+            return Vec::new();
+        };
+
+        let learnable = spell_check_config
+            .build(self.dict.clone())
+            .learnable_words(&self.document, threshold);
+
+        let applies = lints.iter().any(|lint| {
+            lint.lint_kind.is_spelling()
+                && learnable
+                    .iter()
+                    .any(|word| word.occurrences.contains(&lint.span))
+        });
+
+        if !applies {
+            return Vec::new();
+        }
+
+        learnable.into_iter().map(|word| word.word).collect()
+    }
 }
 
 impl Default for DocumentState {
@@ -92,7 +337,83 @@ impl Default for DocumentState {
             linter: Default::default(),
             language_id: Default::default(),
             ignored_lints: Default::default(),
+            ignored_words: Default::default(),
             url: Url::parse("https://example.net").unwrap(),
+            parser: None,
+            incremental: false,
+            generation: 0,
+            lints: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use harper_core::linting::LintGroup;
+    use harper_core::parsers::PlainEnglish;
+    use harper_core::{Document, FstDictionary, LayeredDictionary, MutableDictionary, Span};
+
+    use super::DocumentState;
+
+    fn document_state(text: &str) -> DocumentState {
+        let dict = Arc::new(LayeredDictionary::new().with_dictionary(FstDictionary::curated()));
+
+        DocumentState {
+            document: Document::new(text, &PlainEnglish, &dict),
+            linter: Arc::new(Mutex::new(LintGroup::new_curated(dict.clone()))),
+            dict,
+            parser: Some(Box::new(PlainEnglish)),
+            incremental: true,
+            ..Default::default()
+        }
+    }
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn edits_only_relint_their_paragraphs() {
+        let mut state = document_state("This is a tset.\n\nThe the dog is here.\n\nAll good.");
+        assert_eq!(state.lint().len(), 2);
+
+        state.apply_edit(Span::new(10, 14), &chars("test"));
+        state.apply_edit(Span::new(0, 0), &chars("Hi. "));
+
+        let lints = state.lint();
+        let expected = document_state(&state.document.get_full_string()).lint();
+
+        assert_eq!(lints, expected);
+        assert_eq!(lints.len(), 1);
+    }
+
+    #[test]
+    fn new_lints_are_found_after_an_edit() {
+        let mut state = document_state("This is a test.\n\nAll good.");
+        assert!(state.lint().is_empty());
+
+        state.apply_edit(Span::new(17, 20), &chars("All all"));
+
+        let lints = state.lint();
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span.start, 17);
+    }
+
+    #[test]
+    fn ignored_words_are_left_out() {
+        let mut state = document_state("This is a tset. Another tset.\n\nThe the dog.");
+        assert_eq!(state.lint().len(), 3);
+
+        let mut ignored = MutableDictionary::new();
+        ignored.append_word_str("tset", Default::default());
+        state.ignored_words = Arc::new(ignored);
+
+        let lints = state.lint();
+
+        assert_eq!(lints.len(), 1);
+        assert!(!lints[0].lint_kind.is_spelling());
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use harper_comments::CommentParser;
+use serde::Deserialize;
+
+/// Guess the language ID a client would give a file, based on its extension.
+///
+/// Only languages `harper-ls` can lint are returned.
+pub fn language_id_from_path(path: &Path) -> Option<&'static str> {
+    match path.extension()?.to_str()? {
+        "md" => Some("markdown"),
+        "lhs" => Some("literate haskell"),
+        "typ" => Some("typst"),
+        "html" => Some("html"),
+        "txt" => Some("plaintext"),
+        _ => CommentParser::filename_to_filetype(path),
+    }
+}
+
+/// The user's mapping of file types to the language IDs `harper-ls` knows how to parse, as set
+/// by the `fileTypes` setting.
+///
+/// Keys that start with a `.` are file extensions (like `.mdx`), while the rest are language IDs
+/// sent by the client (like `quarto`).
+/// Values are language IDs `harper-ls` supports, like `markdown`, `typst` or `plaintext`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct FileTypeMap {
+    inner: HashMap<String, String>,
+}
+
+impl FileTypeMap {
+    /// Decide which language ID to parse a document as.
+    ///
+    /// A mapping for the document's extension takes precedence over one for the language ID
+    /// given by the client.
+    /// Without either, the client's language ID is used, falling back to guessing from the
+    /// extension.
+    pub fn resolve<'a>(
+        &'a self,
+        path: Option<&Path>,
+        language_id: Option<&'a str>,
+    ) -> Option<&'a str> {
+        let extension = path
+            .and_then(Path::extension)
+            .and_then(|extension| extension.to_str());
+
+        if let Some(extension) = extension
+            && let Some(mapped) = self.inner.get(&format!(".{extension}"))
+        {
+            return Some(mapped);
+        }
+
+        match language_id {
+            Some(language_id) => Some(
+                self.inner
+                    .get(language_id)
+                    .map(String::as_str)
+                    .unwrap_or(language_id),
+            ),
+            None => language_id_from_path(path?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::FileTypeMap;
+
+    fn map() -> FileTypeMap {
+        serde_json::from_str(r#"{ ".mdx": "markdown", ".txt": "markdown", "quarto": "markdown" }"#)
+            .unwrap()
+    }
+
+    #[test]
+    fn maps_extensions() {
+        assert_eq!(
+            map().resolve(Some(Path::new("/docs/intro.mdx")), Some("mdx")),
+            Some("markdown")
+        );
+        assert_eq!(
+            map().resolve(Some(Path::new("/notes.txt")), None),
+            Some("markdown")
+        );
+    }
+
+    #[test]
+    fn maps_language_ids() {
+        assert_eq!(
+            map().resolve(Some(Path::new("/report.qmd")), Some("quarto")),
+            Some("markdown")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        assert_eq!(
+            map().resolve(Some(Path::new("/main.rs")), Some("rust")),
+            Some("rust")
+        );
+        assert_eq!(
+            FileTypeMap::default().resolve(Some(Path::new("/paper.typ")), None),
+            Some("typst")
+        );
+        assert_eq!(FileTypeMap::default().resolve(None, None), None);
+    }
+}
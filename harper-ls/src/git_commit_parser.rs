@@ -1,5 +1,5 @@
-use harper_core::Lrc;
 use harper_core::parsers::{Markdown, MarkdownOptions, Parser};
+use harper_core::{Lrc, Span};
 
 /// A Harper parser for Git commit files
 #[derive(Clone)]
@@ -21,12 +21,18 @@ impl Parser for GitCommitParser {
     /// Admittedly a somewhat naive implementation.
     /// We're going to get _something_ to work, before we polish it off.
     fn parse(&self, source: &[char]) -> Vec<harper_core::Token> {
-        // Locate the first `#`
-        let end = source
-            .iter()
-            .position(|c| *c == '#')
-            .unwrap_or(source.len());
+        self.inner.parse(&source[0..message_end(source)])
+    }
 
-        self.inner.parse(&source[0..end])
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        self.inner.comments(&source[0..message_end(source)])
     }
 }
+
+/// Locate the first `#`, where Git's own comments begin.
+fn message_end(source: &[char]) -> usize {
+    source
+        .iter()
+        .position(|c| *c == '#')
+        .unwrap_or(source.len())
+}
@@ -0,0 +1,113 @@
+use std::sync::Mutex;
+
+use harper_core::linting::LintGroup;
+use harper_core::{Dialect, LayeredDictionary, Lrc};
+
+/// The [`LintGroup`]s in use by open documents, so documents that are linted with the same
+/// dialect, dictionary and settings share one rather than each building its own.
+///
+/// [`LintGroup::lint`](harper_core::linting::Linter::lint) needs exclusive access, so groups are
+/// shared behind a [`Mutex`].
+#[derive(Default)]
+pub struct LinterCache {
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    dialect: Dialect,
+    dict: Lrc<LayeredDictionary>,
+    settings_id: u64,
+    linter: Lrc<Mutex<LintGroup>>,
+}
+
+impl LinterCache {
+    /// Find the linter for a dialect, dictionary and set of settings, building it with `build`
+    /// if there isn't one yet.
+    ///
+    /// Dictionaries are compared by their words (see [`LayeredDictionary::has_same_words`]), so
+    /// the dictionary the linter was built with is returned as well, for the document to use in
+    /// place of its own copy.
+    pub fn get_or_build(
+        &mut self,
+        dialect: Dialect,
+        dict: &Lrc<LayeredDictionary>,
+        settings_id: u64,
+        build: impl FnOnce() -> LintGroup,
+    ) -> (Lrc<LayeredDictionary>, Lrc<Mutex<LintGroup>>) {
+        if let Some(entry) = self.entries.iter().find(|entry| {
+            entry.dialect == dialect
+                && entry.settings_id == settings_id
+                && entry.dict.has_same_words(dict)
+        }) {
+            return (entry.dict.clone(), entry.linter.clone());
+        }
+
+        // Forget the linters no document uses anymore.
+        self.entries
+            .retain(|entry| Lrc::strong_count(&entry.linter) > 1);
+
+        let linter = Lrc::new(Mutex::new(build()));
+
+        self.entries.push(Entry {
+            dialect,
+            dict: dict.clone(),
+            settings_id,
+            linter: linter.clone(),
+        });
+
+        (dict.clone(), linter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use harper_core::linting::LintGroup;
+    use harper_core::{Dialect, FstDictionary, LayeredDictionary, Lrc, MutableDictionary};
+
+    use super::LinterCache;
+
+    fn dict_with(word: &str) -> Lrc<LayeredDictionary> {
+        let mut words = MutableDictionary::new();
+        words.append_word_str(word, Default::default());
+
+        Lrc::new(
+            LayeredDictionary::new()
+                .with_dictionary(FstDictionary::curated())
+                .with_dictionary(Lrc::new(words)),
+        )
+    }
+
+    #[test]
+    fn shares_linters_between_equal_dictionaries() {
+        let mut cache = LinterCache::default();
+
+        let (_, a) = cache.get_or_build(Dialect::American, &dict_with("blorb"), 0, || {
+            LintGroup::new_curated(dict_with("blorb"))
+        });
+        let (_, b) = cache.get_or_build(Dialect::American, &dict_with("blorb"), 0, || {
+            panic!("The linter should have been reused.")
+        });
+
+        assert!(Lrc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn keeps_linters_apart() {
+        let mut cache = LinterCache::default();
+        let dict = dict_with("blorb");
+
+        let (_, a) = cache.get_or_build(Dialect::American, &dict, 0, LintGroup::default);
+        let (_, b) = cache.get_or_build(Dialect::British, &dict, 0, LintGroup::default);
+        let (_, c) = cache.get_or_build(Dialect::American, &dict, 1, LintGroup::default);
+        let (_, d) = cache.get_or_build(
+            Dialect::American,
+            &dict_with("florp"),
+            0,
+            LintGroup::default,
+        );
+
+        assert!(!Lrc::ptr_eq(&a, &b));
+        assert!(!Lrc::ptr_eq(&a, &c));
+        assert!(!Lrc::ptr_eq(&a, &d));
+    }
+}
@@ -9,8 +9,12 @@ mod config;
 mod diagnostics;
 mod dictionary_io;
 mod document_state;
+mod file_types;
 mod git_commit_parser;
+mod linter_cache;
 mod pos_conv;
+mod settings_cache;
+mod workspace_scan;
 
 use backend::Backend;
 use clap::Parser;
@@ -1,83 +1,65 @@
 //! This module includes various conversions from the index-based [`Span`]s that
 //! Harper uses, and the Ranges that the LSP uses.
 
-use harper_core::Span;
+use harper_core::{LineIndex, PositionEncoding, Span};
 use tower_lsp::lsp_types::{Position, Range};
 
-pub fn span_to_range(source: &[char], span: Span) -> Range {
-    let start = index_to_position(source, span.start);
-    let end = index_to_position(source, span.end);
+/// The LSP counts columns in UTF-16 code units by default.
+const ENCODING: PositionEncoding = PositionEncoding::Utf16;
 
-    Range { start, end }
-}
-
-fn index_to_position(source: &[char], index: usize) -> Position {
-    let before = &source[0..index];
-    let newline_indices: Vec<_> = before
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, c)| if *c == '\n' { Some(idx + 1) } else { None })
-        .collect();
-
-    let lines = newline_indices.len();
-
-    let last_newline_idx = newline_indices.last().copied().unwrap_or(0);
+pub fn span_to_range(line_index: &LineIndex, span: Span) -> Range {
+    let (start, end) = line_index.span_to_range(span, ENCODING);
 
-    let cols: usize = source[last_newline_idx..index]
-        .iter()
-        .map(|c| c.len_utf16())
-        .sum();
-
-    Position {
-        line: lines as u32,
-        character: cols as u32,
+    Range {
+        start: to_lsp(start),
+        end: to_lsp(end),
     }
 }
 
-fn position_to_index(source: &[char], position: Position) -> usize {
-    let mut newline_indices: Vec<_> = source
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, c)| if *c == '\n' { Some(idx + 1) } else { None })
-        .take(position.line as usize + 1)
-        .collect();
-
-    let line_end_idx = newline_indices.pop().unwrap_or(source.len());
-    let line_start_idx = newline_indices.pop().unwrap_or(0);
-
-    let mut traversed_cols = 0;
-
-    for (traversed_chars, c) in source[line_start_idx..line_end_idx].iter().enumerate() {
-        if traversed_cols == position.character as usize {
-            return line_start_idx + traversed_chars;
-        }
-
-        traversed_cols += c.len_utf16();
-    }
+pub fn range_to_span(line_index: &LineIndex, range: Range) -> Span {
+    line_index.range_to_span(from_lsp(range.start), from_lsp(range.end), ENCODING)
+}
 
-    if traversed_cols > 0 {
-        return line_end_idx;
+fn to_lsp(position: harper_core::Position) -> Position {
+    Position {
+        line: position.line as u32,
+        character: position.column as u32,
     }
-
-    line_start_idx
 }
 
-pub fn range_to_span(source: &[char], range: Range) -> Span {
-    let start = position_to_index(source, range.start);
-    let end = position_to_index(source, range.end);
-
-    Span::new(start, end)
+fn from_lsp(position: Position) -> harper_core::Position {
+    harper_core::Position::new(position.line as usize, position.character as usize)
 }
 
 #[cfg(test)]
 mod tests {
+    use harper_core::{LineIndex, Span};
     use tower_lsp::lsp_types::{Position, Range};
 
-    use super::{index_to_position, position_to_index, range_to_span};
+    use super::{range_to_span, span_to_range};
+
+    fn line_index(source: &str) -> LineIndex {
+        LineIndex::new(&source.chars().collect::<Vec<_>>())
+    }
+
+    fn position_to_index(line_index: &LineIndex, position: Position) -> usize {
+        range_to_span(
+            line_index,
+            Range {
+                start: position,
+                end: position,
+            },
+        )
+        .start
+    }
+
+    fn index_to_position(line_index: &LineIndex, index: usize) -> Position {
+        span_to_range(line_index, Span::new(index, index)).start
+    }
 
     #[test]
     fn first_line_correct() {
-        let source: Vec<_> = "Hello there.".chars().collect();
+        let source = line_index("Hello there.");
 
         let start = Position {
             line: 0,
@@ -95,9 +77,7 @@ mod tests {
 
     #[test]
     fn reversible_position_conv() {
-        let source: Vec<_> = "There was a man,\n his voice had timbre,\n unlike a boy."
-            .chars()
-            .collect();
+        let source = line_index("There was a man,\n his voice had timbre,\n unlike a boy.");
 
         let a = Position {
             line: 1,
@@ -118,19 +98,20 @@ mod tests {
 
     #[test]
     fn end_of_line() {
-        let source: Vec<_> = "This is a short test\n".chars().collect();
+        let source = line_index("This is a short test\n");
 
         let a = Position {
             line: 1,
             character: 20,
         };
 
-        assert_eq!(position_to_index(&source, a), 20);
+        // The second line is empty, so the position is clamped to its end.
+        assert_eq!(position_to_index(&source, a), 21);
     }
 
     #[test]
     fn end_of_file() {
-        let source: Vec<_> = "This is a short test".chars().collect();
+        let source = line_index("This is a short test");
 
         let a = Position {
             line: 1,
@@ -142,7 +123,7 @@ mod tests {
 
     #[test]
     fn issue_250() {
-        let source: Vec<_> = "Hello thur\n".chars().collect();
+        let source = line_index("Hello thur\n");
 
         let range = Range {
             start: Position {
@@ -156,7 +137,18 @@ mod tests {
         };
 
         let out = range_to_span(&source, range);
-        assert_eq!(out.start, 9);
-        assert_eq!(out.end, 10);
+        assert_eq!(out.start, 11);
+        assert_eq!(out.end, 11);
+    }
+
+    #[test]
+    fn counts_utf16_columns() {
+        let source = line_index("😀 teh");
+
+        let range = span_to_range(&source, Span::new(2, 5));
+
+        assert_eq!(range.start.character, 3);
+        assert_eq!(range.end.character, 6);
+        assert_eq!(range_to_span(&source, range), Span::new(2, 5));
     }
 }
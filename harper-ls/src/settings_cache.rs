@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use harper_core::{HarperConfig, HarperIgnore, Lrc};
+use tracing::error;
+
+/// The `harper.toml` and `.harperignore` files that apply to each directory, so they aren't
+/// looked for and read again every time a document in it changes.
+///
+/// The cache has to be cleared when one of those files changes.
+#[derive(Default)]
+pub struct SettingsCache {
+    dirs: HashMap<PathBuf, DirSettings>,
+}
+
+/// The settings that apply to the documents in a directory.
+#[derive(Clone)]
+pub struct DirSettings {
+    pub harper_config: Lrc<HarperConfig>,
+    pub ignore: Lrc<HarperIgnore>,
+}
+
+impl DirSettings {
+    /// Errors are logged, and the file that caused them is treated as if it were missing, rather
+    /// than preventing the directory's documents from being linted.
+    fn load(dir: &Path) -> Self {
+        Self {
+            harper_config: Lrc::new(
+                HarperConfig::discover(dir)
+                    .map_err(|err| error!("{err}"))
+                    .unwrap_or_default(),
+            ),
+            ignore: Lrc::new(
+                HarperIgnore::discover(dir)
+                    .map_err(|err| error!("{err}"))
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+impl SettingsCache {
+    /// The settings for the documents in `dir`, loading them if they haven't been yet.
+    pub fn get_or_load(&mut self, dir: &Path) -> DirSettings {
+        self.dirs
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| DirSettings::load(dir))
+            .clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.dirs.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use harper_core::{Dialect, HarperConfig, HarperIgnore, Lrc};
+
+    use super::SettingsCache;
+
+    #[test]
+    fn reads_settings_once_until_cleared() {
+        let dir = std::env::temp_dir().join(format!("harper-ls-settings-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(HarperIgnore::FILE_NAME), "generated.md\n").unwrap();
+
+        let mut cache = SettingsCache::default();
+        let first = cache.get_or_load(&dir);
+
+        fs::write(dir.join(HarperIgnore::FILE_NAME), "").unwrap();
+        fs::write(dir.join(HarperConfig::FILE_NAME), "dialect = \"British\"\n").unwrap();
+
+        let cached = cache.get_or_load(&dir);
+        cache.clear();
+        let reloaded = cache.get_or_load(&dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(Lrc::ptr_eq(&first.ignore, &cached.ignore));
+        assert!(first.ignore.is_ignored(dir.join("generated.md"), false));
+        assert!(!reloaded.ignore.is_ignored(dir.join("generated.md"), false));
+        assert_eq!(cached.harper_config.dialect, None);
+        assert_eq!(reloaded.harper_config.dialect, Some(Dialect::British));
+    }
+}
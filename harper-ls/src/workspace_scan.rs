@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use harper_core::HarperIgnore;
+use ignore::WalkBuilder;
+
+use crate::file_types::FileTypeMap;
+
+/// Find every file under the workspace roots that `harper-ls` can lint, along with its language
+/// ID.
+///
+/// Anything listed in a `.gitignore` or `.harperignore` file is skipped.
+pub fn find_workspace_files(roots: &[PathBuf], file_types: &FileTypeMap) -> Vec<(PathBuf, String)> {
+    let Some((first, rest)) = roots.split_first() else {
+        return Vec::new();
+    };
+
+    let mut builder = WalkBuilder::new(first);
+    builder.add_custom_ignore_filename(HarperIgnore::FILE_NAME);
+
+    for root in rest {
+        builder.add(root);
+    }
+
+    builder
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|entry| {
+            let language_id = file_types.resolve(Some(entry.path()), None)?.to_owned();
+            Some((entry.into_path(), language_id))
+        })
+        .collect()
+}
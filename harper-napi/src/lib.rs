@@ -0,0 +1,358 @@
+#![doc = include_str!("../README.md")]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use harper_core::linting::{
+    LintGroup, LintSession, Linter as _, SpellCheck, Suggestion, SuggestionKind,
+};
+use harper_core::parsers::{Markdown, Parser, PlainEnglish};
+use harper_core::{
+    Document, FstDictionary, HarperConfig, LayeredDictionary, LineIndex, MutableDictionary,
+    PositionEncoding, Span, WordMetadata, remove_overlaps,
+};
+use napi::{Error, Result};
+use napi_derive::napi;
+
+/// The markup language a document is written in.
+#[napi]
+pub enum Language {
+    Plain,
+    Markdown,
+}
+
+impl Language {
+    fn create_parser(&self) -> Box<dyn Parser> {
+        match self {
+            Language::Plain => Box::new(PlainEnglish),
+            Language::Markdown => Box::new(Markdown::default()),
+        }
+    }
+}
+
+/// Lints documents with Harper's curated rules, configured the same way as `harper-ls`: through
+/// `harper.toml` files and word lists on disk.
+#[napi]
+pub struct Linter {
+    /// The settings from the loaded `harper.toml` files, along with the dictionaries to read.
+    config: HarperConfig,
+    /// Words added through [`Self::import_words`].
+    user_dictionary: MutableDictionary,
+    dictionary: Arc<LayeredDictionary>,
+    lint_group: LintGroup,
+}
+
+#[napi]
+impl Linter {
+    /// Create a linter with the curated dictionary and the default rules.
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_config(HarperConfig::default()).expect("No dictionaries to load.")
+    }
+
+    /// Create a linter configured by the `harper.toml` files that apply to a directory, along
+    /// with its workspace dictionary.
+    #[napi(factory)]
+    pub fn for_directory(dir: String) -> Result<Self> {
+        let config = HarperConfig::discover(dir).map_err(to_napi_error)?;
+        Self::with_config(config)
+    }
+
+    fn with_config(config: HarperConfig) -> Result<Self> {
+        let user_dictionary = MutableDictionary::new();
+        let dictionary = Self::construct_dictionary(&config, &user_dictionary)?;
+        let lint_group = Self::construct_lint_group(&config, dictionary.clone());
+
+        Ok(Self {
+            config,
+            user_dictionary,
+            dictionary,
+            lint_group,
+        })
+    }
+
+    fn construct_dictionary(
+        config: &HarperConfig,
+        user_dictionary: &MutableDictionary,
+    ) -> Result<Arc<LayeredDictionary>> {
+        let mut dictionary = LayeredDictionary::new()
+            .with_dictionary(FstDictionary::curated())
+            .with_dictionary(Arc::new(user_dictionary.clone()));
+        config
+            .push_dictionaries(&mut dictionary)
+            .map_err(to_napi_error)?;
+
+        Ok(Arc::new(dictionary))
+    }
+
+    fn construct_lint_group(
+        config: &HarperConfig,
+        dictionary: Arc<LayeredDictionary>,
+    ) -> LintGroup {
+        let mut lint_group = LintGroup::new_curated(dictionary.clone())
+            .with_spell_check(config.configure_spell_check(SpellCheck::new(dictionary)));
+        config.configure_lint_group(&mut lint_group);
+        lint_group
+    }
+
+    /// Rebuild the dictionary and [`Self::lint_group`] after the configuration or the user
+    /// dictionary changes.
+    /// This clears any linter caches, so use it sparingly.
+    fn synchronize(&mut self) -> Result<()> {
+        self.dictionary = Self::construct_dictionary(&self.config, &self.user_dictionary)?;
+        self.lint_group = Self::construct_lint_group(&self.config, self.dictionary.clone());
+        Ok(())
+    }
+
+    /// Apply the settings from a `harper.toml` file, on top of any already loaded.
+    ///
+    /// Dictionaries it lists are read relative to the file.
+    #[napi]
+    pub fn load_config(&mut self, path: String) -> Result<()> {
+        let mut loaded = HarperConfig::load(path).map_err(to_napi_error)?;
+
+        let previous = self.config.clone();
+        self.config.merge_from(&mut loaded);
+
+        self.synchronize().inspect_err(|_| self.config = previous)
+    }
+
+    /// Treat the words in a file, one per line, as correctly spelled.
+    ///
+    /// The file is read again whenever the linter's dictionary is rebuilt.
+    /// A file that doesn't exist yet is treated as empty.
+    #[napi]
+    pub fn import_dictionary(&mut self, path: String) -> Result<()> {
+        self.config.dictionaries.push(PathBuf::from(path));
+
+        self.synchronize()
+            .inspect_err(|_| _ = self.config.dictionaries.pop())
+    }
+
+    /// Treat the provided words as correctly spelled.
+    #[napi]
+    pub fn import_words(&mut self, words: Vec<String>) -> Result<()> {
+        for word in words {
+            self.user_dictionary
+                .append_word_str(&word, WordMetadata::default());
+        }
+
+        self.synchronize()
+    }
+
+    #[napi]
+    pub fn set_rule_enabled(&mut self, rule: String, enabled: bool) {
+        self.config.rules.set_rule_enabled(&rule, enabled);
+        self.lint_group.config.set_rule_enabled(rule, enabled);
+    }
+
+    #[napi]
+    pub fn is_rule_enabled(&self, rule: String) -> bool {
+        self.lint_group.config.is_rule_enabled(&rule)
+    }
+
+    /// Get a description of each rule, keyed by its name.
+    #[napi]
+    pub fn get_lint_descriptions(&self) -> HashMap<String, String> {
+        self.lint_group
+            .all_descriptions()
+            .into_iter()
+            .map(|(name, description)| (name.to_string(), description.to_string()))
+            .collect()
+    }
+
+    /// Perform the configured linting on the provided text.
+    ///
+    /// Offsets are in UTF-16 code units, as used by JavaScript strings.
+    #[napi]
+    pub fn lint(&mut self, text: String, language: Language) -> Vec<LintObject> {
+        let document = Document::new(&text, &language.create_parser(), &self.dictionary);
+
+        let mut lints = self.lint_group.lint(&document);
+        remove_overlaps(&mut lints);
+
+        let source = document.get_source();
+        let line_index = LineIndex::new(source);
+
+        lints
+            .iter()
+            .map(|lint| LintObject::new(lint, source, &line_index))
+            .collect()
+    }
+
+    /// Start a [`Session`] for a document with the provided text.
+    ///
+    /// The session takes a snapshot of the linter's current configuration and dictionary. Later
+    /// changes to the linter do not affect it.
+    #[napi]
+    pub fn create_session(&self, text: String, language: Language) -> Session {
+        Session {
+            inner: LintSession::new(
+                &text,
+                language.create_parser(),
+                self.dictionary.clone(),
+                Self::construct_lint_group(&self.config, self.dictionary.clone()),
+            ),
+            language,
+        }
+    }
+}
+
+/// A document that is kept in sync with an editor through [`Self::apply_edit`].
+///
+/// Each lint is given an ID when it is first reported, and edits only report the lints that were
+/// added or removed.
+#[napi]
+pub struct Session {
+    inner: LintSession<Box<dyn Parser>, Arc<LayeredDictionary>, LintGroup>,
+    language: Language,
+}
+
+#[napi]
+impl Session {
+    /// Get the current text of the document.
+    #[napi]
+    pub fn get_text(&self) -> String {
+        self.inner.document().get_full_string()
+    }
+
+    /// Get the language the document is parsed as.
+    #[napi]
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Get every current lint, with its `id` set.
+    #[napi]
+    pub fn lints(&self) -> Vec<LintObject> {
+        self.to_objects(|_| true)
+    }
+
+    /// Replace the text between `start` and `end` (UTF-16 offsets) with `text`.
+    ///
+    /// Lints that were not affected by the edit keep their IDs and are not reported.
+    #[napi]
+    pub fn apply_edit(&mut self, start: u32, end: u32, text: String) -> SessionChanges {
+        let line_index = LineIndex::new(self.inner.document().get_source());
+        let start = line_index.index_at_offset(start as usize, PositionEncoding::Utf16);
+        let end = line_index
+            .index_at_offset(end as usize, PositionEncoding::Utf16)
+            .max(start);
+
+        let new_text: Vec<char> = text.chars().collect();
+        let changes = self.inner.apply_edit(Span::new(start, end), &new_text);
+
+        SessionChanges {
+            added: self.to_objects(|id| changes.added.contains(&id)),
+            removed: changes.removed,
+        }
+    }
+
+    fn to_objects(&self, filter: impl Fn(u32) -> bool) -> Vec<LintObject> {
+        let source = self.inner.document().get_source();
+        let line_index = LineIndex::new(source);
+
+        self.inner
+            .lints()
+            .iter()
+            .filter(|(id, _)| filter(*id))
+            .map(|(id, lint)| LintObject {
+                id: Some(*id),
+                ..LintObject::new(lint, source, &line_index)
+            })
+            .collect()
+    }
+}
+
+/// The lints that were added and removed by [`Session::apply_edit`].
+#[napi(object)]
+pub struct SessionChanges {
+    /// The lints that are new since the last edit, with offsets into the updated text.
+    pub added: Vec<LintObject>,
+    /// The IDs of the lints that no longer apply.
+    pub removed: Vec<u32>,
+}
+
+/// A problem found in a document, in the same shape as `lintAsObjects` in `harper.js`.
+///
+/// Offsets are in UTF-16 code units.
+#[napi(object)]
+pub struct LintObject {
+    /// The ID given to the lint by a [`Session`], if it came from one.
+    pub id: Option<u32>,
+    pub rule: Option<String>,
+    pub kind: String,
+    pub severity: Option<String>,
+    pub message: String,
+    pub problem_text: String,
+    pub start: u32,
+    pub end: u32,
+    pub suggestions: Vec<SuggestionObject>,
+}
+
+impl LintObject {
+    fn new(lint: &harper_core::linting::Lint, source: &[char], line_index: &LineIndex) -> Self {
+        Self {
+            id: None,
+            rule: lint.rule.clone(),
+            kind: lint.lint_kind.to_string_key(),
+            severity: lint.severity.map(|s| s.to_string_key()),
+            message: lint.message.clone(),
+            problem_text: lint.span.get_content_string(source),
+            start: line_index.offset(lint.span.start, PositionEncoding::Utf16) as u32,
+            end: line_index.offset(lint.span.end, PositionEncoding::Utf16) as u32,
+            suggestions: lint
+                .suggestions
+                .iter()
+                .map(|suggestion| SuggestionObject::new(suggestion, lint.span, line_index))
+                .collect(),
+        }
+    }
+}
+
+/// A suggested fix, described by the edits it would make.
+#[napi(object)]
+pub struct SuggestionObject {
+    /// One of `Replace`, `Remove`, `InsertAfter`, `InsertBefore` or `Edits`.
+    pub kind: String,
+    pub edits: Vec<EditObject>,
+}
+
+impl SuggestionObject {
+    fn new(suggestion: &Suggestion, span: Span, line_index: &LineIndex) -> Self {
+        let kind = match suggestion.kind {
+            SuggestionKind::ReplaceWith(_) => "Replace",
+            SuggestionKind::Remove => "Remove",
+            SuggestionKind::InsertAfter(_) => "InsertAfter",
+            SuggestionKind::InsertBefore(_) => "InsertBefore",
+            SuggestionKind::Edits(_) => "Edits",
+        };
+
+        Self {
+            kind: kind.to_string(),
+            edits: suggestion
+                .to_edits(span)
+                .into_iter()
+                .map(|edit| EditObject {
+                    start: line_index.offset(edit.span.start, PositionEncoding::Utf16) as u32,
+                    end: line_index.offset(edit.span.end, PositionEncoding::Utf16) as u32,
+                    replacement: edit.replacement.iter().collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A single change to a document: replace the text between `start` and `end` with `replacement`.
+#[napi(object)]
+pub struct EditObject {
+    pub start: u32,
+    pub end: u32,
+    pub replacement: String,
+}
+
+fn to_napi_error(err: impl ToString) -> Error {
+    Error::from_reason(err.to_string())
+}
@@ -0,0 +1,58 @@
+const assert = require('node:assert');
+const fs = require('node:fs');
+const os = require('node:os');
+const path = require('node:path');
+const test = require('node:test');
+
+const { Language, Linter } = require('../harper.node');
+
+test('finds lints in plain text', () => {
+	const linter = new Linter();
+	const lints = linter.lint('This is an test.', Language.Plain);
+
+	assert.strictEqual(lints.length, 1);
+	assert.strictEqual(lints[0].problemText, 'an');
+	assert.ok(lints[0].suggestions.length > 0);
+});
+
+test('offsets are in UTF-16 code units', () => {
+	const linter = new Linter();
+	const text = '😀 This is a tset.';
+	const [lint] = linter.lint(text, Language.Plain);
+
+	assert.strictEqual(text.slice(lint.start, lint.end), 'tset');
+});
+
+test('rules can be disabled', () => {
+	const linter = new Linter();
+	linter.setRuleEnabled('SpellCheck', false);
+
+	assert.strictEqual(linter.isRuleEnabled('SpellCheck'), false);
+	assert.deepStrictEqual(linter.lint('This is a tset.', Language.Plain), []);
+});
+
+test('reads dictionaries and configuration from disk', () => {
+	const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'harper-napi-'));
+	fs.writeFileSync(path.join(dir, 'words.txt'), 'Zorbulon\n');
+	fs.writeFileSync(path.join(dir, 'harper.toml'), 'dictionaries = ["words.txt"]\n');
+
+	const linter = Linter.forDirectory(dir);
+
+	assert.deepStrictEqual(linter.lint('This is Zorbulon.', Language.Plain), []);
+});
+
+test('sessions report only the lints that changed', () => {
+	const linter = new Linter();
+	const session = linter.createSession('This is a tset.\n\nThe the dog is here.', Language.Markdown);
+
+	const [first, second] = session.lints();
+	const changes = session.applyEdit(10, 14, 'test');
+
+	assert.deepStrictEqual(changes.removed, [first.id]);
+	assert.deepStrictEqual(changes.added, []);
+	assert.deepStrictEqual(
+		session.lints().map((lint) => lint.id),
+		[second.id],
+	);
+	assert.strictEqual(session.getText(), 'This is a test.\n\nThe the dog is here.');
+});
@@ -0,0 +1,292 @@
+#![doc = include_str!("../README.md")]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use harper_core::linting::Linter as _;
+use harper_core::parsers::{Markdown, PlainEnglish};
+use harper_core::{
+    Dictionary as _, FstDictionary, LayeredDictionary, MutableDictionary, WordMetadata,
+    remove_overlaps, stats,
+};
+use pyo3::prelude::*;
+
+/// The words Harper considers to be spelled correctly: the curated dictionary, along with any
+/// words added to it.
+#[pyclass(module = "harper")]
+#[derive(Clone, Default)]
+struct Dictionary {
+    words: MutableDictionary,
+}
+
+impl Dictionary {
+    fn layered(&self) -> Arc<LayeredDictionary> {
+        Arc::new(
+            LayeredDictionary::new()
+                .with_dictionary(FstDictionary::curated())
+                .with_dictionary(Arc::new(self.words.clone())),
+        )
+    }
+}
+
+#[pymethods]
+impl Dictionary {
+    #[new]
+    #[pyo3(signature = (words = Vec::new()))]
+    fn new(words: Vec<String>) -> Self {
+        let mut dictionary = Self::default();
+
+        for word in words {
+            dictionary.add_word(&word);
+        }
+
+        dictionary
+    }
+
+    fn add_word(&mut self, word: &str) {
+        self.words.append_word_str(word, WordMetadata::default());
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        self.words.contains_word_str(word) || FstDictionary::curated().contains_word_str(word)
+    }
+
+    /// The words added to the dictionary, not including the curated ones.
+    fn words(&self) -> Vec<String> {
+        self.words
+            .words_iter()
+            .map(|word| word.iter().collect())
+            .collect()
+    }
+}
+
+/// A parsed piece of text, ready to be linted or measured.
+#[pyclass(module = "harper", frozen)]
+struct Document {
+    inner: harper_core::Document,
+}
+
+#[pymethods]
+impl Document {
+    /// Parse `text` as plain English, or as Markdown if `markdown` is set.
+    #[new]
+    #[pyo3(signature = (text, markdown = false, dictionary = None))]
+    fn new(text: &str, markdown: bool, dictionary: Option<PyRef<Dictionary>>) -> Self {
+        let dictionary =
+            dictionary.map_or_else(|| Dictionary::default().layered(), |d| d.layered());
+
+        let inner = if markdown {
+            harper_core::Document::new(text, &Markdown::default(), &dictionary)
+        } else {
+            harper_core::Document::new(text, &PlainEnglish, &dictionary)
+        };
+
+        Self { inner }
+    }
+
+    #[getter]
+    fn text(&self) -> String {
+        self.inner.get_full_string()
+    }
+
+    /// Measure the document's prose.
+    fn stats(&self) -> DocumentStats {
+        DocumentStats {
+            inner: stats::DocumentStats::new(&self.inner),
+        }
+    }
+}
+
+/// A collection of rules, each of which can be turned on or off.
+#[pyclass(module = "harper")]
+struct LintGroup {
+    inner: harper_core::linting::LintGroup,
+}
+
+#[pymethods]
+impl LintGroup {
+    /// Create a group containing Harper's curated rules, with their default configuration.
+    #[new]
+    #[pyo3(signature = (dictionary = None))]
+    fn new(dictionary: Option<PyRef<Dictionary>>) -> Self {
+        let dictionary =
+            dictionary.map_or_else(|| Dictionary::default().layered(), |d| d.layered());
+
+        Self {
+            inner: harper_core::linting::LintGroup::new_curated(dictionary),
+        }
+    }
+
+    fn set_rule_enabled(&mut self, rule: &str, enabled: bool) {
+        self.inner.config.set_rule_enabled(rule, enabled);
+    }
+
+    fn is_rule_enabled(&self, rule: &str) -> bool {
+        self.inner.config.is_rule_enabled(rule)
+    }
+
+    /// A description of each rule, keyed by its name.
+    fn rules(&self) -> HashMap<String, String> {
+        self.inner
+            .all_descriptions()
+            .into_iter()
+            .map(|(name, description)| (name.to_string(), description.to_string()))
+            .collect()
+    }
+
+    fn lint(&mut self, document: PyRef<Document>) -> Vec<Lint> {
+        let mut lints = self.inner.lint(&document.inner);
+        remove_overlaps(&mut lints);
+
+        lints.into_iter().map(Lint::new).collect()
+    }
+}
+
+/// A problem found in a document.
+///
+/// `start` and `end` are indices into the document's text, so `text[lint.start:lint.end]` is the
+/// problematic text.
+#[pyclass(module = "harper", frozen)]
+struct Lint {
+    inner: harper_core::linting::Lint,
+}
+
+impl Lint {
+    fn new(inner: harper_core::linting::Lint) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl Lint {
+    #[getter]
+    fn start(&self) -> usize {
+        self.inner.span.start
+    }
+
+    #[getter]
+    fn end(&self) -> usize {
+        self.inner.span.end
+    }
+
+    #[getter]
+    fn message(&self) -> String {
+        self.inner.message.clone()
+    }
+
+    /// The general category of the problem, like `Spelling` or `Repetition`.
+    #[getter]
+    fn kind(&self) -> String {
+        self.inner.lint_kind.to_string_key()
+    }
+
+    /// The name of the rule that found the problem, if known.
+    #[getter]
+    fn rule(&self) -> Option<String> {
+        self.inner.rule.clone()
+    }
+
+    /// Descriptions of the suggested fixes, like `Replace with: “the”`.
+    #[getter]
+    fn suggestions(&self) -> Vec<String> {
+        self.inner
+            .suggestions
+            .iter()
+            .map(|suggestion| suggestion.to_string())
+            .collect()
+    }
+
+    /// Apply the suggestion at `index` to the text the lint was found in, returning the result.
+    #[pyo3(signature = (text, index = 0))]
+    fn apply_suggestion(&self, text: &str, index: usize) -> PyResult<String> {
+        let suggestion = self.inner.suggestions.get(index).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err("suggestion index out of range")
+        })?;
+
+        let mut source: Vec<char> = text.chars().collect();
+        suggestion.apply(self.inner.span, &mut source);
+
+        Ok(source.into_iter().collect())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Lint(start={}, end={}, kind={:?}, message={:?})",
+            self.start(),
+            self.end(),
+            self.kind(),
+            self.inner.message
+        )
+    }
+}
+
+/// Counts taken from the prose of one or more documents.
+#[pyclass(module = "harper")]
+#[derive(Clone)]
+struct DocumentStats {
+    inner: stats::DocumentStats,
+}
+
+#[pymethods]
+impl DocumentStats {
+    #[getter]
+    fn words(&self) -> usize {
+        self.inner.words
+    }
+
+    #[getter]
+    fn sentences(&self) -> usize {
+        self.inner.sentences
+    }
+
+    #[getter]
+    fn syllables(&self) -> usize {
+        self.inner.syllables
+    }
+
+    #[getter]
+    fn passive_sentences(&self) -> usize {
+        self.inner.passive_sentences
+    }
+
+    /// Add the counts from another `DocumentStats` to this one.
+    fn merge(&mut self, other: PyRef<DocumentStats>) {
+        self.inner.merge(&other.inner);
+    }
+
+    fn words_per_sentence(&self) -> Option<f64> {
+        self.inner.words_per_sentence()
+    }
+
+    fn flesch_reading_ease(&self) -> Option<f64> {
+        self.inner.flesch_reading_ease()
+    }
+
+    fn flesch_kincaid_grade(&self) -> Option<f64> {
+        self.inner.flesch_kincaid_grade()
+    }
+
+    fn passive_percentage(&self) -> Option<f64> {
+        self.inner.passive_percentage()
+    }
+}
+
+/// Count the lints produced by each rule.
+#[pyfunction]
+fn count_rules(lints: Vec<PyRef<Lint>>) -> HashMap<String, usize> {
+    stats::count_rules(lints.iter().map(|lint| &lint.inner))
+        .into_iter()
+        .collect()
+}
+
+#[pymodule]
+fn harper(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Dictionary>()?;
+    m.add_class::<Document>()?;
+    m.add_class::<LintGroup>()?;
+    m.add_class::<Lint>()?;
+    m.add_class::<DocumentStats>()?;
+    m.add_function(wrap_pyfunction!(count_rules, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+import harper
+
+
+def test_finds_repeated_words():
+    text = "I like the the fruit."
+    lints = harper.LintGroup().lint(harper.Document(text))
+
+    assert len(lints) == 1
+    assert lints[0].rule == "RepeatedWords"
+    assert text[lints[0].start : lints[0].end] == "the the"
+    assert lints[0].apply_suggestion(text) == "I like the fruit."
+
+
+def test_offsets_are_string_indices():
+    text = "“😀 I like the the fruit.”"
+    lints = harper.LintGroup().lint(harper.Document(text))
+
+    assert text[lints[0].start : lints[0].end] == "the the"
+
+
+def test_added_words_are_not_misspelled():
+    dictionary = harper.Dictionary(["Harperish"])
+    document = harper.Document("This is Harperish.", dictionary=dictionary)
+
+    assert dictionary.contains_word("Harperish")
+    assert harper.LintGroup(dictionary).lint(document) == []
+
+
+def test_disabled_rules_are_skipped():
+    group = harper.LintGroup()
+    group.set_rule_enabled("RepeatedWords", False)
+
+    assert not group.is_rule_enabled("RepeatedWords")
+    assert group.lint(harper.Document("I like the the fruit.")) == []
+
+
+def test_counts_rules():
+    lints = harper.LintGroup().lint(harper.Document("I like the the fruit. It is an tree."))
+
+    assert harper.count_rules(lints) == {"RepeatedWords": 1, "AnA": 1}
+
+
+def test_measures_documents():
+    stats = harper.Document("The cake was eaten. I like cake.").stats()
+
+    assert stats.words == 7
+    assert stats.sentences == 2
+    assert stats.passive_sentences == 1
+    assert stats.flesch_reading_ease() is not None
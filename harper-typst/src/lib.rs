@@ -4,11 +4,14 @@ mod typst_translator;
 use offset_cursor::OffsetCursor;
 use typst_translator::TypstTranslator;
 
-use harper_core::{Token, parsers::Parser};
+use std::cell::RefCell;
+use std::ops::Range;
+
+use harper_core::{Heading, ItemNumber, ListItem, Outliner, Span, Token, parsers::Parser};
 use itertools::Itertools;
 use typst_syntax::{
-    Source,
-    ast::{AstNode, Markup},
+    LinkedNode, Source, SyntaxKind, SyntaxNode,
+    ast::{self, AstNode, Markup},
 };
 
 /// A parser that wraps Harper's `PlainEnglish` parser allowing one to ingest Typst files.
@@ -16,10 +19,8 @@ pub struct Typst;
 
 impl Parser for Typst {
     fn parse(&self, source: &[char]) -> Vec<Token> {
-        let source_str: String = source.iter().collect();
-
         // Transform the source into an AST through the `typst_syntax` crate
-        let typst_document = Source::detached(source_str);
+        let typst_document = detach(source);
         let typst_tree = Markup::from_untyped(typst_document.root())
             .expect("Unable to create typst document from parsed tree!");
 
@@ -31,6 +32,265 @@ impl Parser for Typst {
             .flatten()
             .collect_vec()
     }
+
+    fn list_items(&self, source: &[char]) -> Vec<ListItem> {
+        let typst_document = detach(source);
+
+        let mut found = Vec::new();
+        let mut list_count = 0;
+        collect_list_items(
+            &LinkedNode::new(typst_document.root()),
+            &mut found,
+            &mut list_count,
+        );
+        found.sort_by_key(|item| item.text.start);
+
+        let mut offset = OffsetCursor::new(&typst_document);
+
+        found
+            .into_iter()
+            .map(|item| {
+                // The number comes before the text, so the cursor only moves forward.
+                let number = item.number.map(|(value, digits)| {
+                    let start = offset.push_to(digits.start);
+                    let end = start.push_to(digits.end);
+                    offset = start;
+
+                    ItemNumber {
+                        value,
+                        span: Span::new(start.char, end.char),
+                    }
+                });
+
+                let start = offset.push_to(item.text.start);
+                let end = start.push_to(item.text.end);
+                offset = start;
+
+                ListItem {
+                    span: Span::new(start.char, end.char),
+                    list: item.list,
+                    number,
+                }
+            })
+            .collect()
+    }
+
+    fn headings(&self, source: &[char]) -> Vec<Heading> {
+        self.outline(source)
+    }
+
+    fn comments(&self, source: &[char]) -> Vec<Span> {
+        let typst_document = detach(source);
+
+        let mut comments = Vec::new();
+        let mut offset = OffsetCursor::new(&typst_document);
+
+        let mut stack = vec![LinkedNode::new(typst_document.root())];
+
+        while let Some(node) = stack.pop() {
+            let range = if matches!(
+                node.kind(),
+                SyntaxKind::LineComment | SyntaxKind::BlockComment
+            ) {
+                Some(node.range())
+            } else {
+                directive_range(&node, typst_document.text())
+            };
+
+            if let Some(range) = range {
+                let start = offset.push_to(range.start);
+                let end = start.push_to(range.end);
+                offset = start;
+
+                comments.push(Span::new(start.char, end.char));
+
+                continue;
+            }
+
+            stack.extend(node.children().rev());
+        }
+
+        comments
+    }
+}
+
+impl Outliner for Typst {
+    fn outline(&self, source: &[char]) -> Vec<Heading> {
+        let typst_document = detach(source);
+
+        let mut headings = Vec::new();
+        let mut offset = OffsetCursor::new(&typst_document);
+
+        let mut stack = vec![LinkedNode::new(typst_document.root())];
+
+        while let Some(node) = stack.pop() {
+            if let Some(heading) = node.cast::<ast::Heading>() {
+                let range = node.range();
+                let start = offset.push_to(range.start);
+                let end = start.push_to(range.end);
+                offset = start;
+
+                let mut text = String::new();
+                collect_text(heading.body().to_untyped(), &mut text);
+
+                headings.push(Heading {
+                    text: text.split_whitespace().join(" "),
+                    level: heading.depth().get(),
+                    span: Span::new(start.char, end.char),
+                });
+
+                continue;
+            }
+
+            stack.extend(node.children().rev());
+        }
+
+        headings
+    }
+}
+
+thread_local! {
+    /// The last text parsed on this thread.
+    ///
+    /// A [`Document`](harper_core::Document) asks for the tokens, list items, headings and
+    /// comments of the same text one after another, so they can all share one parse.
+    static LAST_PARSED: RefCell<Option<Source>> = const { RefCell::new(None) };
+}
+
+/// Create a Typst [`Source`] for the text, reusing the last one parsed on this thread if it was
+/// for the same text.
+///
+/// `typst_syntax` only parses UTF-8, so unlike the rest of Harper, it can't work on the chars
+/// directly and the text has to be copied into a [`String`] first.
+fn detach(source: &[char]) -> Source {
+    LAST_PARSED.with_borrow_mut(|last| {
+        if let Some(parsed) = last
+            && parsed.text().chars().eq(source.iter().copied())
+        {
+            // Cloning only shares the parse.
+            return parsed.clone();
+        }
+
+        let parsed = Source::detached(source.iter().collect::<String>());
+        *last = Some(parsed.clone());
+        parsed
+    })
+}
+
+/// The byte range of a `#harper.…` directive, if the node is one.
+///
+/// The range runs to the end of the line, so the directive can be followed by rule names.
+fn directive_range(node: &LinkedNode, text: &str) -> Option<Range<usize>> {
+    let access = node.cast::<ast::FieldAccess>()?;
+
+    let ast::Expr::Ident(target) = access.target() else {
+        return None;
+    };
+
+    if target.as_str() != "harper" {
+        return None;
+    }
+
+    let start = node.range().start;
+    let end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+
+    Some(start..end)
+}
+
+/// A list item, with byte ranges instead of spans.
+struct FoundListItem {
+    text: Range<usize>,
+    list: usize,
+    /// The number written before the item and the range of its digits, if any.
+    number: Option<(usize, Range<usize>)>,
+}
+
+/// Find every list item beneath a node.
+///
+/// Typst has no node for a list as a whole, so consecutive items of the same kind that share a
+/// parent are considered one list.
+fn collect_list_items(node: &LinkedNode, found: &mut Vec<FoundListItem>, list_count: &mut usize) {
+    // The kind of item the current list is made of and its identifier, if there is one.
+    let mut current: Option<(SyntaxKind, usize)> = None;
+
+    for child in node.children() {
+        let kind = child.kind();
+
+        match kind {
+            SyntaxKind::ListItem | SyntaxKind::EnumItem => {
+                let list = match current {
+                    Some((current_kind, list)) if current_kind == kind => list,
+                    _ => {
+                        *list_count += 1;
+                        *list_count - 1
+                    }
+                };
+                current = Some((kind, list));
+
+                if let Some(body) = child.children().find(|c| c.kind() == SyntaxKind::Markup) {
+                    // The item's own text stops where a nested list begins.
+                    let text: Vec<_> = body
+                        .children()
+                        .take_while(|c| {
+                            !matches!(c.kind(), SyntaxKind::ListItem | SyntaxKind::EnumItem)
+                        })
+                        .filter(|c| !matches!(c.kind(), SyntaxKind::Space | SyntaxKind::Parbreak))
+                        .collect();
+
+                    // Items written as `+` are numbered automatically, so only explicit numbers
+                    // are reported.
+                    let number = child
+                        .children()
+                        .find(|c| c.kind() == SyntaxKind::EnumMarker)
+                        .and_then(|marker| {
+                            let digits = marker.text().trim_end_matches('.');
+                            let value = digits.parse().ok()?;
+                            let start = marker.range().start;
+
+                            Some((value, start..start + digits.len()))
+                        });
+
+                    if let (Some(first), Some(last)) = (text.first(), text.last()) {
+                        found.push(FoundListItem {
+                            text: first.range().start..last.range().end,
+                            list,
+                            number,
+                        });
+                    }
+
+                    collect_list_items(&body, found, list_count);
+                }
+            }
+            SyntaxKind::Space | SyntaxKind::Parbreak => (),
+            _ => {
+                current = None;
+                collect_list_items(&child, found, list_count);
+            }
+        }
+    }
+}
+
+/// Collect the visible text of a piece of Typst markup.
+fn collect_text(node: &SyntaxNode, text: &mut String) {
+    match node.kind() {
+        SyntaxKind::Text
+        | SyntaxKind::Space
+        | SyntaxKind::Shorthand
+        | SyntaxKind::Escape
+        | SyntaxKind::SmartQuote => text.push_str(node.text()),
+        SyntaxKind::Raw => {
+            for child in node.children() {
+                if child.kind() == SyntaxKind::Text {
+                    text.push_str(child.text());
+                }
+            }
+        }
+        _ => {
+            for child in node.children() {
+                collect_text(child, text);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -39,7 +299,22 @@ mod tests {
     use ordered_float::OrderedFloat;
 
     use super::Typst;
-    use harper_core::{Document, NounData, Number, Punctuation, TokenKind, WordMetadata};
+    use harper_core::parsers::Parser;
+    use harper_core::{Document, NounData, Number, Outliner, Punctuation, TokenKind, WordMetadata};
+
+    #[test]
+    fn passes_over_the_same_text_share_a_parse() {
+        let source: Vec<char> = "= Title\n\n- An item.".chars().collect();
+        let other: Vec<char> = "Other text.".chars().collect();
+
+        let first = super::detach(&source);
+        let second = super::detach(&source);
+        assert!(std::ptr::eq(first.root(), second.root()));
+
+        let third = super::detach(&other);
+        assert!(!std::ptr::eq(first.root(), third.root()));
+        assert_eq!(third.text(), "Other text.");
+    }
 
     #[test]
     fn number() {
@@ -309,4 +584,93 @@ mod tests {
             ]
         ));
     }
+
+    #[test]
+    fn extracts_outline() {
+        let source: Vec<_> = "= Introduction\nSome text.\n\n== The *Bold* Part\n#[\n=== Nested]\n"
+            .chars()
+            .collect();
+
+        let outline = Typst.outline(&source);
+
+        assert_eq!(outline.len(), 3);
+        assert_eq!(outline[0].text, "Introduction");
+        assert_eq!(outline[0].level, 1);
+        assert_eq!(
+            outline[0].span.get_content_string(&source),
+            "= Introduction"
+        );
+        assert_eq!(outline[1].text, "The Bold Part");
+        assert_eq!(outline[1].level, 2);
+        assert_eq!(outline[2].text, "Nested");
+        assert_eq!(outline[2].level, 3);
+    }
+
+    #[test]
+    fn extracts_list_items() {
+        let source: Vec<_> = "- Apples\n- *Oranges*.\n  + Navel\n+ One\n\nText.\n\n- Pears\n"
+            .chars()
+            .collect();
+
+        let items = Typst.list_items(&source);
+        let texts = items
+            .iter()
+            .map(|item| (item.span.get_content_string(&source), item.list))
+            .collect_vec();
+
+        assert_eq!(
+            texts,
+            [
+                ("Apples".to_string(), 0),
+                ("*Oranges*.".to_string(), 0),
+                ("Navel".to_string(), 1),
+                ("One".to_string(), 2),
+                ("Pears".to_string(), 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_written_list_numbers() {
+        let source: Vec<_> = "1. One\n3. Three\n+ Four\n".chars().collect();
+
+        let numbers = Typst
+            .list_items(&source)
+            .iter()
+            .map(|item| {
+                item.number
+                    .map(|n| (n.value, n.span.get_content_string(&source)))
+            })
+            .collect_vec();
+
+        assert_eq!(
+            numbers,
+            [Some((1, "1".to_string())), Some((3, "3".to_string())), None]
+        );
+    }
+
+    #[test]
+    fn extracts_comments() {
+        let source: Vec<_> = "// harper: disable
+Some `harper: enable` text.
+#harper.ignore SpellCheck
+/* block */ after"
+            .chars()
+            .collect();
+
+        let comments = Typst
+            .comments(&source)
+            .iter()
+            .map(|span| span.get_content_string(&source))
+            .collect_vec();
+
+        assert_eq!(
+            comments,
+            [
+                "// harper: disable",
+                "harper.ignore SpellCheck",
+                "/* block */"
+            ]
+        );
+    }
 }
@@ -4,16 +4,21 @@ use std::convert::Into;
 use std::sync::Arc;
 
 use harper_core::language_detection::is_doc_likely_english;
-use harper_core::linting::{LintGroup, Linter as _};
+use harper_core::linting::{LintGroup, Linter as _, SpellCheck};
 use harper_core::parsers::{IsolateEnglish, Markdown, Parser, PlainEnglish};
+use harper_core::spell::hunspell;
 use harper_core::{
-    CharString, Dictionary, Document, FstDictionary, IgnoredLints, Lrc, MergedDictionary,
-    MutableDictionary, WordMetadata, remove_overlaps,
+    CharString, Dictionary, Document, FstDictionary, HarperConfig, IgnoredLints, LayeredDictionary,
+    LineIndex, Lrc, MutableDictionary, PositionEncoding, WordMetadata, remove_overlaps,
 };
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 
+mod session;
+
+pub use session::Session;
+
 /// Setup the WebAssembly module's logging.
 ///
 ///
@@ -69,8 +74,17 @@ pub struct Linter {
     ///
     /// To make changes affect linting, run [`Self::synchronize_lint_dict`].
     user_dictionary: MutableDictionary,
-    dictionary: Arc<MergedDictionary>,
+    /// The dictionary underneath [`Self::user_dictionary`].
+    /// Usually the curated dictionary, unless replaced by [`Self::load_dictionary`].
+    base_dictionary: Arc<dyn Dictionary>,
+    /// Whether [`Self::base_dictionary`] is still the empty placeholder from
+    /// [`Self::new_deferred`].
+    /// Spell checking is skipped until a real dictionary is loaded.
+    awaiting_dictionary: bool,
+    dictionary: Arc<LayeredDictionary>,
     ignored_lints: IgnoredLints,
+    /// Settings from [`Self::set_config_from_toml`].
+    harper_config: HarperConfig,
 }
 
 #[wasm_bindgen]
@@ -78,36 +92,172 @@ impl Linter {
     /// Construct a new `Linter`.
     /// Note that this can mean constructing the curated dictionary, which is the most expensive operation
     /// in Harper.
+    ///
+    /// In a build without the `embedded-dictionary` feature, this is the same as
+    /// [`Self::new_deferred`] until [`Self::load_curated_word_list`] has been called.
     pub fn new() -> Self {
-        let dictionary = Self::construct_merged_dict(MutableDictionary::default());
+        if hunspell::default_word_list().is_none() {
+            return Self::new_deferred();
+        }
+
+        Self::with_base_dictionary(FstDictionary::curated(), false)
+    }
+
+    /// Construct a new `Linter` without building the curated dictionary, so it is ready sooner.
+    ///
+    /// Until [`Self::load_curated_dictionary`] or [`Self::load_dictionary`] is called, the linter
+    /// knows no words: spell checking is skipped and rules that depend on the dictionary find
+    /// less.
+    pub fn new_deferred() -> Self {
+        Self::with_base_dictionary(Arc::new(MutableDictionary::new()), true)
+    }
+
+    fn with_base_dictionary(
+        base_dictionary: Arc<dyn Dictionary>,
+        awaiting_dictionary: bool,
+    ) -> Self {
+        let dictionary =
+            Self::construct_layered_dict(base_dictionary.clone(), MutableDictionary::default());
         let lint_group = LintGroup::new_curated_empty_config(dictionary.clone());
 
         Self {
             lint_group,
             user_dictionary: MutableDictionary::new(),
+            base_dictionary,
+            awaiting_dictionary,
             dictionary,
             ignored_lints: IgnoredLints::default(),
+            harper_config: HarperConfig::default(),
         }
     }
 
+    /// Whether the linter was created with [`Self::new_deferred`] and is still waiting for its
+    /// dictionary.
+    pub fn is_awaiting_dictionary(&self) -> bool {
+        self.awaiting_dictionary
+    }
+
     /// Update the dictionary inside [`Self::lint_group`] to include [`Self::user_dictionary`].
     /// This clears any linter caches, so use it sparingly.
     fn synchronize_lint_dict(&mut self) {
-        let mut lint_config = self.lint_group.config.clone();
-        self.dictionary = Self::construct_merged_dict(self.user_dictionary.clone());
-        self.lint_group = LintGroup::new_curated_empty_config(self.dictionary.clone());
-        self.lint_group.config.merge_from(&mut lint_config);
+        self.dictionary = Self::construct_layered_dict(
+            self.base_dictionary.clone(),
+            self.user_dictionary.clone(),
+        );
+        self.lint_group = self.construct_lint_group();
+    }
+
+    /// Construct a new [`LintGroup`] with the same dictionary and configuration as
+    /// [`Self::lint_group`].
+    fn construct_lint_group(&self) -> LintGroup {
+        let mut lint_group = LintGroup::new_curated_empty_config(self.dictionary.clone())
+            .with_dialect(self.lint_group.dialect())
+            .with_spell_check(
+                self.harper_config
+                    .configure_spell_check(SpellCheck::new(self.dictionary.clone())),
+            );
+        lint_group
+            .config
+            .merge_from(&mut self.lint_group.config.clone());
+        lint_group
+            .severity_config
+            .merge_from(&mut self.lint_group.severity_config.clone());
+        lint_group
+    }
+
+    /// Apply the settings from the contents of a `harper.toml` file.
+    ///
+    /// The file's `dictionaries` are ignored, since there is no file system to read them from.
+    /// Use [`Self::import_words`] instead.
+    pub fn set_config_from_toml(&mut self, toml: String) -> Result<(), String> {
+        let harper_config = HarperConfig::from_toml_str(&toml).map_err(|err| err.to_string())?;
+
+        harper_config.configure_lint_group(&mut self.lint_group);
+        self.lint_group = std::mem::take(&mut self.lint_group).with_spell_check(
+            harper_config.configure_spell_check(SpellCheck::new(self.dictionary.clone())),
+        );
+        self.harper_config = harper_config;
+
+        Ok(())
     }
 
-    /// Construct the actual dictionary to be used for linting and parsing from the curated dictionary
-    /// and [`Self::user_dictionary`].
-    fn construct_merged_dict(user_dictionary: MutableDictionary) -> Arc<MergedDictionary> {
-        let mut lint_dict = MergedDictionary::new();
+    /// Construct the actual dictionary to be used for linting and parsing from
+    /// [`Self::base_dictionary`] and [`Self::user_dictionary`].
+    fn construct_layered_dict(
+        base_dictionary: Arc<dyn Dictionary>,
+        user_dictionary: MutableDictionary,
+    ) -> Arc<LayeredDictionary> {
+        Arc::new(
+            LayeredDictionary::new()
+                .with_dictionary(base_dictionary)
+                .with_dictionary(Arc::new(user_dictionary)),
+        )
+    }
 
-        lint_dict.add_dictionary(FstDictionary::curated());
-        lint_dict.add_dictionary(Arc::new(user_dictionary.clone()));
+    /// Replace the curated dictionary used for spell checking with one loaded at runtime, such as
+    /// a newer or customized copy fetched from a URL.
+    ///
+    /// The word list and attribute list use the same formats as Harper's `dictionary.dict` and
+    /// `affixes.json`.
+    /// Words added through [`Self::import_words`] are kept.
+    pub fn load_dictionary(
+        &mut self,
+        word_list: String,
+        attribute_list: String,
+    ) -> Result<(), String> {
+        let dictionary = FstDictionary::from_word_list(&word_list, &attribute_list)
+            .map_err(|err| err.to_string())?;
+
+        self.replace_base_dictionary(Arc::new(dictionary));
 
-        Arc::new(lint_dict)
+        Ok(())
+    }
+
+    /// Load the curated dictionary included with Harper, for a linter created with
+    /// [`Self::new_deferred`].
+    ///
+    /// This is the most expensive operation in Harper, so integrations may want to call it once
+    /// the page is idle.
+    pub fn load_curated_dictionary(&mut self) {
+        // Without the `embedded-dictionary` feature, there is nothing to load until the word
+        // list has been provided.
+        if hunspell::default_word_list().is_none() {
+            return;
+        }
+
+        self.replace_base_dictionary(FstDictionary::curated());
+    }
+
+    /// Provide Harper's curated word list (`dictionary.dict`), for a build without the
+    /// `embedded-dictionary` feature, and load it like [`Self::load_curated_dictionary`].
+    ///
+    /// The word list is shared by every linter, so it only needs to be provided once. Other
+    /// linters pick it up with [`Self::load_curated_dictionary`].
+    #[cfg(not(feature = "embedded-dictionary"))]
+    pub fn load_curated_word_list(&mut self, word_list: String) -> Result<(), String> {
+        if hunspell::default_word_list().is_none() {
+            hunspell::install_default_word_list(word_list).map_err(|err| err.to_string())?;
+        }
+
+        self.load_curated_dictionary();
+
+        Ok(())
+    }
+
+    fn replace_base_dictionary(&mut self, dictionary: Arc<dyn Dictionary>) {
+        self.base_dictionary = dictionary;
+        self.awaiting_dictionary = false;
+        self.synchronize_lint_dict();
+    }
+
+    /// Enable the curated rules that have not been configured, leaving out the ones that can't
+    /// work yet.
+    fn fill_config_with_curated(&self, lint_group: &mut LintGroup) {
+        lint_group.config.fill_with_curated();
+
+        if self.awaiting_dictionary {
+            lint_group.config.set_rule_enabled("SpellCheck", false);
+        }
     }
 
     /// Helper method to quickly check if a plain string is likely intended to be English
@@ -152,6 +302,12 @@ impl Linter {
             .unwrap()
     }
 
+    /// Get a JSON array describing each linting rule, including its kind, default state and
+    /// examples.
+    pub fn get_rule_descriptions_as_json(&self) -> String {
+        serde_json::to_string(&self.lint_group.rule_descriptions()).unwrap()
+    }
+
     pub fn get_lint_config_as_object(&self) -> JsValue {
         // Important for downstream JSON serialization
         let serializer = serde_wasm_bindgen::Serializer::json_compatible();
@@ -176,31 +332,80 @@ impl Linter {
         self.ignored_lints.ignore_lint(&lint.inner, &document);
     }
 
+    /// Start a [`Session`] for a document with the provided text.
+    ///
+    /// The session takes a snapshot of the linter's current configuration, dictionary and ignored
+    /// lints. Later changes to the linter do not affect it.
+    pub fn create_session(&self, text: String, language: Language) -> Session {
+        let mut lint_group = self.construct_lint_group();
+        self.fill_config_with_curated(&mut lint_group);
+
+        Session::new(
+            &text,
+            language,
+            lint_group,
+            self.dictionary.clone(),
+            self.ignored_lints.clone(),
+        )
+    }
+
     /// Perform the configured linting on the provided text.
     pub fn lint(&mut self, text: String, language: Language) -> Vec<Lint> {
+        let (source, lints) = self.lint_source(&text, language);
+
+        lints
+            .into_iter()
+            .map(|l| Lint::new(l, source.to_vec(), language))
+            .collect()
+    }
+
+    /// Perform the configured linting on the provided text, returning an array of plain objects
+    /// instead of [`Lint`] instances.
+    ///
+    /// Each object contains the rule that produced it, its kind, severity, message and problem
+    /// text, along with `start` and `end` offsets and a list of suggestions, each described as
+    /// the edits it would make.
+    /// All offsets are in UTF-16 code units, so they can be used to index JavaScript strings
+    /// directly, or passed to editors like CodeMirror or Monaco.
+    pub fn lint_as_objects(&mut self, text: String, language: Language) -> JsValue {
+        let (source, lints) = self.lint_source(&text, language);
+        let line_index = LineIndex::new(&source);
+
+        let objects: Vec<_> = lints
+            .iter()
+            .map(|lint| LintObject::new(lint, &source, &line_index))
+            .collect();
+
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        objects.serialize(&serializer).unwrap()
+    }
+
+    fn lint_source(
+        &mut self,
+        text: &str,
+        language: Language,
+    ) -> (Lrc<Vec<char>>, Vec<harper_core::linting::Lint>) {
         let source: Vec<_> = text.chars().collect();
         let source = Lrc::new(source);
 
         let parser = language.create_parser();
 
-        let document =
-            Document::new_from_vec(source.clone(), &parser, &MutableDictionary::curated());
+        let document = Document::new_from_vec(source.clone(), &parser, &self.dictionary);
 
         let temp = self.lint_group.config.clone();
-        self.lint_group.config.fill_with_curated();
+        let mut lint_group = std::mem::take(&mut self.lint_group);
+        self.fill_config_with_curated(&mut lint_group);
 
-        let mut lints = self.lint_group.lint(&document);
+        let mut lints = lint_group.lint(&document);
 
-        self.lint_group.config = temp;
+        lint_group.config = temp;
+        self.lint_group = lint_group;
 
         remove_overlaps(&mut lints);
 
         self.ignored_lints.remove_ignored(&mut lints, &document);
 
-        lints
-            .into_iter()
-            .map(|l| Lint::new(l, source.to_vec(), language))
-            .collect()
+        (source, lints)
     }
 
     /// Export the linter's ignored lints as a privacy-respecting JSON list of hashes.
@@ -239,6 +444,20 @@ impl Linter {
         }
     }
 
+    /// Import the words from a user dictionary: a plain-text list with one word per line, like the
+    /// ones used by `harper-ls`.
+    /// Blank lines are ignored.
+    pub fn import_word_list(&mut self, word_list: String) {
+        self.import_words(
+            word_list
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect(),
+        );
+    }
+
     /// Export words from the dictionary.
     /// Note: this will only return words previously added by [`Self::import_words`].
     pub fn export_words(&mut self) -> Vec<String> {
@@ -291,6 +510,10 @@ pub enum SuggestionKind {
     Remove = 1,
     /// Insert additional text after the error.
     InsertAfter = 2,
+    /// Insert additional text before the error.
+    InsertBefore = 3,
+    /// Make several independent edits. See [`Suggestion::edits`].
+    Edits = 4,
 }
 
 #[wasm_bindgen]
@@ -300,23 +523,62 @@ impl Suggestion {
     }
 
     /// Get the text that is going to replace the problematic section.
-    /// If [`Self::kind`] is `SuggestionKind::Remove`, this will return an empty
-    /// string.
+    /// If [`Self::kind`] is `SuggestionKind::Remove` or `SuggestionKind::Edits`, this will return
+    /// an empty string.
     pub fn get_replacement_text(&self) -> String {
-        match &self.inner {
-            harper_core::linting::Suggestion::Remove => "".to_string(),
-            harper_core::linting::Suggestion::ReplaceWith(chars) => chars.iter().collect(),
-            harper_core::linting::Suggestion::InsertAfter(chars) => chars.iter().collect(),
+        match &self.inner.kind {
+            harper_core::linting::SuggestionKind::Remove => "".to_string(),
+            harper_core::linting::SuggestionKind::ReplaceWith(chars) => chars.iter().collect(),
+            harper_core::linting::SuggestionKind::InsertBefore(chars) => chars.iter().collect(),
+            harper_core::linting::SuggestionKind::InsertAfter(chars) => chars.iter().collect(),
+            harper_core::linting::SuggestionKind::Edits(_) => "".to_string(),
         }
     }
 
     pub fn kind(&self) -> SuggestionKind {
-        match &self.inner {
-            harper_core::linting::Suggestion::Remove => SuggestionKind::Remove,
-            harper_core::linting::Suggestion::ReplaceWith(_) => SuggestionKind::Replace,
-            harper_core::linting::Suggestion::InsertAfter(_) => SuggestionKind::InsertAfter,
+        match &self.inner.kind {
+            harper_core::linting::SuggestionKind::Remove => SuggestionKind::Remove,
+            harper_core::linting::SuggestionKind::ReplaceWith(_) => SuggestionKind::Replace,
+            harper_core::linting::SuggestionKind::InsertBefore(_) => SuggestionKind::InsertBefore,
+            harper_core::linting::SuggestionKind::InsertAfter(_) => SuggestionKind::InsertAfter,
+            harper_core::linting::SuggestionKind::Edits(_) => SuggestionKind::Edits,
         }
     }
+
+    /// Get the individual changes this suggestion would make, given the span of the lint it
+    /// belongs to.
+    /// Works for every kind of suggestion, not just `SuggestionKind::Edits`.
+    pub fn edits(&self, span: Span) -> Vec<Edit> {
+        self.inner
+            .to_edits(span.into())
+            .into_iter()
+            .map(|edit| Edit {
+                span: edit.span.into(),
+                replacement: edit.replacement.iter().collect(),
+            })
+            .collect()
+    }
+}
+
+/// A single change to a document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[wasm_bindgen]
+pub struct Edit {
+    span: Span,
+    replacement: String,
+}
+
+#[wasm_bindgen]
+impl Edit {
+    /// The location of the text to be replaced.
+    /// If empty, the replacement should be inserted at its start.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn get_replacement_text(&self) -> String {
+        self.replacement.clone()
+    }
 }
 
 /// An error found in provided text.
@@ -359,6 +621,11 @@ impl Lint {
         self.inner.lint_kind.to_string()
     }
 
+    /// Get a string representing the configured severity of the lint, if there is one.
+    pub fn severity(&self) -> Option<String> {
+        self.inner.severity.map(|s| s.to_string_key())
+    }
+
     /// Equivalent to calling `.length` on the result of `suggestions()`.
     pub fn suggestion_count(&self) -> usize {
         self.inner.suggestions.len()
@@ -384,6 +651,77 @@ impl Lint {
     }
 }
 
+/// A [`Lint`] as a plain JavaScript object, returned by [`Linter::lint_as_objects`].
+///
+/// Offsets are in UTF-16 code units.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LintObject {
+    rule: Option<String>,
+    kind: String,
+    severity: Option<String>,
+    message: String,
+    problem_text: String,
+    start: usize,
+    end: usize,
+    suggestions: Vec<SuggestionObject>,
+}
+
+impl LintObject {
+    fn new(lint: &harper_core::linting::Lint, source: &[char], line_index: &LineIndex) -> Self {
+        Self {
+            rule: lint.rule.clone(),
+            kind: lint.lint_kind.to_string_key(),
+            severity: lint.severity.map(|s| s.to_string_key()),
+            message: lint.message.clone(),
+            problem_text: lint.span.get_content_string(source),
+            start: line_index.offset(lint.span.start, PositionEncoding::Utf16),
+            end: line_index.offset(lint.span.end, PositionEncoding::Utf16),
+            suggestions: lint
+                .suggestions
+                .iter()
+                .map(|suggestion| SuggestionObject::new(suggestion, lint.span, line_index))
+                .collect(),
+        }
+    }
+}
+
+/// A [`Suggestion`] as a plain JavaScript object, described by the edits it would make.
+#[derive(Debug, Serialize)]
+struct SuggestionObject {
+    kind: SuggestionKind,
+    edits: Vec<EditObject>,
+}
+
+impl SuggestionObject {
+    fn new(
+        suggestion: &harper_core::linting::Suggestion,
+        span: harper_core::Span,
+        line_index: &LineIndex,
+    ) -> Self {
+        Self {
+            kind: Suggestion::new(suggestion.clone()).kind(),
+            edits: suggestion
+                .to_edits(span)
+                .into_iter()
+                .map(|edit| EditObject {
+                    start: line_index.offset(edit.span.start, PositionEncoding::Utf16),
+                    end: line_index.offset(edit.span.end, PositionEncoding::Utf16),
+                    replacement: edit.replacement.iter().collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An [`Edit`] as a plain JavaScript object.
+#[derive(Debug, Serialize)]
+struct EditObject {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
 #[wasm_bindgen]
 pub fn get_default_lint_config_as_json() -> String {
     let config = LintGroup::new_curated(MutableDictionary::new().into()).config;
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use harper_core::linting::{Lint, LintGroup, LintSession};
+use harper_core::parsers::Parser;
+use harper_core::{IgnoredLints, LayeredDictionary, LineIndex, PositionEncoding, Span};
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{Language, LintObject};
+
+/// A document that is kept in sync with an editor through [`Self::apply_edit`].
+///
+/// Rather than re-parsing the whole document on every keystroke, only the paragraphs around each
+/// edit are parsed again. Each lint is given an ID when it is first reported, and edits only
+/// report the lints that were added or removed, so editors can leave the rest of their
+/// decorations alone.
+///
+/// Create one with [`Linter::create_session`](crate::Linter::create_session).
+#[wasm_bindgen]
+pub struct Session {
+    inner: LintSession<Box<dyn Parser>, Arc<LayeredDictionary>, LintGroup>,
+    language: Language,
+}
+
+impl Session {
+    pub(crate) fn new(
+        text: &str,
+        language: Language,
+        lint_group: LintGroup,
+        dictionary: Arc<LayeredDictionary>,
+        ignored_lints: IgnoredLints,
+    ) -> Self {
+        let inner = LintSession::new(text, language.create_parser(), dictionary, lint_group)
+            .with_ignored_lints(ignored_lints);
+
+        Self { inner, language }
+    }
+
+    fn to_objects<'a>(&self, lints: impl IntoIterator<Item = &'a (u32, Lint)>) -> Vec<SessionLint> {
+        let source = self.inner.document().get_source();
+        let line_index = LineIndex::new(source);
+
+        lints
+            .into_iter()
+            .map(|(id, lint)| SessionLint {
+                id: *id,
+                lint: LintObject::new(lint, source, &line_index),
+            })
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+impl Session {
+    /// Get the current text of the document.
+    pub fn get_text(&self) -> String {
+        self.inner.document().get_full_string()
+    }
+
+    /// Get the language the document is parsed as.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Get every current lint, in the same form as
+    /// [`Linter::lint_as_objects`](crate::Linter::lint_as_objects), with an additional `id`.
+    pub fn lints(&self) -> JsValue {
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        self.to_objects(self.inner.lints())
+            .serialize(&serializer)
+            .unwrap()
+    }
+
+    /// Replace the text between `start` and `end` (UTF-16 offsets, as used by JavaScript strings)
+    /// with `text`.
+    ///
+    /// Returns an object with two fields: `added`, the lints that are new since the last edit
+    /// (with offsets into the updated text), and `removed`, the IDs of the lints that no longer
+    /// apply.
+    /// Lints that were not affected by the edit keep their IDs and are not reported.
+    pub fn apply_edit(&mut self, start: usize, end: usize, text: String) -> JsValue {
+        let line_index = LineIndex::new(self.inner.document().get_source());
+        let start = line_index.index_at_offset(start, PositionEncoding::Utf16);
+        let end = line_index
+            .index_at_offset(end, PositionEncoding::Utf16)
+            .max(start);
+
+        let new_text: Vec<char> = text.chars().collect();
+        let changes = self.inner.apply_edit(Span::new(start, end), &new_text);
+
+        let changes = SessionChanges {
+            added: self.to_objects(
+                self.inner
+                    .lints()
+                    .iter()
+                    .filter(|(id, _)| changes.added.contains(id)),
+            ),
+            removed: changes.removed,
+        };
+
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        changes.serialize(&serializer).unwrap()
+    }
+}
+
+/// A [`LintObject`] along with the ID it was given by a [`Session`].
+#[derive(Debug, Serialize)]
+struct SessionLint {
+    id: u32,
+    #[serde(flatten)]
+    lint: LintObject,
+}
+
+/// The lints that were added and removed by [`Session::apply_edit`].
+#[derive(Debug, Serialize)]
+struct SessionChanges {
+    added: Vec<SessionLint>,
+    removed: Vec<u32>,
+}
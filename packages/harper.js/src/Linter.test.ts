@@ -54,6 +54,20 @@ for (const [linterName, Linter] of Object.entries(linters)) {
 		expect(results[2].length).toBe(1);
 	});
 
+	test(`${linterName} returns lints as objects with UTF-16 offsets`, async () => {
+		const linter = new Linter();
+
+		const text = '😀 The the problem is...';
+		const lints = await linter.lintAsObjects(text);
+
+		expect(lints.length).toBe(1);
+
+		const lint = lints[0];
+		expect(lint.rule).toBe('RepeatedWords');
+		expect(text.slice(lint.start, lint.end)).toBe(lint.problemText);
+		expect(lint.suggestions[0].edits.length).toBeGreaterThan(0);
+	});
+
 	test(`${linterName} detects lorem ipsum paragraph as not english`, async () => {
 		const linter = new Linter();
 
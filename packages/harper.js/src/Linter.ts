@@ -1,5 +1,5 @@
 import type { Lint, Span, Suggestion } from 'wasm';
-import { LintConfig, LintOptions } from './main';
+import { LintConfig, LintObject, LintOptions } from './main';
 
 /** An interface for an object that can perform linting actions. */
 export default interface Linter {
@@ -11,6 +11,10 @@ export default interface Linter {
 	/** Lint the provided text. */
 	lint(text: string, options?: LintOptions): Promise<Lint[]>;
 
+	/** Lint the provided text, returning plain objects with UTF-16 offsets instead of `Lint` instances.
+	 * Useful for handing lints directly to an editor's decorations. */
+	lintAsObjects(text: string, options?: LintOptions): Promise<LintObject[]>;
+
 	/** Apply a suggestion to the given text, returning the transformed result. */
 	applySuggestion(text: string, suggestion: Suggestion, span: Span): Promise<string>;
 
@@ -2,7 +2,7 @@ import type { Lint, Span, Suggestion, Linter as WasmLinter } from 'wasm';
 import { Language } from 'wasm';
 import Linter from './Linter';
 import loadWasm from './loadWasm';
-import { LintConfig, LintOptions } from './main';
+import { LintConfig, LintObject, LintOptions } from './main';
 
 /** A Linter that runs in the current JavaScript context (meaning it is allowed to block the event loop).  */
 export default class LocalLinter implements Linter {
@@ -35,6 +35,14 @@ export default class LocalLinter implements Linter {
 		return lints;
 	}
 
+	async lintAsObjects(text: string, options?: LintOptions): Promise<LintObject[]> {
+		await this.initialize();
+		return this.inner!.lint_as_objects(
+			text,
+			options?.language === 'plaintext' ? Language.Plain : Language.Markdown
+		);
+	}
+
 	async applySuggestion(text: string, suggestion: Suggestion, span: Span): Promise<string> {
 		const wasm = await loadWasm();
 		return wasm.apply_suggestion(text, span, suggestion);
@@ -39,6 +39,14 @@ test('works with numbers', async () => {
 	expect(typeof end).toBe(typeof start);
 });
 
+test('works with plain objects', async () => {
+	const start = { start: 4, end: 7, replacement: 'the' };
+
+	const end = await deserializeArg(structuredClone(await serializeArg(start)));
+
+	expect(end).toStrictEqual(start);
+});
+
 test('works with Spans', async () => {
 	const start = Span.new(123, 321);
 
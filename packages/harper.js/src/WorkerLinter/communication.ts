@@ -11,6 +11,7 @@ export type Type =
 	| 'Lint'
 	| 'Span'
 	| 'Array'
+	| 'object'
 	| 'undefined';
 
 /** Serializable argument to a procedure to be run on the web worker. */
@@ -61,6 +62,11 @@ export async function serializeArg(arg: any): Promise<RequestArg> {
 		return { json, type };
 	}
 
+	// Plain objects, like `LintOptions` or the results of `lintAsObjects`.
+	if (Object.getPrototypeOf(arg) === Object.prototype) {
+		return { json: JSON.stringify(arg), type: 'object' };
+	}
+
 	throw new Error('Unhandled case');
 }
 
@@ -73,6 +79,7 @@ export async function deserializeArg(requestArg: RequestArg): Promise<any> {
 		case 'boolean':
 		case 'number':
 		case 'string':
+		case 'object':
 			return JSON.parse(requestArg.json);
 		case 'Suggestion':
 			return Suggestion.from_json(requestArg.json);
@@ -3,7 +3,7 @@ import type { Lint, Suggestion, Span } from 'wasm';
 import Linter from '../Linter';
 import Worker from './worker.js?worker&inline';
 import { getWasmUri } from '../loadWasm';
-import { LintConfig, LintOptions } from '../main';
+import { LintConfig, LintObject, LintOptions } from '../main';
 
 /** The data necessary to complete a request once the worker has responded. */
 type RequestItem = {
@@ -65,6 +65,10 @@ export default class WorkerLinter implements Linter {
 		return this.rpc('lint', [text, options]);
 	}
 
+	lintAsObjects(text: string, options?: LintOptions): Promise<LintObject[]> {
+		return this.rpc('lintAsObjects', [text, options]);
+	}
+
 	applySuggestion(text: string, suggestion: Suggestion, span: Span): Promise<string> {
 		return this.rpc('applySuggestion', [text, suggestion, span]);
 	}
@@ -11,6 +11,35 @@ export type { Linter, Lint, Span, Suggestion };
  * This is a record, since you shouldn't hard-code the existence of any particular rules and should generalize based on this struct. */
 export type LintConfig = Record<string, boolean | undefined>;
 
+/** A single change to a string, as part of a `SuggestionObject`.
+ * Offsets are in UTF-16 code units, the same unit used to index JavaScript strings.
+ * If `start` and `end` are equal, `replacement` should be inserted at `start`. */
+export type EditObject = {
+	start: number;
+	end: number;
+	replacement: string;
+};
+
+/** A suggestion to fix a `LintObject`, described by the edits it would make. */
+export type SuggestionObject = {
+	kind: keyof typeof SuggestionKind;
+	edits: EditObject[];
+};
+
+/** A lint as a plain object, as returned by `Linter.lintAsObjects`.
+ * Offsets are in UTF-16 code units, so they can be passed directly to editors like CodeMirror or Monaco. */
+export type LintObject = {
+	/** The name of the rule that produced the lint, as used in `LintConfig`. */
+	rule: string | null;
+	kind: string;
+	severity: string | null;
+	message: string;
+	problemText: string;
+	start: number;
+	end: number;
+	suggestions: SuggestionObject[];
+};
+
 /** The option used to configure the parser for an individual linting operation. */
 export type LintOptions = {
 	/** The markup language that is being passed. Defaults to `markdown`. */
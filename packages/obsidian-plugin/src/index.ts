@@ -14,6 +14,10 @@ function suggestionToLabel(sug: Suggestion) {
 		return `Replace with “${sug.get_replacement_text()}”`;
 	} else if (sug.kind() == SuggestionKind.InsertAfter) {
 		return `Insert “${sug.get_replacement_text()}” after this.`;
+	} else if (sug.kind() == SuggestionKind.InsertBefore) {
+		return `Insert “${sug.get_replacement_text()}” before this.`;
+	} else {
+		return 'Apply fix';
 	}
 }
 
@@ -212,6 +216,18 @@ export default class HarperPlugin extends Plugin {
 											insert: sug.get_replacement_text()
 										}
 									});
+								} else {
+									view.dispatch({
+										changes: sug.edits(lint.span()).map((edit) => {
+											const editSpan = edit.span();
+
+											return {
+												from: charIndexToCodePointIndex(editSpan.start, chars),
+												to: charIndexToCodePointIndex(editSpan.end, chars),
+												insert: edit.get_replacement_text()
+											};
+										})
+									});
 								}
 							}
 						};
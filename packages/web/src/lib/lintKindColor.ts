@@ -16,6 +16,8 @@ export default function lintKindColor(lintKindKey: string): string {
 			return '#0EAD69';
 		case 'WordChoice':
 			return '#0EAD69';
+		case 'Forbidden':
+			return '#EE4266';
 		case 'Miscellaneous':
 			return '#3BCEAC';
 		default: